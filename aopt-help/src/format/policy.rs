@@ -78,7 +78,7 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
 
         for store in item.iter() {
             if let Some(store) = stores.iter().find(|v| &v.name() == store) {
-                let hint = store.hint();
+                let hint = store.usage();
 
                 if !hint.is_empty() {
                     if store.position() {
@@ -321,7 +321,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
 
         for store in item.iter() {
             if let Some(store) = stores.iter().find(|v| &v.name() == store) {
-                let hint = store.hint();
+                let hint = store.usage();
 
                 if !hint.is_empty() {
                     if store.position() {
@@ -382,6 +382,16 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         let command_usage = if app.has_cmd() { "<COMMAND>" } else { "" };
         let args = args.join(" ");
 
+        if let Some(usage) = app.usage() {
+            let args = if self.hiding_pos { &block_hint } else { &args };
+
+            return usage
+                .replace("{name}", &global.name())
+                .replace("{options}", &global_usage)
+                .replace("{command}", command_usage)
+                .replace("{args}", args)
+                .into();
+        }
         if !global.name().is_empty() {
             ret += &global.name();
             ret += " ";
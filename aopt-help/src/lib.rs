@@ -50,6 +50,8 @@ pub struct AppHelp<'a, W> {
     wrap_max_width: usize,
 
     usage_new_line: usize,
+
+    usage: Option<Cow<'a, str>>,
 }
 
 impl Default for AppHelp<'_, Stdout> {
@@ -62,6 +64,7 @@ impl Default for AppHelp<'_, Stdout> {
             global: 0,
             wrap_max_width: 0,
             usage_new_line: 0,
+            usage: None,
         }
     }
 }
@@ -84,6 +87,7 @@ impl<'a, W: Write> AppHelp<'a, W> {
             global: 0,
             wrap_max_width: max_width,
             usage_new_line,
+            usage: None,
         }
         .with_global(name, head, foot)
     }
@@ -125,6 +129,11 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self.usage_new_line
     }
 
+    /// The usage template override the generated usage line.
+    pub fn usage(&self) -> Option<&Cow<'a, str>> {
+        self.usage.as_ref()
+    }
+
     pub fn global(&self) -> &Command<'a> {
         &self.cmds[self.global]
     }
@@ -203,6 +212,15 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self
     }
 
+    /// Override the generated usage line with given template.
+    ///
+    /// The placeholders `{name}`, `{options}`, `{command}` and `{args}` in template
+    /// will be replaced with the name of app and the generated part of usage.
+    pub fn with_usage<S: Into<Cow<'a, str>>>(mut self, usage: S) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
     pub fn set_name<S: Into<Cow<'a, str>>>(&mut self, name: S) -> &mut Self {
         self.global_mut().set_name(name);
         self
@@ -228,6 +246,11 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self
     }
 
+    pub fn set_usage<S: Into<Cow<'a, str>>>(&mut self, usage: S) -> &mut Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
     pub fn add_block(&mut self, block: Block<'a, Cow<'a, str>>) -> Result<&mut Self> {
         if self.find_block(block.name()).is_some() {
             Err(Error::DuplicatedBlockName(block.name().to_string()))
@@ -8,6 +8,8 @@ pub struct Store<'a> {
 
     hint: Cow<'a, str>,
 
+    usage: Option<Cow<'a, str>>,
+
     help: Cow<'a, str>,
 
     r#type: Cow<'a, str>,
@@ -29,6 +31,7 @@ impl<'a> Store<'a> {
         Self {
             name: name.into(),
             hint: hint.into(),
+            usage: None,
             help: help.into(),
             r#type: r#type.into(),
             optional,
@@ -44,6 +47,11 @@ impl<'a> Store<'a> {
         self.hint.clone()
     }
 
+    /// The hint display in usage line, default is same as [`hint`](Store::hint).
+    pub fn usage(&self) -> Cow<'a, str> {
+        self.usage.clone().unwrap_or_else(|| self.hint.clone())
+    }
+
     pub fn help(&self) -> Cow<'a, str> {
         self.help.clone()
     }
@@ -70,6 +78,11 @@ impl<'a> Store<'a> {
         self
     }
 
+    pub fn set_usage<S: Into<Cow<'a, str>>>(&mut self, usage: S) -> &mut Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
     pub fn set_help<S: Into<Cow<'a, str>>>(&mut self, help: S) -> &mut Self {
         self.help = help.into();
         self
@@ -1,15 +1,10 @@
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum Align {
+    #[default]
     Left,
     Right,
 }
 
-impl Default for Align {
-    fn default() -> Self {
-        Self::Left
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct Style {
     pub align: Align,
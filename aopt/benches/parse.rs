@@ -0,0 +1,168 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::hint::black_box;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use aopt::prelude::*;
+use aopt::Error;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use criterion::Throughput;
+
+/// Count the allocations, used to compare the allocations of policies.
+struct Counter;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+/// Return the allocations of `f`.
+fn allocs(mut f: impl FnMut()) -> usize {
+    let start = ALLOCS.load(Ordering::Relaxed);
+
+    f();
+    ALLOCS.load(Ordering::Relaxed) - start
+}
+
+const ARGS: [&str; 12] = [
+    "app",
+    "--name=aopt",
+    "-v",
+    "--count",
+    "42",
+    "-d",
+    "--ratio=0.5",
+    "--tag",
+    "a",
+    "--tag=b",
+    "foo.txt",
+    "bar.txt",
+];
+
+const FLAGS: [&str; 8] = [
+    "app",
+    "-a",
+    "--force",
+    "-v",
+    "src",
+    "-q",
+    "--dry-run",
+    "dst",
+];
+
+fn setup_flags<P>(parser: &mut Parser<'_, P>) -> Result<(), Error>
+where
+    P: Policy<Set = ASet>,
+{
+    parser.add_opt("--all;-a=b")?;
+    parser.add_opt("--force;-f=b")?;
+    parser.add_opt("--verbose;-v=b")?;
+    parser.add_opt("--quiet;-q=b")?;
+    parser.add_opt("--dry-run;-n=b")?;
+    parser.add_opt("src=p@1")?.set_pos_type::<String>();
+    parser.add_opt("dst=p@2")?.set_pos_type::<String>();
+    Ok(())
+}
+
+fn setup<P>(parser: &mut Parser<'_, P>) -> Result<(), Error>
+where
+    P: Policy<Set = ASet>,
+{
+    parser.add_opt("--name=s")?;
+    parser.add_opt("-v=b")?;
+    parser.add_opt("--count=i")?;
+    parser.add_opt("-d=b")?;
+    parser.add_opt("--ratio=f")?;
+    parser.add_opt("--tag=s")?;
+    parser.add_opt("files=p@1..")?.set_pos_type::<String>();
+    Ok(())
+}
+
+/// Compare the throughput with and without `sync` feature:
+///
+/// ```shell
+/// cargo bench -p aopt --bench parse
+/// cargo bench -p aopt --bench parse --features sync
+/// ```
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group(if cfg!(feature = "sync") {
+        "parse_arc"
+    } else {
+        "parse_rc"
+    });
+    let mut parser = AFwdParser::default();
+
+    setup(&mut parser).unwrap();
+    group.throughput(Throughput::Elements(ARGS.len() as u64));
+    group.bench_function("fwd", |b| {
+        b.iter(|| {
+            parser.reset().unwrap();
+            black_box(parser.parse(Args::from(black_box(ARGS))).unwrap())
+        })
+    });
+    group.finish();
+}
+
+/// Compare the [`FlagPolicy`] with [`FwdPolicy`] on the flag only command line.
+fn flag(c: &mut Criterion) {
+    let mut group = c.benchmark_group("flag");
+    let mut fwd = AFwdParser::default();
+    let mut flag = AFlagParser::default();
+
+    setup_flags(&mut fwd).unwrap();
+    setup_flags(&mut flag).unwrap();
+
+    let args = Args::from(FLAGS);
+
+    // the allocations of initializing and storing values are included
+    for (name, count) in [
+        (
+            "fwd",
+            allocs(|| {
+                fwd.reset().unwrap();
+                fwd.parse(args.clone()).unwrap();
+            }),
+        ),
+        (
+            "flag",
+            allocs(|| {
+                flag.reset().unwrap();
+                flag.parse(args.clone()).unwrap();
+            }),
+        ),
+    ] {
+        println!("flag/{name}: {count} allocations per parse");
+    }
+    group.throughput(Throughput::Elements(FLAGS.len() as u64));
+    group.bench_function("fwd", |b| {
+        b.iter(|| {
+            fwd.reset().unwrap();
+            black_box(fwd.parse(Args::from(black_box(FLAGS))).unwrap())
+        })
+    });
+    group.bench_function("flag", |b| {
+        b.iter(|| {
+            flag.reset().unwrap();
+            black_box(flag.parse(Args::from(black_box(FLAGS))).unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, flag);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+use std::time::Instant;
+
+use aopt::prelude::*;
+
+// Measure the parsing time with many options,
+// run with `cargo run --release --example 07_many_options`
+pub fn main() -> Result<(), aopt::Error> {
+    for count in [100, 500, 1000, 2000] {
+        let mut parser = AFwdParser::default();
+
+        for i in 0..count {
+            parser.add_opt(format!("--opt{i}=i").as_str())?;
+            parser.add_opt(format!("--flag{i}=b").as_str())?;
+        }
+
+        let mut args = vec!["app".to_owned()];
+
+        for i in (0..count).step_by(5) {
+            args.push(format!("--opt{i}={i}"));
+            args.push(format!("--flag{i}"));
+        }
+
+        let rounds = 10;
+        let start = Instant::now();
+
+        for _ in 0..rounds {
+            parser
+                .parse(Args::from(args.iter().map(String::as_str)))?
+                .ok()?;
+        }
+
+        let elapsed = start.elapsed() / rounds;
+
+        assert_eq!(parser.find_val::<i64>("--opt0")?, &0);
+        println!(
+            "options: {:>5}, arguments: {:>4}, parse: {:?}",
+            count * 2,
+            args.len() - 1,
+            elapsed
+        );
+    }
+    Ok(())
+}
@@ -1,16 +1,38 @@
+pub(crate) mod argfile;
+#[cfg(feature = "lexer")]
+pub(crate) mod lexer;
+pub(crate) mod token;
+
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::fmt::Display;
 use std::ops::Deref;
 
+use crate::opt::Opt;
+use crate::opt::Style;
 use crate::parser::Return;
+use crate::set::Set;
+use crate::set::SetOpt;
+use crate::set::REDACTED;
 use crate::str::CowOsStrUtils;
 use crate::ARef;
 use crate::Error;
 
+pub use self::argfile::ArgFile;
+pub use self::argfile::ArgFileEncoding;
+#[cfg(feature = "lexer")]
+pub use self::lexer::Lexeme;
+#[cfg(feature = "lexer")]
+pub use self::lexer::Lexer;
+pub use self::token::tokenize;
+pub use self::token::Token;
+pub use self::token::Tokenizer;
+
 const EQUAL: char = '=';
 
+const QUOTES: [char; 2] = ['"', '\''];
+
 #[derive(Debug, Clone, Default)]
 pub struct ArgInfo<'a> {
     pub name: Cow<'a, str>,
@@ -65,10 +87,33 @@ impl<'a> ArgInfo<'a> {
     /// # }
     /// ```
     pub fn parse(val: &'a OsStr) -> Result<Self, Error> {
+        Self::parse_with(val, EQUAL)
+    }
+
+    /// Same as [`parse`](ArgInfo::parse), but split the name and value with `sep`,
+    /// the `sep` must be an ASCII character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use aopt::args::ArgInfo;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    ///     let output = ArgInfo::parse_with(OsStr::new("--foo:a=b"), ':')?;
+    ///
+    ///     assert_eq!(output.name, "--foo");
+    ///     assert_eq!(output.value.as_deref(), Some(OsStr::new("a=b")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with(val: &'a OsStr, sep: char) -> Result<Self, Error> {
         let arg_display = format!("{}", std::path::Path::new(val).display());
 
-        crate::trace!("parsing command line argument {val:?}");
-        if let Some((name, value)) = crate::str::split_once(val, EQUAL) {
+        crate::trace!("parsing command line argument {val:?} with separator {sep:?}");
+        if let Some((name, value)) = crate::str::split_once(val, sep) {
             // - convert the name to &str, the name must be valid utf8
             let name = name
                 .to_str(|v| v.trim())
@@ -92,6 +137,127 @@ impl<'a> ArgInfo<'a> {
             })
         }
     }
+
+    /// Same as [`parse`](ArgInfo::parse), but the `=` inside quotes is not treated as delimiter,
+    /// and the quotes around the value are removed by [`unquote`].
+    /// Fallback to [`parse`](ArgInfo::parse) if the quotes are unbalanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use aopt::args::ArgInfo;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    ///     let output = ArgInfo::parse_quoted(OsStr::new("--filter='name=foo,label=a=b'"))?;
+    ///
+    ///     assert_eq!(output.name, "--filter");
+    ///     assert_eq!(output.value.as_deref(), Some(OsStr::new("name=foo,label=a=b")));
+    ///
+    ///     let output = ArgInfo::parse_quoted(OsStr::new(r#"--filter="k=\"v\"""#))?;
+    ///
+    ///     assert_eq!(output.value.as_deref(), Some(OsStr::new(r#"k="v""#)));
+    ///
+    ///     let output = ArgInfo::parse_quoted(OsStr::new("'k=v'"))?;
+    ///
+    ///     assert_eq!(output.name, "'k=v'");
+    ///     assert_eq!(output.value, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_quoted(val: &'a OsStr) -> Result<Self, Error> {
+        Self::parse_quoted_with(val, EQUAL)
+    }
+
+    /// Same as [`parse_quoted`](ArgInfo::parse_quoted), but split the name and value with `sep`.
+    pub fn parse_quoted_with(val: &'a OsStr, sep: char) -> Result<Self, Error> {
+        let str = match val.to_str() {
+            Some(str) => str,
+            None => return Self::parse_with(val, sep),
+        };
+
+        match find_delimiter(str, sep) {
+            Some(Some(idx)) => {
+                let name = str[0..idx].trim();
+                let value = &str[idx + 1..];
+
+                if name.is_empty() {
+                    return Err(Error::arg(str, "can not be empty"));
+                }
+                Ok(Self {
+                    name: Cow::Borrowed(name),
+                    value: Some(unquote_os(OsStr::new(value))),
+                })
+            }
+            Some(None) => Ok(Self {
+                name: Cow::Borrowed(str),
+                value: None,
+            }),
+            None => Self::parse_with(val, sep),
+        }
+    }
+}
+
+/// Return the index of first `sep` outside the quotes,
+/// or None if the quotes are unbalanced.
+fn find_delimiter(val: &str, sep: char) -> Option<Option<usize>> {
+    let mut quote = None;
+    let mut escape = false;
+
+    for (idx, ch) in val.char_indices() {
+        match quote {
+            Some('"') if escape => escape = false,
+            Some('"') if ch == '\\' => escape = true,
+            Some(end) if ch == end => quote = None,
+            Some(_) => {}
+            None if ch == sep => return Some(Some(idx)),
+            None if QUOTES.contains(&ch) => quote = Some(ch),
+            None => {}
+        }
+    }
+    quote.is_none().then_some(None)
+}
+
+/// Remove the quotes if the whole `val` is wrapped in balanced single or double quotes.
+///
+/// In double quotes, `\"` and `\\` are unescaped. Return None if `val` is not quoted.
+pub fn unquote(val: &str) -> Option<String> {
+    let mut chars = val.chars();
+    let quote = chars.next().filter(|v| QUOTES.contains(v))?;
+    let mut ret = String::default();
+    let mut escape = false;
+    let mut closed = false;
+
+    for ch in chars {
+        if closed {
+            // there are characters after the closing quote
+            return None;
+        }
+        if escape {
+            if ch != '"' && ch != '\\' {
+                ret.push('\\');
+            }
+            ret.push(ch);
+            escape = false;
+        } else if quote == '"' && ch == '\\' {
+            escape = true;
+        } else if ch == quote {
+            closed = true;
+        } else {
+            ret.push(ch);
+        }
+    }
+    closed.then_some(ret)
+}
+
+/// Same as [`unquote`], return the `val` as is if it is not quoted.
+pub fn unquote_os(val: &OsStr) -> Cow<'_, OsStr> {
+    val.to_str()
+        .and_then(unquote)
+        .map(|v| Cow::Owned(OsString::from(v)))
+        .unwrap_or(Cow::Borrowed(val))
 }
 
 #[derive(Debug, Clone, Default)]
@@ -122,6 +288,13 @@ impl<T: Into<OsString>, I: IntoIterator<Item = T>> From<I> for Args {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Args {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary::<Vec<String>>()?.into_iter()))
+    }
+}
+
 impl From<Args> for Vec<OsString> {
     fn from(value: Args) -> Self {
         value.unwrap_or_clone()
@@ -161,17 +334,135 @@ pub fn iter2<'a, 'b>(
         .scan(args.iter().skip(1), |i, e| Some((e, i.next())))
 }
 
+/// Quote `val` so it can be copied into a POSIX shell, return it as is if no quoting needed.
+///
+/// The value is wrapped in single quotes, and the single quote inside is written as `'\''`.
+/// Invalid unicode is replaced lossily.
+///
+/// ```rust
+/// # use aopt::args::quote;
+/// # use std::ffi::OsStr;
+/// assert_eq!(quote(OsStr::new("--opt=42")), "--opt=42");
+/// assert_eq!(quote(OsStr::new("a b")), "'a b'");
+/// assert_eq!(quote(OsStr::new("it's")), r"'it'\''s'");
+/// assert_eq!(quote(OsStr::new("")), "''");
+/// ```
+pub fn quote(val: &OsStr) -> Cow<'_, str> {
+    let val = val.to_string_lossy();
+
+    if !val.is_empty()
+        && val
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "-_./=:,+@%^".contains(ch))
+    {
+        val
+    } else {
+        Cow::Owned(format!("'{}'", val.replace('\'', r"'\''")))
+    }
+}
+
+impl Args {
+    /// Display the arguments with the values of [`sensitive`](crate::opt::Opt::sensitive)
+    /// options replaced by [`REDACTED`](crate::set::REDACTED).
+    ///
+    /// Both `--opt=value` and `--opt value` are recognized, the latter only if
+    /// the option accepts [`Style::Argument`].
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--token=s")?.set_sensitive(true);
+    /// parser.add_opt("--name=s")?;
+    ///
+    /// let args = Args::from(["app", "--token", "secret", "--name=foo bar", "--token=a b"]);
+    ///
+    /// assert_eq!(
+    ///     args.redacted(parser.optset()).to_string(),
+    ///     "app --token ****** '--name=foo bar' --token=******"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn redacted<S>(&self, set: &S) -> RedactedArgs<'_>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let sensitive = set
+            .iter()
+            .filter(|opt| opt.sensitive())
+            .flat_map(|opt| {
+                let argument = opt.mat_style(Style::Argument);
+
+                std::iter::once(opt.name())
+                    .chain(opt.alias().into_iter().flatten().map(String::as_str))
+                    .map(move |name| (name.to_string(), argument))
+            })
+            .collect();
+
+        RedactedArgs {
+            args: &self.inner,
+            sensitive,
+        }
+    }
+}
+
 impl Display for Args {
+    /// Display the arguments separated by space, [`quote`]d if necessary.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, arg) in self.inner.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(&quote(arg))?;
+        }
+        Ok(())
+    }
+}
+
+/// The [`Display`] of [`Args`] which hide the values of sensitive options,
+/// see [`Args::redacted`].
+#[derive(Debug, Clone)]
+pub struct RedactedArgs<'a> {
+    args: &'a [OsString],
+
+    sensitive: Vec<(String, bool)>,
+}
+
+impl Display for RedactedArgs<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Args {{[{}]}}",
-            self.inner
-                .iter()
-                .map(|v| format!("{:?}", v))
-                .collect::<Vec<String>>()
-                .join(", ")
-        )
+        let mut hide_next = false;
+
+        for (idx, arg) in self.args.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(" ")?;
+            }
+            if std::mem::take(&mut hide_next) {
+                f.write_str(REDACTED)?;
+                continue;
+            }
+            let raw = arg.to_string_lossy();
+            let found = self.sensitive.iter().find_map(|(name, argument)| {
+                if raw.as_ref() == name {
+                    hide_next = *argument;
+                    None
+                } else {
+                    raw.strip_prefix(name.as_str())
+                        .filter(|v| v.starts_with(EQUAL))
+                        .map(|_| name)
+                }
+            });
+
+            match found {
+                Some(name) => write!(f, "{name}{EQUAL}{REDACTED}")?,
+                None => f.write_str(&quote(arg))?,
+            }
+        }
+        Ok(())
     }
 }
 
@@ -180,6 +471,8 @@ mod test {
 
     use std::ffi::OsStr;
 
+    use super::unquote;
+    use super::ArgInfo;
     use super::Args;
 
     #[test]
@@ -216,4 +509,50 @@ mod test {
 
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote("'a=b,c'").as_deref(), Some("a=b,c"));
+        assert_eq!(unquote(r#""k=\"v\"""#).as_deref(), Some(r#"k="v""#));
+        assert_eq!(unquote(r#""a\b""#).as_deref(), Some(r#"a\b"#));
+        assert_eq!(unquote(r#"'k=\"v\"'"#).as_deref(), Some(r#"k=\"v\""#));
+        assert_eq!(unquote("'a'b'"), None);
+        assert_eq!(unquote("'ab"), None);
+        assert_eq!(unquote("ab"), None);
+
+        let info = ArgInfo::parse_quoted(OsStr::new("--opt='a")).unwrap();
+
+        assert_eq!(info.name, "--opt");
+        assert_eq!(info.value.as_deref(), Some(OsStr::new("'a")));
+
+        let info = ArgInfo::parse_quoted(OsStr::new("'--opt=a")).unwrap();
+
+        assert_eq!(info.name, "'--opt");
+        assert_eq!(info.value.as_deref(), Some(OsStr::new("a")));
+    }
+
+    #[test]
+    fn test_display() {
+        let args = Args::from(["app", "--opt=a b", "", "it's", "-c"]);
+
+        assert_eq!(args.to_string(), r"app '--opt=a b' '' 'it'\''s' -c");
+    }
+
+    #[test]
+    fn test_separator() {
+        let info = ArgInfo::parse_with(OsStr::new("--opt:a:b"), ':').unwrap();
+
+        assert_eq!(info.name, "--opt");
+        assert_eq!(info.value.as_deref(), Some(OsStr::new("a:b")));
+
+        let info = ArgInfo::parse_with(OsStr::new("--opt=a"), ':').unwrap();
+
+        assert_eq!(info.name, "--opt=a");
+        assert_eq!(info.value, None);
+
+        let info = ArgInfo::parse_quoted_with(OsStr::new("--opt:'a:b'"), ':').unwrap();
+
+        assert_eq!(info.name, "--opt");
+        assert_eq!(info.value.as_deref(), Some(OsStr::new("a:b")));
+    }
 }
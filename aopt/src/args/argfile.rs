@@ -0,0 +1,409 @@
+use std::ffi::OsString;
+use std::path::Path;
+
+use crate::raise_error;
+use crate::Error;
+
+use super::Args;
+
+const BOM_UTF8: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+const BOM_UTF16LE: [u8; 2] = [0xFF, 0xFE];
+
+const BOM_UTF16BE: [u8; 2] = [0xFE, 0xFF];
+
+/// The encoding of argument file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ArgFileEncoding {
+    /// Detect the encoding by byte order mark, fallback to UTF-8 if no BOM found.
+    #[default]
+    Auto,
+
+    Utf8,
+
+    Utf16Le,
+
+    Utf16Be,
+}
+
+/// Expand the argument file (response file) such as `@args.txt` into the arguments it contains.
+///
+/// Every line of the file is split into arguments by whitespace,
+/// the argument contains whitespace can be wrapped in single or double quotes.
+/// In double quotes, `\"` and `\\` are unescaped, the backslash is kept in other place
+/// so the Windows path can be written as is.
+///
+/// * The lines start with one of [`comments`](ArgFile::comments) are ignored, default is `#`.
+/// * The line end with [`continuation`](ArgFile::continuation) is joined with next line, it is disabled by default.
+/// * The file is decoded with [`encoding`](ArgFile::encoding), the UTF-16 files generated on Windows are
+///   detected by byte order mark.
+/// * The argument files are expanded recursively, up to [`depth`](ArgFile::depth) levels.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let path = std::env::temp_dir().join("aopt_argfile_doc.txt");
+///
+/// std::fs::write(&path, "# build options\n--jobs 4 \\\n  --name \"hello world\"\n").unwrap();
+///
+/// let argfile = ArgFile::default().with_continuation(Some('\\'));
+/// let args = argfile.expand(Args::from(["app".into(), format!("@{}", path.display()), "foo".into()]))?;
+///
+/// assert_eq!(
+///     args.as_slice(),
+///     ["app", "--jobs", "4", "--name", "hello world", "foo"]
+/// );
+/// # std::fs::remove_file(&path).unwrap();
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArgFile {
+    prefix: char,
+
+    comments: Vec<String>,
+
+    continuation: Option<char>,
+
+    encoding: ArgFileEncoding,
+
+    depth: usize,
+}
+
+impl Default for ArgFile {
+    fn default() -> Self {
+        Self {
+            prefix: '@',
+            comments: vec![String::from("#")],
+            continuation: None,
+            encoding: ArgFileEncoding::default(),
+            depth: 8,
+        }
+    }
+}
+
+impl ArgFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the prefix of argument file, default is `@`.
+    pub fn with_prefix(mut self, prefix: char) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Add a comment prefix.
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comments.push(comment.into());
+        self
+    }
+
+    /// Set the comment prefixes, use an empty vector to disable comments.
+    pub fn with_comments(mut self, comments: Vec<String>) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    pub fn with_continuation(mut self, continuation: Option<char>) -> Self {
+        self.continuation = continuation;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: ArgFileEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set the maximum nesting levels of argument files.
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn set_prefix(&mut self, prefix: char) -> &mut Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub fn set_comments(&mut self, comments: Vec<String>) -> &mut Self {
+        self.comments = comments;
+        self
+    }
+
+    pub fn set_continuation(&mut self, continuation: Option<char>) -> &mut Self {
+        self.continuation = continuation;
+        self
+    }
+
+    pub fn set_encoding(&mut self, encoding: ArgFileEncoding) -> &mut Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn set_depth(&mut self, depth: usize) -> &mut Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn prefix(&self) -> char {
+        self.prefix
+    }
+
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    pub fn continuation(&self) -> Option<char> {
+        self.continuation
+    }
+
+    pub fn encoding(&self) -> ArgFileEncoding {
+        self.encoding
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Replace the argument files in `args` with the arguments they contain.
+    pub fn expand(&self, args: Args) -> Result<Args, Error> {
+        let mut ret = vec![];
+
+        self.expand_into(args.iter().cloned(), &mut ret, 0)?;
+        Ok(Args::from(ret))
+    }
+
+    /// Read the arguments from file `path`, the argument files in it are not expanded.
+    pub fn read(&self, path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|e| raise_error!("can not read argument file `{}`: {}", path.display(), e))?;
+        let content = self
+            .decode(&bytes)
+            .map_err(|e| raise_error!("invalid argument file `{}`", path.display()).cause_by(e))?;
+
+        Ok(self.split(&content))
+    }
+
+    /// Decode the content of argument file with [`encoding`](ArgFile::encoding), the BOM is removed.
+    pub fn decode(&self, bytes: &[u8]) -> Result<String, Error> {
+        let encoding = match self.encoding {
+            ArgFileEncoding::Auto if bytes.starts_with(&BOM_UTF16LE) => ArgFileEncoding::Utf16Le,
+            ArgFileEncoding::Auto if bytes.starts_with(&BOM_UTF16BE) => ArgFileEncoding::Utf16Be,
+            ArgFileEncoding::Auto => ArgFileEncoding::Utf8,
+            encoding => encoding,
+        };
+
+        match encoding {
+            ArgFileEncoding::Utf16Le => decode_utf16(bytes, &BOM_UTF16LE, u16::from_le_bytes),
+            ArgFileEncoding::Utf16Be => decode_utf16(bytes, &BOM_UTF16BE, u16::from_be_bytes),
+            _ => {
+                let bytes = bytes.strip_prefix(&BOM_UTF8).unwrap_or(bytes);
+
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|e| raise_error!("content is not valid UTF-8: {}", e))
+            }
+        }
+    }
+
+    /// Split the content of argument file into arguments.
+    pub fn split(&self, content: &str) -> Vec<String> {
+        let mut ret = vec![];
+        let mut logical = String::default();
+
+        for line in content.lines() {
+            let trimmed = line.trim_start();
+
+            if logical.is_empty()
+                && self
+                    .comments
+                    .iter()
+                    .any(|v| trimmed.starts_with(v.as_str()))
+            {
+                continue;
+            }
+            match self.continuation {
+                Some(ch) if line.ends_with(ch) => {
+                    logical.push_str(&line[..line.len() - ch.len_utf8()]);
+                    logical.push(' ');
+                }
+                _ => {
+                    logical.push_str(line);
+                    split_line(&logical, &mut ret);
+                    logical.clear();
+                }
+            }
+        }
+        split_line(&logical, &mut ret);
+        ret
+    }
+
+    fn expand_into(
+        &self,
+        args: impl Iterator<Item = OsString>,
+        ret: &mut Vec<OsString>,
+        depth: usize,
+    ) -> Result<(), Error> {
+        for arg in args {
+            match arg.to_str().and_then(|v| v.strip_prefix(self.prefix)) {
+                Some(path) if !path.is_empty() => {
+                    if depth >= self.depth {
+                        return Err(raise_error!(
+                            "argument file `{}` nested too deeply, the maximum is {}",
+                            path,
+                            self.depth
+                        ));
+                    }
+                    let args = self.read(path)?;
+
+                    self.expand_into(args.into_iter().map(OsString::from), ret, depth + 1)?;
+                }
+                _ => ret.push(arg),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode_utf16(bytes: &[u8], bom: &[u8], map: fn([u8; 2]) -> u16) -> Result<String, Error> {
+    let bytes = bytes.strip_prefix(bom).unwrap_or(bytes);
+
+    if !bytes.len().is_multiple_of(2) {
+        return Err(raise_error!("content is not valid UTF-16: odd length"));
+    }
+    let units = bytes.chunks_exact(2).map(|v| map([v[0], v[1]]));
+
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|e| raise_error!("content is not valid UTF-16: {}", e))
+}
+
+/// Split the line by whitespace, the quoted part is kept as one argument.
+fn split_line(line: &str, ret: &mut Vec<String>) {
+    let mut arg = String::default();
+    let mut quote = None;
+    let mut has_arg = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some('"') if ch == '\\' && matches!(chars.peek(), Some('"' | '\\')) => {
+                arg.extend(chars.next());
+            }
+            Some(_) => arg.push(ch),
+            None if ch == '"' || ch == '\'' => {
+                quote = Some(ch);
+                has_arg = true;
+            }
+            None if ch.is_whitespace() => {
+                if has_arg {
+                    ret.push(std::mem::take(&mut arg));
+                    has_arg = false;
+                }
+            }
+            None => {
+                arg.push(ch);
+                has_arg = true;
+            }
+        }
+    }
+    if has_arg {
+        ret.push(arg);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_argfile() {
+        assert!(testing_argfile_main().is_ok());
+    }
+
+    fn testing_argfile_main() -> Result<(), Error> {
+        let argfile = ArgFile::default()
+            .with_comment("//")
+            .with_continuation(Some('^'));
+
+        assert_eq!(
+            argfile.split(
+                "  # comment\r\n// other\r\n-I C:\\include\\ ^\r\n\"a b\" '' -o out.exe\r\n"
+            ),
+            ["-I", "C:\\include\\", "a b", "", "-o", "out.exe"]
+        );
+        assert_eq!(
+            ArgFile::default().split("--name \"say \\\"hi\\\"\" 'it\\s'"),
+            ["--name", "say \"hi\"", "it\\s"]
+        );
+
+        // UTF-16 LE with BOM generated by Windows
+        let mut bytes = vec![0xFF, 0xFE];
+
+        bytes.extend(
+            "--path 路径\r\n"
+                .encode_utf16()
+                .flat_map(|v| v.to_le_bytes()),
+        );
+        assert_eq!(argfile.decode(&bytes)?, "--path 路径\r\n");
+
+        let mut bytes = vec![0xFE, 0xFF];
+
+        bytes.extend("-v".encode_utf16().flat_map(|v| v.to_be_bytes()));
+        assert_eq!(argfile.decode(&bytes)?, "-v");
+        assert_eq!(argfile.decode(b"\xEF\xBB\xBF-v")?, "-v");
+        assert!(argfile.decode(b"\xFF\xFE-").is_err());
+        assert!(argfile
+            .clone()
+            .with_encoding(ArgFileEncoding::Utf8)
+            .decode(b"\xFF\xFE-\x00")
+            .is_err());
+
+        // nested argument files
+        let dir = std::env::temp_dir();
+        let inner = dir.join("aopt_argfile_inner.txt");
+        let outer = dir.join("aopt_argfile_outer.txt");
+
+        std::fs::write(&inner, "--jobs 4").unwrap();
+        std::fs::write(&outer, format!("--debug\n@{}\n", inner.display())).unwrap();
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--debug=b")?;
+        parser.add_opt("--jobs=u")?;
+        parser.add_opt("file=p@1")?.set_pos_type::<String>();
+        parser.parse(argfile.expand(Args::from([
+            "app".to_owned(),
+            format!("@{}", outer.display()),
+            "foo".to_owned(),
+            "@".to_owned(),
+        ]))?)?;
+
+        assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+        assert_eq!(parser.find_val::<u64>("--jobs")?, &4);
+        assert_eq!(parser.find_val::<String>("file")?, "foo");
+
+        let recursive = dir.join("aopt_argfile_recursive.txt");
+
+        std::fs::write(&recursive, format!("@{}", recursive.display())).unwrap();
+        assert!(argfile
+            .expand(Args::from([format!("@{}", recursive.display())]))
+            .is_err());
+        assert!(argfile
+            .expand(Args::from(["@aopt_argfile_not_exist.txt"]))
+            .is_err());
+
+        for path in [inner, outer, recursive] {
+            std::fs::remove_file(path).unwrap();
+        }
+        Ok(())
+    }
+}
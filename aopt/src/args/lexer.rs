@@ -0,0 +1,416 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ops::Range;
+
+use crate::set::OptValidator;
+use crate::set::PrefixOptValidator;
+
+use super::token::DISABLE;
+use super::token::STOP;
+use super::ArgInfo;
+use super::Args;
+use super::Token;
+
+const EQUAL: char = '=';
+
+/// The spans of command line argument generated by [`Lexer`].
+///
+/// The struct of the argument are:
+///
+/// ```plaintext
+/// [prefix][name][trailing][sep][value]
+///    |      |       |       |     |
+///    |      |       |       |     The value after separator, it is optional.
+///    |      |       |       |
+///    |      |       |       The separator of name and value, such as `=`.
+///    |      |       |
+///    |      |       The rest of name if the prefix is short prefix, such as `bc` of `-abc`.
+///    |      |
+///    |      The name of option without prefix.
+///    |
+///    The prefix of option, such as `--` or `-`.
+/// ```
+///
+/// All the spans are byte offsets of the argument,
+/// the argument not like an option has no spans, such as `--` or `file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lexeme<'a> {
+    arg: &'a OsStr,
+
+    prefix: Option<Range<usize>>,
+
+    name: Option<Range<usize>>,
+
+    trailing: Option<Range<usize>>,
+
+    sep: Option<Range<usize>>,
+
+    value: Option<Range<usize>>,
+}
+
+impl<'a> Lexeme<'a> {
+    /// Create a lexeme without spans.
+    pub fn new(arg: &'a OsStr) -> Self {
+        Self {
+            arg,
+            prefix: None,
+            name: None,
+            trailing: None,
+            sep: None,
+            value: None,
+        }
+    }
+
+    pub fn arg(&self) -> &'a OsStr {
+        self.arg
+    }
+
+    pub fn prefix_span(&self) -> Option<Range<usize>> {
+        self.prefix.clone()
+    }
+
+    pub fn name_span(&self) -> Option<Range<usize>> {
+        self.name.clone()
+    }
+
+    pub fn trailing_span(&self) -> Option<Range<usize>> {
+        self.trailing.clone()
+    }
+
+    pub fn sep_span(&self) -> Option<Range<usize>> {
+        self.sep.clone()
+    }
+
+    pub fn value_span(&self) -> Option<Range<usize>> {
+        self.value.clone()
+    }
+
+    pub fn is_option(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// Return true if the argument is `--`.
+    pub fn is_stop(&self) -> bool {
+        self.arg == STOP
+    }
+
+    pub fn is_noa(&self) -> bool {
+        !self.is_option() && !self.is_stop()
+    }
+
+    pub fn prefix(&self) -> Option<&'a str> {
+        self.str_of(self.prefix.clone())
+    }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.str_of(self.name.clone())
+    }
+
+    pub fn trailing(&self) -> Option<&'a str> {
+        self.str_of(self.trailing.clone())
+    }
+
+    pub fn sep(&self) -> Option<&'a str> {
+        self.str_of(self.sep.clone())
+    }
+
+    pub fn value(&self) -> Option<Cow<'a, OsStr>> {
+        let sep = self.sep()?.chars().next()?;
+
+        // the separator is the first one in argument
+        crate::str::split_once(self.arg, sep).map(|(_, value)| value)
+    }
+
+    /// Return the name with the trailing, such as `abc` of `-abc`.
+    pub fn full_name(&self) -> Option<&'a str> {
+        let name = self.name.as_ref()?;
+        let end = self.trailing.as_ref().map(|v| v.end).unwrap_or(name.end);
+
+        self.str_of(Some(name.start..end))
+    }
+
+    /// Convert the lexeme to [`ArgInfo`], return `None` if the argument is not an option.
+    pub fn to_arg_info(&self) -> Option<ArgInfo<'a>> {
+        let prefix = self.prefix.as_ref()?;
+        let end = self.trailing.as_ref().or(self.name.as_ref())?.end;
+
+        Some(ArgInfo {
+            name: Cow::Borrowed(self.str_of(Some(prefix.start..end))?),
+            value: self.value(),
+        })
+    }
+
+    /// Convert the lexeme to [`Token`], the trailing is a part of name.
+    pub fn to_token(&self) -> Token<'a> {
+        match (self.prefix(), self.full_name()) {
+            (Some(prefix), Some(name)) => Token::Option {
+                prefix: Cow::Borrowed(prefix),
+                name: Cow::Borrowed(name),
+                value: self.value(),
+                disable: prefix.ends_with(DISABLE),
+            },
+            _ if self.is_stop() => Token::Stop,
+            _ => Token::Noa(Cow::Borrowed(self.arg)),
+        }
+    }
+
+    fn str_of(&self, span: Option<Range<usize>>) -> Option<&'a str> {
+        std::str::from_utf8(self.arg.as_encoded_bytes().get(span?)?).ok()
+    }
+}
+
+impl<'a> From<Lexeme<'a>> for Token<'a> {
+    fn from(value: Lexeme<'a>) -> Self {
+        value.to_token()
+    }
+}
+
+/// An experimental low-level lexer split the command line argument into spans of [`Lexeme`].
+///
+/// Unlike [`Tokenizer`](super::Tokenizer), the lexer keeps the byte offsets of each part,
+/// and the name of short prefix is split into one character name and the trailing.
+/// It can be used to implement the syntaxes not supported by [`UserStyle`](crate::parser::UserStyle),
+/// such as `+RTS ... -RTS` sections, then pass the rest arguments to a [`Policy`](crate::parser::Policy).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use std::ffi::OsStr;
+/// # use std::ffi::OsString;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut lexer = Lexer::default();
+/// let lexeme = lexer.lex(OsStr::new("--foo=bar"));
+///
+/// assert_eq!(lexeme.prefix_span(), Some(0..2));
+/// assert_eq!(lexeme.name_span(), Some(2..5));
+/// assert_eq!(lexeme.sep_span(), Some(5..6));
+/// assert_eq!(lexeme.value_span(), Some(6..9));
+/// assert_eq!(lexeme.name(), Some("foo"));
+/// assert_eq!(lexeme.value().as_deref(), Some(OsStr::new("bar")));
+///
+/// // the name of short prefix is one character
+/// lexer.add_short("-");
+/// let lexeme = lexer.lex(OsStr::new("-abc"));
+///
+/// assert_eq!(lexeme.name(), Some("a"));
+/// assert_eq!(lexeme.trailing(), Some("bc"));
+/// assert_eq!(lexeme.to_token().name(), Some("abc"));
+///
+/// // collect the `+RTS ... -RTS` section
+/// lexer.validator_mut().add_prefix("+");
+/// let args = Args::from(["app", "+RTS", "-N4", "-H", "-RTS", "--foo=bar"]);
+/// let mut rts = vec![];
+/// let mut rest: Vec<OsString> = vec![];
+/// let mut in_rts = false;
+///
+/// for lexeme in lexer.lex_args(&args) {
+///     match (lexeme.prefix(), lexeme.full_name()) {
+///         (Some("+"), Some("RTS")) => in_rts = true,
+///         (Some("-"), Some("RTS")) if in_rts => in_rts = false,
+///         _ if in_rts => rts.push(lexeme.full_name().unwrap_or_default()),
+///         _ => rest.push(lexeme.arg().into()),
+///     }
+/// }
+/// assert_eq!(rts, ["N4", "H"]);
+///
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--foo=s")?;
+/// parser.parse(Args::from(rest))?;
+/// assert_eq!(parser.find_val::<String>("--foo")?, "bar");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    validator: PrefixOptValidator,
+
+    short: Vec<String>,
+
+    sep: char,
+}
+
+impl Default for Lexer {
+    fn default() -> Self {
+        Self::new(PrefixOptValidator::default())
+    }
+}
+
+impl Lexer {
+    pub fn new(validator: PrefixOptValidator) -> Self {
+        Self {
+            validator,
+            short: vec![],
+            sep: EQUAL,
+        }
+    }
+
+    pub fn with_validator(mut self, validator: PrefixOptValidator) -> Self {
+        self.validator = validator;
+        self
+    }
+
+    /// Set the short prefixes, the name after them is one character.
+    pub fn with_short(mut self, short: Vec<String>) -> Self {
+        self.short = short;
+        self
+    }
+
+    /// Set the separator of name and value, the `sep` must be an ASCII character.
+    pub fn with_sep(mut self, sep: char) -> Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_validator(&mut self, validator: PrefixOptValidator) -> &mut Self {
+        self.validator = validator;
+        self
+    }
+
+    pub fn set_short(&mut self, short: Vec<String>) -> &mut Self {
+        self.short = short;
+        self
+    }
+
+    /// Add a short prefix, it should be a prefix of the validator too.
+    pub fn add_short(&mut self, prefix: &str) -> &mut Self {
+        self.short.push(prefix.to_string());
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: char) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn validator(&self) -> &PrefixOptValidator {
+        &self.validator
+    }
+
+    pub fn validator_mut(&mut self) -> &mut PrefixOptValidator {
+        &mut self.validator
+    }
+
+    pub fn short(&self) -> &[String] {
+        &self.short
+    }
+
+    pub fn sep(&self) -> char {
+        self.sep
+    }
+
+    /// Split the argument into [`Lexeme`], the argument is not an option if
+    /// it has no valid prefix or the name is empty or not valid utf8.
+    pub fn lex<'a>(&self, arg: &'a OsStr) -> Lexeme<'a> {
+        let mut lexeme = Lexeme::new(arg);
+
+        if arg == STOP {
+            return lexeme;
+        }
+        let bytes = arg.as_encoded_bytes();
+        let sep_at = bytes.iter().position(|ch| char::from(*ch) == self.sep);
+        let name_end = sep_at.unwrap_or(bytes.len());
+        let Ok(name) = std::str::from_utf8(&bytes[..name_end]) else {
+            return lexeme;
+        };
+        let Ok((prefix, _)) = self.validator.split(&Cow::Borrowed(name)) else {
+            return lexeme;
+        };
+        let name_beg = prefix.len();
+
+        if name_beg == name_end {
+            return lexeme;
+        }
+        lexeme.prefix = Some(0..name_beg);
+        if self.short.iter().any(|v| v.as_str() == prefix.as_ref()) {
+            let len = name[name_beg..].chars().next().map_or(0, char::len_utf8);
+
+            lexeme.name = Some(name_beg..name_beg + len);
+            if name_beg + len < name_end {
+                lexeme.trailing = Some(name_beg + len..name_end);
+            }
+        } else {
+            lexeme.name = Some(name_beg..name_end);
+        }
+        if let Some(at) = sep_at {
+            lexeme.sep = Some(at..at + 1);
+            lexeme.value = Some(at + 1..bytes.len());
+        }
+        crate::trace!("lexing command line argument {arg:?} ==> {lexeme:?}");
+        lexeme
+    }
+
+    /// Split all the arguments into [`Lexeme`]s, the arguments after `--` have no spans.
+    pub fn lex_args<'a>(&self, args: &'a Args) -> Vec<Lexeme<'a>> {
+        let mut stopped = false;
+        let mut lexemes = Vec::with_capacity(args.len());
+
+        for arg in args.iter() {
+            if stopped {
+                lexemes.push(Lexeme::new(arg.as_os_str()));
+            } else {
+                let lexeme = self.lex(arg);
+
+                stopped = lexeme.is_stop();
+                lexemes.push(lexeme);
+            }
+        }
+        lexemes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn testing_lexer() {
+        let lexer = Lexer::default();
+
+        for (arg, prefix, name, value) in [
+            ("--foo", "--", "foo", None),
+            ("-f=42", "-", "f", Some("42")),
+            ("--/bar", "--/", "bar", None),
+            ("-/b=", "-/", "b", Some("")),
+        ] {
+            let lexeme = lexer.lex(OsStr::new(arg));
+
+            assert_eq!(lexeme.prefix(), Some(prefix), "lexing {arg}");
+            assert_eq!(lexeme.name(), Some(name));
+            assert_eq!(lexeme.value().as_deref(), value.map(OsStr::new));
+            assert_eq!(lexeme.sep().is_some(), value.is_some());
+            assert_eq!(lexeme.to_token(), tokenize(OsStr::new(arg)).unwrap());
+            assert_eq!(lexeme.to_token().to_os_string(), OsStr::new(arg));
+        }
+        for arg in ["foo", "-", "=foo", "a=b", "--"] {
+            let lexeme = lexer.lex(OsStr::new(arg));
+
+            assert!(!lexeme.is_option(), "lexing {arg}");
+            assert!(lexeme.to_arg_info().is_none());
+            assert_eq!(lexeme.to_token().to_os_string(), OsStr::new(arg));
+        }
+        assert!(lexer.lex(OsStr::new("--")).is_stop());
+
+        let lexer = Lexer::default().with_short(vec!["-".to_string()]);
+        let lexeme = lexer.lex(OsStr::new("-ofile=a.txt"));
+        let info = lexeme.to_arg_info().unwrap();
+
+        assert_eq!(lexeme.name_span(), Some(1..2));
+        assert_eq!(lexeme.trailing_span(), Some(2..6));
+        assert_eq!(lexeme.value_span(), Some(7..12));
+        assert_eq!(info.name, "-ofile");
+        assert_eq!(info.value.as_deref(), Some(OsStr::new("a.txt")));
+
+        let args = Args::from(["app", "--", "-a"]);
+        let lexemes = lexer.lex_args(&args);
+
+        assert!(lexemes[1].is_stop());
+        assert!(lexemes[2].is_noa());
+    }
+}
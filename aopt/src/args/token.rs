@@ -0,0 +1,301 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use crate::set::OptValidator;
+use crate::set::PrefixOptValidator;
+use crate::Error;
+
+use super::ArgInfo;
+use super::Args;
+
+pub(crate) const STOP: &str = "--";
+
+pub(crate) const DISABLE: char = '/';
+
+/// The token of command line argument, generated by [`Tokenizer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// The argument looks like an option, such as `--foo=bar`, `-f` or `--/bar`.
+    Option {
+        /// The prefix of option, such as `--` or `-`.
+        prefix: Cow<'a, str>,
+
+        /// The name of option without prefix.
+        name: Cow<'a, str>,
+
+        /// The value after `=` if any.
+        value: Option<Cow<'a, OsStr>>,
+
+        /// True if the prefix end with `/`, such as `--/bar`.
+        disable: bool,
+    },
+
+    /// The `--` argument.
+    Stop,
+
+    /// The argument not like an option.
+    Noa(Cow<'a, OsStr>),
+}
+
+impl Token<'_> {
+    pub fn is_option(&self) -> bool {
+        matches!(self, Self::Option { .. })
+    }
+
+    pub fn is_stop(&self) -> bool {
+        matches!(self, Self::Stop)
+    }
+
+    pub fn is_noa(&self) -> bool {
+        matches!(self, Self::Noa(_))
+    }
+
+    /// Return the option name without prefix, or `None` if the token is not an option.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Option { name, .. } => Some(name.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Return the value of option, or `None` if the token is not an option or has no value.
+    pub fn value(&self) -> Option<&OsStr> {
+        match self {
+            Self::Option { value, .. } => value.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Convert the token back to command line argument.
+    pub fn to_os_string(&self) -> OsString {
+        match self {
+            Self::Option {
+                prefix,
+                name,
+                value,
+                ..
+            } => {
+                let mut ret = OsString::from(format!("{prefix}{name}"));
+
+                if let Some(value) = value {
+                    ret.push("=");
+                    ret.push(value);
+                }
+                ret
+            }
+            Self::Stop => OsString::from(STOP),
+            Self::Noa(val) => val.to_os_string(),
+        }
+    }
+}
+
+/// Split the command line arguments into [`Token`]s without an option set.
+///
+/// The argument is split into prefix, name and value using the prefixes of
+/// [`PrefixOptValidator`], an option with prefix end with `/` is disable style.
+/// It can be used to pre-analyze or rewrite the arguments before passing them to a
+/// [`Policy`](crate::parser::Policy).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use std::borrow::Cow;
+/// # use std::ffi::OsStr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let tokenizer = Tokenizer::default();
+/// let args = Args::from(["app", "--foo=bar", "-/b", "file", "--", "-c"]);
+/// let tokens = tokenizer.tokenize_args(&args)?;
+///
+/// assert_eq!(
+///     tokens[1],
+///     Token::Option {
+///         prefix: Cow::Borrowed("--"),
+///         name: Cow::Borrowed("foo"),
+///         value: Some(Cow::Borrowed(OsStr::new("bar"))),
+///         disable: false,
+///     }
+/// );
+/// assert_eq!(tokens[2].name(), Some("b"));
+/// assert!(matches!(tokens[2], Token::Option { disable: true, .. }));
+/// assert!(tokens[3].is_noa());
+/// assert!(tokens[4].is_stop());
+/// assert!(tokens[5].is_noa());
+///
+/// // rename `--foo` to `--bar`
+/// let args: Args = tokens
+///     .into_iter()
+///     .map(|mut token| {
+///         if let Token::Option { name, .. } = &mut token {
+///             if name == "foo" {
+///                 *name = Cow::Borrowed("bar");
+///             }
+///         }
+///         token.to_os_string()
+///     })
+///     .collect::<Vec<_>>()
+///     .into();
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--bar=s")?;
+/// parser.add_opt("-b=b")?;
+/// parser.parse(args)?;
+/// assert_eq!(parser.find_val::<String>("--bar")?, "bar");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Tokenizer {
+    validator: PrefixOptValidator,
+
+    quoted: bool,
+}
+
+impl Tokenizer {
+    pub fn new(validator: PrefixOptValidator) -> Self {
+        Self {
+            validator,
+            quoted: false,
+        }
+    }
+
+    pub fn with_validator(mut self, validator: PrefixOptValidator) -> Self {
+        self.validator = validator;
+        self
+    }
+
+    /// Process the quotes around the value like [`ArgInfo::parse_quoted`].
+    pub fn with_quoted(mut self, quoted: bool) -> Self {
+        self.quoted = quoted;
+        self
+    }
+
+    pub fn set_validator(&mut self, validator: PrefixOptValidator) -> &mut Self {
+        self.validator = validator;
+        self
+    }
+
+    pub fn set_quoted(&mut self, quoted: bool) -> &mut Self {
+        self.quoted = quoted;
+        self
+    }
+
+    pub fn validator(&self) -> &PrefixOptValidator {
+        &self.validator
+    }
+
+    pub fn validator_mut(&mut self) -> &mut PrefixOptValidator {
+        &mut self.validator
+    }
+
+    pub fn quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Split the argument into [`Token`].
+    pub fn tokenize<'a>(&self, arg: &'a OsStr) -> Result<Token<'a>, Error> {
+        if arg == STOP {
+            return Ok(Token::Stop);
+        }
+        let info = if self.quoted {
+            ArgInfo::parse_quoted(arg)
+        } else {
+            ArgInfo::parse(arg)
+        };
+        let ArgInfo { name, value } = match info {
+            Ok(info) => info,
+            Err(_) => return Ok(Token::Noa(Cow::Borrowed(arg))),
+        };
+
+        match self.validator.split(&name) {
+            Ok((prefix, name)) if !name.is_empty() => Ok(Token::Option {
+                disable: prefix.ends_with(DISABLE),
+                prefix,
+                name,
+                value,
+            }),
+            _ => Ok(Token::Noa(Cow::Borrowed(arg))),
+        }
+    }
+
+    /// Split all the arguments into [`Token`]s, the arguments after `--` are [`Token::Noa`].
+    pub fn tokenize_args<'a>(&self, args: &'a Args) -> Result<Vec<Token<'a>>, Error> {
+        let mut stopped = false;
+        let mut tokens = Vec::with_capacity(args.len());
+
+        for arg in args.iter() {
+            if stopped {
+                tokens.push(Token::Noa(Cow::Borrowed(arg.as_os_str())));
+            } else {
+                let token = self.tokenize(arg)?;
+
+                stopped = token.is_stop();
+                tokens.push(token);
+            }
+        }
+        Ok(tokens)
+    }
+}
+
+/// Split the argument into [`Token`] using default [`Tokenizer`].
+pub fn tokenize(arg: &OsStr) -> Result<Token<'_>, Error> {
+    Tokenizer::default().tokenize(arg)
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_tokenize() {
+        assert!(testing_tokenize_main().is_ok());
+    }
+
+    fn testing_tokenize_main() -> Result<(), Error> {
+        for (arg, prefix, name, value, disable) in [
+            ("--foo", "--", "foo", None, false),
+            ("-f=42", "-", "f", Some("42"), false),
+            ("--/bar", "--/", "bar", None, true),
+            ("-/b=", "-/", "b", Some(""), true),
+        ] {
+            let token = tokenize(OsStr::new(arg))?;
+
+            assert_eq!(token.name(), Some(name), "tokenize {arg}");
+            assert_eq!(token.value(), value.map(OsStr::new));
+            assert!(matches!(token, Token::Option { disable: d, .. } if d == disable));
+            assert!(matches!(&token, Token::Option { prefix: p, .. } if p == prefix));
+            assert_eq!(token.to_os_string(), OsStr::new(arg));
+        }
+        for arg in ["foo", "-", "=foo", "a=b"] {
+            let token = tokenize(OsStr::new(arg))?;
+
+            assert!(token.is_noa(), "tokenize {arg}");
+            assert_eq!(token.to_os_string(), OsStr::new(arg));
+        }
+        assert!(tokenize(OsStr::new("--"))?.is_stop());
+
+        let args = Args::from(["app", "--", "--", "-a"]);
+        let tokens = Tokenizer::default().tokenize_args(&args)?;
+
+        assert!(tokens[1].is_stop());
+        assert!(tokens[2].is_noa());
+        assert!(tokens[3].is_noa());
+
+        let mut tokenizer = Tokenizer::default().with_quoted(true);
+
+        tokenizer.validator_mut().add_prefix("+");
+        assert_eq!(
+            tokenizer.tokenize(OsStr::new("+x='a=b'"))?.value(),
+            Some(OsStr::new("a=b"))
+        );
+        assert_eq!(tokenizer.tokenize(OsStr::new("+x"))?.name(), Some("x"));
+        Ok(())
+    }
+}
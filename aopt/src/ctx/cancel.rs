@@ -0,0 +1,89 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::Error;
+
+/// A shareable flag used to cancel the parsing process or a long-running handler.
+///
+/// The token is always backed by an [`Arc`], so it can be triggered from another thread
+/// (a signal handler, a watchdog, etc.) even if the `sync` feature is not enabled.
+/// The policy will check the token between arguments,
+/// and handlers can call [`check_cancelled`](crate::ctx::Ctx::check_cancelled) to exit early.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::err::Kind;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let token = CancelToken::default();
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().set_cancel_token(token.clone());
+/// parser.add_opt("--work=b")?.on({
+///     let token = token.clone();
+///     move |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+///         // pretend the user pressed Ctrl-C during the work
+///         token.cancel();
+///         ctx.check_cancelled()?;
+///         Ok(Some(true))
+///     }
+/// })?;
+///
+/// let ret = parser.parse(Args::from(["app", "--work"]));
+///
+/// assert!(ret.is_err());
+/// assert_eq!(ret.unwrap_err().kind(), &Kind::Cancelled);
+/// assert!(token.is_cancelled());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation, all the clones of the token will be cancelled.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Clear the cancellation flag so the token can be reused.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+
+    /// Return [`Error::cancelled`] if the token is cancelled.
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_cancelled() {
+            Err(Error::cancelled())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Cancel the token when user press Ctrl-C.
+    ///
+    /// Only one Ctrl-C handler can be registered in a process,
+    /// an error will be returned if the handler already set.
+    #[cfg(feature = "ctrlc")]
+    pub fn cancel_on_ctrlc(&self) -> Result<&Self, Error> {
+        let token = self.clone();
+
+        ctrlc::set_handler(move || token.cancel()).map_err(|e| {
+            crate::raise_error!("can not set Ctrl-C handler for CancelToken: {:?}", e)
+        })?;
+        Ok(self)
+    }
+}
@@ -1,12 +1,19 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::fmt::Display;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::args::Args;
+use crate::ctx::CancelToken;
 use crate::opt::Style;
 use crate::parser::Action;
 use crate::str::display_of_osstr;
 use crate::str::display_of_str;
+use crate::value::BoolLiterals;
+use crate::value::Decoding;
+use crate::value::FloatSyntax;
+use crate::value::NumLocale;
 use crate::value::RawValParser;
 use crate::Error;
 use crate::Uid;
@@ -147,11 +154,31 @@ pub struct Ctx<'a> {
 
     pub(crate) inner_ctx: Option<InnerCtx<'a>>,
 
+    /// The index of NOA, the uid and style of [`Cmd`](Style::Cmd) or [`Pos`](Style::Pos) matched it.
+    pub(crate) noa_matched: Vec<(usize, Uid, Style)>,
+
+    /// The options would be matched in dry-run mode.
+    pub(crate) dry_matched: Vec<InnerCtx<'a>>,
+
     #[cfg(not(feature = "sync"))]
     action: std::cell::RefCell<Action>,
 
     #[cfg(feature = "sync")]
     action: std::sync::Mutex<Action>,
+
+    cancel: CancelToken,
+
+    deadline: Option<Instant>,
+
+    decoding: Decoding,
+
+    num_locale: NumLocale,
+
+    float_syntax: FloatSyntax,
+
+    bool_literals: BoolLiterals,
+
+    dry_run: bool,
 }
 
 impl Clone for Ctx<'_> {
@@ -160,10 +187,19 @@ impl Clone for Ctx<'_> {
             orig: self.orig.clone(),
             args: self.args.clone(),
             inner_ctx: self.inner_ctx.clone(),
+            noa_matched: self.noa_matched.clone(),
+            dry_matched: self.dry_matched.clone(),
             #[cfg(not(feature = "sync"))]
             action: self.action.clone(),
             #[cfg(feature = "sync")]
             action: std::sync::Mutex::new(*self.action.lock().unwrap()),
+            cancel: self.cancel.clone(),
+            deadline: self.deadline,
+            decoding: self.decoding,
+            num_locale: self.num_locale,
+            float_syntax: self.float_syntax,
+            bool_literals: self.bool_literals.clone(),
+            dry_run: self.dry_run,
         }
     }
 }
@@ -183,6 +219,44 @@ impl<'a> Ctx<'a> {
         self.inner_ctx = Some(inner_ctx);
         self
     }
+
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Set the deadline of current parsing, [`None`] means no deadline.
+    ///
+    /// The policies set it from the `timeout` when the parsing starts.
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    pub fn with_decoding(mut self, decoding: Decoding) -> Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn with_num_locale(mut self, num_locale: NumLocale) -> Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn with_float_syntax(mut self, float_syntax: FloatSyntax) -> Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn with_bool_literals(mut self, bool_literals: BoolLiterals) -> Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
 }
 
 impl<'a> Ctx<'a> {
@@ -246,6 +320,41 @@ impl<'a> Ctx<'a> {
         Ok(self.args.get(idx).copied())
     }
 
+    /// The raw argument of matched option, such as `--opt=value`.
+    pub fn matched_raw(&self) -> Result<Option<&'a OsStr>, Error> {
+        self.arg_at(self.idx()?)
+    }
+
+    /// The arguments after the matched option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--exec=b")?
+    ///     .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+    ///         assert_eq!(ctx.matched_raw()?, Some(OsStr::new("--exec")));
+    ///         assert_eq!(ctx.remainder()?, &[OsStr::new("ls"), OsStr::new("-l")]);
+    ///         Ok(Some(true))
+    ///     })?;
+    /// parser.parse(Args::from(["app", "--exec", "ls", "-l"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<bool>("--exec")?, &true);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remainder(&self) -> Result<&[&'a OsStr], Error> {
+        Ok(self.args.get(self.idx()? + 1..).unwrap_or_default())
+    }
+
     pub fn take_args(&mut self) -> Vec<&OsStr> {
         std::mem::take(&mut self.args)
     }
@@ -303,6 +412,157 @@ impl<'a> Ctx<'a> {
         self.inner_ctx = inner_ctx;
         self
     }
+
+    /// Record the NOA at `idx` is matched by the [`Cmd`](Style::Cmd) or [`Pos`](Style::Pos) option `uid`.
+    pub(crate) fn add_noa_matched(&mut self, idx: usize, uid: Uid, style: Style) -> &mut Self {
+        self.noa_matched.push((idx, uid, style));
+        self
+    }
+
+    /// Record the current [`InnerCtx`] would be invoked in dry-run mode.
+    pub(crate) fn add_dry_matched(&mut self) -> Result<&mut Self, Error> {
+        let inner_ctx = self.inner_ctx()?.clone();
+
+        self.dry_matched.push(inner_ctx);
+        Ok(self)
+    }
+
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) -> &mut Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Set the deadline of current parsing, [`None`] means no deadline.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) -> &mut Self {
+        self.deadline = deadline;
+        self
+    }
+
+    pub fn set_decoding(&mut self, decoding: Decoding) -> &mut Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn set_num_locale(&mut self, num_locale: NumLocale) -> &mut Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn set_float_syntax(&mut self, float_syntax: FloatSyntax) -> &mut Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn set_bool_literals(&mut self, bool_literals: BoolLiterals) -> &mut Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+impl Ctx<'_> {
+    /// The [`CancelToken`] shared with the policy.
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Return [`Error::cancelled`] if cancellation is requested,
+    /// long-running handlers should call it periodically.
+    pub fn check_cancelled(&self) -> Result<(), Error> {
+        self.cancel.check()
+    }
+
+    /// The deadline of current parsing, it is set by the `timeout` of policy.
+    ///
+    /// The handler wrappers, such as [`wrap_handler`](crate::ctx::wrap_handler),
+    /// check it before and after invoking the handler.
+    /// They can not preempt a running handler, a handler blocked on I/O will not be interrupted
+    /// when the deadline passed, the [`Error::timeout`] is returned after it finished.
+    /// Handlers calling the network should pass the [`remaining`](Ctx::remaining) time to their requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.policy_mut().set_timeout(Some(Duration::from_millis(10)));
+    /// parser.add_opt("--token=s")?.on(|_, _, ctx| {
+    ///     assert!(ctx.remaining().is_some());
+    ///     // validating the token slowly
+    ///     std::thread::sleep(Duration::from_millis(20));
+    ///     Ok(Some(ctx.value::<String>()?))
+    /// })?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "--token", "secret"]));
+    ///
+    /// assert!(ret.unwrap_err().is_timeout());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// The remaining time before the deadline, return [`Duration::ZERO`] if it is passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Return true if the deadline is set and passed.
+    pub fn is_timeout(&self) -> bool {
+        self.deadline
+            .map(|deadline| Instant::now() >= deadline)
+            .unwrap_or_default()
+    }
+
+    /// Return [`Error::timeout`] if the deadline is passed.
+    pub fn check_deadline(&self) -> Result<(), Error> {
+        if self.is_timeout() {
+            Err(Error::timeout())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The [`Decoding`] used by value parsers converting raw value to string.
+    pub fn decoding(&self) -> Decoding {
+        self.decoding
+    }
+
+    /// The [`NumLocale`] used by the integer and float value parsers.
+    pub fn num_locale(&self) -> NumLocale {
+        self.num_locale
+    }
+
+    /// The [`FloatSyntax`] used by the float value parsers.
+    pub fn float_syntax(&self) -> FloatSyntax {
+        self.float_syntax
+    }
+
+    /// The [`BoolLiterals`] used by the `bool` value parser.
+    pub fn bool_literals(&self) -> &BoolLiterals {
+        &self.bool_literals
+    }
+
+    /// If true, the policy matches the options without invoking the handlers.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
 }
 
 impl Ctx<'_> {
@@ -0,0 +1,147 @@
+use std::ops::Deref;
+
+use crate::ctx::Ctx;
+use crate::opt::Style;
+use crate::value::RawValParser;
+use crate::Error;
+use crate::Uid;
+
+/// The parsed value of option together with the information of matching,
+/// such as the [`Style`], the index of argument and the name actually used.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--count;-c=i")?
+///     .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+///         let count = ctx.matched::<i64>()?;
+///
+///         assert_eq!(count.name(), Some("-c"));
+///         assert_eq!(count.style(), Style::Argument);
+///         assert_eq!(count.idx(), 2);
+///         Ok(Some(*count + 1))
+///     })?;
+/// parser.parse(Args::from(["app", "foo", "-c", "41"]))?;
+///
+/// assert_eq!(parser.find_val::<i64>("--count")?, &42);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matched<T> {
+    value: T,
+
+    uid: Uid,
+
+    idx: usize,
+
+    style: Style,
+
+    name: Option<String>,
+}
+
+impl<T: RawValParser> Matched<T> {
+    /// Parse the value from [`Ctx`] and collect the information of matching.
+    pub fn extract(ctx: &Ctx) -> Result<Self, Error> {
+        Ok(Self {
+            value: ctx.value::<T>()?,
+            uid: ctx.uid()?,
+            idx: ctx.idx()?,
+            style: ctx.style()?,
+            name: ctx.name()?.map(|v| v.to_string()),
+        })
+    }
+}
+
+impl<T> Matched<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn uid(&self) -> Uid {
+        self.uid
+    }
+
+    /// The index of argument matched.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The name of option actually used, it may be an alias.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Matched<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl Ctx<'_> {
+    /// Get the value and the information of matching, see [`Matched`].
+    pub fn matched<T: RawValParser>(&self) -> Result<Matched<T>, Error> {
+        Matched::extract(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_matched() {
+        assert!(testing_matched_main().is_ok());
+    }
+
+    fn testing_matched_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--name;-n=s")?
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                let name = ctx.matched::<String>()?;
+
+                assert_eq!(name.name(), Some("--name"));
+                assert_eq!(name.style(), Style::Argument);
+                assert_eq!(name.idx(), 1);
+                Ok(Some(name.into_value()))
+            })?;
+        parser
+            .add_opt("file=p@1")?
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                let file = ctx.matched::<String>()?;
+
+                assert_eq!(file.name(), Some("foo.txt"));
+                assert_eq!(file.style(), Style::Pos);
+                assert_eq!(file.idx(), 1);
+                assert_eq!(file.value(), "foo.txt");
+                Ok(Some(file.into_value()))
+            })?;
+        parser.parse(Args::from(["app", "--name=aopt", "foo.txt"]))?;
+
+        assert_eq!(parser.find_val::<String>("--name")?, "aopt");
+        assert_eq!(parser.find_val::<String>("file")?, "foo.txt");
+        Ok(())
+    }
+}
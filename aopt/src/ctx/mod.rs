@@ -1,16 +1,22 @@
+pub(crate) mod cancel;
 pub(crate) mod context;
 #[cfg_attr(feature = "sync", path = "../sync/ctx/invoke.rs")]
 #[cfg_attr(not(feature = "sync"), path = "invoke.rs")]
 pub(crate) mod invoke;
+pub(crate) mod matched;
 pub(crate) mod store;
 
+pub use self::cancel::CancelToken;
 pub use self::context::Ctx;
 pub use self::context::InnerCtx;
 pub use self::invoke::HandlerCollection;
 pub use self::invoke::HandlerEntry;
 pub use self::invoke::HandlerEntryThen;
 pub use self::invoke::InvokeHandler;
+pub use self::invoke::InvokeMiddleware;
+pub use self::invoke::InvokeNext;
 pub use self::invoke::Invoker;
+pub use self::matched::Matched;
 pub use self::store::NullStore;
 pub use self::store::Store;
 pub use self::store::VecStore;
@@ -20,6 +26,27 @@ use crate::set::SetExt;
 use crate::set::SetOpt;
 use crate::Error;
 
+/// Replace the argument of `ctx` with the value normalized by the
+/// [`Normalizer`](crate::value::Normalizer) of option, do nothing if the option has no normalizer.
+pub fn normalize_arg<Set>(set: &Set, ctx: &mut Ctx) -> Result<(), Error>
+where
+    Set: crate::set::Set,
+    SetOpt<Set>: Opt,
+{
+    let uid = ctx.uid()?;
+
+    if let Some(normalizer) = set.get(uid).and_then(|opt| opt.normalizer()) {
+        if let Some(arg) = ctx.arg()? {
+            let arg = normalizer.normalize(arg)?;
+
+            ctx.set_arg(Some(std::borrow::Cow::Owned(arg)))?;
+        }
+    }
+    Ok(())
+}
+
+// The wrappers check the deadline of `Ctx` before and after invoking the handler,
+// they can not preempt a running handler, see `Ctx::deadline`.
 pub use __wrapper::wrap_handler;
 pub use __wrapper::wrap_handler_action;
 pub use __wrapper::wrap_handler_fallback;
@@ -43,7 +70,9 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + Send + Sync + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
 
             if val.is_some() {
                 let arg = ctx.arg()?.map(|v| v.as_ref());
@@ -72,7 +101,9 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + Send + Sync + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
 
             if val.is_some() {
                 let arg = ctx.arg()?.map(|v| v.as_ref());
@@ -97,7 +128,10 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + Send + Sync + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
+
             let arg = ctx.arg()?.map(|v| v.as_ref());
             let uid = ctx.uid()?;
             let mut act = *set.opt(uid)?.action();
@@ -117,7 +151,10 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + Send + Sync + 'a,
     {
         Box::new(move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
+
             let arg = ctx.arg()?.map(|v| v.as_ref());
             let uid = ctx.uid()?;
 
@@ -144,7 +181,9 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
 
             if val.is_some() {
                 let arg = ctx.arg()?.map(|v| v.as_ref());
@@ -173,7 +212,9 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
 
             if val.is_some() {
                 let arg = ctx.arg()?.map(|v| v.as_ref());
@@ -198,7 +239,10 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + 'a,
     {
         move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
+
             let arg = ctx.arg()?.map(|v| v.as_ref());
             let uid = ctx.uid()?;
             let mut act = *set.opt(uid)?.action();
@@ -219,7 +263,10 @@ mod __wrapper {
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, E> + 'a,
     {
         Box::new(move |set: &mut Set, ser: &mut Ser, ctx: &mut Ctx| {
+            ctx.check_deadline()?;
             let val = (handler)(set, ser, ctx).map_err(Into::into)?;
+            ctx.check_deadline()?;
+
             let arg = ctx.arg()?.map(|v| v.as_ref());
             let uid = ctx.uid()?;
 
@@ -11,6 +11,7 @@ use crate::Uid;
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind {
     MissingValue,
 
@@ -41,6 +42,16 @@ pub enum Kind {
     UnexceptedPos,
 
     ThreadLocalAccess,
+
+    Cancelled,
+
+    SubCommandFailed,
+
+    Timeout,
+
+    PosCount,
+
+    OptConflict,
 }
 
 impl Kind {
@@ -49,17 +60,50 @@ impl Kind {
             Kind::UnexceptedPos => Some("can not insert Pos@1 if Cmd exist"),
             Kind::ThreadLocalAccess => Some("failed access thread local variable"),
             Kind::NoParserMatched => Some("all parser passed to `getopt!` match failed"),
+            Kind::Cancelled => Some("parsing cancelled by CancelToken"),
+            Kind::Timeout => Some("handler execution exceeded the deadline"),
             _ => None,
         }
     }
+
+    /// A stable code of error kind, such as `missing_value`.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Kind::MissingValue => "missing_value",
+            Kind::PosRequired => "pos_required",
+            Kind::OptRequired => "opt_required",
+            Kind::CmdRequired => "cmd_required",
+            Kind::OptionNotFound => "option_not_found",
+            Kind::ExtractValue => "extract_value",
+            Kind::RawValParse => "raw_val_parse",
+            Kind::Arg => "arg",
+            Kind::IndexParse => "index_parse",
+            Kind::CreateStrParse => "create_str_parse",
+            Kind::Failure => "failure",
+            Kind::Error => "error",
+            Kind::NoParserMatched => "no_parser_matched",
+            Kind::UnexceptedPos => "unexcepted_pos",
+            Kind::ThreadLocalAccess => "thread_local_access",
+            Kind::Cancelled => "cancelled",
+            Kind::SubCommandFailed => "sub_command_failed",
+            Kind::Timeout => "timeout",
+            Kind::PosCount => "pos_count",
+            Kind::OptConflict => "opt_conflict",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
     uid: Option<Uid>,
 
     kind: Kind,
 
+    field: Option<String>,
+
+    token: Option<String>,
+
     desp: Option<String>,
 
     cause: Option<Box<Error>>,
@@ -75,7 +119,7 @@ impl std::error::Error for Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let desp = self.desp.as_deref().or(self.kind.desp());
+        let desp = self.desp();
 
         assert!(
             desp.is_some(),
@@ -96,6 +140,8 @@ impl Error {
         Self {
             kind,
             uid: None,
+            field: None,
+            token: None,
             desp: None,
             cause: None,
         }
@@ -115,6 +161,18 @@ impl Error {
         self
     }
 
+    /// Set the name of option or argument which the error is related to.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Set the raw value which the error is related to.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
     pub fn with_desp(mut self, desp: String) -> Self {
         self.desp = Some(desp);
         self
@@ -128,10 +186,108 @@ impl Error {
         &self.kind
     }
 
+    /// Return the stable code of error, see [`Kind::code`].
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// Return the name of option or argument which the error is related to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--name=s!")?;
+    ///
+    /// let ret = parser.parse(Args::from(["app"]))?;
+    /// let error = ret.failure().unwrap();
+    ///
+    /// assert_eq!(error.code(), "opt_required");
+    /// assert_eq!(error.field(), Some("--name"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// Return the raw value which the error is related to, such as `1.5x` of `--ratio=1.5x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--ratio=f")?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "--ratio=1.5x"]))?;
+    /// let error = ret.failure().unwrap().find_kind(ErrorKind::RawValParse).unwrap();
+    ///
+    /// assert_eq!(error.token(), Some("1.5x"));
+    /// assert_eq!(
+    ///     error.caused_by().and_then(|v| v.desp()),
+    ///     Some("invalid float literal")
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Return the description of error without uid.
+    pub fn desp(&self) -> Option<&str> {
+        self.desp.as_deref().or(self.kind.desp())
+    }
+
     pub fn caused_by(&self) -> Option<&Error> {
         self.cause.as_deref()
     }
 
+    /// Return an iterator over the error and the errors in its cause chain.
+    pub fn chain(&self) -> impl Iterator<Item = &Error> {
+        std::iter::successors(Some(self), |v| v.caused_by())
+    }
+
+    /// Find the first error with given [`Kind`] in the cause chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use aopt::ErrorKind;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--count=i")?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "--count=foo"]))?;
+    /// let error = Error::sp_sub_command("app", "None").cause_by(ret.failure().unwrap().clone());
+    ///
+    /// assert_eq!(error.kind(), &ErrorKind::SubCommandFailed);
+    /// assert!(error.find_kind(ErrorKind::RawValParse).is_some());
+    /// assert!(error.find_kind(ErrorKind::MissingValue).is_none());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_kind(&self, kind: Kind) -> Option<&Error> {
+        self.chain().find(|v| v.kind == kind)
+    }
+
     /// The error can be moitted if [`is_failure`](Error::is_failure) return true.
     pub fn is_failure(&self) -> bool {
         let kind = &self.kind;
@@ -144,8 +300,11 @@ impl Error {
                 | Kind::OptionNotFound
                 | Kind::CmdRequired
                 | Kind::PosRequired
+                | Kind::PosCount
+                | Kind::OptConflict
                 | Kind::OptRequired
                 | Kind::MissingValue
+                | Kind::SubCommandFailed
         )
     }
 
@@ -154,6 +313,24 @@ impl Error {
         Self::new(Kind::UnexceptedPos)
     }
 
+    /// The parsing process or handler was cancelled by [`CancelToken`](crate::ctx::CancelToken).
+    pub fn cancelled() -> Self {
+        Self::new(Kind::Cancelled)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == Kind::Cancelled
+    }
+
+    /// The handler was not finished before the deadline, see [`Ctx::deadline`](crate::ctx::Ctx::deadline).
+    pub fn timeout() -> Self {
+        Self::new(Kind::Timeout)
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.kind == Kind::Timeout
+    }
+
     pub fn thread_local_access() -> Self {
         Self::new(Kind::ThreadLocalAccess)
     }
@@ -174,8 +351,21 @@ impl Error {
 
     pub fn sp_rawval(val: Option<&OsStr>, hint: impl Into<String>) -> Self {
         let desp = format!("invalid value `{}`: {}", display_of_osstr(val), hint.into());
+        let error = Self::new(Kind::RawValParse).with_desp(desp);
+
+        match val {
+            Some(val) => error.with_token(val.to_string_lossy()),
+            None => error,
+        }
+    }
 
-        Self::new(Kind::RawValParse).with_desp(desp)
+    /// Failed to parse the raw value into type `ty`, the `error` is kept as the cause.
+    pub fn sp_parse_val<E>(val: Option<&OsStr>, ty: &str, error: E) -> Self
+    where
+        E: std::error::Error + Display,
+    {
+        Self::sp_rawval(val, format!("not a valid value of type {}", ty))
+            .cause_by(Self::from(error))
     }
 
     pub fn index_parse(pat: impl Into<String>, hint: impl Into<String>) -> Self {
@@ -203,9 +393,12 @@ impl Error {
     }
 
     pub fn sp_missing_value(name: impl Into<String>) -> Self {
-        let desp = format!("missing value for option `{}`", name.into());
+        let name = name.into();
+        let desp = format!("missing value for option `{}`", name);
 
-        Self::new(Kind::MissingValue).with_desp(desp)
+        Self::new(Kind::MissingValue)
+            .with_desp(desp)
+            .with_field(name)
     }
 
     pub fn sp_pos_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -219,7 +412,16 @@ impl Error {
             }
         };
 
-        Self::new(Kind::PosRequired).with_desp(desp)
+        Self::new(Kind::PosRequired)
+            .with_desp(desp)
+            .with_fields(&names)
+    }
+
+    /// The number of positional arguments is out of the bound, see [`PosCount`](crate::parser::PosCount).
+    pub fn sp_pos_count(count: usize, expect: impl Display) -> Self {
+        let desp = format!("expect {} positional arguments, but got {}", expect, count);
+
+        Self::new(Kind::PosCount).with_desp(desp)
     }
 
     pub fn sp_opt_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -233,7 +435,54 @@ impl Error {
             }
         };
 
-        Self::new(Kind::OptRequired).with_desp(desp)
+        Self::new(Kind::OptRequired)
+            .with_desp(desp)
+            .with_fields(&names)
+    }
+
+    /// The force required options are missing, `opts` are the hint and help of them,
+    /// such as ``missing required: `--out` (Set the output), `--name` ``.
+    pub fn sp_opt_missing<S: Into<String>>(opts: Vec<(S, S)>) -> Self {
+        let opts: Vec<(String, String)> = opts
+            .into_iter()
+            .map(|(hint, help)| (hint.into(), help.into()))
+            .collect();
+        let names: Vec<String> = opts.iter().map(|(hint, _)| hint.clone()).collect();
+        let desp = opts
+            .iter()
+            .map(|(hint, help)| {
+                if help.is_empty() {
+                    format!("`{}`", hint)
+                } else {
+                    format!("`{}` ({})", hint, help)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Self::new(Kind::OptRequired)
+            .with_desp(format!("missing required: {}", desp))
+            .with_fields(&names)
+    }
+
+    /// The option `depend` is required by option `name`.
+    pub fn sp_opt_require_by(name: impl Into<String>, depend: impl Into<String>) -> Self {
+        let depend = depend.into();
+        let desp = format!("option `{}` is required by `{}`", depend, name.into());
+
+        Self::new(Kind::OptRequired)
+            .with_desp(desp)
+            .with_field(depend)
+    }
+
+    /// The option `name` can not be set together with option `other`.
+    pub fn sp_opt_conflict(name: impl Into<String>, other: impl Into<String>) -> Self {
+        let name = name.into();
+        let desp = format!("option `{}` conflicts with `{}`", name, other.into());
+
+        Self::new(Kind::OptConflict)
+            .with_desp(desp)
+            .with_field(name)
     }
 
     pub fn sp_cmd_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -247,13 +496,57 @@ impl Error {
             }
         };
 
-        Self::new(Kind::CmdRequired).with_desp(desp)
+        Self::new(Kind::CmdRequired)
+            .with_desp(desp)
+            .with_fields(&names)
+    }
+
+    pub fn sp_sub_command(name: impl Into<String>, hint: impl Into<String>) -> Self {
+        let name = name.into();
+        let desp = format!("Parsing command `{}` failed: {}", name, hint.into());
+
+        Self::new(Kind::SubCommandFailed)
+            .with_desp(desp)
+            .with_field(name)
     }
 
     pub fn sp_not_found(name: impl Into<String>) -> Self {
-        let desp = format!("can not find option `{}`", name.into());
+        let name = name.into();
+        let desp = format!("can not find option `{}`", name);
 
-        Self::new(Kind::OptionNotFound).with_desp(desp)
+        Self::new(Kind::OptionNotFound)
+            .with_desp(desp)
+            .with_field(name)
+    }
+
+    /// Same as [`sp_not_found`](Error::sp_not_found), suggest the `similar` names in description.
+    pub fn sp_not_found_similar<S: Into<String>>(name: impl Into<String>, similar: Vec<S>) -> Self {
+        let name = name.into();
+        let similar: Vec<String> = similar
+            .into_iter()
+            .map(|v| format!("`{}`", v.into()))
+            .collect();
+        let desp = if similar.is_empty() {
+            format!("can not find option `{}`", name)
+        } else {
+            format!(
+                "can not find option `{}`, did you mean {}?",
+                name,
+                similar.join(", ")
+            )
+        };
+
+        Self::new(Kind::OptionNotFound)
+            .with_desp(desp)
+            .with_field(name)
+    }
+
+    // only set the field if there is one name
+    fn with_fields(self, names: &[String]) -> Self {
+        match names {
+            [name] => self.with_field(name.clone()),
+            _ => self,
+        }
     }
 
     pub fn sp_extract(msg: impl Into<String>) -> Self {
@@ -1,20 +1,24 @@
 use crate::ctx::Invoker;
 use crate::opt::AOpt;
+use crate::opt::ConfigBuildInfer;
 use crate::opt::Creator;
 use crate::opt::OptConfig;
 use crate::opt::StrParser;
 use crate::parser::DefaultSetChecker;
 use crate::parser::DelayPolicy;
+use crate::parser::FlagPolicy;
 use crate::parser::FwdPolicy;
 use crate::parser::Parser;
 use crate::parser::Policy;
 use crate::parser::PrePolicy;
+use crate::parser::UserStyle;
 use crate::prelude::OptParser;
 use crate::prelude::OptValidator;
 use crate::prelude::SetChecker;
 use crate::ser::AppServices;
 use crate::set::OptSet;
 use crate::set::PrefixOptValidator;
+use crate::value::Passthrough;
 use crate::Error;
 
 pub trait APolicyExt<P: Policy> {
@@ -39,12 +43,16 @@ pub type APrePolicy = PrePolicy<ASet, ASer, DefaultSetChecker<ASet>>;
 
 pub type ADelayPolicy = DelayPolicy<ASet, ASer, DefaultSetChecker<ASet>>;
 
+pub type AFlagPolicy = FlagPolicy<ASet, ASer, DefaultSetChecker<ASet>>;
+
 pub type AFwdParser<'a> = Parser<'a, AFwdPolicy>;
 
 pub type APreParser<'a> = Parser<'a, APrePolicy>;
 
 pub type ADelayParser<'a> = Parser<'a, ADelayPolicy>;
 
+pub type AFlagParser<'a> = Parser<'a, AFlagPolicy>;
+
 impl<Set, Ser, Chk> APolicyExt<FwdPolicy<Set, Ser, Chk>> for FwdPolicy<Set, Ser, Chk>
 where
     Ser: Default,
@@ -101,3 +109,141 @@ where
         Invoker::<Set, Ser>::default()
     }
 }
+
+impl<Set, Ser, Chk> APolicyExt<FlagPolicy<Set, Ser, Chk>> for FlagPolicy<Set, Ser, Chk>
+where
+    Ser: Default,
+    Chk: SetChecker<Set>,
+    Set: crate::set::Set + Default,
+{
+    fn default_set(&self) -> Set {
+        Set::default()
+    }
+
+    fn default_ser(&self) -> Ser {
+        Ser::default()
+    }
+
+    fn default_inv<'a>(&self) -> <FlagPolicy<Set, Ser, Chk> as Policy>::Inv<'a> {
+        Invoker::<Set, Ser>::default()
+    }
+}
+
+impl APrePolicy {
+    /// Create the policy for `git` style application, the global options
+    /// are processed before sub commands.
+    ///
+    /// * The policy is in [`partial`](PrePolicy::with_partial) mode,
+    ///   the arguments of sub command are left in [`Return::args`](crate::parser::Return::args).
+    /// * After the sub command, only the [`global`](crate::opt::Opt::global) options are matched.
+    /// * Support `--opt=value`, `--opt value`, `--flag` and combined boolean options like `-ab`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = APreParser::git_style();
+    ///
+    /// parser.add_opt("-C=s")?;
+    /// parser.add_opt("--paginate=b")?.set_global(true);
+    /// parser.add_opt("commit=c")?;
+    ///
+    /// let ret = parser.parse(Args::from(["git", "-C", "repo", "commit", "-a", "-m", "msg", "--paginate"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<String>("-C")?, "repo");
+    /// assert_eq!(parser.find_val::<bool>("--paginate")?, &true);
+    ///
+    /// // the arguments of sub command
+    /// let mut commit = AFwdParser::default();
+    ///
+    /// commit.add_opt("-a=b")?;
+    /// commit.add_opt("-m=s")?;
+    /// commit.parse(Args::from(ret.clone_args()))?;
+    ///
+    /// assert_eq!(commit.find_val::<bool>("-a")?, &true);
+    /// assert_eq!(commit.find_val::<String>("-m")?, "msg");
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn git_style() -> Self {
+        Self::default().with_partial(true).with_styles(vec![
+            UserStyle::EqualWithValue,
+            UserStyle::Argument,
+            UserStyle::Boolean,
+            UserStyle::CombinedOption,
+        ])
+    }
+}
+
+impl AFwdPolicy {
+    /// Create the policy for compiler style application.
+    ///
+    /// Support `-O2` or `-Wall` which the value is embedded in the option string,
+    /// beside the `--opt=value`, `--opt value` and `--flag`.
+    /// Add the value-carrying names with [`add_embedded`](crate::parser::OptStyleManager::add_embedded)
+    /// if they are overlapped with other options.
+    pub fn compiler_style() -> Self {
+        Self::default().with_styles(vec![
+            UserStyle::EqualWithValue,
+            UserStyle::Argument,
+            UserStyle::Boolean,
+            UserStyle::EmbeddedValue,
+            UserStyle::EmbeddedValuePlus,
+        ])
+    }
+}
+
+impl APreParser<'_> {
+    /// Create a parser using [`APrePolicy::git_style`].
+    pub fn git_style() -> Self {
+        Parser::new_policy(APrePolicy::git_style())
+    }
+}
+
+impl AFwdParser<'_> {
+    /// Create a parser using [`AFwdPolicy::compiler_style`].
+    ///
+    /// The `+` is added to the option prefixes, and an [`Passthrough`] option `--`
+    /// collects the arguments after it verbatim.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::value::Passthrough;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsString;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::compiler_style()?;
+    ///
+    /// parser.add_opt("-O=i")?;
+    /// parser.add_opt("-W=s")?.set_action(Action::App);
+    /// parser.add_opt("+x=b")?;
+    /// parser.add_opt("-o=s")?;
+    /// parser.add_opt("file=p@1")?.set_pos_type::<String>();
+    ///
+    /// parser.parse(Args::from(["cc", "-O2", "-Wall", "-Wextra", "+x", "-o", "a.out", "main.c", "--", "-v"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<i64>("-O")?, &2);
+    /// assert_eq!(parser.find_vals::<String>("-W")?, &["all", "extra"]);
+    /// assert_eq!(parser.find_val::<bool>("+x")?, &true);
+    /// assert_eq!(parser.find_val::<String>("-o")?, "a.out");
+    /// assert_eq!(parser.find_val::<String>("file")?, "main.c");
+    /// assert_eq!(parser.find_val::<Passthrough>("--")?.args(), &[OsString::from("-v")]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compiler_style() -> Result<Self, Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::compiler_style());
+
+        parser.validator_mut().add_prefix("+");
+        parser.add_opt("passthrough".infer::<Passthrough>())?;
+        Ok(parser)
+    }
+}
@@ -4,6 +4,7 @@ use std::ffi::OsStr;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
+use crate::opt::Opt;
 use crate::opt::Style;
 use crate::opt::BOOL_TRUE;
 use crate::parser::FailManager;
@@ -12,8 +13,11 @@ use crate::set::OptValidator;
 use crate::str::CowStrUtils;
 use crate::trace;
 use crate::Error;
+use crate::Uid;
 
+use super::invoke_or_dry_run;
 use super::process_handler_ret;
+use super::sort_by_priority;
 use super::style::*;
 use super::GuessPolicy;
 use super::InnerCtxSaver;
@@ -189,16 +193,16 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<MainStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, true)?;
                     }
                 }
             }
-            UserStyle::Pos => {
+            UserStyle::Pos | UserStyle::Assign => {
                 if let Some(mut policy) =
                     GuessPolicy::<PosStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, true)?;
                     }
                 }
@@ -207,7 +211,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<CmdStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, true)?;
                     }
                 }
@@ -216,7 +220,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -226,7 +230,7 @@ where
                     GuessPolicy::<ArgumentStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
                     consume = true;
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -235,7 +239,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EmbeddedValueStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -246,7 +250,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, overload, consume)? {
+                    if self.match_multi(style, &mut policy, overload, consume)? {
                         matched = self.invoke_multi(&mut policy, false)?;
                     }
                 }
@@ -257,7 +261,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, overload, consume)? {
+                    if self.match_multi(style, &mut policy, overload, consume)? {
                         matched = self.invoke_multi(&mut policy, false)?;
                     }
                 }
@@ -266,7 +270,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<BooleanStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -275,7 +279,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<FlagStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, consume)? {
+                    if self.match_style(style, &mut policy, overload, consume)? {
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -303,18 +307,18 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<MainStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
                     }
                 }
             }
-            UserStyle::Pos => {
+            UserStyle::Pos | UserStyle::Assign => {
                 if let Some(mut policy) =
                     GuessPolicy::<PosStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -325,7 +329,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<CmdStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -336,7 +340,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -347,7 +351,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<ArgumentStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, true)? {
+                    if self.match_style(style, &mut policy, overload, true)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default()
                                 .with_policy_ctx(vec![inner_ctx])
@@ -360,7 +364,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EmbeddedValueStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -373,7 +377,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, overload, false)? {
+                    if self.match_multi(style, &mut policy, overload, false)? {
                         ret = Some(self.collect_ctxs(&mut policy, false)?);
                     }
                 }
@@ -384,7 +388,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, overload, false)? {
+                    if self.match_multi(style, &mut policy, overload, false)? {
                         ret = Some(self.collect_ctxs(&mut policy, false)?);
                     }
                 }
@@ -393,7 +397,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<BooleanStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -404,7 +408,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<FlagStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, overload, false)? {
+                    if self.match_style(style, &mut policy, overload, false)? {
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
@@ -735,11 +739,63 @@ where
     where
         T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
     {
-        let uids = self.set.keys();
+        self.match_impl(None, policy, overload, consume)
+    }
+
+    /// Match the `policy` with the options allow the user `style`.
+    pub fn match_style<T>(
+        &mut self,
+        style: &UserStyle,
+        policy: &mut T,
+        overload: bool,
+        consume: bool,
+    ) -> Result<bool, Error>
+    where
+        T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
+    {
+        self.match_impl(Some(style), policy, overload, consume)
+    }
+
+    /// Return the uids of options may match the argument.
+    /// The options are looked up by name if `style` is option style,
+    /// the [`Cmd`](crate::opt::Cmd), [`Pos`](crate::opt::Pos) and [`Main`](crate::opt::Main)
+    /// are matched by other ways, return all the uids for them.
+    /// The uids are sorted by the priority of options.
+    fn candidates(&mut self, style: Style, name: Option<&str>) -> Vec<Uid> {
+        let mut uids = match (style, name) {
+            (Style::Argument | Style::Boolean | Style::Combined | Style::Flag, Some(name)) => {
+                self.set.candidates(name)
+            }
+            _ => self.set.keys(),
+        };
+
+        sort_by_priority(self.set, &mut uids);
+        uids
+    }
+
+    /// Return false if the option of `uid` not allow the user `style`.
+    fn allow_style(&self, uid: Uid, style: Option<&UserStyle>) -> bool {
+        match (style, self.set.get(uid)) {
+            (Some(style), Some(opt)) => opt.mat_user_style(style),
+            _ => true,
+        }
+    }
+
+    fn match_impl<T>(
+        &mut self,
+        style: Option<&UserStyle>,
+        policy: &mut T,
+        overload: bool,
+        consume: bool,
+    ) -> Result<bool, Error>
+    where
+        T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
+    {
+        let uids = self.candidates(policy.style(), policy.name().map(|v| v.as_ref()));
 
         for uid in uids {
             // if overload is true select all the option may match the `policy`
-            if !policy.filter(uid, self.set) {
+            if self.allow_style(uid, style) && !policy.filter(uid, self.set) {
                 if let Err(e) = policy.r#match(uid, self.set, overload, consume) {
                     let e = e.into();
 
@@ -758,18 +814,20 @@ where
 
     fn match_multi(
         &mut self,
+        style: &UserStyle,
         policy: &mut MultiOpt<SingleOpt<Set>, Set>,
         overload: bool,
         consume: bool,
     ) -> Result<bool, Error> {
-        let uids = self.set.keys();
         let any_match = policy.any_match();
 
         trace!("any match = {}", any_match);
         for sub_policy in policy.sub_policys_mut() {
+            let uids = self.candidates(sub_policy.style(), sub_policy.name().map(|v| v.as_ref()));
+
             // process all uids with each policy first
             for uid in uids.iter() {
-                if !sub_policy.filter(*uid, self.set) {
+                if self.allow_style(*uid, Some(style)) && !sub_policy.filter(*uid, self.set) {
                     if let Err(e) = sub_policy.r#match(*uid, self.set, overload, consume) {
                         if e.is_failure() {
                             self.fail.push(e);
@@ -804,7 +862,7 @@ where
                 .set_inner_ctx(Some(inner_ctx.clone().with_uid(uid)));
 
             // invoke the handler of `uid`
-            let invoke_ret = self.inv.invoke_fb(&uid, self.set, self.ser, self.ctx);
+            let invoke_ret = invoke_or_dry_run(self.inv, &uid, self.set, self.ser, self.ctx);
             let when_fail = |e| {
                 self.fail.push(e);
                 Ok(())
@@ -814,6 +872,9 @@ where
             if process_handler_ret(invoke_ret, |_| Ok(()), when_fail)? {
                 result = true;
                 policy.apply(uid, self.set).map_err(Into::into)?;
+                if matches!(policy.style(), Style::Cmd | Style::Pos) {
+                    self.ctx.add_noa_matched(policy.idx(), uid, policy.style());
+                }
                 if !all {
                     // may return if first matched, for option
                     // otherwise invoke all the handler, for noa
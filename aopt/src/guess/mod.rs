@@ -7,7 +7,10 @@ pub mod style;
 use std::borrow::Cow;
 use std::ffi::OsStr;
 
+use crate::ctx::Ctx;
+use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
+use crate::opt::Opt;
 use crate::opt::Style;
 use crate::Error;
 use crate::Uid;
@@ -165,6 +168,32 @@ pub trait PolicyBuild<'a> {
     fn with_arg(self, argument: Option<Cow<'a, OsStr>>) -> Self;
 }
 
+/// Invoke the handler of `uid`, or only record the match if the `ctx` is in dry-run mode.
+pub(crate) fn invoke_or_dry_run<'a, Set, Ser, Inv>(
+    inv: &mut Inv,
+    uid: &Uid,
+    set: &mut Set,
+    ser: &mut Ser,
+    ctx: &mut Ctx,
+) -> Result<bool, Error>
+where
+    Set: crate::set::Set,
+    Inv: HandlerCollection<'a, Set, Ser>,
+{
+    if ctx.dry_run() {
+        ctx.add_dry_matched()?;
+        Ok(true)
+    } else {
+        inv.invoke_fb(uid, set, ser, ctx)
+    }
+}
+
+/// Sort the `uids` by [`priority`](crate::opt::Opt::priority) of options, the higher one first.
+/// The sort is stable, the options have same priority keep the order they are inserted.
+pub(crate) fn sort_by_priority<Set: crate::set::Set>(set: &Set, uids: &mut [Uid]) {
+    uids.sort_by_key(|uid| std::cmp::Reverse(set.get(*uid).map(Opt::priority).unwrap_or(0)));
+}
+
 /// Process the return value of handler:
 /// call the callback `when_ret` and return the return value of handler if `Ok`;
 /// ignore failure and call the callback `when_fail` on the failure if `Err`
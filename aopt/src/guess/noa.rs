@@ -6,6 +6,7 @@ use std::marker::PhantomData;
 use crate::ctx::InnerCtx;
 use crate::opt::Opt;
 use crate::opt::Style;
+use crate::opt::CMD_WILDCARD;
 use crate::set::Set;
 use crate::set::SetOpt;
 use crate::Error;
@@ -202,7 +203,24 @@ where
 
             if matched {
                 if !opt.ignore_name() {
-                    matched = matched && opt.mat_name(self.name().map(|v| v.as_ref()));
+                    if opt.mat_style(Style::Cmd) && opt.name() == CMD_WILDCARD {
+                        matched = self.name.as_ref().is_some_and(|name| {
+                            !set.iter().any(|v| {
+                                v.mat_style(Style::Cmd)
+                                    && v.name() != CMD_WILDCARD
+                                    && (v.mat_name(Some(name)) || v.mat_alias(name))
+                            })
+                        });
+                        if matched {
+                            // record the actual command name as raw value
+                            self.arg = self.name.clone().map(|name| match name {
+                                Cow::Borrowed(v) => Cow::Borrowed(OsStr::new(v)),
+                                Cow::Owned(v) => Cow::Owned(v.into()),
+                            });
+                        }
+                    } else {
+                        matched = matched && opt.mat_name(self.name().map(|v| v.as_ref()));
+                    }
                 }
                 if !opt.ignore_alias() && opt.alias().is_some() {
                     if let Some(name) = &self.name {
@@ -17,8 +17,12 @@ pub mod value;
 pub type Uid = u64;
 pub type HashMap<K, V> = ahash::HashMap<K, V>;
 
+/// The reference counted pointer shared the data such as [`Args`](crate::args::Args),
+/// it is [`Arc`](std::sync::Arc) if `sync` feature enabled.
 #[cfg(feature = "sync")]
 pub type ARef<T> = std::sync::Arc<T>;
+/// The reference counted pointer shared the data such as [`Args`](crate::args::Args),
+/// it is [`Rc`](std::rc::Rc) if `sync` feature not enabled, avoids the atomic operations in single-threaded tools.
 #[cfg(not(feature = "sync"))]
 pub type ARef<T> = std::rc::Rc<T>;
 
@@ -33,7 +37,28 @@ pub(crate) mod log {
     }
 }
 
+/// Enter a span for the parse phase, the span is exited when the guard dropped.
+#[cfg(feature = "log")]
+macro_rules! trace_span {
+    ($($arg:tt)*) => {
+        tracing::trace_span!($($arg)*).entered()
+    };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! trace_span {
+    ($($arg:tt)*) => {
+        $crate::NoopSpan
+    };
+}
+
+pub(crate) use trace_span;
+
+#[cfg(not(feature = "log"))]
+pub(crate) struct NoopSpan;
+
 pub use crate::err::Error;
+pub use crate::err::Kind as ErrorKind;
 pub use crate::err::Result;
 
 use std::any::TypeId;
@@ -219,17 +244,30 @@ macro_rules! getopt {
 }
 
 pub mod prelude {
+    pub use crate::args::tokenize;
+    pub use crate::args::ArgFile;
+    pub use crate::args::ArgFileEncoding;
     pub use crate::args::Args;
+    #[cfg(feature = "lexer")]
+    pub use crate::args::Lexeme;
+    #[cfg(feature = "lexer")]
+    pub use crate::args::Lexer;
+    pub use crate::args::Token;
+    pub use crate::args::Tokenizer;
     pub use crate::ctx::wrap_handler;
     pub use crate::ctx::wrap_handler_action;
     pub use crate::ctx::wrap_handler_fallback_action;
+    pub use crate::ctx::CancelToken;
     pub use crate::ctx::Ctx;
     pub use crate::ctx::HandlerCollection;
     pub use crate::ctx::InnerCtx;
+    pub use crate::ctx::InvokeNext;
     pub use crate::ctx::Invoker;
+    pub use crate::ctx::Matched;
     pub use crate::ctx::NullStore;
     pub use crate::ctx::Store;
     pub use crate::ctx::VecStore;
+    pub use crate::err::Kind as ErrorKind;
     pub use crate::ext::*;
     pub use crate::getopt;
     pub use crate::map::ErasedTy;
@@ -261,30 +299,53 @@ pub mod prelude {
     pub use crate::opt::Style;
     pub use crate::parser::DefaultSetChecker;
     pub use crate::parser::DelayPolicy;
+    pub use crate::parser::DryMatch;
+    #[cfg(feature = "miette")]
+    pub use crate::parser::FailureReport;
+    pub use crate::parser::FlagPolicy;
     pub use crate::parser::FwdPolicy;
     pub use crate::parser::HCOptSet;
+    pub use crate::parser::NoaMatch;
+    pub use crate::parser::ObserverRef;
     pub use crate::parser::OptStyleManager;
     pub use crate::parser::Parser;
     pub use crate::parser::ParserCommit;
     pub use crate::parser::ParserCommitWithValue;
+    pub use crate::parser::Phase;
+    pub use crate::parser::PhaseList;
+    pub use crate::parser::PhaseStep;
     pub use crate::parser::Policy;
+    pub use crate::parser::PolicyChain;
+    pub use crate::parser::PolicyObserver;
     pub use crate::parser::PolicyParser;
     pub use crate::parser::PolicySettings;
+    pub use crate::parser::PosCount;
     pub use crate::parser::PrePolicy;
     pub use crate::parser::Return;
+    pub use crate::parser::RuleChecker;
     pub use crate::parser::UserStyle;
+    pub use crate::parser::ValueRule;
     pub use crate::ser::AppServices;
+    #[cfg(feature = "lazy")]
+    pub use crate::ser::Lazy;
     pub use crate::ser::ServicesValExt;
     pub use crate::ser::UsrValService;
     pub use crate::set::ctor_default_name;
+    pub use crate::set::diff_set;
+    pub use crate::set::profile_handler;
     pub use crate::set::Commit;
+    pub use crate::set::Conflict;
     pub use crate::set::Ctor;
     pub use crate::set::Filter;
     pub use crate::set::FilterMatcher;
     pub use crate::set::FilterMut;
+    pub use crate::set::OptDiff;
     pub use crate::set::OptSet;
+    pub use crate::set::OptSpec;
     pub use crate::set::OptValidator;
     pub use crate::set::PrefixOptValidator;
+    pub use crate::set::Profile;
+    pub use crate::set::Redactor;
     pub use crate::set::Set;
     pub use crate::set::SetCfg;
     pub use crate::set::SetChecker;
@@ -292,16 +353,28 @@ pub mod prelude {
     pub use crate::set::SetCommitWithValue;
     pub use crate::set::SetExt;
     pub use crate::set::SetOpt;
+    #[cfg(feature = "serde")]
+    pub use crate::set::SetSchema;
     pub use crate::set::SetValueFindExt;
+    pub use crate::set::ValueSources;
     pub use crate::value::AnyValue;
+    pub use crate::value::BoolLiterals;
+    pub use crate::value::ColorChoice;
+    pub use crate::value::Decoding;
     pub use crate::value::ErasedValue;
+    pub use crate::value::FloatSyntax;
     pub use crate::value::Infer;
     pub use crate::value::InitializeValue;
+    pub use crate::value::Normalize;
+    pub use crate::value::Normalizer;
+    pub use crate::value::NumLocale;
     pub use crate::value::RawValParser;
     pub use crate::value::ValAccessor;
     pub use crate::value::ValInitializer;
     pub use crate::value::ValStorer;
     pub use crate::value::ValValidator;
+    pub use crate::value::ValueEnum;
+    pub use crate::value::ValueSource;
     pub use crate::ARef;
     pub use crate::GetoptRes;
     pub use crate::Uid;
@@ -6,6 +6,7 @@ use crate::map::ErasedTy;
 use crate::set::SetExt;
 use crate::set::SetOpt;
 use crate::value::AnyValue;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -14,11 +15,15 @@ use super::Opt;
 /// The default action type for option value saving, see [`Action::process`].
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum Action {
     /// Set and replace current value of [`AnyValue`]
     Set,
 
+    /// Same as [`Set`](Action::Set), but return a failure if the option is specified more than once
+    SetOnce,
+
     /// Append value into [`AnyValue`]
     App,
 
@@ -41,6 +46,10 @@ impl Action {
         matches!(self, Self::Set)
     }
 
+    pub fn is_set_once(&self) -> bool {
+        matches!(self, Self::SetOnce)
+    }
+
     pub fn is_app(&self) -> bool {
         matches!(self, Self::App)
     }
@@ -72,7 +81,7 @@ impl Action {
         );
         if let Some(val) = val {
             match self {
-                Action::Set => {
+                Action::Set | Action::SetOnce => {
                     handler.set(vec![val]);
                 }
                 Action::App => {
@@ -124,6 +133,8 @@ impl Action {
 ///
 /// * [`Action::Set`] : Set the option value to `vec![ val ]`.
 ///
+/// * [`Action::SetOnce`] : Same as [`Action::Set`], return a failure if the option already has a value from command line.
+///
 /// * [`Action::App`] : Append the value to value vector.
 ///
 /// * [`Action::Pop`] : Pop last value from value vector.
@@ -153,6 +164,7 @@ where
     ) -> Result<Self::Ret, Self::Error> {
         let opt = set.opt_mut(uid)?;
 
+        check_once(opt, self)?;
         crate::trace!("storing value of {} = `{:?}`", opt.name(), raw);
         let (raw_handler, handler) = opt.accessor_mut().handlers();
         // Set the value if return Some(Value)
@@ -160,12 +172,28 @@ where
     }
 }
 
+/// Return a failure if the option using [`Action::SetOnce`] already has a value from command line,
+/// so that the policy can continue with other options.
+pub(crate) fn check_once<O: Opt>(opt: &O, act: &Action) -> Result<(), Error> {
+    if act.is_set_once() && opt.accessor().source() == ValueSource::Cli {
+        Err(
+            crate::raise_failure!("option `{}` specified multiple times", opt.name())
+                .with_uid(opt.uid()),
+        )
+    } else {
+        Ok(())
+    }
+}
+
 impl std::fmt::Display for Action {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Action::Set => {
                 write!(f, "Action::Set")
             }
+            Action::SetOnce => {
+                write!(f, "Action::SetOnce")
+            }
             Action::App => {
                 write!(f, "Action::App")
             }
@@ -184,3 +212,56 @@ impl std::fmt::Display for Action {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_set_once() {
+        assert!(testing_set_once_main().is_ok());
+    }
+
+    fn testing_set_once_main() -> Result<(), Error> {
+        /// Return true if the failure of set once reported in the return value or error.
+        fn reported(ret: &Result<Return, Error>, name: &str) -> bool {
+            let msg = format!("option `{name}` specified multiple times");
+
+            match ret {
+                Ok(ret) => ret.failure(),
+                Err(e) => Some(e),
+            }
+            .is_some_and(|e| e.chain().any(|v| v.to_string().contains(&msg)))
+        }
+
+        fn check<P>(mut parser: Parser<'_, P>, report: bool) -> Result<(), Error>
+        where
+            P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+        {
+            parser.add_opt("--name;-n=s")?.set_action(Action::SetOnce);
+            parser.add_opt("--debug=b")?.set_action(Action::SetOnce);
+
+            parser.parse(Args::from(["app", "-n", "foo", "--debug"]))?;
+            assert_eq!(parser.find_val::<String>("--name")?, "foo");
+            assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+
+            let ret = parser.parse(Args::from(["app", "-n", "foo", "--name=bar"]));
+
+            assert_eq!(reported(&ret, "--name"), report);
+            // the first value is kept
+            assert_eq!(parser.find_val::<String>("--name")?, "foo");
+
+            let ret = parser.parse(Args::from(["app", "--debug", "--debug"]));
+
+            assert_eq!(reported(&ret, "--debug"), report);
+            Ok(())
+        }
+
+        check(AFwdParser::default(), true)?;
+        // the pre policy ignores the failure, leave the argument to others
+        check(APreParser::default(), false)?;
+        check(ADelayParser::default(), true)?;
+        Ok(())
+    }
+}
@@ -13,8 +13,10 @@ use crate::opt::Opt;
 #[allow(unused)]
 use crate::opt::Pos;
 use crate::opt::Style;
+use crate::parser::UserStyle;
 use crate::raise_error;
 use crate::value::ErasedValue;
+use crate::value::Normalizer;
 use crate::value::ValAccessor;
 use crate::Error;
 use crate::Uid;
@@ -79,6 +81,24 @@ pub struct AOpt {
     ignore_alias: bool,
 
     ignore_index: bool,
+
+    global: bool,
+
+    sensitive: bool,
+
+    force_if_cmd: Option<String>,
+
+    choices: Option<Vec<String>>,
+
+    requires: Vec<String>,
+
+    conflicts_with: Vec<String>,
+
+    priority: i32,
+
+    normalizer: Option<Normalizer>,
+
+    user_styles: Vec<UserStyle>,
 }
 
 impl AOpt {
@@ -98,6 +118,15 @@ impl AOpt {
             ignore_name: false,
             ignore_alias: false,
             ignore_index: false,
+            global: false,
+            sensitive: false,
+            force_if_cmd: None,
+            choices: None,
+            requires: vec![],
+            conflicts_with: vec![],
+            priority: 0,
+            normalizer: None,
+            user_styles: vec![],
         }
     }
 
@@ -137,6 +166,60 @@ impl AOpt {
         self
     }
 
+    /// If the option can be matched after the sub command.
+    pub fn with_global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
+
+    /// If the value of option should be redacted when it is persisted.
+    pub fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    /// The option is force required if the [`Cmd`](crate::opt::Cmd) `cmd` is matched.
+    pub fn with_force_if_cmd(mut self, cmd: Option<String>) -> Self {
+        self.force_if_cmd = cmd;
+        self
+    }
+
+    /// Set the possible values of option.
+    pub fn with_choices(mut self, choices: Option<Vec<String>>) -> Self {
+        self.choices = choices;
+        self
+    }
+
+    /// The options must be set if the option is set.
+    pub fn with_requires(mut self, names: Vec<String>) -> Self {
+        self.requires = names;
+        self
+    }
+
+    /// The options can not be set if the option is set.
+    pub fn with_conflicts_with(mut self, names: Vec<String>) -> Self {
+        self.conflicts_with = names;
+        self
+    }
+
+    /// The priority of option when matching an argument, default is 0.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// The normalizer applied to the raw value before the handler invoked.
+    pub fn with_normalizer(mut self, normalizer: Option<Normalizer>) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// The user styles allowed by option, empty means all the user style are allowed.
+    pub fn with_user_style(mut self, styles: Vec<UserStyle>) -> Self {
+        self.user_styles = styles;
+        self
+    }
+
     /// Set the hint of option, such as `--option`.
     pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
         self.help.set_hint(hint);
@@ -238,6 +321,51 @@ impl AOpt {
         self
     }
 
+    pub fn set_global(&mut self, global: bool) -> &mut Self {
+        self.global = global;
+        self
+    }
+
+    pub fn set_sensitive(&mut self, sensitive: bool) -> &mut Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    pub fn set_force_if_cmd(&mut self, cmd: Option<String>) -> &mut Self {
+        self.force_if_cmd = cmd;
+        self
+    }
+
+    pub fn set_choices(&mut self, choices: Option<Vec<String>>) -> &mut Self {
+        self.choices = choices;
+        self
+    }
+
+    pub fn set_requires(&mut self, names: Vec<String>) -> &mut Self {
+        self.requires = names;
+        self
+    }
+
+    pub fn set_conflicts_with(&mut self, names: Vec<String>) -> &mut Self {
+        self.conflicts_with = names;
+        self
+    }
+
+    pub fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    pub fn set_user_style(&mut self, styles: Vec<UserStyle>) -> &mut Self {
+        self.user_styles = styles;
+        self
+    }
+
     pub fn add_alias(&mut self, name: impl Into<String>) -> &mut Self {
         if let Some(alias) = &mut self.alias {
             alias.push(name.into());
@@ -324,6 +452,38 @@ impl Opt for AOpt {
         self.ignore_index
     }
 
+    fn global(&self) -> bool {
+        self.global
+    }
+
+    fn sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    fn force_if_cmd(&self) -> Option<&str> {
+        self.force_if_cmd.as_deref()
+    }
+
+    fn choices(&self) -> Option<&Vec<String>> {
+        self.choices.as_ref()
+    }
+
+    fn requires(&self) -> &[String] {
+        &self.requires
+    }
+
+    fn conflicts_with(&self) -> &[String] {
+        &self.conflicts_with
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn normalizer(&self) -> Option<&Normalizer> {
+        self.normalizer.as_ref()
+    }
+
     fn set_uid(&mut self, uid: Uid) {
         self.uid = uid;
     }
@@ -336,6 +496,15 @@ impl Opt for AOpt {
         self.styles.iter().any(|v| v == &style)
     }
 
+    fn mat_user_style(&self, style: &UserStyle) -> bool {
+        // the `name=value` NOA only matched by the options set it explicitly
+        if style == &UserStyle::Assign {
+            self.user_styles.contains(style)
+        } else {
+            self.user_styles.is_empty() || self.user_styles.contains(style)
+        }
+    }
+
     fn mat_force(&self, force: bool) -> bool {
         self.force() == force
     }
@@ -422,6 +591,15 @@ impl TryFrom<OptConfig> for AOpt {
         let ignore_name = value.ignore_name();
         let ignore_alias = value.ignore_alias();
         let ignore_index = value.ignore_index();
+        let global = value.global();
+        let sensitive = value.sensitive();
+        let force_if_cmd = value.take_force_if_cmd();
+        let choices = value.take_choices();
+        let requires = value.take_requires().unwrap_or_default();
+        let conflicts_with = value.take_conflicts_with().unwrap_or_default();
+        let priority = value.take_priority().unwrap_or_default();
+        let normalizer = value.take_normalizer();
+        let user_styles = value.take_user_style().unwrap_or_default();
 
         let force = force.unwrap_or(false);
         let action = action.unwrap_or(Action::App);
@@ -475,7 +653,16 @@ impl TryFrom<OptConfig> for AOpt {
                 .with_opt_help(help)
                 .with_ignore_name(ignore_name)
                 .with_ignore_alias(ignore_alias)
-                .with_ignore_index(ignore_index),
+                .with_ignore_index(ignore_index)
+                .with_global(global)
+                .with_sensitive(sensitive)
+                .with_force_if_cmd(force_if_cmd)
+                .with_choices(choices)
+                .with_requires(requires)
+                .with_conflicts_with(conflicts_with)
+                .with_priority(priority)
+                .with_normalizer(normalizer)
+                .with_user_style(user_styles),
         )
     }
 }
@@ -7,7 +7,9 @@ use crate::opt::Action;
 use crate::opt::Index;
 use crate::opt::Information;
 use crate::opt::OptParser;
+use crate::parser::UserStyle;
 use crate::typeid;
+use crate::value::Normalizer;
 use crate::value::Placeholder;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
@@ -126,13 +128,22 @@ where
         merge!(has_alias, set_alias, take_alias);
         merge!(has_hint, set_hint, take_hint);
         merge!(has_help, set_help, take_help);
+        merge!(has_force_if_cmd, set_force_if_cmd, take_force_if_cmd);
+        merge!(has_choices, set_choices, take_choices);
+        merge!(has_requires, set_requires, take_requires);
+        merge!(has_priority, set_priority, take_priority);
+        merge!(has_conflicts_with, set_conflicts_with, take_conflicts_with);
         merge!(has_action, set_action, take_action);
         merge!(has_storer, set_storer, take_storer);
         merge!(has_style, set_style, take_style);
+        merge!(has_user_style, set_user_style, take_user_style);
         merge!(has_initializer, set_initializer, take_initializer);
+        merge!(has_normalizer, set_normalizer, take_normalizer);
         config.set_ignore_name(config.ignore_name() || init.ignore_name());
         config.set_ignore_alias(config.ignore_alias() || init.ignore_alias());
         config.set_ignore_index(config.ignore_index() || init.ignore_index());
+        config.set_global(config.global() || init.global());
+        config.set_sensitive(config.sensitive() || init.sensitive());
         Ok(config)
     }
 }
@@ -171,6 +182,9 @@ pub trait ConfigValue {
     /// The style support by current option.
     fn style(&self) -> Option<&Vec<Style>>;
 
+    /// The user style allowed by current option, all the user style are allowed if it is [`None`].
+    fn user_style(&self) -> Option<&Vec<UserStyle>>;
+
     /// Value initializer for option.
     fn initializer(&self) -> Option<&ValInitializer>;
 
@@ -207,6 +221,9 @@ pub trait ConfigValue {
     /// The style support by current option.
     fn style_mut(&mut self) -> Option<&mut Vec<Style>>;
 
+    /// The user style allowed by current option.
+    fn user_style_mut(&mut self) -> Option<&mut Vec<UserStyle>>;
+
     /// Value initializer for option.
     fn initializer_mut(&mut self) -> Option<&mut ValInitializer>;
 
@@ -216,6 +233,30 @@ pub trait ConfigValue {
 
     fn ignore_index(&self) -> bool;
 
+    /// If the option can be matched after the sub command.
+    fn global(&self) -> bool;
+
+    /// If the value of option should be redacted when it is persisted.
+    fn sensitive(&self) -> bool;
+
+    /// The option is force required if the [`Cmd`](crate::opt::Cmd) is matched.
+    fn force_if_cmd(&self) -> Option<&str>;
+
+    /// The possible values of option, they are used in documentation only.
+    fn choices(&self) -> Option<&Vec<String>>;
+
+    /// The options must be set if the option is set.
+    fn requires(&self) -> Option<&Vec<String>>;
+
+    /// The options can not be set if the option is set.
+    fn conflicts_with(&self) -> Option<&Vec<String>>;
+
+    /// The priority of option when matching an argument, the higher one is matched first.
+    fn priority(&self) -> Option<i32>;
+
+    /// The normalizer applied to the raw value of option.
+    fn normalizer(&self) -> Option<&Normalizer>;
+
     fn has_ctor(&self) -> bool;
 
     fn has_type(&self) -> bool;
@@ -228,6 +269,18 @@ pub trait ConfigValue {
 
     fn has_hint(&self) -> bool;
 
+    fn has_force_if_cmd(&self) -> bool;
+
+    fn has_choices(&self) -> bool;
+
+    fn has_requires(&self) -> bool;
+
+    fn has_conflicts_with(&self) -> bool;
+
+    fn has_priority(&self) -> bool;
+
+    fn has_normalizer(&self) -> bool;
+
     fn has_help(&self) -> bool;
 
     fn has_alias(&self) -> bool;
@@ -238,6 +291,8 @@ pub trait ConfigValue {
 
     fn has_style(&self) -> bool;
 
+    fn has_user_style(&self) -> bool;
+
     fn has_initializer(&self) -> bool;
 
     fn set_ctor(&mut self, ctor: impl Into<String>) -> &mut Self;
@@ -270,6 +325,8 @@ pub trait ConfigValue {
 
     fn set_style(&mut self, styles: Vec<Style>) -> &mut Self;
 
+    fn set_user_style(&mut self, styles: Vec<UserStyle>) -> &mut Self;
+
     fn set_initializer(&mut self, initializer: ValInitializer) -> &mut Self;
 
     fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self;
@@ -278,6 +335,26 @@ pub trait ConfigValue {
 
     fn set_ignore_index(&mut self, ignore_index: bool) -> &mut Self;
 
+    fn set_global(&mut self, global: bool) -> &mut Self;
+
+    fn set_sensitive(&mut self, sensitive: bool) -> &mut Self;
+
+    fn set_force_if_cmd(&mut self, cmd: impl Into<String>) -> &mut Self;
+
+    fn set_choices(&mut self, choices: Vec<impl Into<String>>) -> &mut Self;
+
+    fn set_requires(&mut self, names: Vec<impl Into<String>>) -> &mut Self;
+
+    fn add_requires(&mut self, name: impl Into<String>) -> &mut Self;
+
+    fn set_conflicts_with(&mut self, names: Vec<impl Into<String>>) -> &mut Self;
+
+    fn add_conflicts_with(&mut self, name: impl Into<String>) -> &mut Self;
+
+    fn set_priority(&mut self, priority: i32) -> &mut Self;
+
+    fn set_normalizer(&mut self, normalizer: impl Into<Normalizer>) -> &mut Self;
+
     fn take_ctor(&mut self) -> Option<String>;
 
     fn take_type(&mut self) -> Option<TypeId>;
@@ -292,6 +369,18 @@ pub trait ConfigValue {
 
     fn take_hint(&mut self) -> Option<String>;
 
+    fn take_force_if_cmd(&mut self) -> Option<String>;
+
+    fn take_choices(&mut self) -> Option<Vec<String>>;
+
+    fn take_requires(&mut self) -> Option<Vec<String>>;
+
+    fn take_conflicts_with(&mut self) -> Option<Vec<String>>;
+
+    fn take_priority(&mut self) -> Option<i32>;
+
+    fn take_normalizer(&mut self) -> Option<Normalizer>;
+
     fn take_help(&mut self) -> Option<String>;
 
     fn take_action(&mut self) -> Option<Action>;
@@ -300,6 +389,8 @@ pub trait ConfigValue {
 
     fn take_style(&mut self) -> Option<Vec<Style>>;
 
+    fn take_user_style(&mut self) -> Option<Vec<UserStyle>>;
+
     fn take_initializer(&mut self) -> Option<ValInitializer>;
 
     fn infer_builtin_ty(&mut self);
@@ -322,6 +413,8 @@ pub trait ConfigValue {
 
     fn with_style(self, styles: Vec<Style>) -> Self;
 
+    fn with_user_style(self, styles: Vec<UserStyle>) -> Self;
+
     fn with_action(self, action: Action) -> Self;
 
     fn with_storer(self, storer: ValStorer) -> Self;
@@ -332,6 +425,22 @@ pub trait ConfigValue {
 
     fn with_ignore_name(self, ignore_name: bool) -> Self;
 
+    fn with_global(self, global: bool) -> Self;
+
+    fn with_sensitive(self, sensitive: bool) -> Self;
+
+    fn with_force_if_cmd(self, cmd: impl Into<String>) -> Self;
+
+    fn with_choices(self, choices: Vec<impl Into<String>>) -> Self;
+
+    fn with_requires(self, names: Vec<impl Into<String>>) -> Self;
+
+    fn with_conflicts_with(self, names: Vec<impl Into<String>>) -> Self;
+
+    fn with_priority(self, priority: i32) -> Self;
+
+    fn with_normalizer(self, normalizer: impl Into<Normalizer>) -> Self;
+
     fn with_initializer(self, initializer: ValInitializer) -> Self;
 }
 
@@ -366,7 +475,25 @@ pub struct OptConfig {
 
     ignore_index: bool,
 
+    global: bool,
+
+    sensitive: bool,
+
+    force_if_cmd: Option<String>,
+
+    choices: Option<Vec<String>>,
+
+    requires: Option<Vec<String>>,
+
+    conflicts_with: Option<Vec<String>>,
+
+    priority: Option<i32>,
+
+    normalizer: Option<Normalizer>,
+
     styles: Option<Vec<Style>>,
+
+    user_styles: Option<Vec<UserStyle>>,
 }
 
 impl ConfigValue for OptConfig {
@@ -414,6 +541,10 @@ impl ConfigValue for OptConfig {
         self.styles.as_ref()
     }
 
+    fn user_style(&self) -> Option<&Vec<UserStyle>> {
+        self.user_styles.as_ref()
+    }
+
     fn initializer(&self) -> Option<&ValInitializer> {
         self.initializer.as_ref()
     }
@@ -462,6 +593,10 @@ impl ConfigValue for OptConfig {
         self.styles.as_mut()
     }
 
+    fn user_style_mut(&mut self) -> Option<&mut Vec<UserStyle>> {
+        self.user_styles.as_mut()
+    }
+
     fn initializer_mut(&mut self) -> Option<&mut ValInitializer> {
         self.initializer.as_mut()
     }
@@ -478,6 +613,38 @@ impl ConfigValue for OptConfig {
         self.ignore_index
     }
 
+    fn global(&self) -> bool {
+        self.global
+    }
+
+    fn sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    fn force_if_cmd(&self) -> Option<&str> {
+        self.force_if_cmd.as_deref()
+    }
+
+    fn choices(&self) -> Option<&Vec<String>> {
+        self.choices.as_ref()
+    }
+
+    fn requires(&self) -> Option<&Vec<String>> {
+        self.requires.as_ref()
+    }
+
+    fn conflicts_with(&self) -> Option<&Vec<String>> {
+        self.conflicts_with.as_ref()
+    }
+
+    fn priority(&self) -> Option<i32> {
+        self.priority
+    }
+
+    fn normalizer(&self) -> Option<&Normalizer> {
+        self.normalizer.as_ref()
+    }
+
     fn has_ctor(&self) -> bool {
         self.ctor.is_some()
     }
@@ -502,6 +669,30 @@ impl ConfigValue for OptConfig {
         self.hint.is_some()
     }
 
+    fn has_force_if_cmd(&self) -> bool {
+        self.force_if_cmd.is_some()
+    }
+
+    fn has_choices(&self) -> bool {
+        self.choices.is_some()
+    }
+
+    fn has_requires(&self) -> bool {
+        self.requires.is_some()
+    }
+
+    fn has_conflicts_with(&self) -> bool {
+        self.conflicts_with.is_some()
+    }
+
+    fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    fn has_normalizer(&self) -> bool {
+        self.normalizer.is_some()
+    }
+
     fn has_help(&self) -> bool {
         self.help.is_some()
     }
@@ -522,6 +713,10 @@ impl ConfigValue for OptConfig {
         self.styles.is_some()
     }
 
+    fn has_user_style(&self) -> bool {
+        self.user_styles.is_some()
+    }
+
     fn has_initializer(&self) -> bool {
         self.initializer.is_some()
     }
@@ -612,6 +807,11 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn set_user_style(&mut self, styles: Vec<UserStyle>) -> &mut Self {
+        self.user_styles = Some(styles);
+        self
+    }
+
     fn set_initializer(&mut self, initializer: ValInitializer) -> &mut Self {
         self.initializer = Some(initializer);
         self
@@ -632,6 +832,56 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn set_global(&mut self, global: bool) -> &mut Self {
+        self.global = global;
+        self
+    }
+
+    fn set_sensitive(&mut self, sensitive: bool) -> &mut Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    fn set_force_if_cmd(&mut self, cmd: impl Into<String>) -> &mut Self {
+        self.force_if_cmd = Some(cmd.into());
+        self
+    }
+
+    fn set_choices(&mut self, choices: Vec<impl Into<String>>) -> &mut Self {
+        self.choices = Some(choices.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn set_requires(&mut self, names: Vec<impl Into<String>>) -> &mut Self {
+        self.requires = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn add_requires(&mut self, name: impl Into<String>) -> &mut Self {
+        self.requires.get_or_insert(vec![]).push(name.into());
+        self
+    }
+
+    fn set_conflicts_with(&mut self, names: Vec<impl Into<String>>) -> &mut Self {
+        self.conflicts_with = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn add_conflicts_with(&mut self, name: impl Into<String>) -> &mut Self {
+        self.conflicts_with.get_or_insert(vec![]).push(name.into());
+        self
+    }
+
+    fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    fn set_normalizer(&mut self, normalizer: impl Into<Normalizer>) -> &mut Self {
+        self.normalizer = Some(normalizer.into());
+        self
+    }
+
     fn take_ctor(&mut self) -> Option<String> {
         self.ctor.take()
     }
@@ -660,6 +910,30 @@ impl ConfigValue for OptConfig {
         self.hint.take()
     }
 
+    fn take_force_if_cmd(&mut self) -> Option<String> {
+        self.force_if_cmd.take()
+    }
+
+    fn take_choices(&mut self) -> Option<Vec<String>> {
+        self.choices.take()
+    }
+
+    fn take_requires(&mut self) -> Option<Vec<String>> {
+        self.requires.take()
+    }
+
+    fn take_conflicts_with(&mut self) -> Option<Vec<String>> {
+        self.conflicts_with.take()
+    }
+
+    fn take_priority(&mut self) -> Option<i32> {
+        self.priority.take()
+    }
+
+    fn take_normalizer(&mut self) -> Option<Normalizer> {
+        self.normalizer.take()
+    }
+
     fn take_help(&mut self) -> Option<String> {
         self.help.take()
     }
@@ -676,6 +950,10 @@ impl ConfigValue for OptConfig {
         self.styles.take()
     }
 
+    fn take_user_style(&mut self) -> Option<Vec<UserStyle>> {
+        self.user_styles.take()
+    }
+
     fn take_initializer(&mut self) -> Option<ValInitializer> {
         self.initializer.take()
     }
@@ -747,6 +1025,11 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_user_style(mut self, styles: Vec<UserStyle>) -> Self {
+        self.user_styles = Some(styles);
+        self
+    }
+
     fn with_action(mut self, action: Action) -> Self {
         self.action = Some(action);
         self
@@ -772,12 +1055,86 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
+
+    fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    fn with_force_if_cmd(mut self, cmd: impl Into<String>) -> Self {
+        self.force_if_cmd = Some(cmd.into());
+        self
+    }
+
+    fn with_choices(mut self, choices: Vec<impl Into<String>>) -> Self {
+        self.choices = Some(choices.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn with_requires(mut self, names: Vec<impl Into<String>>) -> Self {
+        self.requires = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn with_conflicts_with(mut self, names: Vec<impl Into<String>>) -> Self {
+        self.conflicts_with = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    fn with_normalizer(mut self, normalizer: impl Into<Normalizer>) -> Self {
+        self.normalizer = Some(normalizer.into());
+        self
+    }
+
     fn with_initializer(mut self, initializer: ValInitializer) -> Self {
         self.initializer = Some(initializer);
         self
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OptConfig {
+    /// Generate a configuration of option with valid name, such as `--foo=s`.
+    ///
+    /// The storer and initializer are not set, they are decided by the creator.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        const PREFIXES: [&str; 2] = ["-", "--"];
+        const CTORS: [&str; 6] = ["b", "i", "u", "f", "s", "r"];
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        fn name(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<String> {
+            let mut name = String::from(*u.choose(&PREFIXES)?);
+
+            name.push(*u.choose(&CHARS[..26])? as char);
+            for _ in 0..u.int_in_range(0..=7)? {
+                name.push(*u.choose(CHARS)? as char);
+            }
+            Ok(name)
+        }
+
+        let mut config = Self::default()
+            .with_ctor(*u.choose(&CTORS)?)
+            .with_name(name(u)?)
+            .with_force(u.arbitrary()?)
+            .with_help(u.arbitrary::<String>()?)
+            .with_action(u.arbitrary()?);
+
+        for _ in 0..u.int_in_range(0..=2)? {
+            config.add_alias(name(u)?);
+        }
+        Ok(config)
+    }
+}
+
 pub trait ConfigBuildMutable {
     type Cfg;
 
@@ -882,7 +1239,25 @@ pub trait ConfigBuildWith {
 
     fn with_ignore_name(self, ignore_name: bool) -> Self::Output;
 
+    fn with_global(self, global: bool) -> Self::Output;
+
+    fn with_sensitive(self, sensitive: bool) -> Self::Output;
+
+    fn with_force_if_cmd(self, cmd: impl Into<String>) -> Self::Output;
+
+    fn with_choices(self, choices: Vec<impl Into<String>>) -> Self::Output;
+
+    fn with_requires(self, names: Vec<impl Into<String>>) -> Self::Output;
+
+    fn with_conflicts_with(self, names: Vec<impl Into<String>>) -> Self::Output;
+
+    fn with_priority(self, priority: i32) -> Self::Output;
+
+    fn with_normalizer(self, normalizer: impl Into<Normalizer>) -> Self::Output;
+
     fn with_style(self, styles: Vec<Style>) -> Self::Output;
+
+    fn with_user_style(self, styles: Vec<UserStyle>) -> Self::Output;
 }
 
 impl<T> ConfigBuildWith for T
@@ -957,10 +1332,55 @@ where
         self
     }
 
+    fn with_global(mut self, global: bool) -> Self::Output {
+        self.config_mut().set_global(global);
+        self
+    }
+
+    fn with_sensitive(mut self, sensitive: bool) -> Self::Output {
+        self.config_mut().set_sensitive(sensitive);
+        self
+    }
+
+    fn with_force_if_cmd(mut self, cmd: impl Into<String>) -> Self::Output {
+        self.config_mut().set_force_if_cmd(cmd);
+        self
+    }
+
+    fn with_choices(mut self, choices: Vec<impl Into<String>>) -> Self::Output {
+        self.config_mut().set_choices(choices);
+        self
+    }
+
+    fn with_requires(mut self, names: Vec<impl Into<String>>) -> Self::Output {
+        self.config_mut().set_requires(names);
+        self
+    }
+
+    fn with_conflicts_with(mut self, names: Vec<impl Into<String>>) -> Self::Output {
+        self.config_mut().set_conflicts_with(names);
+        self
+    }
+
+    fn with_priority(mut self, priority: i32) -> Self::Output {
+        self.config_mut().set_priority(priority);
+        self
+    }
+
+    fn with_normalizer(mut self, normalizer: impl Into<Normalizer>) -> Self::Output {
+        self.config_mut().set_normalizer(normalizer);
+        self
+    }
+
     fn with_style(mut self, styles: Vec<Style>) -> Self::Output {
         self.config_mut().set_style(styles);
         self
     }
+
+    fn with_user_style(mut self, styles: Vec<UserStyle>) -> Self::Output {
+        self.config_mut().set_user_style(styles);
+        self
+    }
 }
 
 pub struct ConfigBuilder<C, I> {
@@ -1167,12 +1587,75 @@ macro_rules! def_help_for {
                 )
             }
 
+            fn with_global(self, global: bool) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_global(global)),
+                )
+            }
+
+            fn with_sensitive(self, sensitive: bool) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_sensitive(sensitive)),
+                )
+            }
+
+            fn with_force_if_cmd(self, cmd: impl Into<String>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_force_if_cmd(cmd)),
+                )
+            }
+
+            fn with_choices(self, choices: Vec<impl Into<String>>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_choices(choices)),
+                )
+            }
+
+            fn with_requires(self, names: Vec<impl Into<String>>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_requires(names)),
+                )
+            }
+
+            fn with_conflicts_with(self, names: Vec<impl Into<String>>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_conflicts_with(names)),
+                )
+            }
+
+            fn with_priority(self, priority: i32) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_priority(priority)),
+                )
+            }
+
+            fn with_normalizer(self, normalizer: impl Into<Normalizer>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_normalizer(normalizer)),
+                )
+            }
+
             fn with_style(self, styles: Vec<Style>) -> Self::Output {
                 ConfigBuilderWith::new(
                     self,
                     ConfigBuilder::new(OptConfig::default().with_style(styles)),
                 )
             }
+
+            fn with_user_style(self, styles: Vec<UserStyle>) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_user_style(styles)),
+                )
+            }
         }
     };
 }
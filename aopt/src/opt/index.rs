@@ -32,6 +32,7 @@ use crate::Error;
 ///
 /// For option check, see [`SetChecker`](crate::set::SetChecker) for more information.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
 pub enum Index {
     /// The forward index of NOA, fixed position.
@@ -223,6 +224,91 @@ impl Index {
         }
     }
 
+    /// Describe the position in human terms, such as `third and later arguments`.
+    ///
+    /// The index `0` is the first NOA, which is usually the program or sub command name.
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// #
+    /// assert_eq!(Index::forward(1).to_desc(), "first argument");
+    /// assert_eq!(Index::backward(0).to_desc(), "last argument");
+    /// assert_eq!(Index::range(Some(3), None).to_desc(), "third and later arguments");
+    /// assert_eq!(Index::range(Some(1), Some(3)).to_desc(), "first to second arguments");
+    /// assert_eq!(Index::list(vec![1, 3]).to_desc(), "first or third argument");
+    /// assert_eq!(Index::except(vec![2]).to_desc(), "any argument except the second");
+    /// ```
+    pub fn to_desc(&self) -> String {
+        match self {
+            Index::Forward(offset) => {
+                format!("{} argument", Self::ordinal(*offset))
+            }
+            Index::Backward(0) => "last argument".to_string(),
+            Index::Backward(offset) => {
+                format!("{} to last argument", Self::ordinal(offset + 1))
+            }
+            Index::List(list) => {
+                format!("{} argument", Self::join_ordinal(list, "or"))
+            }
+            Index::Except(list) => {
+                format!(
+                    "any argument except the {}",
+                    Self::join_ordinal(list, "and")
+                )
+            }
+            Index::Range(0, None) => "any argument".to_string(),
+            Index::Range(start, None) => {
+                format!("{} and later arguments", Self::ordinal(*start))
+            }
+            Index::Range(start, Some(end)) => {
+                if *end <= *start {
+                    String::default()
+                } else if end - 1 == *start {
+                    format!("{} argument", Self::ordinal(*start))
+                } else {
+                    format!(
+                        "{} to {} arguments",
+                        Self::ordinal(*start),
+                        Self::ordinal(end - 1)
+                    )
+                }
+            }
+            Index::AnyWhere => "any argument".to_string(),
+            Index::Null => String::default(),
+        }
+    }
+
+    fn ordinal(index: usize) -> String {
+        const WORDS: [&str; 11] = [
+            "command", "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth",
+            "ninth", "tenth",
+        ];
+
+        if let Some(word) = WORDS.get(index) {
+            word.to_string()
+        } else {
+            let suffix = match (index % 10, index % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+
+            format!("{}{}", index, suffix)
+        }
+    }
+
+    fn join_ordinal(list: &[usize], conj: &str) -> String {
+        let mut words: Vec<String> = list.iter().map(|v| Self::ordinal(*v)).collect();
+
+        match words.pop() {
+            Some(last) if !words.is_empty() => format!("{} {} {}", words.join(", "), conj, last),
+            Some(last) => last,
+            None => String::default(),
+        }
+    }
+
     pub fn forward(index: usize) -> Self {
         Self::Forward(index)
     }
@@ -335,10 +421,8 @@ impl Index {
                     }
                 }
             }
-            Self::Except(list) => {
-                if noa_index < noa_count && !list.contains(&noa_index) {
-                    return Some(noa_index);
-                }
+            Self::Except(list) if noa_index < noa_count && !list.contains(&noa_index) => {
+                return Some(noa_index);
             }
             Self::Range(start, end) => match (start, end) {
                 (start, None) => {
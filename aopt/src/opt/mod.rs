@@ -42,7 +42,10 @@ use std::fmt::Debug;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use crate::parser::UserStyle;
+use crate::value::Normalizer;
 use crate::value::ValAccessor;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -50,6 +53,39 @@ pub const BOOL_TRUE: &str = "true";
 
 pub const BOOL_FALSE: &str = "false";
 
+/// The name of wildcard [`Cmd`], it matches any sub command not matched by other [`Cmd`].
+///
+/// The raw value of wildcard [`Cmd`] is the actual sub command name, and the value is `true`.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use std::ffi::OsString;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("build=c")?;
+/// parser.add_opt("*=c")?;
+/// parser.parse(Args::from(["git", "st"]))?;
+///
+/// assert!(!parser.find_opt("build")?.matched());
+/// assert_eq!(parser.find_val::<bool>("*=c")?, &true);
+/// assert_eq!(parser.find_opt("*=c")?.rawval()?, &OsString::from("st"));
+///
+/// parser.reset()?;
+/// parser.parse(Args::from(["git", "build"]))?;
+///
+/// assert!(parser.find_opt("build")?.matched());
+/// assert!(!parser.find_opt("*=c")?.matched());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub const CMD_WILDCARD: &str = "*";
+
 /// Cmd represents a sub command flag wrapped the `bool` option, it is force required in default.
 ///
 /// See [`cmd_check`](crate::set::SetChecker::cmd_check) of
@@ -335,18 +371,60 @@ pub trait Opt: Debug {
 
     fn accessor_mut(&mut self) -> &mut ValAccessor;
 
+    /// The source which supplied the value of option.
+    fn source(&self) -> ValueSource {
+        self.accessor().source()
+    }
+
     fn ignore_alias(&self) -> bool;
 
     fn ignore_name(&self) -> bool;
 
     fn ignore_index(&self) -> bool;
 
+    /// If the option can be matched after the sub command, see [`PrePolicy`](crate::parser::PrePolicy).
+    ///
+    /// Only [`PrePolicy`](crate::parser::PrePolicy) support it currently,
+    /// it is ignored by [`FwdPolicy`](crate::parser::FwdPolicy) and [`DelayPolicy`](crate::parser::DelayPolicy).
+    fn global(&self) -> bool;
+
+    /// If the value of option should be redacted when it is persisted, see [`Redactor`](crate::set::Redactor).
+    fn sensitive(&self) -> bool;
+
+    /// The option is force required if the [`Cmd`] is matched,
+    /// see [`DefaultSetChecker`](crate::parser::DefaultSetChecker).
+    fn force_if_cmd(&self) -> Option<&str>;
+
+    /// The possible values of option, they are used in documentation only,
+    /// use [`ValValidator`](crate::value::ValValidator) check the value.
+    fn choices(&self) -> Option<&Vec<String>>;
+
+    /// The options must be set if the option is set,
+    /// see [`DefaultSetChecker`](crate::parser::DefaultSetChecker).
+    fn requires(&self) -> &[String];
+
+    /// The options can not be set if the option is set,
+    /// see [`DefaultSetChecker`](crate::parser::DefaultSetChecker).
+    fn conflicts_with(&self) -> &[String];
+
+    /// The priority of option when more than one options may match an argument,
+    /// the option has higher priority is matched first,
+    /// the options have same priority are matched in the order they are inserted.
+    fn priority(&self) -> i32;
+
+    /// The normalizer applied to the raw value before the handler invoked,
+    /// see [`Normalizer`].
+    fn normalizer(&self) -> Option<&Normalizer>;
+
     fn set_uid(&mut self, uid: Uid);
 
     fn set_matched(&mut self, matched: bool);
 
     fn mat_style(&self, style: Style) -> bool;
 
+    /// Return true if the option can be set in given user style.
+    fn mat_user_style(&self, style: &UserStyle) -> bool;
+
     fn mat_force(&self, force: bool) -> bool;
 
     fn mat_name(&self, name: Option<&str>) -> bool;
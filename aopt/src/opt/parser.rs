@@ -1,5 +1,6 @@
 use super::{ConstrctInfo, OptParser};
 use crate::opt::Index;
+use crate::opt::CMD_WILDCARD;
 use crate::Error;
 
 /// Parse the option string with given prefixes, return an [`ConstrctInfo`].
@@ -29,6 +30,8 @@ use crate::Error;
 ///      |     The delimiter of option name and type.
 ///      |
 ///      The option name part, it must be provide by user.
+///      The name `*` is only allowed when followed by `=`, such as `*=c`,
+///      see [`CMD_WILDCARD`](crate::opt::CMD_WILDCARD).
 /// ```
 ///
 /// # Example
@@ -70,6 +73,16 @@ impl StrParser {
     pub fn parse_creator_string(&self, dat: &str) -> Result<ConstrctInfo, Error> {
         use neure::prelude::*;
 
+        // `*` is also the optional marker, so the wildcard name must followed by type
+        if let Some(rest) = dat
+            .strip_prefix(CMD_WILDCARD)
+            .filter(|v| v.starts_with('='))
+        {
+            return self
+                .parse_creator_string(rest)
+                .map(|ci| ci.with_name(Some(CMD_WILDCARD.to_string())));
+        }
+
         let start = re::start();
         let end = re::end();
         let name = ['=', '!', '*', '@', ';', ':'].not().repeat_one_more();
@@ -154,6 +167,7 @@ mod test {
             "-b;bool=f",
             "-/b;--/bool=i",
             "-/b;bool=a",
+            "*=c",
             "",
         ];
         let options_test = [
@@ -177,6 +191,7 @@ mod test {
             (Some("-b"), Some(vec!["bool"]), Some("f")),
             (Some("-/b"), Some(vec!["--/bool"]), Some("i")),
             (Some("-/b"), Some(vec!["bool"]), Some("a")),
+            (Some("*"), None, Some("c")),
             (None, None, None),
         ];
         let helps = [": This is an option help message", ""];
@@ -2,8 +2,10 @@
 ///
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Style {
+    #[default]
     Null,
 
     /// The style indicate the `NOA` are set base on position.
@@ -30,12 +32,6 @@ pub enum Style {
     Flag,
 }
 
-impl Default for Style {
-    fn default() -> Self {
-        Self::Null
-    }
-}
-
 impl std::fmt::Display for Style {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
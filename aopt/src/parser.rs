@@ -1,22 +1,47 @@
+pub(crate) mod assign;
 pub(crate) mod checker;
 pub(crate) mod commit;
+#[cfg(feature = "miette")]
+pub(crate) mod diagnostic;
 pub(crate) mod failure;
+pub(crate) mod observer;
 pub(crate) mod optset;
+pub(crate) mod pipeline;
+pub(crate) mod policy_chain;
 pub(crate) mod policy_delay;
+pub(crate) mod policy_flag;
 pub(crate) mod policy_fwd;
 pub(crate) mod policy_pre;
+pub(crate) mod poscount;
 pub(crate) mod returnval;
+pub(crate) mod rule;
 pub(crate) mod style;
 
 pub use self::checker::DefaultSetChecker;
 pub use self::commit::ParserCommit;
 pub use self::commit::ParserCommitWithValue;
+#[cfg(feature = "miette")]
+pub use self::diagnostic::FailureReport;
 pub use self::failure::FailManager;
+pub use self::observer::ObserverRef;
+pub use self::observer::Phase;
+pub use self::observer::PolicyObserver;
 pub use self::optset::HCOptSet;
+pub use self::pipeline::PhaseHandler;
+pub use self::pipeline::PhaseList;
+pub use self::pipeline::PhaseStep;
+pub use self::policy_chain::PolicyChain;
 pub use self::policy_delay::DelayPolicy;
+pub use self::policy_flag::FlagPolicy;
 pub use self::policy_fwd::FwdPolicy;
 pub use self::policy_pre::PrePolicy;
+pub use self::poscount::PosCount;
+pub use self::returnval::DryMatch;
+pub(crate) use self::returnval::MatchedSnapshot;
+pub use self::returnval::NoaMatch;
 pub use self::returnval::Return;
+pub use self::rule::RuleChecker;
+pub use self::rule::ValueRule;
 pub use self::style::OptStyleManager;
 pub use self::style::UserStyle;
 
@@ -377,6 +402,58 @@ where
         self.style_manager_mut().push(UserStyle::Flag);
         self
     }
+
+    /// Parse the `args` with `styles` instead of the [`OptStyleManager`] of policy,
+    /// the style manager of policy is restored after parsing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.enable_combined();
+    /// parser.add_opt("-a=b")?;
+    /// parser.add_opt("-b=b")?;
+    /// parser.add_opt("-ab=b")?;
+    ///
+    /// // the arguments from config file, `-ab` is not a combined option
+    /// let styles = parser.style_manager().clone().with(vec![
+    ///     UserStyle::EqualWithValue,
+    ///     UserStyle::Argument,
+    ///     UserStyle::Boolean,
+    /// ]);
+    ///
+    /// parser.parse_with_styles(Args::from(["app", "-ab"]), styles)?;
+    /// assert_eq!(parser.find_val::<bool>("-ab")?, &true);
+    /// assert_eq!(parser.find_val::<bool>("-a")?, &false);
+    /// assert!(parser.styles().contains(&UserStyle::CombinedOption));
+    ///
+    /// parser.reset()?;
+    /// parser.parse(Args::from(["app", "-ba"]))?;
+    /// assert_eq!(parser.find_val::<bool>("-a")?, &true);
+    /// assert_eq!(parser.find_val::<bool>("-b")?, &true);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_styles(
+        &mut self,
+        args: Args,
+        styles: OptStyleManager,
+    ) -> Result<<P as Policy>::Ret, Error>
+    where
+        P::Set: Set,
+    {
+        let orig = std::mem::replace(self.style_manager_mut(), styles);
+        let ret = PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy);
+
+        *self.style_manager_mut() = orig;
+        ret
+    }
 }
 
 impl<P: Policy> PolicyParser<P> for Parser<'_, P>
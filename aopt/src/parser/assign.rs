@@ -0,0 +1,80 @@
+use std::ffi::OsStr;
+
+use super::FailManager;
+use super::UserStyle;
+use crate::ctx::Ctx;
+use crate::ctx::HandlerCollection;
+use crate::guess::InvokeGuess;
+use crate::opt::Opt;
+use crate::set::OptValidator;
+use crate::set::SetOpt;
+use crate::trace;
+use crate::value::Assign;
+use crate::Error;
+
+/// Invoke the options allow [`Assign`](UserStyle::Assign) style with the `name=value` NOAs of `args`,
+/// return the NOAs not matched by them.
+///
+/// The assignments are indexed from 1 in the order they appear, the name of program is kept at 0.
+/// Do nothing if no option allow the style.
+pub(crate) fn guess_assign<'a, 'c, Set, Inv, Ser>(
+    set: &mut Set,
+    inv: &mut Inv,
+    ser: &mut Ser,
+    ctx: &mut Ctx<'a>,
+    fail: &mut FailManager,
+    args: Vec<&'a OsStr>,
+    overload: bool,
+) -> Result<Vec<&'a OsStr>, Error>
+where
+    SetOpt<Set>: Opt,
+    Set: crate::set::Set + OptValidator,
+    Inv: HandlerCollection<'c, Set, Ser>,
+{
+    let candidates: Vec<usize> = (1..args.len())
+        .filter(|idx| Assign::split(args[*idx]).is_some())
+        .collect();
+
+    if candidates.is_empty() || !set.iter().any(|v| v.mat_user_style(&UserStyle::Assign)) {
+        return Ok(args);
+    }
+
+    let prev = ctx.args().to_vec();
+    let assigns: Vec<_> = std::iter::once(args[0])
+        .chain(candidates.iter().map(|idx| args[*idx]))
+        .collect();
+    let total = assigns.len();
+    let mut matched = vec![];
+
+    ctx.set_args(assigns.clone());
+    let mut guess = InvokeGuess {
+        set,
+        inv,
+        ser,
+        total,
+        ctx,
+        fail,
+        name: None,
+        arg: None,
+        next: None,
+        idx: 0,
+    };
+
+    for (idx, orig) in candidates.into_iter().enumerate() {
+        guess.idx = idx + 1;
+        guess.name = crate::str::osstr_to_str_i(&assigns, guess.idx);
+        trace!("guess Assign argument = {:?} @ {}", guess.name, guess.idx);
+        if let Some(ret) = guess.guess_and_invoke(&UserStyle::Assign, overload)? {
+            if ret.matched {
+                matched.push(orig);
+            }
+        }
+    }
+    ctx.set_args(prev);
+    Ok(args
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| !matched.contains(idx))
+        .map(|(_, arg)| arg)
+        .collect())
+}
@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use crate::opt::Index;
 use crate::opt::Opt;
 use crate::opt::Style;
+use crate::raise_error;
 use crate::set::SetChecker;
 use crate::set::SetOpt;
 use crate::trace;
@@ -47,6 +48,26 @@ where
     pub fn opt<'a>(set: &'a S, id: &Uid) -> &'a SetOpt<S> {
         set.get(*id).unwrap()
     }
+
+    /// Find the option by name or alias, return an error if it not exists.
+    pub fn find<'a>(set: &'a S, name: &str) -> Result<&'a SetOpt<S>, Error> {
+        set.iter()
+            .find(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name))
+            .ok_or_else(|| raise_error!("can not find option `{}` in the relations", name))
+    }
+
+    /// Invoke the [`deferred`](crate::value::ValStorer::with_deferred) validator of option,
+    /// return a failure if it return false.
+    pub fn validate(opt: &SetOpt<S>) -> Result<(), Error> {
+        if opt.accessor().validate()? {
+            Ok(())
+        } else {
+            Err(
+                Error::raise_failure(format!("value check failed: `{}`", opt.hint()))
+                    .with_uid(opt.uid()),
+            )
+        }
+    }
 }
 
 impl<S> SetChecker<S> for DefaultSetChecker<S>
@@ -84,23 +105,35 @@ where
     /// Call the [`valid`](crate::opt::Opt::valid) check the
     /// options([`Argument`](crate::opt::Style::Argument),
     /// [`Boolean`](crate::opt::Style::Boolean), [`Combined`](crate::opt::Style::Combined)),
-    /// [`Flag`](crate::opt::Style::Flag)
+    /// [`Flag`](crate::opt::Style::Flag), then invoke the deferred validator of them.
+    /// All the missing options are reported in one failure.
     fn opt_check(&self, set: &mut S) -> Result<bool, Error> {
+        let opts: Vec<_> = set
+            .iter()
+            .filter(|opt| {
+                opt.mat_style(Style::Argument)
+                    || opt.mat_style(Style::Boolean)
+                    || opt.mat_style(Style::Combined)
+                    || opt.mat_style(Style::Flag)
+            })
+            .collect();
+        let missing: Vec<_> = opts.iter().filter(|opt| !opt.valid()).collect();
+
         trace!("in opt check, call valid on all Opt ...");
-        for opt in set.iter().filter(|opt| {
-            opt.mat_style(Style::Argument)
-                || opt.mat_style(Style::Boolean)
-                || opt.mat_style(Style::Combined)
-                || opt.mat_style(Style::Flag)
-        }) {
-            if !opt.valid() {
-                return Err(Error::sp_opt_require(vec![opt.hint()]).with_uid(opt.uid()));
-            }
+        if let Some(first) = missing.first() {
+            let uid = first.uid();
+            let opts = missing.iter().map(|opt| (opt.hint(), opt.help())).collect();
+
+            return Err(Error::sp_opt_missing(opts).with_uid(uid));
+        }
+        for opt in opts {
+            Self::validate(opt)?;
         }
         Ok(true)
     }
 
     /// Check if the [`Pos`](crate::opt::Style::Pos) is valid, it must be set if it is force reuqired.
+    /// Then invoke the deferred validator of them.
     fn pos_check(&self, set: &mut S) -> Result<bool, Error> {
         let mut index_map = HashMap::<usize, Vec<Uid>>::default();
         let mut float_vec: Vec<Uid> = vec![];
@@ -173,6 +206,9 @@ where
                 return Err(Error::sp_pos_require(names).with_uid(float_vec[0]));
             }
         }
+        for opt in set.iter().filter(|opt| opt.mat_style(Style::Pos)) {
+            Self::validate(opt)?;
+        }
         Ok(true)
     }
 
@@ -201,8 +237,45 @@ where
         Ok(true)
     }
 
-    /// Call [`valid`](crate::opt::Opt::valid) on options those style are [`Main`](Style::Main).
+    /// Check the options [`force_if_cmd`](crate::opt::Opt::force_if_cmd) are set if the [`Cmd`](Style::Cmd) matched,
+    /// and the [`requires`](crate::opt::Opt::requires) and [`conflicts_with`](crate::opt::Opt::conflicts_with)
+    /// relations of matched options, then call [`valid`](crate::opt::Opt::valid) on options those style are [`Main`](Style::Main).
     fn post_check(&self, set: &mut S) -> Result<bool, Error> {
+        trace!("in post check, checking the options required by Cmd ...");
+        for opt in set.iter() {
+            if let Some(cmd) = opt.force_if_cmd() {
+                let cmd_matched = set.iter().any(|v| {
+                    v.mat_style(Style::Cmd)
+                        && v.matched()
+                        && (v.mat_name(Some(cmd)) || v.mat_alias(cmd))
+                });
+
+                if cmd_matched && !opt.matched() {
+                    return Err(Error::sp_opt_require(vec![opt.hint()]).with_uid(opt.uid()));
+                }
+            }
+        }
+        trace!("in post check, checking the relations of matched options ...");
+        for opt in set.iter().filter(|opt| opt.matched()) {
+            for name in opt.requires() {
+                let depend = Self::find(set, name)?;
+
+                if !depend.matched() {
+                    return Err(
+                        Error::sp_opt_require_by(opt.hint(), depend.hint()).with_uid(depend.uid())
+                    );
+                }
+            }
+            for name in opt.conflicts_with() {
+                let other = Self::find(set, name)?;
+
+                if other.matched() {
+                    return Err(
+                        Error::sp_opt_conflict(opt.hint(), other.hint()).with_uid(opt.uid())
+                    );
+                }
+            }
+        }
         trace!("in post check, call valid on Main ...");
         Ok(set
             .iter()
@@ -15,6 +15,7 @@ use crate::opt::Opt;
 use crate::opt::Pos;
 use crate::raise_error;
 use crate::set::Commit;
+use crate::set::Conflict;
 use crate::set::Set;
 use crate::set::SetCfg;
 use crate::set::SetCommit;
@@ -64,6 +65,23 @@ where
     }
 }
 
+impl<'a, I, S, Ser, U> Drop for ParserCommit<'a, '_, I, S, Ser, U>
+where
+    S: Set,
+    U: Infer + 'static,
+    U::Val: RawValParser,
+    I: HandlerCollection<'a, S, Ser>,
+    SetOpt<S>: Opt,
+    SetCfg<S>: ConfigValue + Default,
+{
+    fn drop(&mut self) {
+        if self.inner.as_ref().is_some_and(|v| v.drop) {
+            self.commit_inner_change()
+                .unwrap_or_else(|e| panic!("catch error in ParserCommit::drop: {:?}", e));
+        }
+    }
+}
+
 impl<'a, I, S, Ser, U> Commit<S> for ParserCommit<'a, '_, I, S, Ser, U>
 where
     S: Set,
@@ -241,8 +259,25 @@ where
         Ok(HandlerEntry::new(ser.unwrap(), uid).fallback(handler))
     }
 
+    /// Set the behavior when an option with the same name already exists,
+    /// see [`SetCommit::on_conflict`].
+    ///
+    /// For [`Conflict::Replace`], the handler of the existing option is removed.
+    pub fn on_conflict(mut self, conflict: Conflict) -> Self {
+        self.inner = self.inner.take().map(|v| v.on_conflict(conflict));
+        self
+    }
+
     pub(crate) fn commit_inner_change(&mut self) -> Result<Uid, Error> {
-        self.inner_mut()?.commit_change()
+        let inner = self.inner_mut()?;
+        let uid = inner.commit_change()?;
+
+        if inner.take_replaced() {
+            if let Some(inv_ser) = self.inv_ser.as_mut() {
+                inv_ser.remove_handler(&uid);
+            }
+        }
+        Ok(uid)
     }
 
     /// Run the commit.
@@ -268,6 +303,12 @@ where
         self.set_storer(ValStorer::from(validator))
     }
 
+    /// Set the validator checking all the values after they are stored,
+    /// see [`ValStorer::with_deferred`].
+    pub fn set_deferred_validator(self, validator: ValValidator<Vec<U::Val>>) -> Self {
+        self.set_storer(ValStorer::new_deferred(validator))
+    }
+
     /// Add default [`storer`](ValStorer::fallback) of type [`U::Val`](Infer::Val).
     pub fn add_default_storer(self) -> Self {
         self.set_storer(ValStorer::fallback::<U::Val>())
@@ -525,8 +566,25 @@ where
         Ok(HandlerEntry::new(ser.unwrap(), uid).fallback(handler))
     }
 
+    /// Set the behavior when an option with the same name already exists,
+    /// see [`SetCommit::on_conflict`].
+    ///
+    /// For [`Conflict::Replace`], the handler of the existing option is removed.
+    pub fn on_conflict(mut self, conflict: Conflict) -> Self {
+        self.inner = self.inner.take().map(|v| v.on_conflict(conflict));
+        self
+    }
+
     pub(crate) fn commit_inner_change(&mut self) -> Result<Uid, Error> {
-        self.inner_mut()?.commit_inner_change()
+        let inner = self.inner_mut()?.inner_mut()?;
+        let uid = inner.commit_change()?;
+
+        if inner.take_replaced() {
+            if let Some(inv_ser) = self.inv_ser.as_mut() {
+                inv_ser.remove_handler(&uid);
+            }
+        }
+        Ok(uid)
     }
 
     /// Run the commit.
@@ -538,6 +596,29 @@ where
     }
 }
 
+impl<'a, I, S, Ser, U, T> Drop for ParserCommitWithValue<'a, '_, I, S, Ser, U, T>
+where
+    S: Set,
+    U: Infer + 'static,
+    T: ErasedTy,
+    U::Val: RawValParser,
+    I: HandlerCollection<'a, S, Ser>,
+    SetOpt<S>: Opt,
+    SetCfg<S>: ConfigValue + Default,
+{
+    fn drop(&mut self) {
+        if self
+            .inner
+            .as_ref()
+            .and_then(|v| v.inner().ok())
+            .is_some_and(|v| v.drop)
+        {
+            self.commit_inner_change()
+                .unwrap_or_else(|e| panic!("catch error in ParserCommitWithValue::drop: {:?}", e));
+        }
+    }
+}
+
 impl<'a, I, S, Ser, U, T> Commit<S> for ParserCommitWithValue<'a, '_, I, S, Ser, U, T>
 where
     S: Set,
@@ -571,6 +652,12 @@ where
     pub fn set_validator(self, validator: ValValidator<U::Val>) -> Self {
         self.set_storer(ValStorer::from(validator))
     }
+
+    /// Set the validator checking all the values after they are stored,
+    /// see [`ValStorer::with_deferred`].
+    pub fn set_deferred_validator(self, validator: ValValidator<Vec<U::Val>>) -> Self {
+        self.set_storer(ValStorer::new_deferred(validator))
+    }
 }
 
 impl<'a, I, S, Ser, U, T> ParserCommitWithValue<'a, '_, I, S, Ser, U, T>
@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use miette::Diagnostic;
+use miette::LabeledSpan;
+use miette::SourceCode;
+
+use crate::args::Args;
+use crate::parser::Return;
+use crate::Error;
+
+impl Diagnostic for Error {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("aopt::{}", self.kind().code())))
+    }
+}
+
+/// A [`Diagnostic`] of parsing failure, it labels the offending argument
+/// in the command line joined by space.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--count=i")?;
+///
+/// let ret = parser.parse(Args::from(["app", "--count", "ten"]))?;
+/// let report = ret.report().unwrap();
+///
+/// assert_eq!(report.source_code(), "app --count ten");
+/// assert_eq!(report.span(), Some((4, 7)));
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FailureReport {
+    error: Error,
+
+    source: String,
+
+    span: Option<(usize, usize)>,
+}
+
+impl FailureReport {
+    /// Create the report of `error`, the offending argument is searched by the
+    /// [`field`](Error::field) of error in `orig`.
+    pub fn new(error: Error, orig: &Args) -> Self {
+        Self::new_with_name(error, orig, None)
+    }
+
+    /// Same as [`new`](Self::new), search the argument by `name` if the error has no field.
+    pub fn new_with_name(error: Error, orig: &Args, name: Option<&str>) -> Self {
+        let args: Vec<_> = orig
+            .iter()
+            .map(|v| v.to_string_lossy().to_string())
+            .collect();
+        let source = args.join(" ");
+        let span = Self::search(&args, [error.field(), name]);
+
+        Self {
+            error,
+            source,
+            span,
+        }
+    }
+
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// The command line joined by space.
+    pub fn source_code(&self) -> &str {
+        &self.source
+    }
+
+    /// The offset and length of offending argument in [`source_code`](Self::source_code).
+    pub fn span(&self) -> Option<(usize, usize)> {
+        self.span
+    }
+
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+
+    // the program name will not be searched
+    fn search(args: &[String], names: [Option<&str>; 2]) -> Option<(usize, usize)> {
+        names.into_iter().flatten().find_map(|name| {
+            let mut offset = args.first().map(|v| v.len() + 1)?;
+
+            for arg in args.iter().skip(1) {
+                if !name.is_empty() && arg.starts_with(name) {
+                    return Some((offset, arg.len()));
+                }
+                offset += arg.len() + 1;
+            }
+            None
+        })
+    }
+}
+
+impl Display for FailureReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for FailureReport {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(&self.error)
+    }
+}
+
+impl Diagnostic for FailureReport {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Diagnostic::code(&self.error)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let (offset, len) = self.span?;
+        let label = LabeledSpan::new(Some(self.error.kind().code().to_string()), offset, len);
+
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+impl Return {
+    /// Create a [`FailureReport`] if parsing failed.
+    pub fn report(&self) -> Option<FailureReport> {
+        let name = self.ctx().guess.as_ref().and_then(|v| v.name.as_deref());
+
+        self.failure()
+            .map(|e| FailureReport::new_with_name(e.clone(), self.orig_args(), name))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use miette::Diagnostic;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_report() {
+        assert!(testing_report_main().is_ok());
+    }
+
+    fn testing_report_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--count=i")?.add_alias("-c");
+        parser.add_opt("--name=s")?;
+        parser.add_opt("file=p@1")?.set_pos_type::<i64>();
+
+        let ret = parser.parse(Args::from(["app", "-c=ten", "foo"]))?;
+        let report = ret.report().unwrap();
+
+        assert_eq!(report.source_code(), "app -c=ten foo");
+        assert_eq!(report.span(), Some((4, 6)));
+        assert_eq!(
+            report.code().map(|v| v.to_string()).as_deref(),
+            Some("aopt::option_not_found")
+        );
+        assert_eq!(report.labels().map(|v| v.count()), Some(1));
+
+        let ret = parser.parse(Args::from(["app", "--name"]))?;
+        let report = ret.report().unwrap();
+
+        assert_eq!(report.span(), Some((4, 6)));
+
+        assert!(parser
+            .parse(Args::from(["app", "--name", "foo", "1"]))?
+            .report()
+            .is_none());
+        Ok(())
+    }
+}
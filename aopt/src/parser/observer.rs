@@ -0,0 +1,83 @@
+use std::ffi::OsStr;
+
+use crate::ARef;
+use crate::Uid;
+
+/// The phase of policy parsing process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Processing the options.
+    Opt,
+
+    /// Processing the [`Cmd`](crate::opt::Cmd).
+    Cmd,
+
+    /// Processing the [`Pos`](crate::opt::Pos).
+    Pos,
+
+    /// Processing the [`Main`](crate::opt::Main).
+    Main,
+}
+
+/// Receive the checkpoint events of policy during parsing.
+///
+/// All the methods have an empty default implementation,
+/// use the interior mutability if the observer need record something.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use std::ffi::OsStr;
+/// # use std::sync::Mutex;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// #[derive(Default)]
+/// struct Trace(Mutex<Vec<String>>);
+///
+/// impl PolicyObserver for Trace {
+///     fn on_arg_start(&self, idx: usize, arg: &OsStr) {
+///         self.0.lock().unwrap().push(format!("{idx}: {}", arg.display()));
+///     }
+///
+///     fn on_opt_matched(&self, idx: usize, _: Uid) {
+///         self.0.lock().unwrap().push(format!("{idx}: matched"));
+///     }
+///
+///     fn on_phase_end(&self, phase: Phase) {
+///         self.0.lock().unwrap().push(format!("{phase:?}"));
+///     }
+/// }
+///
+/// let trace = ARef::new(Trace::default());
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().set_observer(trace.clone());
+/// parser.add_opt("--debug=b")?;
+/// parser.parse(Args::from(["app", "--debug", "foo"]))?;
+///
+/// assert_eq!(
+///     trace.0.lock().unwrap().as_slice(),
+///     ["0: app", "1: --debug", "1: matched", "2: foo", "Opt", "Cmd", "Pos", "Main"]
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub trait PolicyObserver {
+    /// Called before the policy processing the argument at `idx` in [`Phase::Opt`].
+    fn on_arg_start(&self, _idx: usize, _arg: &OsStr) {}
+
+    /// Called when the argument at `idx` matched the option `uid`.
+    fn on_opt_matched(&self, _idx: usize, _uid: Uid) {}
+
+    /// Called when the policy finished the phase.
+    fn on_phase_end(&self, _phase: Phase) {}
+}
+
+#[cfg(feature = "sync")]
+pub type ObserverRef = ARef<dyn PolicyObserver + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type ObserverRef = ARef<dyn PolicyObserver>;
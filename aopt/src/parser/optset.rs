@@ -15,7 +15,9 @@ use crate::opt::OptParser;
 use crate::parser::ParserCommit;
 use crate::parser::Policy;
 use crate::ser::ServicesValExt;
+use crate::set::OptSpec;
 use crate::set::OptValidator;
+use crate::set::Profile;
 use crate::set::SetCfg;
 use crate::set::SetCommit;
 use crate::set::SetOpt;
@@ -312,6 +314,19 @@ where
         Ok(ParserCommit::new(SetCommit::new(set, cfg), inv))
     }
 
+    /// Add the options described by `specs` to the [`Set`](Policy::Set),
+    /// return the [`Uid`]s of them, see [`OptSpec`].
+    pub fn add_opts(&mut self, specs: &[OptSpec]) -> Result<Vec<Uid>, Error> {
+        let mut uids = Vec::with_capacity(specs.len());
+
+        for spec in specs {
+            let commit = self.add_opt(spec.create_str().as_str())?;
+
+            uids.push(spec.apply(commit)?.run()?);
+        }
+        Ok(uids)
+    }
+
     /// Add an option to the [`Set`](Policy::Set), return a [`ParserCommit`].
     ///
     /// ```rust
@@ -457,6 +472,26 @@ where
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         Set::insert(&mut self.set, opt)
     }
+
+    fn candidates(&mut self, name: &str) -> Vec<Uid> {
+        Set::candidates(&mut self.set, name)
+    }
+
+    fn insert_profile(&mut self, profile: Profile) -> Result<(), Error> {
+        Set::insert_profile(&mut self.set, profile)
+    }
+
+    fn profile(&self, name: &str) -> Option<&Profile> {
+        Set::profile(&self.set, name)
+    }
+
+    fn get(&self, uid: Uid) -> Option<&SetOpt<Self>> {
+        Set::get(&self.set, uid)
+    }
+
+    fn get_mut(&mut self, uid: Uid) -> Option<&mut SetOpt<Self>> {
+        Set::get_mut(&mut self.set, uid)
+    }
 }
 
 impl<Set, Inv, Ser> OptValidator for HCOptSet<Set, Inv, Ser>
@@ -472,6 +507,14 @@ where
     fn split<'a>(&self, name: &Cow<'a, str>) -> Result<(Cow<'a, str>, Cow<'a, str>), Self::Error> {
         OptValidator::split(&self.set, name)
     }
+
+    fn check_bare(&mut self, name: &str) -> Result<bool, Self::Error> {
+        OptValidator::check_bare(&mut self.set, name)
+    }
+
+    fn maybe_path(&self, name: &str) -> bool {
+        OptValidator::maybe_path(&self.set, name)
+    }
 }
 
 impl<P: Policy> PolicyParser<P> for HCOptSet<P::Set, P::Inv<'_>, P::Ser>
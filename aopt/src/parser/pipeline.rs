@@ -0,0 +1,277 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use super::Phase;
+use crate::ctx::Ctx;
+use crate::raise_error;
+use crate::ARef;
+use crate::Error;
+
+/// The handler of [`PhaseStep::Custom`], called with the set, services and context.
+///
+/// The NOA arguments are available in [`Ctx::args`] once the [`Phase::Opt`] finished,
+/// set [`Action::Quit`](crate::parser::Action::Quit) to skip the rest phases.
+#[cfg(feature = "sync")]
+pub type PhaseHandler<Set, Ser> =
+    ARef<dyn for<'a> Fn(&mut Set, &mut Ser, &mut Ctx<'a>) -> Result<(), Error> + Send + Sync>;
+
+/// The handler of [`PhaseStep::Custom`], called with the set, services and context.
+///
+/// The NOA arguments are available in [`Ctx::args`] once the [`Phase::Opt`] finished,
+/// set [`Action::Quit`](crate::parser::Action::Quit) to skip the rest phases.
+#[cfg(not(feature = "sync"))]
+pub type PhaseHandler<Set, Ser> =
+    ARef<dyn for<'a> Fn(&mut Set, &mut Ser, &mut Ctx<'a>) -> Result<(), Error>>;
+
+/// One step of the [`PhaseList`].
+pub enum PhaseStep<Set, Ser> {
+    /// Processing the options.
+    Opt,
+
+    /// Processing the [`Cmd`](crate::opt::Cmd).
+    Cmd,
+
+    /// Processing the [`Pos`](crate::opt::Pos).
+    Pos,
+
+    /// Processing the [`Main`](crate::opt::Main).
+    Main,
+
+    /// A phase supplied by user.
+    Custom(PhaseHandler<Set, Ser>),
+}
+
+impl<Set, Ser> PhaseStep<Set, Ser> {
+    #[cfg(feature = "sync")]
+    pub fn custom(
+        handler: impl for<'a> Fn(&mut Set, &mut Ser, &mut Ctx<'a>) -> Result<(), Error>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self::Custom(ARef::new(handler))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    pub fn custom(
+        handler: impl for<'a> Fn(&mut Set, &mut Ser, &mut Ctx<'a>) -> Result<(), Error> + 'static,
+    ) -> Self {
+        Self::Custom(ARef::new(handler))
+    }
+
+    /// Return the [`Phase`] of built-in step, or None for [`PhaseStep::Custom`].
+    pub fn phase(&self) -> Option<Phase> {
+        match self {
+            PhaseStep::Opt => Some(Phase::Opt),
+            PhaseStep::Cmd => Some(Phase::Cmd),
+            PhaseStep::Pos => Some(Phase::Pos),
+            PhaseStep::Main => Some(Phase::Main),
+            PhaseStep::Custom(_) => None,
+        }
+    }
+}
+
+impl<Set, Ser> Clone for PhaseStep<Set, Ser> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Opt => Self::Opt,
+            Self::Cmd => Self::Cmd,
+            Self::Pos => Self::Pos,
+            Self::Main => Self::Main,
+            Self::Custom(handler) => Self::Custom(handler.clone()),
+        }
+    }
+}
+
+impl<Set, Ser> Debug for PhaseStep<Set, Ser> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Opt => write!(f, "Opt"),
+            Self::Cmd => write!(f, "Cmd"),
+            Self::Pos => write!(f, "Pos"),
+            Self::Main => write!(f, "Main"),
+            Self::Custom(_) => f.debug_tuple("Custom").field(&"{ ... }").finish(),
+        }
+    }
+}
+
+impl<Set, Ser> From<Phase> for PhaseStep<Set, Ser> {
+    fn from(value: Phase) -> Self {
+        match value {
+            Phase::Opt => Self::Opt,
+            Phase::Cmd => Self::Cmd,
+            Phase::Pos => Self::Pos,
+            Phase::Main => Self::Main,
+        }
+    }
+}
+
+/// The ordered phases processed by [`FwdPolicy`](crate::parser::FwdPolicy),
+/// the default order is `Opt`, `Cmd`, `Pos` and `Main`.
+///
+/// The list is checked when created:
+/// each built-in phase must appear exactly once, the [`Phase::Opt`] must be
+/// the first built-in phase because it collects the NOA arguments,
+/// and the [`Phase::Main`] must be the last built-in phase.
+/// The [`PhaseStep::Custom`] can be inserted anywhere.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let phases = PhaseList::new(vec![
+///     PhaseStep::Opt,
+///     PhaseStep::custom(|_: &mut ASet, _: &mut ASer, ctx: &mut Ctx| {
+///         // drop the comments such as `#note` from NOA arguments
+///         let args = ctx
+///             .args()
+///             .iter()
+///             .copied()
+///             .filter(|v| !v.to_string_lossy().starts_with('#'))
+///             .collect();
+///         ctx.set_args(args);
+///         Ok(())
+///     }),
+///     PhaseStep::Pos,
+///     PhaseStep::Cmd,
+///     PhaseStep::Main,
+/// ])?;
+/// let mut parser = AFwdParser::new_policy(AFwdPolicy::default().with_phases(phases));
+///
+/// parser.add_opt("--debug=b")?;
+/// parser.add_opt("file=p@2")?.set_pos_type::<String>();
+/// parser.add_opt("list=c")?;
+///
+/// parser.parse(Args::from(["app", "list", "#note", "foo", "--debug"]))?;
+///
+/// assert_eq!(parser.find_val::<String>("file")?, "foo");
+/// assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+///
+/// assert!(PhaseList::<ASet, ASer>::new(vec![PhaseStep::Cmd, PhaseStep::Opt]).is_err());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct PhaseList<Set, Ser> {
+    steps: Vec<PhaseStep<Set, Ser>>,
+}
+
+impl<Set, Ser> PhaseList<Set, Ser> {
+    pub fn new(steps: Vec<PhaseStep<Set, Ser>>) -> Result<Self, Error> {
+        let phases: Vec<_> = steps.iter().filter_map(PhaseStep::phase).collect();
+
+        for phase in [Phase::Opt, Phase::Cmd, Phase::Pos, Phase::Main] {
+            let count = phases.iter().filter(|v| **v == phase).count();
+
+            if count != 1 {
+                return Err(raise_error!(
+                    "phase {:?} must appear exactly once, found {}",
+                    phase,
+                    count
+                ));
+            }
+        }
+        if phases.first() != Some(&Phase::Opt) {
+            return Err(raise_error!("phase Opt must be the first built-in phase"));
+        }
+        if phases.last() != Some(&Phase::Main) {
+            return Err(raise_error!("phase Main must be the last built-in phase"));
+        }
+        Ok(Self { steps })
+    }
+
+    pub fn steps(&self) -> &[PhaseStep<Set, Ser>] {
+        &self.steps
+    }
+}
+
+impl<Set, Ser> Default for PhaseList<Set, Ser> {
+    fn default() -> Self {
+        Self {
+            steps: vec![
+                PhaseStep::Opt,
+                PhaseStep::Cmd,
+                PhaseStep::Pos,
+                PhaseStep::Main,
+            ],
+        }
+    }
+}
+
+impl<Set, Ser> Clone for PhaseList<Set, Ser> {
+    fn clone(&self) -> Self {
+        Self {
+            steps: self.steps.clone(),
+        }
+    }
+}
+
+impl<Set, Ser> Debug for PhaseList<Set, Ser> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.steps.iter()).finish()
+    }
+}
+
+impl<Set, Ser> Deref for PhaseList<Set, Ser> {
+    type Target = [PhaseStep<Set, Ser>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.steps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_phase_list() {
+        assert!(testing_phase_list_main().is_ok());
+    }
+
+    fn testing_phase_list_main() -> Result<(), Error> {
+        type Step = PhaseStep<ASet, ASer>;
+
+        let custom = || Step::custom(|_, _, _| Ok(()));
+
+        assert!(PhaseList::new(vec![Step::Opt, Step::Pos, Step::Cmd, Step::Main]).is_ok());
+        assert!(PhaseList::new(vec![
+            custom(),
+            Step::Opt,
+            custom(),
+            Step::Cmd,
+            Step::Pos,
+            Step::Main,
+            custom()
+        ])
+        .is_ok());
+        assert!(PhaseList::new(vec![Step::Opt, Step::Cmd, Step::Main]).is_err());
+        assert!(
+            PhaseList::new(vec![Step::Opt, Step::Cmd, Step::Pos, Step::Pos, Step::Main]).is_err()
+        );
+        assert!(PhaseList::new(vec![Step::Cmd, Step::Opt, Step::Pos, Step::Main]).is_err());
+        assert!(PhaseList::new(vec![Step::Opt, Step::Cmd, Step::Main, Step::Pos]).is_err());
+
+        // stop after the custom phase
+        let phases = PhaseList::new(vec![
+            Step::Opt,
+            Step::Cmd,
+            Step::custom(|_, _, ctx| {
+                ctx.set_policy_act(crate::parser::Action::Quit);
+                Ok(())
+            }),
+            Step::Pos,
+            Step::Main,
+        ])?;
+        let mut parser = AFwdParser::new_policy(AFwdPolicy::default().with_phases(phases));
+
+        parser.add_opt("file=p@1")?;
+        parser.parse(Args::from(["app", "foo"]))?;
+        assert!(!parser.find_opt("file")?.matched());
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use super::Policy;
+use super::Return;
+use crate::args::Args;
+use crate::ext::APolicyExt;
+use crate::trace;
+use crate::Error;
+
+/// [`PolicyChain`] runs two policies in sequence over the same [`Set`](crate::set::Set),
+/// [`Invoker`](crate::ctx::Invoker) and services.
+///
+/// The arguments left by the first policy, such as the options not matched in non-strict mode
+/// and the `NOA`s, are passed to the second policy.
+/// If the first policy failed, the chain returns its [`Return`] without running the second one.
+/// Chain more policies by nesting the [`PolicyChain`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// // process the known options first, then collect the left arguments
+/// let mut parser = Parser::new_policy(PolicyChain::new(
+///     AFwdPolicy::default().with_strict(false).with_partial(true),
+///     AFwdPolicy::default().with_strict(false),
+/// ));
+///
+/// parser.add_opt("--verbose;-v=b")?;
+/// parser.add_opt("--jobs;-j=u!")?;
+/// parser.add_opt("file=p@1")?.set_pos_type::<String>();
+/// parser.add_opt("rest=p@2..")?.set_pos_type::<String>();
+/// parser.parse(Args::from(["app", "-v", "foo", "--color", "-j=2", "bar"]))?;
+///
+/// assert_eq!(parser.find_val::<bool>("-v")?, &true);
+/// assert_eq!(parser.find_val::<u64>("-j")?, &2);
+/// assert_eq!(parser.find_val::<String>("file")?, "foo");
+/// assert_eq!(
+///     parser.find_vals::<String>("rest")?,
+///     &["--color".to_owned(), "bar".to_owned()]
+/// );
+///
+/// // the second policy is not running if the first one failed
+/// parser.reset()?;
+/// assert!(!parser.parse(Args::from(["app", "foo"]))?.status());
+/// assert!(!parser.find_opt("file")?.matched());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PolicyChain<P1, P2> {
+    first: P1,
+
+    second: P2,
+}
+
+impl<P1, P2> PolicyChain<P1, P2> {
+    pub fn new(first: P1, second: P2) -> Self {
+        Self { first, second }
+    }
+
+    pub fn with_first(mut self, first: P1) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_second(mut self, second: P2) -> Self {
+        self.second = second;
+        self
+    }
+
+    pub fn set_first(&mut self, first: P1) -> &mut Self {
+        self.first = first;
+        self
+    }
+
+    pub fn set_second(&mut self, second: P2) -> &mut Self {
+        self.second = second;
+        self
+    }
+
+    pub fn first(&self) -> &P1 {
+        &self.first
+    }
+
+    pub fn first_mut(&mut self) -> &mut P1 {
+        &mut self.first
+    }
+
+    pub fn second(&self) -> &P2 {
+        &self.second
+    }
+
+    pub fn second_mut(&mut self) -> &mut P2 {
+        &mut self.second
+    }
+
+    /// Split the chain into the policies.
+    pub fn into_inner(self) -> (P1, P2) {
+        (self.first, self.second)
+    }
+}
+
+impl<P1, P2> Policy for PolicyChain<P1, P2>
+where
+    P1: Policy<Ret = Return>,
+    P2: for<'a> Policy<Ret = Return, Set = P1::Set, Ser = P1::Ser, Inv<'a> = P1::Inv<'a>>,
+{
+    type Ret = Return;
+
+    type Set = P1::Set;
+
+    type Inv<'a> = P1::Inv<'a>;
+
+    type Ser = P1::Ser;
+
+    type Error = Error;
+
+    fn parse(
+        &mut self,
+        set: &mut Self::Set,
+        inv: &mut Self::Inv<'_>,
+        ser: &mut Self::Ser,
+        orig: Args,
+    ) -> Result<Self::Ret, Self::Error> {
+        let ret = self.first.parse(set, inv, ser, orig).map_err(Into::into)?;
+
+        if !ret.status() {
+            return Ok(ret);
+        }
+
+        let args = Args::from(ret.clone_args());
+
+        trace!(%args, "hand off the left arguments to next policy");
+        self.second.parse(set, inv, ser, args).map_err(Into::into)
+    }
+}
+
+impl<P1, P2> APolicyExt<PolicyChain<P1, P2>> for PolicyChain<P1, P2>
+where
+    P1: APolicyExt<P1> + Policy<Ret = Return>,
+    P2: for<'a> Policy<Ret = Return, Set = P1::Set, Ser = P1::Ser, Inv<'a> = P1::Inv<'a>>,
+{
+    fn default_ser(&self) -> P1::Ser {
+        self.first.default_ser()
+    }
+
+    fn default_set(&self) -> P1::Set {
+        self.first.default_set()
+    }
+
+    fn default_inv<'a>(&self) -> P1::Inv<'a> {
+        self.first.default_inv()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_chain() {
+        assert!(testing_chain_main().is_ok());
+    }
+
+    fn testing_chain_main() -> Result<(), Error> {
+        // collect the global options, then pass the left to sub command
+        let mut parser = Parser::new_policy(PolicyChain::new(
+            APrePolicy::default().with_partial(true),
+            AFwdPolicy::default(),
+        ));
+
+        parser.add_opt("--debug;-d=b")?;
+        parser.add_opt("build=c")?;
+        parser.add_opt("--release;-r=b")?;
+        parser
+            .add_opt("target=p@2")?
+            .set_pos_type::<String>()
+            .set_force(true)
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                // the index is the index in the left arguments
+                assert_eq!(ctx.idx()?, 2);
+                Ok(Some(ctx.value::<String>()?))
+            })?;
+
+        let ret = parser.parse(Args::from(["app", "-d", "build", "-r", "x86"]))?;
+
+        assert!(ret.status());
+        assert_eq!(ret.args().len(), 3);
+        assert_eq!(parser.find_val::<bool>("-d")?, &true);
+        assert_eq!(parser.find_val::<bool>("build")?, &true);
+        assert_eq!(parser.find_val::<bool>("-r")?, &true);
+        assert_eq!(parser.find_val::<String>("target")?, "x86");
+
+        // the failure of second policy
+        parser.reset()?;
+        assert!(!parser
+            .parse(Args::from(["app", "-d", "build", "-r"]))?
+            .status());
+
+        let (first, _) = parser.policy().clone().into_inner();
+
+        assert!(first.partial());
+        Ok(())
+    }
+}
@@ -1,19 +1,25 @@
-use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
+use std::time::Instant;
 
+use super::assign::guess_assign;
+use super::MatchedSnapshot;
 use super::OptStyleManager;
 use super::Policy;
 use super::PolicySettings;
+use super::PosCount;
 use super::Return;
 use super::UserStyle;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
+use crate::ctx::CancelToken;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
 use crate::ctx::Invoker;
+use crate::guess::invoke_or_dry_run;
 use crate::guess::process_handler_ret;
 use crate::guess::InnerCtxSaver;
 use crate::guess::InvokeGuess;
@@ -27,6 +33,10 @@ use crate::set::SetChecker;
 use crate::set::SetExt;
 use crate::set::SetOpt;
 use crate::trace;
+use crate::value::BoolLiterals;
+use crate::value::Decoding;
+use crate::value::FloatSyntax;
+use crate::value::NumLocale;
 use crate::Error;
 use crate::Uid;
 
@@ -131,6 +141,22 @@ pub struct DelayPolicy<Set, Ser, Chk> {
 
     style_manager: OptStyleManager,
 
+    cancel: CancelToken,
+
+    timeout: Option<Duration>,
+
+    decoding: Decoding,
+
+    num_locale: NumLocale,
+
+    float_syntax: FloatSyntax,
+
+    pos_count: Option<PosCount>,
+
+    dry_run: bool,
+
+    bool_literals: BoolLiterals,
+
     no_delay_opt: Vec<String>,
 
     marker_s: PhantomData<(Set, Ser)>,
@@ -146,6 +172,14 @@ where
             overload: self.overload,
             checker: self.checker.clone(),
             style_manager: self.style_manager.clone(),
+            cancel: self.cancel.clone(),
+            timeout: self.timeout,
+            decoding: self.decoding,
+            num_locale: self.num_locale,
+            float_syntax: self.float_syntax,
+            pos_count: self.pos_count,
+            dry_run: self.dry_run,
+            bool_literals: self.bool_literals.clone(),
             no_delay_opt: self.no_delay_opt.clone(),
             marker_s: self.marker_s,
         }
@@ -162,6 +196,14 @@ where
             .field("overload", &self.overload)
             .field("checker", &self.checker)
             .field("style_manager", &self.style_manager)
+            .field("cancel", &self.cancel)
+            .field("timeout", &self.timeout)
+            .field("decoding", &self.decoding)
+            .field("num_locale", &self.num_locale)
+            .field("float_syntax", &self.float_syntax)
+            .field("pos_count", &self.pos_count)
+            .field("dry_run", &self.dry_run)
+            .field("bool_literals", &self.bool_literals)
             .field("no_delay_opt", &self.no_delay_opt)
             .finish()
     }
@@ -177,6 +219,14 @@ where
             overload: false,
             checker: Chk::default(),
             style_manager: OptStyleManager::default(),
+            cancel: CancelToken::default(),
+            timeout: None,
+            decoding: Decoding::default(),
+            num_locale: NumLocale::default(),
+            float_syntax: FloatSyntax::default(),
+            pos_count: None,
+            dry_run: false,
+            bool_literals: BoolLiterals::default(),
             no_delay_opt: vec![],
             marker_s: PhantomData,
         }
@@ -223,6 +273,134 @@ impl<Set, Ser, Chk> DelayPolicy<Set, Ser, Chk> {
         self
     }
 
+    /// Set the [`CancelToken`] checked by the policy during parsing,
+    /// it is also available to handlers through [`Ctx::cancel_token`].
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) -> &mut Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Set the timeout of handlers, the deadline of parsing is `timeout` after the parsing starts.
+    /// The handler wrappers abort the parsing with [`Error::timeout`] once the deadline passed,
+    /// the deadline is checked between handlers so a running handler is not preempted,
+    /// see [`Ctx::deadline`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Set the [`Decoding`] used by value parsers when converting raw value to string,
+    /// it can be overridden by option with [`ValStorer::new_decoding`](crate::value::ValStorer::new_decoding).
+    pub fn with_decoding(mut self, decoding: Decoding) -> Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn set_decoding(&mut self, decoding: Decoding) -> &mut Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn decoding(&self) -> Decoding {
+        self.decoding
+    }
+
+    /// Set the [`NumLocale`] used by the integer and float value parsers,
+    /// the value not in the locale format is parsed as plain number.
+    pub fn with_num_locale(mut self, num_locale: NumLocale) -> Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn set_num_locale(&mut self, num_locale: NumLocale) -> &mut Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn num_locale(&self) -> NumLocale {
+        self.num_locale
+    }
+
+    /// Set the [`FloatSyntax`] accepted by the float value parsers.
+    pub fn with_float_syntax(mut self, float_syntax: FloatSyntax) -> Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn set_float_syntax(&mut self, float_syntax: FloatSyntax) -> &mut Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn float_syntax(&self) -> FloatSyntax {
+        self.float_syntax
+    }
+
+    /// Set the bound on the total number of positional arguments, see [`PosCount`].
+    pub fn with_pos_count(mut self, pos_count: PosCount) -> Self {
+        self.pos_count = Some(pos_count);
+        self
+    }
+
+    pub fn set_pos_count(&mut self, pos_count: Option<PosCount>) -> &mut Self {
+        self.pos_count = pos_count;
+        self
+    }
+
+    pub fn pos_count(&self) -> Option<PosCount> {
+        self.pos_count
+    }
+
+    /// Enable the dry-run mode, the policy matches the options but not invoke the handlers,
+    /// the values are not stored and the options are not marked as matched after parsing.
+    /// The options would be matched are available in [`dry_matched`](Return::dry_matched).
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Set the [`BoolLiterals`] accepted by the `bool` value parser.
+    pub fn with_bool_literals(mut self, bool_literals: BoolLiterals) -> Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn set_bool_literals(&mut self, bool_literals: BoolLiterals) -> &mut Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn bool_literals(&self) -> &BoolLiterals {
+        &self.bool_literals
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -323,7 +501,11 @@ where
         };
 
         ctx.set_inner_ctx(Some(inner_ctx.with_uid(uid)));
-        let ret = process_handler_ret(inv.invoke_fb(&uid, set, ser, ctx), |_| Ok(()), fail)?;
+        let ret = process_handler_ret(
+            invoke_or_dry_run(inv, &uid, set, ser, ctx),
+            |_| Ok(()),
+            fail,
+        )?;
 
         set.opt_mut(uid)?.set_matched(ret);
         Ok(ret)
@@ -348,7 +530,7 @@ where
             let inner_ctx = delay_ctx.inner_ctx;
             let mut matched = false;
 
-            trace!("invoke the handler: Inner = {:?}", &inner_ctx);
+            trace!(ctx = ?inner_ctx, "invoke the handler");
             for (uid, cache_matched) in delay_ctx.uids.iter().zip(delay_ctx.matched.iter()) {
                 let ret = if let Some(cache_matched) = cache_matched {
                     *cache_matched
@@ -472,83 +654,89 @@ where
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
 
-        trace!("parsing {ctx:?} using delay policy");
+        trace!(policy = "delay", args = %orig.redacted(set), "parsing");
         // set option args, and args length
         ctx.set_args(args.clone());
-        while let Some((idx, (opt, next))) = iter2.next() {
-            let mut matched = false;
-            let mut consume = false;
-            let mut stopped = false;
-
-            // parsing current argument
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
-                trace!(
-                    "guess name: {:?} value: {:?} & next: {:?}",
-                    name,
-                    value,
-                    next
-                );
-                if set.check(&name).map_err(Into::into)? {
-                    let arg = value.clone();
-                    let next = next.map(|v| Cow::Borrowed(*v));
-                    let mut guess = InvokeGuess {
-                        idx,
-                        arg,
-                        set,
-                        inv,
-                        ser,
-                        total,
-                        ctx,
-                        next,
-                        fail: &mut opt_fail,
-                        name: Some(name.clone()),
-                    };
-
-                    for style in opt_styles.iter() {
-                        if let Some(ret) = guess.guess_and_collect(style, overload)? {
-                            // pretend we are matched, cause it is delay
-                            matched = true;
-                            consume = ret.consume;
-                            if let Some(ret) = self.save_or_call(&mut guess, ret, &mut contexts)? {
-                                // if the call returned, set the real return value
-                                (matched, consume) = (ret.matched, ret.consume);
+        let args = {
+            let _opt_span = crate::trace_span!("opt", policy = "delay", total);
+
+            while let Some((idx, (opt, next))) = iter2.next() {
+                ctx.check_cancelled()?;
+
+                let mut matched = false;
+                let mut consume = false;
+                let mut stopped = false;
+
+                // parsing current argument
+                if let Ok(ArgInfo { name, value }) = opt_styles.parse_arg(opt) {
+                    trace!(idx, ?name, ?value, ?next, "guess option");
+                    let like_opt = set.check(&name).map_err(Into::into)?
+                        || (value.is_some() && set.check_bare(&name).map_err(Into::into)?);
+
+                    if like_opt {
+                        let arg = value.clone();
+                        let next = next.map(|v| opt_styles.next_arg(v));
+                        let mut guess = InvokeGuess {
+                            idx,
+                            arg,
+                            set,
+                            inv,
+                            ser,
+                            total,
+                            ctx,
+                            next,
+                            fail: &mut opt_fail,
+                            name: Some(name.clone()),
+                        };
+
+                        for style in opt_styles.resolve(&name).iter() {
+                            if let Some(ret) = guess.guess_and_collect(style, overload)? {
+                                // pretend we are matched, cause it is delay
+                                matched = true;
+                                consume = ret.consume;
+                                if let Some(ret) =
+                                    self.save_or_call(&mut guess, ret, &mut contexts)?
+                                {
+                                    // if the call returned, set the real return value
+                                    (matched, consume) = (ret.matched, ret.consume);
+                                }
+                                if matched {
+                                    break;
+                                }
                             }
-                            if matched {
-                                break;
+                            match guess.ctx.policy_act() {
+                                Action::Stop => {
+                                    stopped = true;
+                                    guess.ctx.reset_policy_act();
+                                    break;
+                                }
+                                Action::Quit => return Ok(()),
+                                Action::Null => {}
                             }
                         }
-                        match guess.ctx.policy_act() {
-                            Action::Stop => {
-                                stopped = true;
-                                guess.ctx.reset_policy_act();
-                                break;
-                            }
-                            Action::Quit => return Ok(()),
-                            Action::Null => {}
+                        if !stopped && !matched && self.strict() {
+                            return Err(opt_fail.cause(Error::sp_not_found(name)));
                         }
+                    } else {
+                        trace!(idx, arg = ?opt, "not like option");
                     }
-                    if !stopped && !matched && self.strict() {
-                        return Err(opt_fail.cause(Error::sp_not_found(name)));
-                    }
-                } else {
-                    trace!("`{:?}` not like option", opt);
+                }
+                if stopped {
+                    // skip current, put left argument to noa args
+                    lefts.extend(iter2.map(|(_, (a, _))| *a));
+                    break;
+                }
+                // if consume the argument, skip it
+                if matched && consume {
+                    iter2.next();
+                } else if !matched {
+                    // add it to NOA if current argument not matched
+                    lefts.push(*opt);
                 }
             }
-            if stopped {
-                // skip current, put left argument to noa args
-                lefts.extend(iter2.map(|(_, (a, _))| *a));
-                break;
-            }
-            // if consume the argument, skip it
-            if matched && consume {
-                iter2.next();
-            } else if !matched {
-                // add it to NOA if current argument not matched
-                lefts.push(*opt);
-            }
-        }
 
-        let args = lefts;
+            guess_assign(set, inv, ser, ctx, &mut opt_fail, lefts, overload)?
+        };
         let total = args.len();
         let mut pos_fail = FailManager::default();
         let mut cmd_fail = FailManager::default();
@@ -557,26 +745,33 @@ where
         ctx.set_args(args.clone());
         // when style is pos, noa index is [1..=len]
         if total > 0 {
-            let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
-            let mut guess = InvokeGuess {
-                set,
-                inv,
-                ser,
-                total,
-                name,
-                ctx,
-                arg: None,
-                next: None,
-                fail: &mut cmd_fail,
-                idx: Self::noa_cmd(),
-            };
+            {
+                let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+                let mut guess = InvokeGuess {
+                    set,
+                    inv,
+                    ser,
+                    total,
+                    name,
+                    ctx,
+                    arg: None,
+                    next: None,
+                    fail: &mut cmd_fail,
+                    idx: Self::noa_cmd(),
+                };
+
+                let _cmd_span = crate::trace_span!("cmd", total);
 
-            trace!("guess Cmd = {:?}", guess.name);
-            guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
-            if let Action::Quit = ctx.policy_act() {
-                return Ok(());
+                trace!(name = ?guess.name, "guess cmd");
+                guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
+                if let Action::Quit = ctx.policy_act() {
+                    return Ok(());
+                }
+                cmd_fail.process_check(self.checker().cmd_check(set))?;
+            }
+            if let Some(pos_count) = &self.pos_count {
+                pos_count.check_ctx(ctx)?;
             }
-            cmd_fail.process_check(self.checker().cmd_check(set))?;
 
             let mut guess = InvokeGuess {
                 set,
@@ -591,10 +786,13 @@ where
                 idx: Self::noa_cmd(),
             };
 
+            let _pos_span = crate::trace_span!("pos", total);
+
             for idx in 1..total {
+                guess.ctx.check_cancelled()?;
                 guess.idx = Self::noa_pos(idx);
                 guess.name = crate::str::osstr_to_str_i(&args, Self::noa_pos(idx));
-                trace!("guess Pos argument = {:?} @ {}", guess.name, guess.idx);
+                trace!(idx = guess.idx, name = ?guess.name, "guess pos");
                 guess.guess_and_invoke(&UserStyle::Pos, overload)?;
                 match guess.ctx.policy_act() {
                     Action::Stop => {
@@ -609,29 +807,36 @@ where
             cmd_fail.process_check(self.checker().cmd_check(set))?;
         }
 
-        trace!("in delay policy, invoke the handler of option");
-        // after cmd and pos callback invoked, invoke the callback of option
-        for saver in contexts {
-            let ret = self.process_delay_ctx(&mut prev_ctx, set, inv, ser, &mut opt_fail, saver)?;
+        {
+            let _delay_span = crate::trace_span!("delay");
 
-            match prev_ctx.policy_act() {
-                Action::Stop => {
-                    prev_ctx.reset_policy_act();
-                    break;
+            trace!("in delay policy, invoke the handler of option");
+            // after cmd and pos callback invoked, invoke the callback of option
+            for saver in contexts {
+                let ret =
+                    self.process_delay_ctx(&mut prev_ctx, set, inv, ser, &mut opt_fail, saver)?;
+
+                match prev_ctx.policy_act() {
+                    Action::Stop => {
+                        prev_ctx.reset_policy_act();
+                        break;
+                    }
+                    Action::Quit => return Ok(()),
+                    Action::Null => {}
+                }
+                if !ret.matched && self.strict() {
+                    return Err(opt_fail.cause(crate::raise_error!(
+                        "option match failed, Ctx = {:?}",
+                        prev_ctx
+                    )));
                 }
-                Action::Quit => return Ok(()),
-                Action::Null => {}
-            }
-            if !ret.matched && self.strict() {
-                return Err(opt_fail.cause(crate::raise_error!(
-                    "option match failed, Ctx = {:?}",
-                    prev_ctx
-                )));
             }
-        }
+            // the handlers of option are invoked with `prev_ctx`
+            ctx.dry_matched.append(&mut prev_ctx.dry_matched);
 
-        opt_fail.process_check(self.checker().opt_check(set))?;
-        pos_fail.process_check(self.checker().pos_check(set))?;
+            opt_fail.process_check(self.checker().opt_check(set))?;
+            pos_fail.process_check(self.checker().pos_check(set))?;
+        }
 
         let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
         let mut main_fail = FailManager::default();
@@ -649,7 +854,9 @@ where
             idx: Self::noa_main(),
         };
 
-        trace!("guess Main {:?}", guess.name);
+        let _main_span = crate::trace_span!("main");
+
+        trace!(name = ?guess.name, "guess main");
         guess.guess_and_invoke(&UserStyle::Main, overload)?;
         main_fail.process_check(self.checker().post_check(set))?;
         Ok(())
@@ -679,9 +886,17 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
-
-        match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_cancel_token(self.cancel.clone())
+            .with_deadline(self.timeout.map(|v| Instant::now() + v))
+            .with_decoding(self.decoding)
+            .with_num_locale(self.num_locale)
+            .with_float_syntax(self.float_syntax)
+            .with_bool_literals(self.bool_literals.clone())
+            .with_dry_run(self.dry_run);
+        let snapshot = self.dry_run.then(|| MatchedSnapshot::new(set));
+        let ret = match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
             Ok(_) => Ok(Return::new(ctx)),
             Err(e) => {
                 if e.is_failure() {
@@ -690,7 +905,12 @@ where
                     Err(e)
                 }
             }
+        };
+
+        if let Some(snapshot) = snapshot {
+            snapshot.restore(set);
         }
+        ret
     }
 }
 
@@ -882,4 +1102,96 @@ mod test {
         assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
         Ok(())
     }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn testing_span() {
+        assert!(testing_span_main().is_ok());
+    }
+
+    #[cfg(feature = "log")]
+    fn testing_span_main() -> Result<(), Error> {
+        use std::sync::Mutex;
+        use tracing::span;
+        use tracing::Event;
+        use tracing::Metadata;
+        use tracing::Subscriber;
+
+        // record every span with the name of the span entered when it is created
+        #[derive(Debug, Default)]
+        struct Recorder {
+            names: Mutex<Vec<&'static str>>,
+            stack: Mutex<Vec<u64>>,
+            parents: Mutex<Vec<(&'static str, Option<&'static str>)>>,
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+                let mut names = self.names.lock().unwrap();
+                let name = attrs.metadata().name();
+                let parent = self
+                    .stack
+                    .lock()
+                    .unwrap()
+                    .last()
+                    .map(|id| names[*id as usize - 1]);
+
+                names.push(name);
+                self.parents.lock().unwrap().push((name, parent));
+                span::Id::from_u64(names.len() as u64)
+            }
+
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+            fn event(&self, _: &Event<'_>) {}
+
+            fn enter(&self, span: &span::Id) {
+                self.stack.lock().unwrap().push(span.into_u64());
+            }
+
+            fn exit(&self, _: &span::Id) {
+                self.stack.lock().unwrap().pop();
+            }
+        }
+
+        fn check_span<'a, P>(mut parser: Parser<'a, P>) -> Result<(), Error>
+        where
+            P: Policy<Set = ASet, Ser = ASer, Inv<'a> = Invoker<'a, ASet, ASer>, Error = Error>,
+        {
+            let recorder = std::sync::Arc::new(Recorder::default());
+
+            parser.add_opt("list=c")?;
+            parser.add_opt("--all=b")?;
+            parser.add_opt("file=p@2")?;
+            tracing::subscriber::with_default(recorder.clone(), || {
+                parser.parse(Args::from(["app", "list", "--all", "foo"]))
+            })?;
+
+            let parents = recorder.parents.lock().unwrap();
+
+            for name in ["opt", "cmd", "pos", "main"] {
+                assert!(
+                    parents.iter().any(|(span, _)| span == &name),
+                    "missing span `{name}`: {parents:?}"
+                );
+            }
+            for (span, parent) in parents.iter() {
+                if ["cmd", "pos", "delay", "main"].contains(span) {
+                    assert_ne!(parent, &Some("opt"), "span nested in `opt`: {parents:?}");
+                }
+            }
+            Ok(())
+        }
+
+        check_span(AFwdParser::default())?;
+        check_span(APreParser::default())?;
+        check_span(ADelayParser::default())?;
+        Ok(())
+    }
 }
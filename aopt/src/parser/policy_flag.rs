@@ -0,0 +1,442 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use super::Policy;
+use super::Return;
+use crate::args::token::STOP;
+use crate::args::Args;
+use crate::ctx::Ctx;
+use crate::ctx::InnerCtx;
+use crate::ctx::Invoker;
+use crate::opt::action::check_once;
+use crate::opt::Opt;
+use crate::opt::Style;
+use crate::opt::BOOL_TRUE;
+use crate::set::SetChecker;
+use crate::set::SetExt;
+use crate::set::SetOpt;
+use crate::trace;
+use crate::Error;
+use crate::Uid;
+
+/// [`FlagPolicy`] is a fast path for command line tools only have boolean flags and positionals.
+///
+/// It stores the value into the [`Opt`] directly while walking the arguments,
+/// without the guess and invoke machinery used by other policies.
+/// The arguments are matched against the names in the set, and one inner context
+/// is reused for all the arguments, so there is no allocation per argument except storing the values:
+///
+/// * An argument matching the name or alias of a [`Boolean`](crate::opt::Style::Boolean)
+///   option sets the option to `true`.
+/// * An argument such as `-abc` sets the flags `-a`, `-b` and `-c` if all of them exist.
+/// * The arguments after `--` are NOA.
+/// * Other arguments are NOA, which will be stored into the
+///   [`Pos`](crate::opt::Style::Pos) options matching the index.
+///
+/// The handlers in [`Invoker`] are not called, and [`Cmd`](crate::opt::Style::Cmd),
+/// [`Main`](crate::opt::Style::Main) or options with value are not supported.
+/// Using [`FwdPolicy`](crate::parser::FwdPolicy) if you need them.
+///
+/// # Examples
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFlagParser::default();
+///
+/// parser.add_opt("--all;-a=b")?;
+/// parser.add_opt("--force;-f=b")?;
+/// parser.add_opt("path=p@1")?.set_pos_type::<String>();
+///
+/// parser.parse(Args::from(["rm", "-a", "target", "--force"]))?;
+///
+/// assert!(*parser.find_val::<bool>("--all")?);
+/// assert!(*parser.find_val::<bool>("--force")?);
+/// assert_eq!(parser.find_val::<String>("path")?, "target");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct FlagPolicy<Set, Ser, Chk> {
+    strict: bool,
+
+    checker: Chk,
+
+    marker_s: PhantomData<(Set, Ser)>,
+}
+
+impl<Set, Ser, Chk> Clone for FlagPolicy<Set, Ser, Chk>
+where
+    Chk: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            strict: self.strict,
+            checker: self.checker.clone(),
+            marker_s: self.marker_s,
+        }
+    }
+}
+
+impl<Set, Ser, Chk> Debug for FlagPolicy<Set, Ser, Chk>
+where
+    Chk: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlagPolicy")
+            .field("strict", &self.strict)
+            .field("checker", &self.checker)
+            .finish()
+    }
+}
+
+impl<Set, Ser, Chk> Default for FlagPolicy<Set, Ser, Chk>
+where
+    Chk: Default,
+{
+    fn default() -> Self {
+        Self {
+            strict: true,
+            checker: Chk::default(),
+            marker_s: PhantomData,
+        }
+    }
+}
+
+impl<Set, Ser, Chk> FlagPolicy<Set, Ser, Chk>
+where
+    Chk: Default,
+{
+    pub fn new(strict: bool) -> Self {
+        Self {
+            strict,
+            ..Default::default()
+        }
+    }
+}
+
+impl<Set, Ser, Chk> FlagPolicy<Set, Ser, Chk> {
+    /// In strict mode, an argument starts with `-` must be a flag or combined flags,
+    /// otherwise the parse fails with an option not found failure.
+    /// If strict mode is disabled, the argument not matched is treated as NOA.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_checker(mut self, checker: Chk) -> Self {
+        self.checker = checker;
+        self
+    }
+
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
+        self.checker = checker;
+        self
+    }
+
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn checker(&self) -> &Chk {
+        &self.checker
+    }
+
+    pub fn checker_mut(&mut self) -> &mut Chk {
+        &mut self.checker
+    }
+}
+
+/// The kind of argument classified by [`FlagPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArgKind {
+    /// The argument is the name or alias of a flag.
+    Flag(Uid),
+
+    /// The argument is combined short flags such as `-abc`.
+    Combined,
+
+    /// The `--`, the arguments after it are NOA.
+    Stop,
+
+    Noa,
+}
+
+impl<Set, Ser, Chk> FlagPolicy<Set, Ser, Chk>
+where
+    SetOpt<Set>: Opt,
+    Chk: SetChecker<Set>,
+    Set: crate::set::Set,
+{
+    /// Find the boolean option matching `name`, the option with higher priority is preferred.
+    fn find_flag(set: &Set, name: &str) -> Option<Uid> {
+        set.iter()
+            .filter(|opt| opt.mat_style(Style::Boolean))
+            .filter(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name))
+            .min_by_key(|opt| std::cmp::Reverse(opt.priority()))
+            .map(|opt| opt.uid())
+    }
+
+    /// Return the name `-c` of short flag in `buf`.
+    fn short(ch: char, buf: &mut [u8; 5]) -> &str {
+        buf[0] = b'-';
+        let len = ch.encode_utf8(&mut buf[1..]).len();
+
+        std::str::from_utf8(&buf[..len + 1]).unwrap_or_default()
+    }
+
+    /// Return the argument if it may be a flag, such as `-a`, `--all` or `-abc`.
+    fn like_flag(arg: &OsStr) -> Option<&str> {
+        arg.to_str().filter(|v| v.len() > 1 && v.starts_with('-'))
+    }
+
+    /// Classify the argument, return a failure if it like a flag but not matched in strict mode.
+    fn classify(&self, set: &Set, arg: &OsStr) -> Result<ArgKind, Error> {
+        let Some(name) = Self::like_flag(arg) else {
+            return Ok(ArgKind::Noa);
+        };
+
+        if name == STOP {
+            return Ok(ArgKind::Stop);
+        }
+        if let Some(uid) = Self::find_flag(set, name) {
+            return Ok(ArgKind::Flag(uid));
+        }
+        let mut buf = [0; 5];
+
+        if !name.starts_with(STOP)
+            && name[1..]
+                .chars()
+                .all(|ch| Self::find_flag(set, Self::short(ch, &mut buf)).is_some())
+        {
+            Ok(ArgKind::Combined)
+        } else if self.strict {
+            Err(Error::sp_not_found(name))
+        } else {
+            Ok(ArgKind::Noa)
+        }
+    }
+
+    /// Return true if the argument is NOA, it is cheap in strict mode
+    /// since the arguments like flag are either matched or failed.
+    fn is_noa(&self, set: &Set, arg: &OsStr) -> bool {
+        match Self::like_flag(arg) {
+            Some(name) => {
+                !self.strict && name != STOP && matches!(self.classify(set, arg), Ok(ArgKind::Noa))
+            }
+            None => true,
+        }
+    }
+
+    /// Store the argument to option, the inner context is updated in place.
+    fn store(opt: &mut SetOpt<Set>, ctx: &mut Ctx<'_>, arg: &OsStr) -> Result<(), Error> {
+        let act = *opt.action();
+
+        ctx.inner_ctx_mut()?.set_uid(opt.uid());
+        check_once(opt, &act)?;
+        opt.accessor_mut().store_all(Some(arg), ctx, &act)?;
+        opt.set_matched(true);
+        Ok(())
+    }
+
+    pub(crate) fn parse_impl<'a>(
+        &mut self,
+        set: &mut Set,
+        orig: &'a Args,
+        ctx: &mut Ctx<'a>,
+    ) -> Result<(), Error> {
+        self.checker().pre_check(set).map_err(|e| e.into())?;
+
+        let bool_true = OsStr::new(BOOL_TRUE);
+        let mut stop = orig.len();
+        let mut total = 1;
+
+        trace!(policy = "flag", args = %orig.redacted(set), "parsing");
+        ctx.set_inner_ctx(Some(
+            InnerCtx::default()
+                .with_total(orig.len())
+                .with_style(Style::Boolean)
+                .with_arg(Some(Cow::Borrowed(bool_true))),
+        ));
+        // set the flags and count NOA, index 0 is the program name
+        for (idx, arg) in orig.iter().enumerate().skip(1) {
+            if idx > stop {
+                total += 1;
+                continue;
+            }
+            let kind = self.classify(set, arg)?;
+            let name = arg.to_str().unwrap_or_default();
+
+            ctx.inner_ctx_mut()?
+                .set_index(idx)
+                .set_name(Some(Cow::Borrowed(name)));
+            match kind {
+                ArgKind::Flag(uid) => Self::store(set.opt_mut(uid)?, ctx, bool_true)?,
+                ArgKind::Combined => {
+                    let mut buf = [0; 5];
+
+                    for ch in name[1..].chars() {
+                        if let Some(uid) = Self::find_flag(set, Self::short(ch, &mut buf)) {
+                            Self::store(set.opt_mut(uid)?, ctx, bool_true)?;
+                        }
+                    }
+                }
+                ArgKind::Stop => stop = idx,
+                ArgKind::Noa => total += 1,
+            }
+        }
+        // store the NOA into the Pos options matching the index
+        if set.iter().any(|opt| opt.mat_style(Style::Pos)) {
+            let mut noa_idx = 0;
+
+            ctx.inner_ctx_mut()?.set_total(total).set_style(Style::Pos);
+            for (idx, arg) in orig.iter().enumerate().skip(1) {
+                let arg = arg.as_os_str();
+
+                if idx == stop || (idx < stop && !self.is_noa(set, arg)) {
+                    continue;
+                }
+                noa_idx += 1;
+                ctx.inner_ctx_mut()?
+                    .set_index(noa_idx)
+                    .set_name(arg.to_str().map(Cow::Borrowed))
+                    .set_arg(Some(Cow::Borrowed(arg)));
+                for opt in set.iter_mut() {
+                    if opt.mat_style(Style::Pos) && opt.mat_index(Some((noa_idx, total))) {
+                        Self::store(opt, ctx, arg)?;
+                    }
+                }
+            }
+        }
+        ctx.set_inner_ctx(None);
+        self.checker().opt_check(set).map_err(|e| e.into())?;
+        self.checker().cmd_check(set).map_err(|e| e.into())?;
+        self.checker().pos_check(set).map_err(|e| e.into())?;
+        self.checker().post_check(set).map_err(|e| e.into())?;
+        Ok(())
+    }
+}
+
+impl<Set, Ser, Chk> Policy for FlagPolicy<Set, Ser, Chk>
+where
+    SetOpt<Set>: Opt,
+    Chk: SetChecker<Set>,
+    Set: crate::set::Set,
+{
+    type Ret = Return;
+
+    type Set = Set;
+
+    type Inv<'a> = Invoker<'a, Set, Ser>;
+
+    type Ser = Ser;
+
+    type Error = Error;
+
+    fn parse(
+        &mut self,
+        set: &mut Self::Set,
+        _: &mut Self::Inv<'_>,
+        _: &mut Self::Ser,
+        orig: Args,
+    ) -> Result<Self::Ret, Self::Error> {
+        let mut ctx = Ctx::default().with_orig(orig.clone());
+
+        match self.parse_impl(set, &orig, &mut ctx) {
+            Ok(_) => Ok(Return::new(ctx)),
+            Err(e) => {
+                if e.is_failure() {
+                    Ok(Return::new(ctx).with_failure(e))
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_1() {
+        assert!(testing_1_main().is_ok());
+    }
+
+    fn testing_1_main() -> Result<(), Error> {
+        let mut policy = AFlagPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        let verbose = set.add_opt("--verbose;-v=b")?.run()?;
+        let quiet = set.add_opt("--quiet=b")?.run()?;
+        let src = set.add_opt("src=p@1")?.set_pos_type::<String>().run()?;
+        let dst = set.add_opt("dst=p@-0")?.set_pos_type::<String>().run()?;
+        let rest = set.add_opt("rest=p@2..")?.set_pos_type::<String>().run()?;
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "a", "-v", "b", "c"]),
+        )?;
+        assert!(*set[verbose].val::<bool>()?);
+        assert!(!*set[quiet].val::<bool>()?);
+        assert_eq!(set[src].val::<String>()?, "a");
+        assert_eq!(set[dst].val::<String>()?, "c");
+        assert_eq!(set[rest].vals::<String>()?, &["b", "c"]);
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        assert!(!policy
+            .parse(&mut set, &mut inv, &mut ser, Args::from(["app", "--debug"]))?
+            .status());
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        policy.set_strict(false);
+        policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "--debug", "--quiet"]),
+        )?;
+        assert!(*set[quiet].val::<bool>()?);
+        assert_eq!(set[src].val::<String>()?, "--debug");
+
+        let all = set.add_opt("-a=b")?.run()?;
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        policy.set_strict(true);
+        policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "-av", "x", "--", "--quiet", "-a"]),
+        )?;
+        assert!(*set[all].val::<bool>()?);
+        assert!(*set[verbose].val::<bool>()?);
+        assert!(!*set[quiet].val::<bool>()?);
+        assert_eq!(set[src].val::<String>()?, "x");
+        assert_eq!(set[dst].val::<String>()?, "-a");
+        Ok(())
+    }
+}
@@ -1,16 +1,26 @@
-use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
+use std::time::Instant;
 
+use super::assign::guess_assign;
 use super::FailManager;
+use super::MatchedSnapshot;
+use super::ObserverRef;
 use super::OptStyleManager;
+use super::Phase;
+use super::PhaseList;
+use super::PhaseStep;
 use super::Policy;
+use super::PolicyObserver;
 use super::PolicySettings;
+use super::PosCount;
 use super::Return;
 use super::UserStyle;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
+use crate::ctx::CancelToken;
 use crate::ctx::Ctx;
 use crate::ctx::Invoker;
 use crate::guess::InvokeGuess;
@@ -21,6 +31,10 @@ use crate::set::OptValidator;
 use crate::set::SetChecker;
 use crate::set::SetOpt;
 use crate::trace;
+use crate::value::BoolLiterals;
+use crate::value::Decoding;
+use crate::value::FloatSyntax;
+use crate::value::NumLocale;
 use crate::Error;
 
 /// [`FwdPolicy`] matching the command line arguments with [`Opt`] in the [`Set`](crate::set::Set).
@@ -96,10 +110,32 @@ pub struct FwdPolicy<Set, Ser, Chk> {
 
     overload: bool,
 
+    partial: bool,
+
     checker: Chk,
 
     style_manager: OptStyleManager,
 
+    cancel: CancelToken,
+
+    timeout: Option<Duration>,
+
+    decoding: Decoding,
+
+    num_locale: NumLocale,
+
+    float_syntax: FloatSyntax,
+
+    pos_count: Option<PosCount>,
+
+    dry_run: bool,
+
+    bool_literals: BoolLiterals,
+
+    observer: Option<ObserverRef>,
+
+    phases: PhaseList<Set, Ser>,
+
     marker_s: PhantomData<(Set, Ser)>,
 }
 
@@ -111,8 +147,19 @@ where
         Self {
             strict: self.strict,
             overload: self.overload,
+            partial: self.partial,
             checker: self.checker.clone(),
             style_manager: self.style_manager.clone(),
+            cancel: self.cancel.clone(),
+            timeout: self.timeout,
+            decoding: self.decoding,
+            num_locale: self.num_locale,
+            float_syntax: self.float_syntax,
+            pos_count: self.pos_count,
+            dry_run: self.dry_run,
+            bool_literals: self.bool_literals.clone(),
+            observer: self.observer.clone(),
+            phases: self.phases.clone(),
             marker_s: self.marker_s,
         }
     }
@@ -126,8 +173,19 @@ where
         f.debug_struct("FwdPolicy")
             .field("strict", &self.strict)
             .field("overload", &self.overload)
+            .field("partial", &self.partial)
             .field("checker", &self.checker)
             .field("style_manager", &self.style_manager)
+            .field("cancel", &self.cancel)
+            .field("timeout", &self.timeout)
+            .field("decoding", &self.decoding)
+            .field("num_locale", &self.num_locale)
+            .field("float_syntax", &self.float_syntax)
+            .field("pos_count", &self.pos_count)
+            .field("dry_run", &self.dry_run)
+            .field("bool_literals", &self.bool_literals)
+            .field("observer", &self.observer.is_some())
+            .field("phases", &self.phases)
             .finish()
     }
 }
@@ -140,7 +198,18 @@ where
         Self {
             strict: true,
             overload: false,
+            partial: false,
             style_manager: OptStyleManager::default(),
+            cancel: CancelToken::default(),
+            timeout: None,
+            decoding: Decoding::default(),
+            num_locale: NumLocale::default(),
+            float_syntax: FloatSyntax::default(),
+            pos_count: None,
+            dry_run: false,
+            bool_literals: BoolLiterals::default(),
+            observer: None,
+            phases: PhaseList::default(),
             checker: Chk::default(),
             marker_s: PhantomData,
         }
@@ -183,6 +252,213 @@ impl<Set, Ser, Chk> FwdPolicy<Set, Ser, Chk> {
         self
     }
 
+    /// In partial mode, the policy stop after processing options and [`Cmd`](crate::opt::Cmd),
+    /// the [`Pos`](crate::opt::Pos) and [`Main`](crate::opt::Main) are not processed.
+    /// The unconsumed NOA arguments are available in [`Return::args`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsString;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.policy_mut().set_partial(true);
+    /// parser.add_opt("--debug=b")?;
+    /// parser.add_opt("file=p@1")?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "--debug", "foo", "bar"]))?;
+    ///
+    /// assert!(ret.status());
+    /// assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+    /// // Pos not processed
+    /// assert!(!parser.find_opt("file")?.matched());
+    /// assert_eq!(ret.args(), &[OsString::from("app"), "foo".into(), "bar".into()]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    pub fn set_partial(&mut self, partial: bool) -> &mut Self {
+        self.partial = partial;
+        self
+    }
+
+    pub fn partial(&self) -> bool {
+        self.partial
+    }
+
+    /// Set the [`CancelToken`] checked by the policy during parsing,
+    /// it is also available to handlers through [`Ctx::cancel_token`].
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) -> &mut Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Set the timeout of handlers, the deadline of parsing is `timeout` after the parsing starts.
+    /// The handler wrappers abort the parsing with [`Error::timeout`] once the deadline passed,
+    /// the deadline is checked between handlers so a running handler is not preempted,
+    /// see [`Ctx::deadline`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Set the [`Decoding`] used by value parsers when converting raw value to string,
+    /// it can be overridden by option with [`ValStorer::new_decoding`](crate::value::ValStorer::new_decoding).
+    pub fn with_decoding(mut self, decoding: Decoding) -> Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn set_decoding(&mut self, decoding: Decoding) -> &mut Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn decoding(&self) -> Decoding {
+        self.decoding
+    }
+
+    /// Set the [`NumLocale`] used by the integer and float value parsers,
+    /// the value not in the locale format is parsed as plain number.
+    pub fn with_num_locale(mut self, num_locale: NumLocale) -> Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn set_num_locale(&mut self, num_locale: NumLocale) -> &mut Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn num_locale(&self) -> NumLocale {
+        self.num_locale
+    }
+
+    /// Set the [`FloatSyntax`] accepted by the float value parsers.
+    pub fn with_float_syntax(mut self, float_syntax: FloatSyntax) -> Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn set_float_syntax(&mut self, float_syntax: FloatSyntax) -> &mut Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn float_syntax(&self) -> FloatSyntax {
+        self.float_syntax
+    }
+
+    /// Set the bound on the total number of positional arguments, see [`PosCount`].
+    pub fn with_pos_count(mut self, pos_count: PosCount) -> Self {
+        self.pos_count = Some(pos_count);
+        self
+    }
+
+    pub fn set_pos_count(&mut self, pos_count: Option<PosCount>) -> &mut Self {
+        self.pos_count = pos_count;
+        self
+    }
+
+    pub fn pos_count(&self) -> Option<PosCount> {
+        self.pos_count
+    }
+
+    /// Enable the dry-run mode, the policy matches the options but not invoke the handlers,
+    /// the values are not stored and the options are not marked as matched after parsing.
+    /// The options would be matched are available in [`dry_matched`](Return::dry_matched).
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Set the [`BoolLiterals`] accepted by the `bool` value parser.
+    pub fn with_bool_literals(mut self, bool_literals: BoolLiterals) -> Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn set_bool_literals(&mut self, bool_literals: BoolLiterals) -> &mut Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn bool_literals(&self) -> &BoolLiterals {
+        &self.bool_literals
+    }
+
+    /// Set the [`PolicyObserver`] receive the checkpoint events during parsing.
+    pub fn with_observer(mut self, observer: ObserverRef) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub fn set_observer(&mut self, observer: ObserverRef) -> &mut Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub fn observer(&self) -> Option<&ObserverRef> {
+        self.observer.as_ref()
+    }
+
+    /// Set the order of phases processed by the policy, see [`PhaseList`].
+    pub fn with_phases(mut self, phases: PhaseList<Set, Ser>) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn set_phases(&mut self, phases: PhaseList<Set, Ser>) -> &mut Self {
+        self.phases = phases;
+        self
+    }
+
+    pub fn phases(&self) -> &PhaseList<Set, Ser> {
+        &self.phases
+    }
+
+    fn notify(&self, f: impl FnOnce(&dyn PolicyObserver)) {
+        if let Some(observer) = &self.observer {
+            f(observer.as_ref());
+        }
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -270,6 +546,35 @@ where
     ) -> Result<(), <Self as Policy>::Error> {
         self.checker().pre_check(set).map_err(|e| e.into())?;
 
+        trace!(policy = "fwd", args = %orig.redacted(set), "parsing");
+        for step in self.phases.iter() {
+            let next = match step {
+                PhaseStep::Opt => self.parse_opt(set, inv, ser, orig, ctx)?,
+                PhaseStep::Cmd => self.parse_cmd(set, inv, ser, ctx)?,
+                PhaseStep::Pos => self.parse_pos(set, inv, ser, ctx)?,
+                PhaseStep::Main => self.parse_main(set, inv, ser, ctx)?,
+                PhaseStep::Custom(handler) => {
+                    handler(set, ser, ctx)?;
+                    !matches!(ctx.policy_act(), Action::Quit)
+                }
+            };
+
+            if !next {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Process the options, the NOA arguments are set to [`Ctx::args`].
+    fn parse_opt<'a>(
+        &self,
+        set: &mut <Self as Policy>::Set,
+        inv: &mut <Self as Policy>::Inv<'_>,
+        ser: &mut <Self as Policy>::Ser,
+        orig: &'a Args,
+        ctx: &mut Ctx<'a>,
+    ) -> Result<bool, <Self as Policy>::Error> {
         let overload = self.overload();
         let opt_styles = &self.style_manager;
         let args: Vec<_> = orig.iter().map(|v| v.as_os_str()).collect();
@@ -278,23 +583,24 @@ where
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
 
-        trace!("parsing {ctx:?} using fwd policy");
+        let _opt_span = crate::trace_span!("opt", policy = "fwd", total);
         ctx.set_args(args.clone());
         while let Some((idx, (opt, next))) = iter2.next() {
+            ctx.check_cancelled()?;
+            self.notify(|o| o.on_arg_start(idx, opt));
+
             let mut matched = false;
             let mut consume = false;
             let mut stopped = false;
 
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
-                trace!(
-                    "guess name: {:?} value: {:?} & next: {:?}",
-                    name,
-                    value,
-                    next
-                );
-                if set.check(&name).map_err(Into::into)? {
+            if let Ok(ArgInfo { name, value }) = opt_styles.parse_arg(opt) {
+                trace!(idx, ?name, ?value, ?next, "guess option");
+                let like_opt = set.check(&name).map_err(Into::into)?
+                    || (value.is_some() && set.check_bare(&name).map_err(Into::into)?);
+
+                if like_opt {
                     let arg = value.clone();
-                    let next = next.map(|v| Cow::Borrowed(*v));
+                    let next = next.map(|v| opt_styles.next_arg(v));
                     let mut guess = InvokeGuess {
                         idx,
                         arg,
@@ -308,7 +614,7 @@ where
                         name: Some(name.clone()),
                     };
 
-                    for style in opt_styles.iter() {
+                    for style in opt_styles.resolve(&name).iter() {
                         if let Some(ret) = guess.guess_and_invoke(style, overload)? {
                             (matched, consume) = (ret.matched, ret.consume);
                         }
@@ -318,18 +624,23 @@ where
                                 guess.ctx.reset_policy_act();
                                 break;
                             }
-                            Action::Quit => return Ok(()),
+                            Action::Quit => return Ok(false),
                             Action::Null => {}
                         }
                         if matched {
                             break;
                         }
                     }
+                    if matched {
+                        if let Ok(uid) = guess.ctx.uid() {
+                            self.notify(|o| o.on_opt_matched(idx, uid));
+                        }
+                    }
                     if !stopped && !matched && self.strict() {
                         return Err(opt_fail.cause(Error::sp_not_found(name)));
                     }
                 } else {
-                    trace!("`{:?}` not like option", opt);
+                    trace!(idx, arg = ?opt, "not like option");
                 }
             }
             if stopped {
@@ -346,14 +657,26 @@ where
             }
         }
 
+        let lefts = guess_assign(set, inv, ser, ctx, &mut opt_fail, lefts, overload)?;
+
         opt_fail.process_check(self.checker().opt_check(set))?;
+        self.notify(|o| o.on_phase_end(Phase::Opt));
+        ctx.set_args(lefts);
+        Ok(true)
+    }
 
-        let args = lefts;
+    fn parse_cmd(
+        &self,
+        set: &mut <Self as Policy>::Set,
+        inv: &mut <Self as Policy>::Inv<'_>,
+        ser: &mut <Self as Policy>::Ser,
+        ctx: &mut Ctx<'_>,
+    ) -> Result<bool, <Self as Policy>::Error> {
+        let overload = self.overload();
+        let args = ctx.args.clone();
         let total = args.len();
-        let mut pos_fail = FailManager::default();
         let mut cmd_fail = FailManager::default();
 
-        ctx.set_args(args.clone());
         // when style is pos, noa index is [1..=len]
         if total > 0 {
             let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
@@ -370,12 +693,35 @@ where
                 idx: Self::noa_cmd(),
             };
 
-            trace!("guess Cmd = {:?}", guess.name);
+            let _cmd_span = crate::trace_span!("cmd", total);
+
+            trace!(name = ?guess.name, "guess cmd");
             guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
             if let Action::Quit = ctx.policy_act() {
-                return Ok(());
+                return Ok(false);
+            }
+        }
+        cmd_fail.process_check(self.checker().cmd_check(set))?;
+        self.notify(|o| o.on_phase_end(Phase::Cmd));
+        Ok(!self.partial())
+    }
+
+    fn parse_pos(
+        &self,
+        set: &mut <Self as Policy>::Set,
+        inv: &mut <Self as Policy>::Inv<'_>,
+        ser: &mut <Self as Policy>::Ser,
+        ctx: &mut Ctx<'_>,
+    ) -> Result<bool, <Self as Policy>::Error> {
+        let overload = self.overload();
+        let args = ctx.args.clone();
+        let total = args.len();
+        let mut pos_fail = FailManager::default();
+
+        if total > 0 {
+            if let Some(pos_count) = &self.pos_count {
+                pos_count.check_ctx(ctx)?;
             }
-            cmd_fail.process_check(self.checker().cmd_check(set))?;
 
             let mut guess = InvokeGuess {
                 set,
@@ -390,27 +736,40 @@ where
                 idx: Self::noa_cmd(),
             };
 
+            let _pos_span = crate::trace_span!("pos", total);
+
             for idx in 1..total {
+                guess.ctx.check_cancelled()?;
                 guess.idx = Self::noa_pos(idx);
                 guess.name = crate::str::osstr_to_str_i(&args, Self::noa_pos(idx));
-                trace!("guess Pos argument = {:?} @ {}", guess.name, guess.idx);
+                trace!(idx = guess.idx, name = ?guess.name, "guess pos");
                 guess.guess_and_invoke(&UserStyle::Pos, overload)?;
                 match guess.ctx.policy_act() {
                     Action::Stop => {
                         guess.ctx.reset_policy_act();
                         break;
                     }
-                    Action::Quit => return Ok(()),
+                    Action::Quit => return Ok(false),
                     Action::Null => {}
                 }
             }
-        } else {
-            cmd_fail.process_check(self.checker().cmd_check(set))?;
         }
-
         pos_fail.process_check(self.checker().pos_check(set))?;
+        self.notify(|o| o.on_phase_end(Phase::Pos));
+        Ok(true)
+    }
 
-        let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
+    fn parse_main(
+        &self,
+        set: &mut <Self as Policy>::Set,
+        inv: &mut <Self as Policy>::Inv<'_>,
+        ser: &mut <Self as Policy>::Ser,
+        ctx: &mut Ctx<'_>,
+    ) -> Result<bool, <Self as Policy>::Error> {
+        let overload = self.overload();
+        let args = ctx.args.clone();
+        let total = args.len();
+        let name = crate::str::osstr_to_str_i(&args, Self::noa_main());
         let mut main_fail = FailManager::default();
         let mut guess = InvokeGuess {
             set,
@@ -425,10 +784,13 @@ where
             idx: Self::noa_main(),
         };
 
-        trace!("guess Main {:?}", guess.name);
+        let _main_span = crate::trace_span!("main");
+
+        trace!(name = ?guess.name, "guess main");
         guess.guess_and_invoke(&UserStyle::Main, overload)?;
         main_fail.process_check(self.checker().post_check(set))?;
-        Ok(())
+        self.notify(|o| o.on_phase_end(Phase::Main));
+        Ok(true)
     }
 }
 
@@ -455,9 +817,17 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
-
-        match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_cancel_token(self.cancel.clone())
+            .with_deadline(self.timeout.map(|v| Instant::now() + v))
+            .with_decoding(self.decoding)
+            .with_num_locale(self.num_locale)
+            .with_float_syntax(self.float_syntax)
+            .with_bool_literals(self.bool_literals.clone())
+            .with_dry_run(self.dry_run);
+        let snapshot = self.dry_run.then(|| MatchedSnapshot::new(set));
+        let ret = match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
             Ok(_) => Ok(Return::new(ctx)),
             Err(e) => {
                 if e.is_failure() {
@@ -466,7 +836,12 @@ where
                     Err(e)
                 }
             }
+        };
+
+        if let Some(snapshot) = snapshot {
+            snapshot.restore(set);
         }
+        ret
     }
 }
 
@@ -1053,4 +1428,46 @@ mod test {
         policy.parse(&mut set, &mut inv, &mut ser, args)?;
         Ok(())
     }
+
+    #[test]
+    fn testing_timeout() {
+        assert!(testing_timeout_main().is_ok());
+    }
+
+    fn testing_timeout_main() -> Result<(), Error> {
+        use std::time::Duration;
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--slow=b")?.on(|_, _, _| {
+            std::thread::sleep(Duration::from_millis(20));
+            Ok(Some(true))
+        })?;
+        parser
+            .add_opt("--fast=b")?
+            .on(|_, _, ctx| Ok(Some(!ctx.is_timeout())))?;
+
+        let args = Args::from(["app", "--slow", "--fast"]);
+
+        assert!(parser.parse(args.clone())?.status());
+        assert_eq!(parser.find_val::<bool>("--fast")?, &true);
+
+        parser.reset()?;
+        parser
+            .policy_mut()
+            .set_timeout(Some(Duration::from_millis(10)));
+
+        let ret = parser.parse(args.clone());
+
+        assert!(ret.unwrap_err().is_timeout());
+        // the handler after deadline is not invoked
+        assert_eq!(parser.find_val::<bool>("--fast")?, &false);
+
+        parser.reset()?;
+        parser
+            .policy_mut()
+            .set_timeout(Some(Duration::from_secs(60)));
+        assert!(parser.parse(args)?.status());
+        Ok(())
+    }
 }
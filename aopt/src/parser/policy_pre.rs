@@ -1,32 +1,51 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
+use std::time::Instant;
 
+use super::assign::guess_assign;
 use super::FailManager;
+use super::MatchedSnapshot;
+use super::ObserverRef;
 use super::OptStyleManager;
+use super::Phase;
 use super::Policy;
+use super::PolicyObserver;
 use super::PolicySettings;
+use super::PosCount;
 use super::Return;
 use super::UserStyle;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
+use crate::ctx::CancelToken;
 use crate::ctx::Ctx;
 use crate::ctx::Invoker;
 use crate::guess::InvokeGuess;
 use crate::opt::Opt;
 use crate::opt::OptParser;
+use crate::opt::Style;
 use crate::parser::Action;
 use crate::set::OptValidator;
 use crate::set::SetChecker;
 use crate::set::SetOpt;
 use crate::trace;
+use crate::value::BoolLiterals;
+use crate::value::Decoding;
+use crate::value::FloatSyntax;
+use crate::value::NumLocale;
 use crate::Error;
 
 /// [`PrePolicy`] matching the command line arguments with [`Opt`] in the [`Set`](crate::set::Set).
 /// [`PrePolicy`] will skip any special [`Error`] during [`parse`](Policy::parse) process.
 /// [`PrePolicy`] will return Some([`Return`]) if match successful.
 /// [`PrePolicy`] doesn’t consume the `NOA` when process [`guess_and_invoke`](crate::guess::InvokeGuess#method.guess_and_invoke).
+/// If any [`global`](crate::opt::Opt::global) option exists in the set,
+/// once the first `NOA` matched the name of a [`Cmd`](crate::opt::Cmd) option,
+/// only the arguments match the global options exactly will be processed,
+/// the left arguments are passed to the sub command as `NOA`,
+/// see [`is_global_opt`](PrePolicy::is_global_opt).
 ///
 /// # Example
 /// ```rust
@@ -116,8 +135,28 @@ pub struct PrePolicy<Set, Ser, Chk> {
 
     overload: bool,
 
+    partial: bool,
+
     style_manager: OptStyleManager,
 
+    cancel: CancelToken,
+
+    timeout: Option<Duration>,
+
+    decoding: Decoding,
+
+    num_locale: NumLocale,
+
+    float_syntax: FloatSyntax,
+
+    pos_count: Option<PosCount>,
+
+    dry_run: bool,
+
+    bool_literals: BoolLiterals,
+
+    observer: Option<ObserverRef>,
+
     checker: Chk,
 
     marker_s: PhantomData<(Set, Ser)>,
@@ -131,7 +170,17 @@ where
         Self {
             strict: self.strict,
             overload: self.overload,
+            partial: self.partial,
             style_manager: self.style_manager.clone(),
+            cancel: self.cancel.clone(),
+            timeout: self.timeout,
+            decoding: self.decoding,
+            num_locale: self.num_locale,
+            float_syntax: self.float_syntax,
+            pos_count: self.pos_count,
+            dry_run: self.dry_run,
+            bool_literals: self.bool_literals.clone(),
+            observer: self.observer.clone(),
             checker: self.checker.clone(),
             marker_s: self.marker_s,
         }
@@ -146,7 +195,17 @@ where
         f.debug_struct("PrePolicy")
             .field("strict", &self.strict)
             .field("overload", &self.overload)
+            .field("partial", &self.partial)
             .field("style_manager", &self.style_manager)
+            .field("cancel", &self.cancel)
+            .field("timeout", &self.timeout)
+            .field("decoding", &self.decoding)
+            .field("num_locale", &self.num_locale)
+            .field("float_syntax", &self.float_syntax)
+            .field("pos_count", &self.pos_count)
+            .field("dry_run", &self.dry_run)
+            .field("bool_literals", &self.bool_literals)
+            .field("observer", &self.observer.is_some())
             .field("checker", &self.checker)
             .finish()
     }
@@ -160,7 +219,17 @@ where
         Self {
             strict: false,
             overload: false,
+            partial: false,
             style_manager: OptStyleManager::default(),
+            cancel: CancelToken::default(),
+            timeout: None,
+            decoding: Decoding::default(),
+            num_locale: NumLocale::default(),
+            float_syntax: FloatSyntax::default(),
+            pos_count: None,
+            dry_run: false,
+            bool_literals: BoolLiterals::default(),
+            observer: None,
             checker: Chk::default(),
             marker_s: PhantomData,
         }
@@ -203,6 +272,172 @@ impl<Set, Ser, Chk> PrePolicy<Set, Ser, Chk> {
         self
     }
 
+    /// In partial mode, the policy stop after processing options and [`Cmd`](crate::opt::Cmd),
+    /// the [`Pos`](crate::opt::Pos) and [`Main`](crate::opt::Main) are not processed.
+    /// The unconsumed NOA arguments are available in [`Return::args`].
+    pub fn with_partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    pub fn set_partial(&mut self, partial: bool) -> &mut Self {
+        self.partial = partial;
+        self
+    }
+
+    pub fn partial(&self) -> bool {
+        self.partial
+    }
+
+    /// Set the [`CancelToken`] checked by the policy during parsing,
+    /// it is also available to handlers through [`Ctx::cancel_token`].
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) -> &mut Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Set the timeout of handlers, the deadline of parsing is `timeout` after the parsing starts.
+    /// The handler wrappers abort the parsing with [`Error::timeout`] once the deadline passed,
+    /// the deadline is checked between handlers so a running handler is not preempted,
+    /// see [`Ctx::deadline`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Set the [`Decoding`] used by value parsers when converting raw value to string,
+    /// it can be overridden by option with [`ValStorer::new_decoding`](crate::value::ValStorer::new_decoding).
+    pub fn with_decoding(mut self, decoding: Decoding) -> Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn set_decoding(&mut self, decoding: Decoding) -> &mut Self {
+        self.decoding = decoding;
+        self
+    }
+
+    pub fn decoding(&self) -> Decoding {
+        self.decoding
+    }
+
+    /// Set the [`NumLocale`] used by the integer and float value parsers,
+    /// the value not in the locale format is parsed as plain number.
+    pub fn with_num_locale(mut self, num_locale: NumLocale) -> Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn set_num_locale(&mut self, num_locale: NumLocale) -> &mut Self {
+        self.num_locale = num_locale;
+        self
+    }
+
+    pub fn num_locale(&self) -> NumLocale {
+        self.num_locale
+    }
+
+    /// Set the [`FloatSyntax`] accepted by the float value parsers.
+    pub fn with_float_syntax(mut self, float_syntax: FloatSyntax) -> Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn set_float_syntax(&mut self, float_syntax: FloatSyntax) -> &mut Self {
+        self.float_syntax = float_syntax;
+        self
+    }
+
+    pub fn float_syntax(&self) -> FloatSyntax {
+        self.float_syntax
+    }
+
+    /// Set the bound on the total number of positional arguments, see [`PosCount`].
+    pub fn with_pos_count(mut self, pos_count: PosCount) -> Self {
+        self.pos_count = Some(pos_count);
+        self
+    }
+
+    pub fn set_pos_count(&mut self, pos_count: Option<PosCount>) -> &mut Self {
+        self.pos_count = pos_count;
+        self
+    }
+
+    pub fn pos_count(&self) -> Option<PosCount> {
+        self.pos_count
+    }
+
+    /// Enable the dry-run mode, the policy matches the options but not invoke the handlers,
+    /// the values are not stored and the options are not marked as matched after parsing.
+    /// The options would be matched are available in [`dry_matched`](Return::dry_matched).
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Set the [`BoolLiterals`] accepted by the `bool` value parser.
+    pub fn with_bool_literals(mut self, bool_literals: BoolLiterals) -> Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn set_bool_literals(&mut self, bool_literals: BoolLiterals) -> &mut Self {
+        self.bool_literals = bool_literals;
+        self
+    }
+
+    pub fn bool_literals(&self) -> &BoolLiterals {
+        &self.bool_literals
+    }
+
+    /// Set the [`PolicyObserver`] receive the checkpoint events during parsing.
+    pub fn with_observer(mut self, observer: ObserverRef) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub fn set_observer(&mut self, observer: ObserverRef) -> &mut Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub fn observer(&self) -> Option<&ObserverRef> {
+        self.observer.as_ref()
+    }
+
+    fn notify(&self, f: impl FnOnce(&dyn PolicyObserver)) {
+        if let Some(observer) = &self.observer {
+            f(observer.as_ref());
+        }
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -294,6 +529,52 @@ where
     Chk: SetChecker<Set>,
     Set: crate::set::Set + OptParser + OptValidator,
 {
+    /// Return true if `arg` is the name or alias of a [`Cmd`](crate::opt::Style::Cmd) option.
+    pub fn is_sub_cmd(set: &Set, arg: &std::ffi::OsStr) -> bool {
+        arg.to_str().is_some_and(|name| {
+            set.iter().any(|opt| {
+                opt.mat_style(Style::Cmd) && (opt.mat_name(Some(name)) || opt.mat_alias(name))
+            })
+        })
+    }
+
+    /// Return true if `name` matches the name or alias of any [`global`](Opt::global) option.
+    ///
+    /// The `name` is the option part of argument, such as `--opt` of `--opt=value`.
+    /// The embedded value style such as `-ovalue` and the combined style such as `-abc`
+    /// are checked if they are enabled in `styles`,
+    /// the combined style matches only if every option of it is global.
+    pub fn is_global_opt(set: &Set, styles: &OptStyleManager, name: &str) -> bool {
+        let is_global = |name: &str| {
+            set.iter()
+                .any(|opt| opt.global() && (opt.mat_name(Some(name)) || opt.mat_alias(name)))
+        };
+
+        if is_global(name) {
+            return true;
+        }
+        if let Ok((prefix, rest)) = set.split(&Cow::Borrowed(name)) {
+            let prefix_len = prefix.len();
+
+            return styles.resolve(name).iter().any(|style| match style {
+                UserStyle::EmbeddedValue => rest
+                    .char_indices()
+                    .nth(1)
+                    .is_some_and(|(idx, _)| is_global(&name[..prefix_len + idx])),
+                UserStyle::EmbeddedValuePlus => rest
+                    .char_indices()
+                    .skip(2)
+                    .any(|(idx, _)| is_global(&name[..prefix_len + idx])),
+                UserStyle::CombinedOption => {
+                    rest.chars().count() > 1
+                        && rest.chars().all(|ch| is_global(&format!("{prefix}{ch}")))
+                }
+                _ => false,
+            });
+        }
+        false
+    }
+
     pub(crate) fn parse_impl<'a>(
         &mut self,
         set: &mut <Self as Policy>::Set,
@@ -311,79 +592,104 @@ where
         let mut lefts = vec![];
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
+        let has_global = set.iter().any(|opt| opt.global());
+        let mut in_sub = false;
 
-        trace!("parsing {ctx:?} using pre policy");
+        trace!(policy = "pre", args = %orig.redacted(set), "parsing");
         ctx.set_args(args.clone());
-        while let Some((idx, (opt, next))) = iter2.next() {
-            let mut matched = false;
-            let mut consume = false;
-            let mut stopped = false;
-            let mut like_opt = false;
-
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
-                trace!(
-                    "guess name: {:?} value: {:?} & next: {:?}",
-                    name,
-                    value,
-                    next
-                );
-                if let Some(valid) = Self::ig_failure(set.check(&name).map_err(Into::into))? {
-                    if valid {
-                        like_opt = true;
-                        let arg = value.clone();
-                        let next = next.map(|v| Cow::Borrowed(*v));
-                        let mut guess = InvokeGuess {
-                            idx,
-                            arg,
-                            set,
-                            inv,
-                            ser,
-                            total,
-                            ctx,
-                            next,
-                            fail: &mut opt_fail,
-                            name: Some(name.clone()),
-                        };
-
-                        for style in opt_styles.iter() {
-                            if let Some(Some(ret)) =
-                                Self::ig_failure(guess.guess_and_invoke(style, overload))?
-                            {
-                                (matched, consume) = (ret.matched, ret.consume);
-                            }
-                            match guess.ctx.policy_act() {
-                                Action::Stop => {
-                                    stopped = true;
-                                    guess.ctx.reset_policy_act();
+        let lefts = {
+            let _opt_span = crate::trace_span!("opt", policy = "pre", total);
+
+            while let Some((idx, (opt, next))) = iter2.next() {
+                ctx.check_cancelled()?;
+                self.notify(|o| o.on_arg_start(idx, opt));
+
+                let mut matched = false;
+                let mut consume = false;
+                let mut stopped = false;
+                let mut like_opt = false;
+
+                if let Ok(ArgInfo { name, value }) = opt_styles.parse_arg(opt) {
+                    trace!(idx, ?name, ?value, ?next, "guess option");
+                    let valid = match Self::ig_failure(set.check(&name).map_err(Into::into))? {
+                        Some(false) if value.is_some() => {
+                            Self::ig_failure(set.check_bare(&name).map_err(Into::into))?
+                        }
+                        valid => valid,
+                    };
+
+                    if let Some(valid) = valid {
+                        // after the sub command, only the global options are processed,
+                        // others are left to the sub command
+                        if valid && (!in_sub || Self::is_global_opt(set, opt_styles, &name)) {
+                            like_opt = true;
+                            let arg = value.clone();
+                            let next = next.map(|v| opt_styles.next_arg(v));
+                            let mut guess = InvokeGuess {
+                                idx,
+                                arg,
+                                set,
+                                inv,
+                                ser,
+                                total,
+                                ctx,
+                                next,
+                                fail: &mut opt_fail,
+                                name: Some(name.clone()),
+                            };
+
+                            for style in opt_styles.resolve(&name).iter() {
+                                if let Some(Some(ret)) =
+                                    Self::ig_failure(guess.guess_and_invoke(style, overload))?
+                                {
+                                    (matched, consume) = (ret.matched, ret.consume);
+                                }
+                                match guess.ctx.policy_act() {
+                                    Action::Stop => {
+                                        stopped = true;
+                                        guess.ctx.reset_policy_act();
+                                        break;
+                                    }
+                                    Action::Quit => return Ok(()),
+                                    Action::Null => {}
+                                }
+                                if matched {
                                     break;
                                 }
-                                Action::Quit => return Ok(()),
-                                Action::Null => {}
                             }
                             if matched {
-                                break;
+                                if let Ok(uid) = guess.ctx.uid() {
+                                    self.notify(|o| o.on_opt_matched(idx, uid));
+                                }
                             }
                         }
+                    } else {
+                        trace!(idx, arg = ?opt, "not like option");
+                    }
+                }
+                if stopped {
+                    // skip current, put left argument to noa args
+                    lefts.extend(iter2.map(|(_, (a, _))| *a));
+                    break;
+                }
+                // if consume the argument, skip it
+                if matched && consume {
+                    iter2.next();
+                } else if !matched && !self.strict() || !like_opt {
+                    // add it to NOA if current argument not matched
+                    // and not in strict mode or the argument not like an option
+                    lefts.push(*opt);
+                    if has_global && !in_sub && lefts.len() == Self::noa_cmd() + 1 {
+                        in_sub = Self::is_sub_cmd(set, opt);
                     }
-                } else {
-                    trace!("`{:?}` not like option", opt);
                 }
             }
-            if stopped {
-                // skip current, put left argument to noa args
-                lefts.extend(iter2.map(|(_, (a, _))| *a));
-                break;
-            }
-            // if consume the argument, skip it
-            if matched && consume {
-                iter2.next();
-            } else if !matched && !self.strict() || !like_opt {
-                // add it to NOA if current argument not matched
-                // and not in strict mode or the argument not like an option
-                lefts.push(*opt);
-            }
-        }
-        opt_fail.process_check(self.checker().opt_check(set))?;
+            let lefts = guess_assign(set, inv, ser, ctx, &mut opt_fail, lefts, overload)?;
+
+            opt_fail.process_check(self.checker().opt_check(set))?;
+            self.notify(|o| o.on_phase_end(Phase::Opt));
+            lefts
+        };
 
         let args = lefts;
         let total = args.len();
@@ -392,26 +698,37 @@ where
 
         ctx.set_args(args.clone());
         if total > 0 {
-            let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
-            let mut guess = InvokeGuess {
-                set,
-                inv,
-                ser,
-                total,
-                name,
-                ctx,
-                arg: None,
-                next: None,
-                fail: &mut cmd_fail,
-                idx: Self::noa_cmd(),
-            };
-
-            trace!("guess Cmd = {:?}", guess.name);
-            Self::ig_failure(guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
-            if let Action::Quit = ctx.policy_act() {
-                return Ok(());
+            {
+                let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+                let mut guess = InvokeGuess {
+                    set,
+                    inv,
+                    ser,
+                    total,
+                    name,
+                    ctx,
+                    arg: None,
+                    next: None,
+                    fail: &mut cmd_fail,
+                    idx: Self::noa_cmd(),
+                };
+
+                let _cmd_span = crate::trace_span!("cmd", total);
+
+                trace!(name = ?guess.name, "guess cmd");
+                Self::ig_failure(guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
+                if let Action::Quit = ctx.policy_act() {
+                    return Ok(());
+                }
+                cmd_fail.process_check(self.checker().cmd_check(set))?;
+                self.notify(|o| o.on_phase_end(Phase::Cmd));
+                if self.partial() {
+                    return Ok(());
+                }
+            }
+            if let Some(pos_count) = &self.pos_count {
+                pos_count.check_ctx(ctx)?;
             }
-            cmd_fail.process_check(self.checker().cmd_check(set))?;
 
             let mut guess = InvokeGuess {
                 set,
@@ -426,10 +743,13 @@ where
                 idx: Self::noa_cmd(),
             };
 
+            let _pos_span = crate::trace_span!("pos", total);
+
             for idx in 1..total {
+                guess.ctx.check_cancelled()?;
                 guess.idx = Self::noa_pos(idx);
                 guess.name = crate::str::osstr_to_str_i(&args, Self::noa_pos(idx));
-                trace!("guess Pos argument = {:?} @ {}", guess.name, guess.idx);
+                trace!(idx = guess.idx, name = ?guess.name, "guess pos");
                 Self::ig_failure(guess.guess_and_invoke(&UserStyle::Pos, overload))?;
                 match guess.ctx.policy_act() {
                     Action::Stop => {
@@ -442,8 +762,13 @@ where
             }
         } else {
             cmd_fail.process_check(self.checker().cmd_check(set))?;
+            self.notify(|o| o.on_phase_end(Phase::Cmd));
+            if self.partial() {
+                return Ok(());
+            }
         }
         pos_fail.process_check(self.checker().pos_check(set))?;
+        self.notify(|o| o.on_phase_end(Phase::Pos));
 
         let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
         let mut main_fail = FailManager::default();
@@ -460,9 +785,12 @@ where
             idx: Self::noa_main(),
         };
 
-        trace!("guess Main {:?}", guess.name);
+        let _main_span = crate::trace_span!("main");
+
+        trace!(name = ?guess.name, "guess main");
         Self::ig_failure(guess.guess_and_invoke(&UserStyle::Main, overload))?;
         main_fail.process_check(self.checker().post_check(set))?;
+        self.notify(|o| o.on_phase_end(Phase::Main));
         Ok(())
     }
 }
@@ -490,9 +818,17 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
-
-        match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_cancel_token(self.cancel.clone())
+            .with_deadline(self.timeout.map(|v| Instant::now() + v))
+            .with_decoding(self.decoding)
+            .with_num_locale(self.num_locale)
+            .with_float_syntax(self.float_syntax)
+            .with_bool_literals(self.bool_literals.clone())
+            .with_dry_run(self.dry_run);
+        let snapshot = self.dry_run.then(|| MatchedSnapshot::new(set));
+        let ret = match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
             Ok(_) => Ok(Return::new(ctx)),
             Err(e) => {
                 if e.is_failure() {
@@ -501,7 +837,12 @@ where
                     Err(e)
                 }
             }
+        };
+
+        if let Some(snapshot) = snapshot {
+            snapshot.restore(set);
         }
+        ret
     }
 }
 
@@ -0,0 +1,179 @@
+use std::fmt::Display;
+use std::ops::Bound;
+use std::ops::RangeBounds;
+
+use crate::ctx::Ctx;
+use crate::opt::Style;
+use crate::Error;
+
+/// The bound on the total number of positional arguments accepted by policy.
+///
+/// The name of program and the NOA matched by [`Cmd`](crate::opt::Cmd) are not counted.
+/// The policy raises a [`PosCount`](crate::ErrorKind::PosCount) failure
+/// before invoking the handlers of [`Pos`](crate::opt::Pos) if the number is out of the bound.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().set_pos_count(Some(PosCount::exact(2)));
+/// parser.add_opt("--debug=b")?;
+/// parser.add_opt("files=p@1..")?.set_pos_type::<String>();
+///
+/// parser.parse(Args::from(["app", "a.txt", "--debug", "b.txt"]))?;
+/// assert_eq!(parser.find_vals::<String>("files")?, &["a.txt", "b.txt"]);
+///
+/// parser.reset()?;
+/// let ret = parser.parse(Args::from(["app", "a.txt"]))?;
+/// let error = ret.failure().unwrap();
+///
+/// assert_eq!(error.code(), "pos_count");
+/// assert_eq!(
+///     error.desp(),
+///     Some("expect exactly 2 positional arguments, but got 1")
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PosCount {
+    min: usize,
+
+    max: Option<usize>,
+}
+
+impl PosCount {
+    /// Create the bound from range, such as `1..=3` or `2..`.
+    pub fn new(range: impl RangeBounds<usize>) -> Self {
+        let min = match range.start_bound() {
+            Bound::Included(v) => *v,
+            Bound::Excluded(v) => v.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(v) => Some(*v),
+            Bound::Excluded(v) => Some(v.saturating_sub(1)),
+            Bound::Unbounded => None,
+        };
+
+        Self { min, max }
+    }
+
+    pub fn exact(count: usize) -> Self {
+        Self::new(count..=count)
+    }
+
+    pub fn at_least(count: usize) -> Self {
+        Self::new(count..)
+    }
+
+    pub fn at_most(count: usize) -> Self {
+        Self::new(..=count)
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    pub fn contains(&self, count: usize) -> bool {
+        count >= self.min && self.max.is_none_or(|max| count <= max)
+    }
+
+    /// Return a failure if the `count` is out of the bound.
+    pub fn check(&self, count: usize) -> Result<(), Error> {
+        if self.contains(count) {
+            Ok(())
+        } else {
+            Err(Error::sp_pos_count(count, self))
+        }
+    }
+
+    /// Count the positional arguments in the NOAs of `ctx` and check it.
+    pub(crate) fn check_ctx(&self, ctx: &Ctx) -> Result<(), Error> {
+        let cmd = ctx
+            .noa_matched
+            .iter()
+            .any(|(_, _, style)| *style == Style::Cmd);
+
+        self.check(ctx.args.len().saturating_sub(1 + usize::from(cmd)))
+    }
+}
+
+impl Display for PosCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.min, self.max) {
+            (min, Some(max)) if min == max => write!(f, "exactly {}", min),
+            (0, Some(max)) => write!(f, "at most {}", max),
+            (min, Some(max)) => write!(f, "{} to {}", min, max),
+            (min, None) => write!(f, "at least {}", min),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_pos_count() {
+        assert!(testing_pos_count_main().is_ok());
+    }
+
+    fn testing_pos_count_main() -> Result<(), Error> {
+        assert_eq!(PosCount::new(1..3), PosCount::new(1..=2));
+        assert_eq!(PosCount::new(..).to_string(), "at least 0");
+        assert_eq!(PosCount::at_most(3).to_string(), "at most 3");
+        assert_eq!(PosCount::new(1..=3).to_string(), "1 to 3");
+        assert!(PosCount::at_least(1).contains(usize::MAX));
+        assert!(!PosCount::new(1..=3).contains(0));
+        assert!(PosCount::exact(2).check(3).is_err());
+
+        fn check<P>(mut parser: Parser<P>) -> Result<(), Error>
+        where
+            P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+            for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+        {
+            parser.add_opt("list=c")?;
+            parser.add_opt("--all=b")?;
+            parser.add_opt("files=p@2..")?.set_pos_type::<String>();
+
+            // the cmd is not counted
+            let ret = parser.parse(Args::from(["app", "list", "--all", "a", "b"]))?;
+
+            assert!(ret.status());
+
+            parser.reset()?;
+            let ret = parser.parse(Args::from(["app", "list", "a", "b", "c"]))?;
+            let error = ret.failure().unwrap();
+
+            assert_eq!(error.kind(), &ErrorKind::PosCount);
+            assert_eq!(
+                error.desp(),
+                Some("expect 1 to 2 positional arguments, but got 3")
+            );
+            Ok(())
+        }
+
+        check(AFwdParser::new_policy(
+            AFwdPolicy::default().with_pos_count(PosCount::new(1..=2)),
+        ))?;
+        check(APreParser::new_policy(
+            APrePolicy::default().with_pos_count(PosCount::new(1..=2)),
+        ))?;
+        check(ADelayParser::new_policy(
+            ADelayPolicy::default().with_pos_count(PosCount::new(1..=2)),
+        ))?;
+        Ok(())
+    }
+}
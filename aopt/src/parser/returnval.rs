@@ -1,8 +1,11 @@
+use std::ffi::OsStr;
 use std::ffi::OsString;
 
 use crate::args::Args;
 use crate::ctx::Ctx;
+use crate::opt::Opt;
 use crate::opt::Style;
+use crate::set::Set;
 use crate::{Error, Uid};
 
 #[derive(Debug, Clone, Default)]
@@ -20,6 +23,59 @@ pub struct Guess {
     pub total: usize,
 }
 
+/// The NOA matched by a [`Cmd`](Style::Cmd) or [`Pos`](Style::Pos) option.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoaMatch {
+    /// The index of NOA in [`Return::args`].
+    pub idx: usize,
+
+    pub uid: Uid,
+
+    pub style: Style,
+
+    pub arg: OsString,
+}
+
+/// The option would be matched in dry-run mode,
+/// see [`with_dry_run`](crate::parser::FwdPolicy::with_dry_run).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DryMatch {
+    /// The index of argument, it is the index of NOA for [`Cmd`](Style::Cmd),
+    /// [`Pos`](Style::Pos) and [`Main`](Style::Main).
+    pub idx: usize,
+
+    pub uid: Uid,
+
+    pub style: Style,
+
+    /// The name used to match the option, such as `-c` of `-c=42`.
+    pub name: Option<String>,
+
+    /// The argument would be parsed as value of option.
+    pub arg: Option<OsString>,
+}
+
+/// Save the uids of matched options before parsing, using by dry-run mode.
+pub(crate) struct MatchedSnapshot(Vec<Uid>);
+
+impl MatchedSnapshot {
+    pub fn new<S: Set>(set: &S) -> Self {
+        Self(
+            set.iter()
+                .filter(|opt| opt.matched())
+                .map(|opt| opt.uid())
+                .collect(),
+        )
+    }
+
+    /// Restore the matched state of options.
+    pub fn restore<S: Set>(self, set: &mut S) {
+        for opt in set.iter_mut() {
+            opt.set_matched(self.0.contains(&opt.uid()));
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Context {
     pub orig: Args,
@@ -27,6 +83,10 @@ pub struct Context {
     pub args: Vec<OsString>,
 
     pub guess: Option<Guess>,
+
+    pub noa_matched: Vec<NoaMatch>,
+
+    pub dry_matched: Vec<DryMatch>,
 }
 
 /// Return value for [`Policy`](crate::parser::Policy).
@@ -39,12 +99,35 @@ pub struct Return {
 
 impl Return {
     pub fn new(ctx: Ctx<'_>) -> Self {
-        let args = ctx.args.into_iter().map(|v| v.to_os_string()).collect();
+        let args: Vec<_> = ctx.args.into_iter().map(|v| v.to_os_string()).collect();
+        let noa_matched = ctx
+            .noa_matched
+            .into_iter()
+            .map(|(idx, uid, style)| NoaMatch {
+                idx,
+                uid,
+                style,
+                arg: args.get(idx).cloned().unwrap_or_default(),
+            })
+            .collect();
+        let dry_matched = ctx
+            .dry_matched
+            .into_iter()
+            .map(|v| DryMatch {
+                idx: v.idx(),
+                uid: v.uid(),
+                style: v.style(),
+                name: v.name().map(|v| v.to_string()),
+                arg: v.arg().map(|v| v.to_os_string()),
+            })
+            .collect();
 
         Self {
             ctx: Context {
                 orig: ctx.orig,
                 args,
+                noa_matched,
+                dry_matched,
                 guess: ctx.inner_ctx.map(|v| Guess {
                     uid: v.uid(),
                     name: v.name().map(|v| v.to_string()),
@@ -85,6 +168,104 @@ impl Return {
         &self.ctx.orig
     }
 
+    /// The NOAs matched by [`Cmd`](Style::Cmd) or [`Pos`](Style::Pos) options in the order of matching.
+    pub fn noa_matched(&self) -> &[NoaMatch] {
+        &self.ctx.noa_matched
+    }
+
+    /// The options would be matched in dry-run mode, in the order of matching.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::new_policy(AFwdPolicy::default().with_dry_run(true));
+    ///
+    /// parser.add_opt("--count;-c=i")?;
+    /// parser.add_opt("--debug=b")?;
+    /// parser
+    ///     .add_opt("files=p@1..")?
+    ///     .on(|_: &mut ASet, _: &mut ASer, _: &Ctx| -> Result<Option<()>, Error> {
+    ///         unreachable!("handler is not invoked in dry-run mode")
+    ///     })?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "-c=42", "a.txt", "--debug"]))?;
+    /// let matched = ret.dry_matched();
+    ///
+    /// assert!(ret.status());
+    /// assert_eq!(matched.len(), 3);
+    /// assert_eq!(matched[0].name.as_deref(), Some("-c"));
+    /// assert_eq!(matched[0].arg.as_deref(), Some(OsStr::new("42")));
+    /// assert_eq!(matched[1].name.as_deref(), Some("--debug"));
+    /// assert_eq!(matched[2].style, Style::Pos);
+    /// assert_eq!(matched[2].arg.as_deref(), Some(OsStr::new("a.txt")));
+    ///
+    /// // the values are not stored, and the options are not marked as matched
+    /// assert_eq!(parser.find_val::<i64>("--count").ok(), None);
+    /// assert!(!parser.find_opt("--debug")?.matched());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dry_matched(&self) -> &[DryMatch] {
+        &self.ctx.dry_matched
+    }
+
+    /// The NOA matched by [`Cmd`](Style::Cmd) option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// let build = parser.add_opt("build=c")?.run()?;
+    /// let file = parser.add_opt("file=p@2")?.run()?;
+    ///
+    /// parser.add_opt("--debug=b")?;
+    ///
+    /// let ret = parser.parse(Args::from(["app", "build", "--debug", "foo", "bar"]))?;
+    ///
+    /// assert_eq!(ret.cmd_matched().map(|v| v.uid), Some(build));
+    /// assert_eq!(ret.pos_matched(file).collect::<Vec<_>>(), [OsStr::new("foo")]);
+    /// assert_eq!(ret.unmatched().collect::<Vec<_>>(), [OsStr::new("bar")]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cmd_matched(&self) -> Option<&NoaMatch> {
+        self.ctx.noa_matched.iter().find(|v| v.style == Style::Cmd)
+    }
+
+    /// The NOAs bound to the [`Pos`](Style::Pos) option `uid`.
+    pub fn pos_matched(&self, uid: Uid) -> impl Iterator<Item = &OsStr> {
+        self.ctx
+            .noa_matched
+            .iter()
+            .filter(move |v| v.style == Style::Pos && v.uid == uid)
+            .map(|v| v.arg.as_os_str())
+    }
+
+    /// The NOAs not matched by any [`Cmd`](Style::Cmd) or [`Pos`](Style::Pos) option,
+    /// the first argument which is the name of program is not included.
+    pub fn unmatched(&self) -> impl Iterator<Item = &OsStr> {
+        self.ctx
+            .args
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(idx, _)| !self.ctx.noa_matched.iter().any(|v| v.idx == *idx))
+            .map(|(_, arg)| arg.as_os_str())
+    }
+
     /// The [`status`](Return::status) is true if parsing successes
     /// otherwise it will be false if any [`failure`](Error::is_failure) raised.
     pub fn status(&self) -> bool {
@@ -142,3 +323,134 @@ impl From<&mut Return> for bool {
         value.status()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_noa_matched() {
+        assert!(testing_noa_matched_main().is_ok());
+    }
+
+    fn testing_noa_matched_main() -> Result<(), Error> {
+        fn check<P>(mut parser: Parser<P>) -> Result<(), Error>
+        where
+            P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+            for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+        {
+            let cmd = parser.add_opt("list=c")?.run()?;
+            let name = parser.add_opt("name=p@2")?.run()?;
+            let files = parser.add_opt("files=p@3..")?.run()?;
+
+            parser.add_opt("--all=b")?;
+            let skip = parser.add_opt("skip=p@4")?.run()?;
+
+            parser
+                .entry(skip)?
+                .on(|_: &mut ASet, _: &mut ASer, _: &Ctx| Ok(None::<bool>));
+
+            let ret = parser.parse(Args::from([
+                "app", "list", "--all", "foo", "a.txt", "b.txt",
+            ]))?;
+
+            assert!(ret.status());
+            assert_eq!(ret.cmd_matched().map(|v| v.uid), Some(cmd));
+            assert_eq!(ret.cmd_matched().map(|v| v.idx), Some(1));
+            assert_eq!(ret.pos_matched(name).collect::<Vec<_>>(), ["foo"]);
+            assert_eq!(
+                ret.pos_matched(files).collect::<Vec<_>>(),
+                ["a.txt", "b.txt"]
+            );
+            assert_eq!(ret.noa_matched().len(), 4);
+            assert_eq!(ret.unmatched().count(), 0);
+
+            parser.reset()?;
+            let ret = parser.parse(Args::from(["app", "list"]))?;
+
+            assert_eq!(ret.pos_matched(name).count(), 0);
+
+            // the NOA rejected by handler is not bound to the option
+            parser.reset()?;
+            let ret = parser.parse(Args::from(["app", "list", "foo", "a", "b"]))?;
+
+            assert_eq!(ret.pos_matched(skip).count(), 0);
+            assert_eq!(
+                ret.pos_matched(files).collect::<Vec<_>>(),
+                [OsStr::new("a"), OsStr::new("b")]
+            );
+            assert_eq!(ret.unmatched().count(), 0);
+            Ok(())
+        }
+
+        check(AFwdParser::default())?;
+        check(APreParser::default())?;
+        check(ADelayParser::default())?;
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--all=b")?;
+        let ret = parser.parse(Args::from(["app", "foo", "--all", "bar"]))?;
+
+        assert!(ret.cmd_matched().is_none());
+        assert_eq!(ret.unmatched().collect::<Vec<_>>(), ["foo", "bar"]);
+        Ok(())
+    }
+
+    #[test]
+    fn testing_dry_run() {
+        assert!(testing_dry_run_main().is_ok());
+    }
+
+    fn testing_dry_run_main() -> Result<(), Error> {
+        fn check<P>(mut parser: Parser<P>) -> Result<(), Error>
+        where
+            P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+            for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+        {
+            let cmd = parser.add_opt("list=c")?.run()?;
+            let all = parser.add_opt("--all=b!")?.run()?;
+            let depth = parser.add_opt("--depth=i")?.run()?;
+
+            parser.entry(depth)?.on(
+                |_: &mut ASet, _: &mut ASer, _: &Ctx| -> Result<Option<i64>, Error> {
+                    panic!("handler is not invoked in dry-run mode")
+                },
+            );
+
+            let ret = parser.parse(Args::from(["app", "list", "--all", "--depth=2"]))?;
+            let uids: Vec<_> = ret.dry_matched().iter().map(|v| v.uid).collect();
+
+            assert!(ret.status());
+            assert!(uids.contains(&cmd));
+            assert!(uids.contains(&all));
+            assert!(uids.contains(&depth));
+            assert_eq!(ret.cmd_matched().map(|v| v.uid), Some(cmd));
+            assert!(parser.find_val::<i64>("--depth").is_err());
+            assert!(!parser.opt(all)?.matched());
+
+            // the force required option is checked
+            let ret = parser.parse(Args::from(["app", "list", "--depth=2"]))?;
+
+            assert_eq!(
+                ret.failure().map(|v| v.kind()),
+                Some(&ErrorKind::OptRequired)
+            );
+            Ok(())
+        }
+
+        check(AFwdParser::new_policy(
+            AFwdPolicy::default().with_dry_run(true),
+        ))?;
+        check(APreParser::new_policy(
+            APrePolicy::default().with_dry_run(true),
+        ))?;
+        check(ADelayParser::new_policy(
+            ADelayPolicy::default().with_dry_run(true),
+        ))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::opt::Opt;
+use crate::raise_error;
+use crate::set::SetChecker;
+use crate::set::SetOpt;
+use crate::trace;
+use crate::value::ErasedValue;
+use crate::Error;
+
+use super::DefaultSetChecker;
+
+/// Restrict the accepted values of an option depend on the value of another option.
+///
+/// The rule is applied when any raw value of option [`depend`](ValueRule::depend) is one of
+/// [`when`](ValueRule::when), then all the raw values of option [`name`](ValueRule::name)
+/// must be one of [`accepts`](ValueRule::accepts).
+/// The values come from default initializer are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueRule {
+    name: String,
+
+    depend: String,
+
+    when: Vec<String>,
+
+    accepts: Vec<String>,
+}
+
+impl ValueRule {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Apply the rule when option `depend` has one of the `values`.
+    pub fn with_when<S: Into<String>>(
+        mut self,
+        depend: impl Into<String>,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.depend = depend.into();
+        self.when = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the values accepted by the option when the rule is applied.
+    pub fn with_accepts<S: Into<String>>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.accepts = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn depend(&self) -> &str {
+        &self.depend
+    }
+
+    pub fn when(&self) -> &[String] {
+        &self.when
+    }
+
+    pub fn accepts(&self) -> &[String] {
+        &self.accepts
+    }
+
+    fn find<'a, S>(set: &'a S, name: &str) -> Option<&'a SetOpt<S>>
+    where
+        S: crate::set::Set,
+        SetOpt<S>: Opt,
+    {
+        set.iter()
+            .find(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name))
+    }
+
+    /// Check the values of option in `set`, return a failure if the rule is violated.
+    pub fn check<S>(&self, set: &S) -> Result<bool, Error>
+    where
+        S: crate::set::Set,
+        SetOpt<S>: Opt,
+    {
+        let find = |name: &str| {
+            Self::find(set, name)
+                .ok_or_else(|| raise_error!("can not find option `{}` in value rule", name))
+        };
+        let depend = find(&self.depend)?;
+        let opt = find(&self.name)?;
+        let when = depend
+            .accessor()
+            .rawvals()?
+            .iter()
+            .find(|v| v.to_str().is_some_and(|v| self.when.iter().any(|w| w == v)));
+
+        if let Some(when) = when {
+            trace!(
+                "checking rule of `{}` with `{}` = {:?}",
+                self.name,
+                self.depend,
+                when
+            );
+            for val in opt.accessor().rawvals()? {
+                if !val
+                    .to_str()
+                    .is_some_and(|v| self.accepts.iter().any(|a| a == v))
+                {
+                    return Err(Error::sp_rawval(
+                        Some(val),
+                        format!(
+                            "except one of [{}] when `{}` is `{}`",
+                            self.accepts.join(", "),
+                            self.depend,
+                            when.to_string_lossy()
+                        ),
+                    )
+                    .with_uid(opt.uid()));
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// A [`SetChecker`] check the [`ValueRule`]s after other checks of `post_check` passed.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let checker = RuleChecker::<ASet>::default()
+///     .with_rule(
+///         ValueRule::new("--std")
+///             .with_when("--lang", ["c"])
+///             .with_accepts(["c89", "c99", "c11"]),
+///     )
+///     .with_rule(
+///         ValueRule::new("--std")
+///             .with_when("--lang", ["cpp"])
+///             .with_accepts(["c++11", "c++17", "c++20"]),
+///     );
+/// let mut parser = Parser::new_policy(FwdPolicy::<ASet, ASer, _>::default().with_checker(checker));
+///
+/// parser.add_opt("--lang=s")?;
+/// parser.add_opt("--std=s")?;
+///
+/// parser.parse(Args::from(["cc", "--lang=c", "--std=c99"]))?.ok()?;
+/// assert_eq!(parser.find_val::<String>("--std")?, "c99");
+///
+/// parser.reset()?;
+/// parser.parse(Args::from(["cc", "--lang=cpp", "--std=c++17"]))?.ok()?;
+///
+/// parser.reset()?;
+/// let ret = parser.parse(Args::from(["cc", "--lang=cpp", "--std=c99"]))?;
+///
+/// assert!(ret.failure().is_some());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct RuleChecker<S, C = DefaultSetChecker<S>> {
+    rules: Vec<ValueRule>,
+
+    checker: C,
+
+    marker: PhantomData<S>,
+}
+
+impl<S, C: Clone> Clone for RuleChecker<S, C> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+            checker: self.checker.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<S, C: Debug> Debug for RuleChecker<S, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RuleChecker")
+            .field("rules", &self.rules)
+            .field("checker", &self.checker)
+            .finish()
+    }
+}
+
+impl<S, C: Default> Default for RuleChecker<S, C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<S, C> RuleChecker<S, C> {
+    pub fn new(checker: C) -> Self {
+        Self {
+            rules: vec![],
+            checker,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn with_rule(mut self, rule: ValueRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn add_rule(&mut self, rule: ValueRule) -> &mut Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[ValueRule] {
+        &self.rules
+    }
+
+    pub fn checker(&self) -> &C {
+        &self.checker
+    }
+
+    pub fn checker_mut(&mut self) -> &mut C {
+        &mut self.checker
+    }
+}
+
+impl<S, C> SetChecker<S> for RuleChecker<S, C>
+where
+    S: crate::set::Set,
+    SetOpt<S>: Opt,
+    C: SetChecker<S>,
+{
+    type Error = Error;
+
+    fn pre_check(&self, set: &mut S) -> Result<bool, Error> {
+        self.checker.pre_check(set).map_err(Into::into)
+    }
+
+    fn opt_check(&self, set: &mut S) -> Result<bool, Error> {
+        self.checker.opt_check(set).map_err(Into::into)
+    }
+
+    fn pos_check(&self, set: &mut S) -> Result<bool, Error> {
+        self.checker.pos_check(set).map_err(Into::into)
+    }
+
+    fn cmd_check(&self, set: &mut S) -> Result<bool, Error> {
+        self.checker.cmd_check(set).map_err(Into::into)
+    }
+
+    /// Call the `post_check` of inner checker, then check all the [`ValueRule`]s.
+    fn post_check(&self, set: &mut S) -> Result<bool, Error> {
+        if !self.checker.post_check(set).map_err(Into::into)? {
+            return Ok(false);
+        }
+        trace!("in post check, checking {} rules ...", self.rules.len());
+        for rule in self.rules.iter() {
+            rule.check(set)?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_rule() {
+        assert!(testing_rule_main().is_ok());
+    }
+
+    fn testing_rule_main() -> Result<(), Error> {
+        let checker = RuleChecker::<ASet>::default()
+            .with_rule(
+                ValueRule::new("--std")
+                    .with_when("--lang", ["c"])
+                    .with_accepts(["c99", "c11"]),
+            )
+            .with_rule(
+                ValueRule::new("-O")
+                    .with_when("--debug", ["true"])
+                    .with_accepts(["0"]),
+            );
+        let mut parser =
+            Parser::new_policy(FwdPolicy::<ASet, ASer, _>::default().with_checker(checker));
+
+        parser.add_opt("--lang;-l=s")?;
+        parser.add_opt("--std=s")?.set_action(Action::App);
+        parser.add_opt("--debug=b")?;
+        parser.add_opt("-O=i")?;
+
+        for (args, ok) in [
+            (vec!["cc", "--std=c++17"], true),
+            (vec!["cc", "-l", "cpp", "--std=c++17"], true),
+            (vec!["cc", "-l", "c", "--std", "c11", "--std=c99"], true),
+            (vec!["cc", "-l", "c", "--std", "c11", "--std=c++17"], false),
+            (vec!["cc", "--debug", "-O0"], true),
+            (vec!["cc", "--debug", "-O2"], false),
+            (vec!["cc", "-O2"], true),
+        ] {
+            parser.reset()?;
+            let ret = parser.parse(Args::from(args))?;
+
+            assert_eq!(ret.status(), ok);
+        }
+
+        parser.reset()?;
+        let ret = parser.parse(Args::from(["cc", "-l", "c", "--std=c++17"]))?;
+        let failure = ret.failure().unwrap();
+
+        assert_eq!(failure.uid(), Some(parser.find_uid("--std")?));
+        assert!(failure.to_string().contains("`--lang` is `c`"));
+
+        let mut parser = Parser::new_policy(
+            FwdPolicy::<ASet, ASer, _>::default()
+                .with_checker(RuleChecker::<ASet>::default().with_rule(ValueRule::new("--std"))),
+        );
+
+        parser.add_opt("--std=s")?;
+        assert!(parser.parse(Args::from(["cc", "--std=c99"])).is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,10 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::ops::Deref;
 
+use crate::args::ArgInfo;
+use crate::Error;
+
 /// User set option style used for generate [`InvokeGuess`](crate::guess::InvokeGuess).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -12,7 +17,8 @@ pub enum UserStyle {
     /// The first NOA argument.
     Cmd,
 
-    /// Option set style like `--opt=value`, the value is set after `=`.
+    /// Option set style like `--opt=value`, the value is set after `=`,
+    /// the separator can be changed by [`add_separator`](OptStyleManager::add_separator).
     EqualWithValue,
 
     /// Option set style like `--opt value`, the value is set in next argument.
@@ -32,12 +38,71 @@ pub enum UserStyle {
 
     /// Option set style like `--flag`, but the value will be set to None.
     Flag,
+
+    /// NOA argument like `name=value`, such as `CC=clang` of `make build CC=clang`.
+    ///
+    /// The arguments are matched before [`Cmd`](UserStyle::Cmd) and removed from NOA if matched,
+    /// only the options explicitly allow this style can match them, see [`Assign`](crate::value::Assign).
+    Assign,
 }
 
 /// Manage the support option set style[`UserStyle`].
+///
+/// The value-carrying names set by [`with_embedded`](OptStyleManager::with_embedded)
+/// are used to resolve compiler style options such as `-Wall` or `-O2`.
+/// An argument starts with such name always try [`EmbeddedValue`](UserStyle::EmbeddedValue) first,
+/// even it is not in the styles,
+/// and it will never be split by [`CombinedOption`](UserStyle::CombinedOption).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().style_manager_mut().set(vec![
+///     UserStyle::EqualWithValue,
+///     UserStyle::Argument,
+///     UserStyle::Boolean,
+///     UserStyle::CombinedOption,
+/// ]);
+/// parser.add_opt("-W=s")?;
+/// parser.add_opt("-O=i")?;
+/// parser.add_opt("-a=b")?;
+/// parser.add_opt("-l=b")?;
+/// parser.add_opt("-c=b")?;
+///
+/// // no style can handle `-Wall`
+/// assert!(parser.parse(Args::from(["cc", "-Wall", "-lac"]))?.failure().is_some());
+///
+/// parser
+///     .policy_mut()
+///     .style_manager_mut()
+///     .add_embedded("-W")
+///     .add_embedded("-O");
+/// parser.parse(Args::from(["cc", "-Wall", "-O2", "-lac"]))?;
+///
+/// assert_eq!(parser.find_val::<String>("-W")?, "all");
+/// assert_eq!(parser.find_val::<i64>("-O")?, &2);
+/// assert!(*parser.find_val::<bool>("-l")?);
+/// assert!(*parser.find_val::<bool>("-a")?);
+/// assert!(*parser.find_val::<bool>("-c")?);
+/// #
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
 pub struct OptStyleManager {
     styles: Vec<UserStyle>,
+
+    embedded: Vec<String>,
+
+    quoted: bool,
+
+    separators: Vec<(String, char)>,
 }
 
 impl Default for OptStyleManager {
@@ -49,6 +114,9 @@ impl Default for OptStyleManager {
                 UserStyle::Boolean,
                 UserStyle::EmbeddedValue,
             ],
+            embedded: vec![],
+            quoted: false,
+            separators: vec![],
         }
     }
 }
@@ -82,6 +150,179 @@ impl OptStyleManager {
         }
         self
     }
+
+    /// Set the value-carrying option names, such as `-W` or `-O`.
+    pub fn with_embedded(mut self, names: Vec<String>) -> Self {
+        self.embedded = names;
+        self
+    }
+
+    pub fn set_embedded(&mut self, names: Vec<String>) -> &mut Self {
+        self.embedded = names;
+        self
+    }
+
+    pub fn add_embedded(&mut self, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+
+        if !self.embedded.contains(&name) {
+            self.embedded.push(name);
+        }
+        self
+    }
+
+    pub fn embedded(&self) -> &[String] {
+        &self.embedded
+    }
+
+    /// Return true if the `name` is a value-carrying name followed by the value, such as `-Wall`.
+    pub fn is_embedded(&self, name: &str) -> bool {
+        self.embedded
+            .iter()
+            .any(|v| name.len() > v.len() && name.starts_with(v.as_str()))
+    }
+
+    /// Keep the quoted value intact, such as `--filter='name=foo,label=a=b'`.
+    ///
+    /// The `=` inside quotes will not split the argument,
+    /// and the quotes around the value are removed.
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.policy_mut().style_manager_mut().set_quoted(true);
+    /// parser.add_opt("--filter=s")?.set_action(Action::App);
+    /// parser.parse(Args::from([
+    ///     "app",
+    ///     "--filter='name=foo,label=a=b'",
+    ///     "--filter",
+    ///     r#""k=\"v\"""#,
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     parser.find_vals::<String>("--filter")?,
+    ///     &vec!["name=foo,label=a=b".to_owned(), r#"k="v""#.to_owned()]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_quoted(mut self, quoted: bool) -> Self {
+        self.quoted = quoted;
+        self
+    }
+
+    pub fn set_quoted(&mut self, quoted: bool) -> &mut Self {
+        self.quoted = quoted;
+        self
+    }
+
+    pub fn quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Set the separator of name and value for the arguments start with `prefix`,
+    /// such as `--key:value` for prefix `--` and separator `:`.
+    /// The default separator is `=`, and the longest matched prefix is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `sep` is not an ASCII character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.policy_mut().style_manager_mut().add_separator("--", ':');
+    /// parser.add_opt("--define=s")?;
+    /// parser.add_opt("-o=s")?;
+    /// parser.parse(Args::from(["app", "--define:name=foo", "-o=out"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<String>("--define")?, "name=foo");
+    /// assert_eq!(parser.find_val::<String>("-o")?, "out");
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_separator(mut self, prefix: impl Into<String>, sep: char) -> Self {
+        self.add_separator(prefix, sep);
+        self
+    }
+
+    pub fn add_separator(&mut self, prefix: impl Into<String>, sep: char) -> &mut Self {
+        let prefix = prefix.into();
+
+        assert!(
+            sep.is_ascii(),
+            "separator must be an ASCII character: {sep:?}"
+        );
+        self.separators.retain(|(v, _)| v != &prefix);
+        self.separators.push((prefix, sep));
+        self
+    }
+
+    pub fn separators(&self) -> &[(String, char)] {
+        &self.separators
+    }
+
+    /// Return the separator of name and value for the argument, the default is `=`.
+    pub fn separator(&self, arg: &OsStr) -> char {
+        let arg = arg.to_string_lossy();
+
+        self.separators
+            .iter()
+            .filter(|(prefix, _)| arg.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, sep)| *sep)
+            .unwrap_or('=')
+    }
+
+    /// Parse the argument into name and value, the quotes are processed if [`quoted`](OptStyleManager::quoted).
+    pub fn parse_arg<'a>(&self, arg: &'a OsStr) -> Result<ArgInfo<'a>, Error> {
+        let sep = self.separator(arg);
+
+        if self.quoted {
+            ArgInfo::parse_quoted_with(arg, sep)
+        } else {
+            ArgInfo::parse_with(arg, sep)
+        }
+    }
+
+    /// Return the next argument using as option value, the quotes around it
+    /// are removed if [`quoted`](OptStyleManager::quoted).
+    pub fn next_arg<'a>(&self, arg: &'a OsStr) -> Cow<'a, OsStr> {
+        if self.quoted {
+            crate::args::unquote_os(arg)
+        } else {
+            Cow::Borrowed(arg)
+        }
+    }
+
+    /// Return the styles should be tried for the argument `name` in order.
+    pub fn resolve(&self, name: &str) -> Cow<'_, [UserStyle]> {
+        if self.is_embedded(name) {
+            let mut styles = vec![UserStyle::EmbeddedValue];
+
+            styles.extend(
+                self.styles
+                    .iter()
+                    .filter(|v| !matches!(v, UserStyle::EmbeddedValue | UserStyle::CombinedOption))
+                    .cloned(),
+            );
+            Cow::Owned(styles)
+        } else {
+            Cow::Borrowed(&self.styles)
+        }
+    }
 }
 
 impl Deref for OptStyleManager {
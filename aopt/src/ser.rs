@@ -1,3 +1,6 @@
+#[cfg(feature = "lazy")]
+pub(crate) mod lazy;
+
 use std::any::type_name;
 use std::fmt::Debug;
 use std::ops::Deref;
@@ -9,6 +12,11 @@ use crate::map::ErasedTy;
 use crate::raise_error;
 use crate::Error;
 
+#[cfg(feature = "lazy")]
+pub use self::lazy::Lazy;
+#[cfg(feature = "lazy")]
+pub use self::lazy::LazyHandler;
+
 pub trait ServicesValExt {
     /// Get the user value reference of option `uid` from [`AppServices`].
     fn sve_insert<T: ErasedTy>(&mut self, val: T) -> Option<T>;
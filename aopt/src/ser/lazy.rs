@@ -0,0 +1,106 @@
+use std::fmt::Debug;
+
+use crate::Error;
+
+#[cfg(feature = "sync")]
+type LazyCell<T> = std::sync::OnceLock<T>;
+
+#[cfg(not(feature = "sync"))]
+type LazyCell<T> = std::cell::OnceCell<T>;
+
+#[cfg(feature = "sync")]
+pub type LazyHandler<T> = Box<dyn Fn() -> Result<T, Error> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type LazyHandler<T> = Box<dyn Fn() -> Result<T, Error>>;
+
+/// A service value initialized when it is first accessed.
+///
+/// The initializer will be called again if it failed last time.
+///
+/// # Example
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.set_app_data(Lazy::new(|| Ok(vec!["foo".to_owned(), "bar".to_owned()])))?;
+/// parser
+///     .add_opt("--name=s")?
+///     .on(|_: &mut ASet, ser: &mut ASer, ctx: &Ctx| {
+///         let name = ctx.value::<String>()?;
+///         let names = ser.sve_val::<Lazy<Vec<String>>>()?.get()?;
+///
+///         Ok(names.contains(&name).then_some(name))
+///     })?;
+///
+/// assert!(!parser.service().sve_val::<Lazy<Vec<String>>>()?.is_init());
+/// parser.parse(Args::from(["app", "--name", "bar"]))?;
+///
+/// assert!(parser.service().sve_val::<Lazy<Vec<String>>>()?.is_init());
+/// assert_eq!(parser.find_val::<String>("--name")?, "bar");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Lazy<T> {
+    val: LazyCell<T>,
+
+    init: LazyHandler<T>,
+}
+
+impl<T: Debug> Debug for Lazy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lazy")
+            .field("val", &self.val)
+            .field("init", &"{...}")
+            .finish()
+    }
+}
+
+impl<T> Lazy<T> {
+    #[cfg(feature = "sync")]
+    pub fn new(init: impl Fn() -> Result<T, Error> + Send + Sync + 'static) -> Self {
+        Self {
+            val: LazyCell::new(),
+            init: Box::new(init),
+        }
+    }
+
+    #[cfg(not(feature = "sync"))]
+    pub fn new(init: impl Fn() -> Result<T, Error> + 'static) -> Self {
+        Self {
+            val: LazyCell::new(),
+            init: Box::new(init),
+        }
+    }
+
+    pub fn is_init(&self) -> bool {
+        self.val.get().is_some()
+    }
+
+    /// Get the value, initialize it if not initialized.
+    pub fn get(&self) -> Result<&T, Error> {
+        match self.val.get() {
+            Some(val) => Ok(val),
+            None => {
+                let val = (self.init)()?;
+
+                Ok(self.val.get_or_init(|| val))
+            }
+        }
+    }
+
+    /// Get the mutable value, initialize it if not initialized.
+    pub fn get_mut(&mut self) -> Result<&mut T, Error> {
+        self.get()?;
+        Ok(self.val.get_mut().unwrap())
+    }
+
+    /// Take the value out, the value will be initialized again when next accessed.
+    pub fn take(&mut self) -> Option<T> {
+        self.val.take()
+    }
+}
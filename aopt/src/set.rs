@@ -1,21 +1,45 @@
 pub(crate) mod commit;
+pub(crate) mod diff;
 pub(crate) mod filter;
 pub(crate) mod index;
+pub(crate) mod lookup;
 pub(crate) mod optset;
 pub(crate) mod optvalid;
-
+pub(crate) mod profile;
+pub(crate) mod redact;
+#[cfg(feature = "serde")]
+pub(crate) mod schema;
+pub(crate) mod source;
+pub(crate) mod spec;
+
+pub use self::commit::Conflict;
 pub use self::commit::SetCommit;
 pub use self::commit::SetCommitWithValue;
+pub use self::diff::diff_set;
+pub use self::diff::OptDiff;
 pub use self::filter::Filter;
 pub use self::filter::FilterMatcher;
 pub use self::filter::FilterMut;
 pub use self::index::SetIndex;
+pub use self::lookup::NameIndex;
 pub use self::optset::OptSet;
 pub use self::optvalid::OptValidator;
 pub use self::optvalid::PrefixOptValidator;
+pub use self::profile::profile_handler;
+pub use self::profile::Profile;
+pub use self::redact::Redactor;
+pub use self::redact::REDACTED;
+#[cfg(feature = "serde")]
+pub use self::schema::OptSchema;
+#[cfg(feature = "serde")]
+pub use self::schema::SetSchema;
+pub use self::source::ValueSources;
+pub use self::spec::OptSpec;
 
 use std::any::type_name;
+use std::ffi::OsString;
 use std::fmt::Debug;
+use std::fmt::Display;
 use std::slice::Iter;
 use std::slice::IterMut;
 
@@ -27,9 +51,13 @@ use crate::opt::ConfigValue;
 use crate::opt::Index;
 use crate::opt::Opt;
 use crate::opt::OptValueExt;
+use crate::parser::UserStyle;
 use crate::raise_error;
+use crate::raise_failure;
+use crate::value::RawValParser;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -128,8 +156,60 @@ pub trait Set {
 
     fn get_ctor_mut(&mut self, name: &str) -> Option<&mut Self::Ctor>;
 
+    /// Reset the matched state of options, same as [`reset_matched`](Set::reset_matched).
     fn reset(&mut self);
 
+    /// Reset the matched state of options, the values are kept.
+    fn reset_matched(&mut self) {
+        for opt in self.iter_mut() {
+            opt.reset();
+        }
+    }
+
+    /// Clear the values and raw values of options,
+    /// then initialize the values with the initializer of option.
+    ///
+    /// The configuration of options, such as initializer, storer and handlers, are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--name=s")?;
+    /// parser.add_opt("--jobs=i")?.set_value_t(4i64);
+    /// parser.parse(Args::from(["app", "--name=foo", "--jobs=8"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<String>("--name")?, "foo");
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+    ///
+    /// parser.optset_mut().reset_all()?;
+    ///
+    /// assert!(parser.find_val::<String>("--name").is_err());
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &4);
+    /// assert!(!parser.find_opt("--jobs")?.matched());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn reset_values(&mut self) -> Result<(), Error> {
+        for opt in self.iter_mut() {
+            opt.accessor_mut().clear();
+            opt.init()?;
+        }
+        Ok(())
+    }
+
+    /// Reset both the matched state and values of options.
+    fn reset_all(&mut self) -> Result<(), Error> {
+        self.reset_matched();
+        self.reset_values()
+    }
+
     /// Return the number of options.
     fn len(&self) -> usize;
 
@@ -142,6 +222,52 @@ pub trait Set {
         self.iter().map(|v| v.uid()).collect()
     }
 
+    /// Return the uids of options may match the option `name`, in the order of options inserted.
+    ///
+    /// The default implementation return all the uids.
+    fn candidates(&mut self, _name: &str) -> Vec<Uid> {
+        self.keys()
+    }
+
+    /// Add a named bundle of option values, see [`Profile`].
+    fn add_profile<N, I, K, V>(&mut self, name: N, values: I) -> Result<(), Error>
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<OsString>,
+    {
+        self.insert_profile(Profile::new(name, values))
+    }
+
+    /// Insert the `profile`, the profile has same name will be replaced.
+    ///
+    /// The default implementation return an error.
+    fn insert_profile(&mut self, profile: Profile) -> Result<(), Error> {
+        Err(raise_error!(
+            "can not insert profile `{}`, the set not support profile",
+            profile.name()
+        ))
+    }
+
+    /// Return the profile named `name`.
+    fn profile(&self, _name: &str) -> Option<&Profile> {
+        None
+    }
+
+    /// Store the values of profile `name` into options, see [`Profile::apply`].
+    fn apply_profile(&mut self, name: &str) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        let profile = self
+            .profile(name)
+            .cloned()
+            .ok_or_else(|| raise_failure!("can not find profile `{}`", name))?;
+
+        profile.apply(self)
+    }
+
     fn iter(&self) -> Iter<'_, SetOpt<Self>>;
 
     fn iter_mut(&mut self) -> IterMut<'_, SetOpt<Self>>;
@@ -159,12 +285,152 @@ pub trait Set {
     fn get_mut(&mut self, uid: Uid) -> Option<&mut SetOpt<Self>> {
         self.iter_mut().find(|v| v.uid() == uid)
     }
+
+    /// Replace the option `uid` with `opt`, return the old option.
+    /// The uid of `opt` is set to `uid`, return `None` if the option not exist.
+    fn replace(&mut self, uid: Uid, mut opt: SetOpt<Self>) -> Option<SetOpt<Self>> {
+        let exist = self.iter_mut().find(|v| v.uid() == uid)?;
+
+        opt.set_uid(uid);
+        Some(std::mem::replace(exist, opt))
+    }
+
+    /// Return the raw values of options `uids` in the order they appeared in command line,
+    /// each item is the uid of option, the raw value and the index of argument.
+    ///
+    /// It is useful when the relative order of different options matters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::ffi::OsString;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// let inc = parser.add_opt("-I=s")?.run()?;
+    /// let lib = parser.add_opt("-L=s")?.run()?;
+    ///
+    /// parser.parse(Args::from(["cc", "-Iinclude", "-L", "lib", "-I", "src", "-Ltarget"]))?;
+    ///
+    /// let ordered: Vec<_> = parser
+    ///     .optset()
+    ///     .ordered_rawvals(&[inc, lib])
+    ///     .into_iter()
+    ///     .map(|(uid, val, idx)| (uid, val.clone(), idx))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     ordered,
+    ///     vec![
+    ///         (inc, OsString::from("include"), 1),
+    ///         (lib, OsString::from("lib"), 2),
+    ///         (inc, OsString::from("src"), 4),
+    ///         (lib, OsString::from("target"), 6),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn ordered_rawvals(&self, uids: &[Uid]) -> Vec<(Uid, &OsString, usize)> {
+        let mut vals = vec![];
+
+        for opt in uids.iter().filter_map(|uid| self.get(*uid)) {
+            let uid = opt.uid();
+            let indices = opt.accessor().arg_indices();
+
+            if let Ok(rawvals) = opt.rawvals() {
+                vals.extend(
+                    rawvals
+                        .iter()
+                        .zip(indices.iter())
+                        .map(|(val, idx)| (uid, val, *idx)),
+                );
+            }
+        }
+        vals.sort_by_key(|(_, _, idx)| *idx);
+        vals
+    }
+
+    /// Return the source which supplied the value of option `uid`, see [`ValueSources`].
+    fn value_source(&self, uid: Uid) -> Result<ValueSource, Error> {
+        self.get(uid)
+            .map(|v| v.source())
+            .ok_or_else(|| raise_error!("can not find option `{}` by uid", uid).with_uid(uid))
+    }
+
+    /// Return true if the value of option `uid` is supplied by user,
+    /// i.e. it is not from the initializer of option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// let jobs = parser.add_opt("--jobs=u")?.set_value_t(4u64).run()?;
+    /// let debug = parser.add_opt("--debug=b")?.run()?;
+    ///
+    /// parser.parse(Args::from(["app", "--jobs=4"]))?;
+    ///
+    /// // same as the default value, but set by user
+    /// assert_eq!(parser.find_val::<u64>("--jobs")?, &4);
+    /// assert!(parser.is_user_set(jobs)?);
+    /// assert!(!parser.is_user_set(debug)?);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_user_set(&self, uid: Uid) -> Result<bool, Error> {
+        self.value_source(uid).map(|v| !v.is_default())
+    }
 }
 
 pub trait SetExt<C: Ctor> {
-    fn opt(&self, uid: Uid) -> Result<&C::Opt, Error>;
-
-    fn opt_mut(&mut self, uid: Uid) -> Result<&mut C::Opt, Error>;
+    /// Return the option of `index`, which is an uid or a name such as `"--opt"`.
+    ///
+    /// Different from the [`Index`](std::ops::Index) implementation of set, it will not panic
+    /// if the option not exists, and the error suggests the similar names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut set = ASet::default();
+    ///
+    /// let verbose = set.add_opt("--verbose=b")?.add_alias("-v").run()?;
+    ///
+    /// assert_eq!(set.opt(verbose)?.name(), "--verbose");
+    /// assert_eq!(set.opt("-v")?.uid(), verbose);
+    /// set.opt_mut("--verbose")?.set_help("Print more message");
+    ///
+    /// let error = set.opt("--verbsoe").unwrap_err();
+    ///
+    /// assert_eq!(error.kind(), &ErrorKind::OptionNotFound);
+    /// assert_eq!(
+    ///     error.desp(),
+    ///     Some("can not find option `--verbsoe`, did you mean `--verbose`?")
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn opt<I: SetIndex<Self>>(&self, index: I) -> Result<&C::Opt, Error>
+    where
+        Self: Set + Sized;
+
+    fn opt_mut<I: SetIndex<Self>>(&mut self, index: I) -> Result<&mut C::Opt, Error>
+    where
+        Self: Set + Sized;
 
     fn ctor(&self, name: &str) -> Result<&C, Error>;
 
@@ -172,14 +438,18 @@ pub trait SetExt<C: Ctor> {
 }
 
 impl<S: Set> SetExt<S::Ctor> for S {
-    fn opt(&self, uid: Uid) -> Result<&<S::Ctor as Ctor>::Opt, Error> {
-        self.get(uid)
-            .ok_or_else(|| raise_error!("can not find option `{}` by uid", uid).with_uid(uid))
+    fn opt<I: SetIndex<Self>>(&self, index: I) -> Result<&<S::Ctor as Ctor>::Opt, Error>
+    where
+        Self: Set + Sized,
+    {
+        index.ref_from(self)
     }
 
-    fn opt_mut(&mut self, uid: Uid) -> Result<&mut <S::Ctor as Ctor>::Opt, Error> {
-        self.get_mut(uid)
-            .ok_or_else(|| raise_error!("can not find option(mut) `{}` by uid", uid).with_uid(uid))
+    fn opt_mut<I: SetIndex<Self>>(&mut self, index: I) -> Result<&mut <S::Ctor as Ctor>::Opt, Error>
+    where
+        Self: Set + Sized,
+    {
+        index.mut_from(self)
     }
 
     fn ctor(&self, name: &str) -> Result<&S::Ctor, Error> {
@@ -233,6 +503,60 @@ where
         self.opt_mut(self.find_uid(cb)?)?.vals_mut()
     }
 
+    /// Return an iterator over the values of option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--inc;-I=s")?.set_action(Action::App);
+    /// parser.add_opt("--lib;-L=s")?.set_action(Action::App);
+    /// parser.add_opt("--jobs=u")?;
+    /// parser.parse(Args::from(["cc", "-I=a", "-L=b", "-I=c", "--jobs=4"]))?;
+    ///
+    /// let incs: Vec<_> = parser.values_of::<String>("-I")?.map(|v| v.as_str()).collect();
+    ///
+    /// assert_eq!(incs, ["a", "c"]);
+    ///
+    /// // iterate the values of all options holding String
+    /// let dirs: Vec<_> = parser
+    ///     .iter_typed::<String>()
+    ///     .map(|(uid, v)| (uid, v.as_str()))
+    ///     .collect();
+    ///
+    /// assert_eq!(dirs, [(0, "a"), (0, "c"), (1, "b")]);
+    /// assert_eq!(parser.iter_typed::<u64>().count(), 1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn values_of<T: ErasedTy>(
+        &self,
+        cb: impl ConfigBuild<SetCfg<Self>>,
+    ) -> Result<std::slice::Iter<'_, T>, Error> {
+        Ok(self.find_vals::<T>(cb)?.iter())
+    }
+
+    /// Return an iterator over the values of type `T` in all options,
+    /// the item is the [`Uid`] of option and the value.
+    /// The options not holding the values of type `T` are skipped.
+    fn iter_typed<T: ErasedTy>(&self) -> impl Iterator<Item = (Uid, &T)> {
+        self.iter().flat_map(|opt| {
+            let uid = opt.uid();
+
+            opt.vals::<T>()
+                .map(|vals| vals.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .map(move |v| (uid, v))
+        })
+    }
+
     fn take_val<T: ErasedTy>(&mut self, cb: impl ConfigBuild<SetCfg<Self>>) -> Result<T, Error> {
         let opt = self.opt_mut(self.find_uid(cb)?)?;
         let (name, uid) = (opt.name(), opt.uid());
@@ -311,6 +635,162 @@ where
         self
     }
 
+    fn set_global(mut self, global: bool) -> Self {
+        self.cfg_mut().set_global(global);
+        self
+    }
+
+    /// Limit the user styles that can be used to set the option,
+    /// the styles still need to be enabled in the policy.
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--output=s")?
+    ///     .set_user_style(vec![UserStyle::EqualWithValue]);
+    /// parser.add_opt("--level=i")?;
+    ///
+    /// parser.parse(Args::from(["app", "--output=build", "--level", "2"]))?;
+    /// assert_eq!(parser.find_val::<String>("--output")?, "build");
+    /// assert_eq!(parser.find_val::<i64>("--level")?, &2);
+    ///
+    /// // `--output` never consume the next argument
+    /// let ret = parser.parse(Args::from(["app", "--output", "build"]))?;
+    ///
+    /// assert!(ret.failure().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_user_style(mut self, styles: Vec<UserStyle>) -> Self {
+        self.cfg_mut().set_user_style(styles);
+        self
+    }
+
+    fn set_sensitive(mut self, sensitive: bool) -> Self {
+        self.cfg_mut().set_sensitive(sensitive);
+        self
+    }
+
+    /// The option is force required if the [`Cmd`](crate::opt::Cmd) `cmd` is matched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("build=c")?;
+    /// parser.add_opt("clean=c")?;
+    /// parser.add_opt("--output;-o=s")?.set_force_if_cmd("build");
+    ///
+    /// assert!(parser.parse(Args::from(["app", "build", "-o", "target"]))?.status());
+    ///
+    /// parser.reset()?;
+    /// assert!(parser.parse(Args::from(["app", "clean"]))?.status());
+    ///
+    /// parser.reset()?;
+    /// assert!(!parser.parse(Args::from(["app", "build"]))?.status());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_force_if_cmd(mut self, cmd: impl Into<String>) -> Self {
+        self.cfg_mut().set_force_if_cmd(cmd);
+        self
+    }
+
+    /// Add an option must be set if the option is set, it is checked after all the arguments are processed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--user;-u=s")?;
+    /// parser.add_opt("--password;-p=s")?.add_requires("--user");
+    /// parser.add_opt("--anonymous=b")?.add_conflicts_with("--user");
+    ///
+    /// assert!(parser.parse(Args::from(["app", "-u", "root", "-p", "123"]))?.status());
+    ///
+    /// parser.reset()?;
+    /// assert!(parser.parse(Args::from(["app", "--anonymous"]))?.status());
+    ///
+    /// parser.reset()?;
+    /// assert!(!parser.parse(Args::from(["app", "-p", "123"]))?.status());
+    ///
+    /// parser.reset()?;
+    /// let ret = parser.parse(Args::from(["app", "--anonymous", "-u", "root"]))?;
+    ///
+    /// assert_eq!(ret.failure().unwrap().kind(), &ErrorKind::OptConflict);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn add_requires(mut self, name: impl Into<String>) -> Self {
+        self.cfg_mut().add_requires(name);
+        self
+    }
+
+    /// Add an option can not be set if the option is set, see [`add_requires`](Self::add_requires).
+    fn add_conflicts_with(mut self, name: impl Into<String>) -> Self {
+        self.cfg_mut().add_conflicts_with(name);
+        self
+    }
+
+    /// Set the priority of option, default is 0.
+    /// When an argument may match more than one options, such as the options have the same alias,
+    /// the option with higher priority is matched first.
+    /// The options have same priority are matched in the order they are inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--verbose;-v=b")?;
+    /// parser.add_opt("--version;-v=b")?.set_priority(1);
+    ///
+    /// assert!(parser.parse(Args::from(["app", "-v"]))?.status());
+    /// assert!(parser.find_opt("--version")?.matched());
+    /// assert!(!parser.find_opt("--verbose")?.matched());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_priority(mut self, priority: i32) -> Self {
+        self.cfg_mut().set_priority(priority);
+        self
+    }
+
+    /// Set the possible values of option, they are used in documentation only.
+    fn set_choices(mut self, choices: Vec<impl Into<String>>) -> Self {
+        self.cfg_mut().set_choices(choices);
+        self
+    }
+
+    /// Set the [`Normalizer`](crate::value::Normalizer) applied to the raw value of option.
+    fn set_normalizer(mut self, normalizer: impl Into<crate::value::Normalizer>) -> Self {
+        self.cfg_mut().set_normalizer(normalizer);
+        self
+    }
+
     fn set_hint(mut self, hint: impl Into<String>) -> Self {
         self.cfg_mut().set_hint(hint);
         self
@@ -326,6 +806,167 @@ where
         self
     }
 
+    /// Convert the values stored by the storer of option with `f`, see [`ValStorer::map_value`].
+    /// The [`fallback`](ValStorer::fallback) storer of type `T` is wrapped if no storer set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::path::PathBuf;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--jobs=i")?.map_value(|v: i64| v * 2);
+    /// parser
+    ///     .add_opt("--dir=s")?
+    ///     .and_then_value(|s: String| PathBuf::try_from(s));
+    /// parser.add_opt("--port=s")?.and_then_value(|s: String| s.parse::<u16>());
+    ///
+    /// parser.parse(Args::from(["app", "--jobs=4", "--dir", "target", "--port", "8080"]))?;
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+    /// assert_eq!(parser.find_val::<PathBuf>("--dir")?, &PathBuf::from("target"));
+    /// assert_eq!(parser.find_val::<u16>("--port")?, &8080);
+    ///
+    /// parser.reset()?;
+    /// let ret = parser.parse(Args::from(["app", "--port", "65536"]))?;
+    ///
+    /// assert!(ret.failure().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    fn map_value<T, R>(mut self, f: impl FnMut(T) -> R + Send + Sync + 'static) -> Self
+    where
+        T: ErasedTy + RawValParser,
+        R: ErasedTy,
+    {
+        let storer = self.cfg_mut().take_storer();
+
+        self.set_storer(storer.unwrap_or_else(ValStorer::fallback::<T>).map_value(f))
+    }
+
+    /// Convert the values stored by the storer of option with `f`, see [`ValStorer::map_value`].
+    /// The [`fallback`](ValStorer::fallback) storer of type `T` is wrapped if no storer set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::path::PathBuf;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--jobs=i")?.map_value(|v: i64| v * 2);
+    /// parser
+    ///     .add_opt("--dir=s")?
+    ///     .and_then_value(|s: String| PathBuf::try_from(s));
+    /// parser.add_opt("--port=s")?.and_then_value(|s: String| s.parse::<u16>());
+    ///
+    /// parser.parse(Args::from(["app", "--jobs=4", "--dir", "target", "--port", "8080"]))?;
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+    /// assert_eq!(parser.find_val::<PathBuf>("--dir")?, &PathBuf::from("target"));
+    /// assert_eq!(parser.find_val::<u16>("--port")?, &8080);
+    ///
+    /// parser.reset()?;
+    /// let ret = parser.parse(Args::from(["app", "--port", "65536"]))?;
+    ///
+    /// assert!(ret.failure().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "sync"))]
+    fn map_value<T, R>(mut self, f: impl FnMut(T) -> R + 'static) -> Self
+    where
+        T: ErasedTy + RawValParser,
+        R: ErasedTy,
+    {
+        let storer = self.cfg_mut().take_storer();
+
+        self.set_storer(storer.unwrap_or_else(ValStorer::fallback::<T>).map_value(f))
+    }
+
+    /// Same as [`map_value`](Commit::map_value), but the conversion may fail,
+    /// see [`ValStorer::and_then_value`].
+    #[cfg(feature = "sync")]
+    fn and_then_value<T, R, E>(
+        mut self,
+        f: impl FnMut(T) -> Result<R, E> + Send + Sync + 'static,
+    ) -> Self
+    where
+        T: ErasedTy + RawValParser,
+        R: ErasedTy,
+        E: Display,
+    {
+        let storer = self.cfg_mut().take_storer();
+
+        self.set_storer(
+            storer
+                .unwrap_or_else(ValStorer::fallback::<T>)
+                .and_then_value(f),
+        )
+    }
+
+    /// Same as [`map_value`](Commit::map_value), but the conversion may fail,
+    /// see [`ValStorer::and_then_value`].
+    #[cfg(not(feature = "sync"))]
+    fn and_then_value<T, R, E>(mut self, f: impl FnMut(T) -> Result<R, E> + 'static) -> Self
+    where
+        T: ErasedTy + RawValParser,
+        R: ErasedTy,
+        E: Display,
+    {
+        let storer = self.cfg_mut().take_storer();
+
+        self.set_storer(
+            storer
+                .unwrap_or_else(ValStorer::fallback::<T>)
+                .and_then_value(f),
+        )
+    }
+
+    /// Collapse the identical values of type `T` into one when storing with [`Action::App`](crate::opt::Action::App),
+    /// see [`ValStorer::with_dedup`].
+    /// The [`fallback`](ValStorer::fallback) storer of type `T` is used if no storer set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--feature=s")?.set_action(Action::App).set_dedup::<String>();
+    /// parser.add_opt("--port=i")?.set_action(Action::App).set_dedup::<i64>();
+    ///
+    /// parser.parse(Args::from(["app", "--feature", "x", "--feature=x", "--port=80", "--port=80"]))?;
+    /// assert_eq!(parser.find_vals::<String>("--feature")?, &["x"]);
+    /// assert_eq!(parser.find_vals::<i64>("--port")?, &[80]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_dedup<T>(mut self) -> Self
+    where
+        T: ErasedTy + RawValParser + PartialEq,
+    {
+        let storer = self.cfg_mut().take_storer();
+
+        self.set_storer(
+            storer
+                .unwrap_or_else(ValStorer::fallback::<T>)
+                .with_dedup::<T>(),
+        )
+    }
+
     fn set_initializer<T: Into<ValInitializer>>(mut self, initializer: T) -> Self {
         self.cfg_mut().set_initializer(initializer.into());
         self
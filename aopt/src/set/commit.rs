@@ -6,6 +6,7 @@ use crate::opt::AnyOpt;
 use crate::opt::Cmd;
 use crate::opt::ConfigValue;
 use crate::opt::Main;
+use crate::opt::Opt;
 use crate::opt::Pos;
 use crate::prelude::ErasedTy;
 use crate::set::Ctor;
@@ -24,6 +25,26 @@ use crate::Uid;
 
 use super::Commit;
 
+/// The behavior of [`SetCommit`] when an option with the same name already exists,
+/// see [`on_conflict`](SetCommit::on_conflict).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Conflict {
+    /// Add the option anyway, the options with the same name co-exist.
+    #[default]
+    Append,
+
+    /// Return an error.
+    Error,
+
+    /// Replace the existing option, the uid is kept, but the handler of existing option is removed.
+    Replace,
+
+    /// Merge the configuration into the existing option, the unset configurations are taken from it.
+    /// The uid and handler are kept, the values, storer and initializer are kept if
+    /// neither storer nor initializer is set.
+    Merge,
+}
+
 /// Create option using given configurations.
 pub struct SetCommit<'a, S, U>
 where
@@ -35,6 +56,8 @@ where
     info: Option<SetCfg<S>>,
     set: Option<&'a mut S>,
     uid: Option<Uid>,
+    conflict: Conflict,
+    replaced: bool,
     pub(crate) drop: bool,
     marker: PhantomData<U>,
 }
@@ -51,6 +74,8 @@ where
             .field("info", &self.info)
             .field("set", &self.set)
             .field("uid", &self.uid)
+            .field("conflict", &self.conflict)
+            .field("replaced", &self.replaced)
             .field("drop", &self.drop)
             .finish()
     }
@@ -101,6 +126,8 @@ where
             set: Some(set),
             info: Some(info),
             uid: None,
+            conflict: Conflict::default(),
+            replaced: false,
             drop: true,
             marker: PhantomData,
         }
@@ -125,6 +152,8 @@ where
             set: Some(set),
             info: Some(info),
             uid: None,
+            conflict: Conflict::default(),
+            replaced: false,
             drop: true,
             marker: PhantomData,
         }
@@ -142,7 +171,70 @@ where
         let info = self.info.take();
         let info = info.unwrap();
 
-        SetCommit::new(set.unwrap(), info)
+        SetCommit::new(set.unwrap(), info).on_conflict(self.conflict)
+    }
+
+    /// Set the behavior when an option with the same name already exists, default is [`Conflict::Append`].
+    ///
+    /// The error of [`Conflict::Error`] is returned by [`run`](SetCommit::run),
+    /// or panic if the commit is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// let uid = parser.add_opt("--jobs;-j=i: Set the jobs")?.set_value_t(2i64).run()?;
+    ///
+    /// assert!(parser
+    ///     .add_opt("--jobs=i")?
+    ///     .on_conflict(Conflict::Error)
+    ///     .run()
+    ///     .is_err());
+    ///
+    /// // keep the alias, value and the uid of `--jobs`
+    /// let merged = parser
+    ///     .add_opt("--jobs=i: Set the number of jobs")?
+    ///     .on_conflict(Conflict::Merge)
+    ///     .run()?;
+    ///
+    /// parser.parse(Args::from(["app"]))?;
+    ///
+    /// assert_eq!(uid, merged);
+    /// assert_eq!(parser.optset().len(), 1);
+    /// assert_eq!(parser.find_opt("--jobs")?.help(), "Set the number of jobs");
+    /// assert_eq!(parser.find_val::<i64>("-j")?, &2);
+    ///
+    /// // replace `--jobs` with a new option
+    /// parser
+    ///     .add_opt("--jobs=s")?
+    ///     .on_conflict(Conflict::Replace)
+    ///     .run()?;
+    /// parser.parse(Args::from(["app", "--jobs", "max"]))?;
+    ///
+    /// assert_eq!(parser.optset().len(), 1);
+    /// assert!(parser.find_opt("-j").is_err());
+    /// assert_eq!(parser.find_val::<String>("--jobs")?, "max");
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_conflict(mut self, conflict: Conflict) -> Self {
+        self.conflict = conflict;
+        self
+    }
+
+    pub fn conflict(&self) -> Conflict {
+        self.conflict
+    }
+
+    /// Return true if an option was replaced by last commit, the flag is cleared.
+    pub(crate) fn take_replaced(&mut self) -> bool {
+        std::mem::take(&mut self.replaced)
     }
 
     pub(crate) fn commit_change(&mut self) -> Result<Uid, Error> {
@@ -153,18 +245,62 @@ where
 
             let info = std::mem::take(&mut self.info);
             let mut info = info.unwrap();
+            let set = self.set.as_mut().unwrap();
+            let exist = match self.conflict {
+                Conflict::Append => None,
+                _ => info
+                    .name()
+                    .and_then(|name| set.iter().find(|v| v.name() == name))
+                    .map(|v| v.uid()),
+            };
+            let keep_values = exist.is_some()
+                && self.conflict == Conflict::Merge
+                && !info.has_storer()
+                && !info.has_initializer();
+
+            if let Some(uid) = exist {
+                match self.conflict {
+                    Conflict::Error => {
+                        return Err(crate::raise_error!(
+                            "option `{}` already exists",
+                            info.name().unwrap_or_default()
+                        )
+                        .with_uid(uid));
+                    }
+                    Conflict::Merge => merge_opt_config(set.opt(uid)?, &mut info),
+                    _ => {}
+                }
+            }
 
             <U as Infer>::infer_fill_info(&mut info)?;
 
-            let set = self.set.as_mut().unwrap();
             let ctor = info.ctor().ok_or_else(|| {
                 crate::raise_error!("invalid configuration: missing creator name!")
             })?;
 
             trace!("register a opt {:?} with creator({})", info.name(), ctor);
 
-            let opt = set.ctor_mut(ctor)?.new_with(info).map_err(|e| e.into())?;
-            let uid = set.insert(opt);
+            let mut opt = set.ctor_mut(ctor)?.new_with(info).map_err(|e| e.into())?;
+            let uid = match exist {
+                Some(uid) => {
+                    if keep_values {
+                        let exist = set.opt_mut(uid)?;
+
+                        if exist.r#type() != opt.r#type() {
+                            return Err(crate::raise_error!(
+                                "can not merge option `{}` with different type",
+                                opt.name()
+                            )
+                            .with_uid(uid));
+                        }
+                        std::mem::swap(exist.accessor_mut(), opt.accessor_mut());
+                    }
+                    self.replaced = self.conflict == Conflict::Replace;
+                    set.replace(uid, opt);
+                    uid
+                }
+                None => set.insert(opt),
+            };
 
             trace!("--> register option okay: {uid}");
             self.uid = Some(uid);
@@ -243,6 +379,12 @@ where
         self.set_storer(ValStorer::from(validator))
     }
 
+    /// Set the validator checking all the values after they are stored,
+    /// see [`ValStorer::with_deferred`].
+    pub fn set_deferred_validator(self, validator: ValValidator<Vec<U::Val>>) -> Self {
+        self.set_storer(ValStorer::new_deferred(validator))
+    }
+
     /// Add default [`storer`](ValStorer::fallback) of type [`U::Val`](Infer::Val).
     pub fn add_default_storer(self) -> Self {
         self.set_storer(ValStorer::fallback::<U::Val>())
@@ -368,6 +510,13 @@ where
         SetCommitWithValue::new(self.inner.take().unwrap().set_infer::<O>())
     }
 
+    /// Set the behavior when an option with the same name already exists,
+    /// see [`SetCommit::on_conflict`].
+    pub fn on_conflict(mut self, conflict: Conflict) -> Self {
+        self.inner = self.inner.take().map(|v| v.on_conflict(conflict));
+        self
+    }
+
     pub(crate) fn commit_inner_change(&mut self) -> Result<Uid, Error> {
         self.inner_mut()?.commit_change()
     }
@@ -436,6 +585,12 @@ where
     pub fn set_validator(self, validator: ValValidator<U::Val>) -> Self {
         self.set_storer(ValStorer::from(validator))
     }
+
+    /// Set the validator checking all the values after they are stored,
+    /// see [`ValStorer::with_deferred`].
+    pub fn set_deferred_validator(self, validator: ValValidator<Vec<U::Val>>) -> Self {
+        self.set_storer(ValStorer::new_deferred(validator))
+    }
 }
 
 impl<S, U, T> SetCommitWithValue<'_, S, U, T>
@@ -473,3 +628,112 @@ where
         self.inner_mut().unwrap().cfg_mut()
     }
 }
+
+/// Fill the unset configurations of `cfg` from the option `opt`, the aliases are combined.
+fn merge_opt_config<O: Opt, C: ConfigValue>(opt: &O, cfg: &mut C) {
+    if !cfg.has_force() {
+        cfg.set_force(opt.force());
+    }
+    if !cfg.has_index() {
+        if let Some(index) = opt.index() {
+            cfg.set_index(index.clone());
+        }
+    }
+    if !cfg.has_hint() && !opt.hint().is_empty() {
+        cfg.set_hint(opt.hint());
+    }
+    if !cfg.has_help() && !opt.help().is_empty() {
+        cfg.set_help(opt.help());
+    }
+    if !cfg.has_action() {
+        cfg.set_action(*opt.action());
+    }
+    if !cfg.has_force_if_cmd() {
+        if let Some(cmd) = opt.force_if_cmd() {
+            cfg.set_force_if_cmd(cmd);
+        }
+    }
+    if !cfg.has_choices() {
+        if let Some(choices) = opt.choices() {
+            cfg.set_choices(choices.clone());
+        }
+    }
+    if !cfg.has_normalizer() {
+        if let Some(normalizer) = opt.normalizer() {
+            cfg.set_normalizer(normalizer.clone());
+        }
+    }
+    for alias in opt.alias().into_iter().flatten() {
+        if !cfg.alias().is_some_and(|v| v.contains(alias)) {
+            cfg.add_alias(alias.clone());
+        }
+    }
+    cfg.set_ignore_name(cfg.ignore_name() || opt.ignore_name());
+    cfg.set_ignore_alias(cfg.ignore_alias() || opt.ignore_alias());
+    cfg.set_ignore_index(cfg.ignore_index() || opt.ignore_index());
+    cfg.set_global(cfg.global() || opt.global());
+    cfg.set_sensitive(cfg.sensitive() || opt.sensitive());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_conflict() {
+        assert!(testing_conflict_main().is_ok());
+    }
+
+    fn testing_conflict_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+        let double = |_: &mut ASet, _: &mut ASer, ctx: &Ctx| Ok(Some(ctx.value::<i64>()? * 2));
+
+        parser.add_opt("--count;-c=i")?.on(double)?.submit();
+        parser.add_opt("--count=i")?.run()?;
+        assert_eq!(parser.optset().len(), 2);
+
+        let mut parser = AFwdParser::default();
+        let uid = parser.add_opt("--count;-c=i")?.on(double)?.submit();
+
+        assert!(parser
+            .add_opt("--count=i")?
+            .on_conflict(Conflict::Error)
+            .run()
+            .is_err());
+        assert_eq!(
+            parser
+                .add_opt("--count=i: Set count")?
+                .on_conflict(Conflict::Merge)
+                .run()?,
+            uid
+        );
+        parser.parse(Args::from(["app", "-c", "2"]))?;
+        assert_eq!(parser.optset().len(), 1);
+        assert_eq!(parser.find_opt("--count")?.help(), "Set count");
+        assert_eq!(parser.find_val::<i64>("--count")?, &4);
+
+        // the type of existing option is different
+        assert!(parser
+            .add_opt("--count=s")?
+            .on_conflict(Conflict::Merge)
+            .run()
+            .is_err());
+
+        parser.reset_all()?;
+        parser
+            .add_opt("--count=i")?
+            .on_conflict(Conflict::Replace)
+            .set_value_type_only::<i64>()
+            .set_value_t(1);
+        parser.parse(Args::from(["app", "--count", "3"]))?;
+        assert_eq!(parser.optset().len(), 1);
+        assert!(parser.find_opt("-c").is_err());
+        assert_eq!(parser.find_val::<i64>("--count")?, &3);
+
+        parser.reset_all()?;
+        parser.parse(Args::from(["app"]))?;
+        assert_eq!(parser.find_val::<i64>("--count")?, &1);
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::ffi::OsString;
+
+use crate::opt::Opt;
+use crate::opt::OptValueExt;
+use crate::set::Set;
+use crate::set::SetOpt;
+
+/// An option whose raw values are different in two sets, created by [`diff_set`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptDiff {
+    name: String,
+
+    lhs: Vec<OsString>,
+
+    rhs: Vec<OsString>,
+}
+
+impl OptDiff {
+    pub fn new(name: impl Into<String>, lhs: Vec<OsString>, rhs: Vec<OsString>) -> Self {
+        Self {
+            name: name.into(),
+            lhs,
+            rhs,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The raw values in left set, empty if the option is not set.
+    pub fn lhs(&self) -> &[OsString] {
+        &self.lhs
+    }
+
+    /// The raw values in right set, empty if the option is not set.
+    pub fn rhs(&self) -> &[OsString] {
+        &self.rhs
+    }
+}
+
+/// Compare the raw values of options with same name in two sets.
+///
+/// The options only exist in one set are also reported.
+/// Only the raw values from command line are compared,
+/// the default values of option have no raw value.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::set::diff_set;
+/// # use aopt::Error;
+/// # use std::ffi::OsString;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut defaults = AFwdParser::default();
+/// let mut current = AFwdParser::default();
+///
+/// for parser in [&mut defaults, &mut current] {
+///     parser.add_opt("--jobs=i")?.set_value_t(4i64);
+///     parser.add_opt("--debug=b")?;
+///     parser.add_opt("--output=s")?;
+/// }
+/// defaults.parse(Args::from(["app"]))?;
+/// current.parse(Args::from(["app", "--jobs=8", "--debug"]))?;
+///
+/// let diffs = diff_set(defaults.optset(), current.optset());
+///
+/// assert_eq!(diffs.len(), 2);
+/// assert_eq!(diffs[0].name(), "--jobs");
+/// assert!(diffs[0].lhs().is_empty());
+/// assert_eq!(diffs[0].rhs(), &[OsString::from("8")]);
+/// assert_eq!(diffs[1].name(), "--debug");
+/// assert_eq!(diffs[1].rhs(), &[OsString::from("true")]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn diff_set<S>(lhs: &S, rhs: &S) -> Vec<OptDiff>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let rawvals = |opt: &SetOpt<S>| opt.rawvals().cloned().unwrap_or_default();
+    let mut diffs = vec![];
+
+    for opt in lhs.iter() {
+        let name = opt.name();
+        let lvals = rawvals(opt);
+        let rvals = rhs
+            .iter()
+            .find(|v| v.name() == name)
+            .map(rawvals)
+            .unwrap_or_default();
+
+        if lvals != rvals {
+            diffs.push(OptDiff::new(name, lvals, rvals));
+        }
+    }
+    for opt in rhs.iter() {
+        let name = opt.name();
+
+        if !lhs.iter().any(|v| v.name() == name) {
+            let rvals = rawvals(opt);
+
+            if !rvals.is_empty() {
+                diffs.push(OptDiff::new(name, vec![], rvals));
+            }
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use crate::prelude::*;
+    use crate::set::diff_set;
+    use crate::Error;
+
+    #[test]
+    fn testing_1() {
+        assert!(testing_1_main().is_ok());
+    }
+
+    fn testing_1_main() -> Result<(), Error> {
+        let mut lhs = ASet::default();
+        let mut rhs = ASet::default();
+
+        let lopt = lhs.add_opt("--name=s")?.run()?;
+        let ropt = rhs.add_opt("--name=s")?.run()?;
+        let extra = rhs.add_opt("--extra=s")?.run()?;
+
+        lhs.add_opt("--only-left=b")?.run()?;
+        for set in [&mut lhs, &mut rhs] {
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+        }
+        assert!(diff_set(&lhs, &rhs).is_empty());
+
+        lhs[lopt].rawvals_mut()?.push(OsString::from("foo"));
+        rhs[ropt].rawvals_mut()?.push(OsString::from("foo"));
+        rhs[extra].rawvals_mut()?.push(OsString::from("bar"));
+
+        let diffs = diff_set(&lhs, &rhs);
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].name(), "--extra");
+        assert!(diffs[0].lhs().is_empty());
+        assert_eq!(diffs[0].rhs(), &[OsString::from("bar")]);
+
+        rhs[ropt].rawvals_mut()?.push(OsString::from("baz"));
+        assert_eq!(diff_set(&lhs, &rhs)[0].name(), "--name");
+        Ok(())
+    }
+}
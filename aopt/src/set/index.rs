@@ -1,9 +1,17 @@
+use crate::opt::ConfigValue;
+use crate::opt::Opt;
+use crate::raise_error;
 use crate::set::Ctor;
 use crate::set::Set;
-use crate::set::SetExt;
+use crate::set::SetCfg;
+use crate::set::SetValueFindExt;
+use crate::str::edit_distance;
 use crate::Error;
 use crate::Uid;
 
+/// The index type of [`Set`], it is an uid of option or the name of option such as `"--opt"`.
+///
+/// It is using by [`SetExt::opt`](crate::set::SetExt::opt) and the [`Index`](std::ops::Index) implementation of set.
 pub trait SetIndex<S: Set> {
     fn ref_from<'a>(&self, set: &'a S) -> Result<&'a <S::Ctor as Ctor>::Opt, Error>;
 
@@ -14,14 +22,22 @@ macro_rules! impl_num_index_for {
     ($num:ty) => {
         impl<S: Set> SetIndex<S> for $num {
             fn ref_from<'a>(&self, set: &'a S) -> Result<&'a <S::Ctor as Ctor>::Opt, Error> {
-                set.opt(*self as Uid)
+                let uid = *self as Uid;
+
+                set.get(uid).ok_or_else(|| {
+                    raise_error!("can not find option `{}` by uid", uid).with_uid(uid)
+                })
             }
 
             fn mut_from<'a>(
                 &self,
                 set: &'a mut S,
             ) -> Result<&'a mut <S::Ctor as Ctor>::Opt, Error> {
-                set.opt_mut(*self as Uid)
+                let uid = *self as Uid;
+
+                set.get_mut(uid).ok_or_else(|| {
+                    raise_error!("can not find option(mut) `{}` by uid", uid).with_uid(uid)
+                })
             }
         }
     };
@@ -39,3 +55,41 @@ impl_num_index_for!(u64);
 impl_num_index_for!(u128);
 impl_num_index_for!(usize);
 impl_num_index_for!(isize);
+
+impl<S> SetIndex<S> for &'_ str
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+{
+    fn ref_from<'a>(&self, set: &'a S) -> Result<&'a <S::Ctor as Ctor>::Opt, Error> {
+        match set.find_uid(*self) {
+            Ok(uid) => SetIndex::ref_from(&uid, set),
+            Err(e) => Err(not_found(set, self).cause_by(e)),
+        }
+    }
+
+    fn mut_from<'a>(&self, set: &'a mut S) -> Result<&'a mut <S::Ctor as Ctor>::Opt, Error> {
+        match set.find_uid(*self) {
+            Ok(uid) => SetIndex::mut_from(&uid, set),
+            Err(e) => Err(not_found(set, self).cause_by(e)),
+        }
+    }
+}
+
+/// Return the not found error with names and aliases similar to the `name`, at most three.
+fn not_found<S: Set>(set: &S, name: &str) -> Error {
+    let name = name.split_once('=').map_or(name, |(name, _)| name);
+    let limit = (name.chars().count() / 3).max(1);
+    let mut similar: Vec<(usize, &str)> = set
+        .iter()
+        .flat_map(|opt| {
+            std::iter::once(opt.name()).chain(opt.alias().into_iter().flatten().map(String::as_str))
+        })
+        .map(|v| (edit_distance(name, v), v))
+        .filter(|(distance, _)| *distance <= limit)
+        .collect();
+
+    similar.sort_unstable();
+    similar.dedup();
+    Error::sp_not_found_similar(name, similar.into_iter().take(3).map(|(_, v)| v).collect())
+}
@@ -0,0 +1,144 @@
+use ahash::HashMapExt;
+
+use crate::opt::Opt;
+use crate::HashMap;
+use crate::Uid;
+
+/// An index of option names and aliases, used to find the candidate options of an argument.
+///
+/// The index is built lazily by [`candidates`](NameIndex::candidates),
+/// call [`invalidate`](NameIndex::invalidate) if the options changed.
+#[derive(Debug, Clone)]
+pub struct NameIndex {
+    names: HashMap<String, Vec<Uid>>,
+
+    rest: Vec<Uid>,
+
+    dirty: bool,
+}
+
+impl Default for NameIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NameIndex {
+    pub fn new() -> Self {
+        Self {
+            names: HashMap::new(),
+            rest: vec![],
+            dirty: true,
+        }
+    }
+
+    /// Mark the index need rebuild.
+    pub fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Rebuild the index from `opts`.
+    ///
+    /// The options ignore name are always the candidates,
+    /// other options are indexed by their name and aliases.
+    pub fn rebuild<'a, T: Opt + 'a>(&mut self, opts: impl Iterator<Item = &'a T>) {
+        self.names.clear();
+        self.rest.clear();
+        for opt in opts {
+            let uid = opt.uid();
+
+            if opt.ignore_name() {
+                self.rest.push(uid);
+            } else {
+                self.names
+                    .entry(opt.name().to_string())
+                    .or_default()
+                    .push(uid);
+                for alias in opt.alias().into_iter().flatten() {
+                    let uids = self.names.entry(alias.clone()).or_default();
+
+                    if uids.last() != Some(&uid) {
+                        uids.push(uid);
+                    }
+                }
+            }
+        }
+        self.dirty = false;
+    }
+
+    /// Return the uids of options may match the `name`, in the order of options inserted.
+    pub fn candidates<'a, T: Opt + 'a>(
+        &mut self,
+        name: &str,
+        opts: impl Iterator<Item = &'a T>,
+    ) -> Vec<Uid> {
+        if self.dirty {
+            self.rebuild(opts);
+        }
+
+        let named = self.names.get(name).map(Vec::as_slice).unwrap_or_default();
+        let mut ret = Vec::with_capacity(named.len() + self.rest.len());
+        let (mut i, mut j) = (0, 0);
+
+        // merge the sorted uids
+        while i < named.len() && j < self.rest.len() {
+            if named[i] < self.rest[j] {
+                ret.push(named[i]);
+                i += 1;
+            } else {
+                ret.push(self.rest[j]);
+                j += 1;
+            }
+        }
+        ret.extend_from_slice(&named[i..]);
+        ret.extend_from_slice(&self.rest[j..]);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_candidates() {
+        assert!(testing_candidates_main().is_ok());
+    }
+
+    fn testing_candidates_main() -> Result<(), Error> {
+        let mut set = ASet::default();
+
+        let foo = set.add_opt("--foo=s")?.add_alias("-f").run()?;
+        let pos = set.add_opt("bar=p@1")?.run()?;
+        let flag = set.add_opt("--flag=b")?.add_alias("-f").run()?;
+
+        assert_eq!(set.candidates("--foo"), [foo, pos]);
+        assert_eq!(set.candidates("-f"), [foo, pos, flag]);
+        assert_eq!(set.candidates("--none"), [pos]);
+
+        // rebuild after insert
+        let other = set.add_opt("--foo=i")?.run()?;
+
+        assert_eq!(set.candidates("--foo"), [foo, pos, other]);
+
+        // rebuild after the alias changed
+        set.opt_mut(flag)?.add_alias("--switch");
+
+        assert_eq!(set.candidates("--switch"), [pos, flag]);
+        assert_eq!(set.find_uid("--switch")?, flag);
+
+        // rebuild after replace
+        let mut other = ASet::default();
+
+        other.add_opt("--switch=i")?.run()?;
+        set.replace(foo, other.take_options().unwrap().remove(0));
+        assert_eq!(set.candidates("--switch"), [foo, pos, flag]);
+        assert_eq!(set.candidates("-f"), [pos, flag]);
+        Ok(())
+    }
+}
@@ -9,11 +9,15 @@ use crate::opt::ConfigValue;
 use crate::opt::Information;
 use crate::opt::Opt;
 use crate::opt::OptParser;
+use crate::opt::Style;
 use crate::raise_error;
 use crate::set::Ctor;
 use crate::set::Filter;
 use crate::set::FilterMatcher;
 use crate::set::FilterMut;
+use crate::set::NameIndex;
+use crate::set::OptSpec;
+use crate::set::Profile;
 use crate::set::Set;
 use crate::set::SetCfg;
 use crate::set::SetCommit;
@@ -63,6 +67,8 @@ where
     validator: V,
     opts: Vec<C::Opt>,
     creators: HashMap<Cid, C>,
+    index: NameIndex,
+    profiles: Vec<Profile>,
 }
 
 impl<P, C, V> OptSet<P, C, V>
@@ -77,6 +83,8 @@ where
             validator,
             opts: vec![],
             creators: HashMap::new(),
+            index: NameIndex::new(),
+            profiles: vec![],
         }
     }
 }
@@ -95,6 +103,8 @@ where
             .field("validator", &self.validator)
             .field("opts", &self.opts)
             .field("creators", &self.creators)
+            .field("index", &self.index)
+            .field("profiles", &self.profiles)
             .finish()
     }
 }
@@ -111,6 +121,8 @@ where
             validator: V::default(),
             opts: vec![],
             creators: crate::ctors!(C),
+            index: NameIndex::new(),
+            profiles: vec![],
         }
     }
 }
@@ -173,6 +185,7 @@ where
 
         if !self.opts.is_empty() {
             ret = Some(std::mem::take(&mut self.opts));
+            self.index.invalidate();
         }
         ret
     }
@@ -200,6 +213,18 @@ where
         Ok(SetCommit::new(self, cb.build(self.parser())?))
     }
 
+    /// Add the options described by `specs` into current [`OptSet`], return the [`Uid`]s of them.
+    pub fn add_opts(&mut self, specs: &[OptSpec]) -> Result<Vec<Uid>, Error> {
+        let mut uids = Vec::with_capacity(specs.len());
+
+        for spec in specs {
+            let commit = self.add_opt(spec.create_str().as_str())?;
+
+            uids.push(spec.apply(commit)?.run()?);
+        }
+        Ok(uids)
+    }
+
     /// Filter the option by configuration.
     ///
     /// It parsing the given option string `S` using inner [`OptParser`], return an [`Filter`].
@@ -333,26 +358,6 @@ where
     }
 }
 
-impl<P, C, V> SetIndex<OptSet<P, C, V>> for &'_ str
-where
-    C::Opt: Opt,
-    C: Ctor,
-    P: OptParser,
-    V: OptValidator,
-    P::Output: Information,
-    C::Config: ConfigValue + Default,
-{
-    fn ref_from<'a>(&self, set: &'a OptSet<P, C, V>) -> Result<&'a C::Opt, Error> {
-        set.find(*self)
-            .map_err(|e| raise_error!("can not find option {}: {e:?}", *self))
-    }
-
-    fn mut_from<'a>(&self, set: &'a mut OptSet<P, C, V>) -> Result<&'a mut C::Opt, Error> {
-        set.find_mut(*self)
-            .map_err(|e| raise_error!("can not find option {}: {e:?}", *self))
-    }
-}
-
 impl<P, C, V> Set for OptSet<P, C, V>
 where
     C::Opt: Opt,
@@ -393,7 +398,30 @@ where
         self.opts.iter()
     }
 
+    /// The index of names is rebuilt if the options changed by [`insert`](Set::insert),
+    /// [`replace`](Set::replace), [`iter_mut`](Set::iter_mut) or [`get_mut`](Set::get_mut).
+    fn candidates(&mut self, name: &str) -> Vec<Uid> {
+        self.index.candidates(name, self.opts.iter())
+    }
+
+    fn insert_profile(&mut self, profile: Profile) -> Result<(), Error> {
+        match self
+            .profiles
+            .iter_mut()
+            .find(|v| v.name() == profile.name())
+        {
+            Some(exist) => *exist = profile,
+            None => self.profiles.push(profile),
+        }
+        Ok(())
+    }
+
+    fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|v| v.name() == name)
+    }
+
     fn iter_mut(&mut self) -> std::slice::IterMut<'_, SetOpt<Self>> {
+        self.index.invalidate();
         self.opts.iter_mut()
     }
 
@@ -402,6 +430,7 @@ where
 
         opt.set_uid(uid);
         self.opts.push(opt);
+        self.index.invalidate();
         uid
     }
 
@@ -410,8 +439,17 @@ where
     }
 
     fn get_mut(&mut self, id: Uid) -> Option<&mut SetOpt<Self>> {
+        self.index.invalidate();
         self.opts.get_mut(id as usize)
     }
+
+    fn replace(&mut self, uid: Uid, mut opt: SetOpt<Self>) -> Option<SetOpt<Self>> {
+        let exist = self.opts.get_mut(uid as usize)?;
+
+        opt.set_uid(uid);
+        self.index.invalidate();
+        Some(std::mem::replace(exist, opt))
+    }
 }
 
 impl<P, C, V> OptParser for OptSet<P, C, V>
@@ -440,13 +478,36 @@ where
 {
     type Error = Error;
 
+    /// Return false if `name` [`may be a path`](OptValidator::maybe_path) and
+    /// the set has no option named `name`.
     fn check(&mut self, name: &str) -> Result<bool, Self::Error> {
-        OptValidator::check(&mut self.validator, name).map_err(Into::into)
+        Ok(
+            OptValidator::check(&mut self.validator, name).map_err(Into::into)?
+                && (!self.validator.maybe_path(name)
+                    || self
+                        .iter()
+                        .any(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name))),
+        )
     }
 
     fn split<'a>(&self, name: &Cow<'a, str>) -> Result<(Cow<'a, str>, Cow<'a, str>), Self::Error> {
         OptValidator::split(&self.validator, name).map_err(Into::into)
     }
+
+    /// Return true if the validator accept bare word and the set has an option named `name`.
+    fn check_bare(&mut self, name: &str) -> Result<bool, Self::Error> {
+        Ok(
+            OptValidator::check_bare(&mut self.validator, name).map_err(Into::into)?
+                && self.iter().any(|opt| {
+                    opt.mat_style(Style::Argument)
+                        && (opt.mat_name(Some(name)) || opt.mat_alias(name))
+                }),
+        )
+    }
+
+    fn maybe_path(&self, name: &str) -> bool {
+        OptValidator::maybe_path(&self.validator, name)
+    }
 }
 
 #[cfg(test)]
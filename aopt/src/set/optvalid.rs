@@ -11,22 +11,134 @@ pub trait OptValidator {
 
     /// Split the option string into prefix and name.
     fn split<'a>(&self, name: &Cow<'a, str>) -> Result<(Cow<'a, str>, Cow<'a, str>), Self::Error>;
+
+    /// Check the name of `name=value` argument which has no prefix.
+    /// Return true if the argument can be treated as an option assignment.
+    fn check_bare(&mut self, _name: &str) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    /// Return true if the option string may be a path, such as `/tmp/x` with prefix `/`.
+    /// It is treated as option only if the set has an option named `name`.
+    fn maybe_path(&self, _name: &str) -> bool {
+        false
+    }
 }
 
 /// A prefixed validator used in [`Policy`](crate::parser::Policy) and [`InvokeGuess`](crate::guess::InvokeGuess).
 ///
 /// The default prefixes are `--/`, `--`, `-/`, `-` and `/`(only for windows).
+///
+/// With [`with_bare`](PrefixOptValidator::with_bare), the `name=value` arguments such as `if=/dev/zero`
+/// are treated as option assignments if the [`Set`](crate::set::Set) has an option named `name`,
+/// otherwise they are NOA.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.validator_mut().set_bare(true);
+/// parser.add_opt("if=s")?;
+/// parser.add_opt("of=s")?;
+/// parser.add_opt("args=p@1..")?.set_pos_type::<String>();
+///
+/// parser.parse(Args::from(["dd", "if=/dev/zero", "of=out.img", "count=1"]))?;
+///
+/// assert_eq!(parser.find_val::<String>("if")?, "/dev/zero");
+/// assert_eq!(parser.find_val::<String>("of")?, "out.img");
+/// assert_eq!(parser.find_vals::<String>("args")?, &["count=1"]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The argument begin with a [`path prefix`](PrefixOptValidator::add_path_prefix) is treated as option
+/// only if the [`Set`](crate::set::Set) has an option named it, otherwise it is NOA.
+/// The `/` is a path prefix by default on windows.
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.validator_mut().add_prefix("/").add_path_prefix("/");
+/// parser.add_opt("/v=b")?;
+/// parser.add_opt("files=p@1..")?.set_pos_type::<String>();
+///
+/// parser.parse(Args::from(["app", "/v", "/tmp/x"]))?;
+///
+/// assert_eq!(parser.find_val::<bool>("/v")?, &true);
+/// assert_eq!(parser.find_vals::<String>("files")?, &["/tmp/x"]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The prefixes are matched in order of priority, the longest prefix is preferred if they have same priority,
+/// such as `--foo` is split into `--` and `foo` with prefixes `-` and `--`.
+/// Change the priority with [`set_priority`](PrefixOptValidator::set_priority),
+/// or remove the prefix with [`remove_prefix`](PrefixOptValidator::remove_prefix).
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use std::borrow::Cow;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut validator = PrefixOptValidator::new(vec!["-".to_owned(), "--".to_owned()]);
+///
+/// validator.add_prefix("+").add_prefix("-");
+/// assert_eq!(validator.prefix(), ["--", "-", "+"]);
+/// assert_eq!(
+///     validator.split(&Cow::Borrowed("--foo"))?,
+///     (Cow::Borrowed("--"), Cow::Borrowed("foo"))
+/// );
+///
+/// // prefer `-` over other prefixes, `--foo` is split into `-` and `-foo`
+/// validator.set_priority("-", 1);
+/// assert_eq!(validator.prefix(), ["-", "--", "+"]);
+/// assert_eq!(
+///     validator.split(&Cow::Borrowed("--foo"))?,
+///     (Cow::Borrowed("-"), Cow::Borrowed("-foo"))
+/// );
+///
+/// // remove `+`, the `+foo` is not an option
+/// validator.remove_prefix("+").set_priority("-", 0);
+/// assert_eq!(validator.prefix(), ["--", "-"]);
+/// assert!(validator.check("-f")?);
+/// assert!(!validator.check("+f")?);
+/// #
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
-pub struct PrefixOptValidator(Vec<String>);
+pub struct PrefixOptValidator {
+    /// The prefixes with priority, in order of matching.
+    prefix: Vec<(String, i32)>,
+
+    path_prefix: Vec<String>,
+
+    bare: bool,
+}
 
 #[cfg(target_os = "windows")]
 impl Default for PrefixOptValidator {
     fn default() -> Self {
-        Self::new(
+        let mut ret = Self::new(
             ["--/", "--", "-/", "-", "/"]
                 .map(|v| v.to_string())
                 .to_vec(),
-        )
+        );
+
+        ret.add_path_prefix("/");
+        ret
     }
 }
 
@@ -38,46 +150,138 @@ impl Default for PrefixOptValidator {
 }
 
 impl PrefixOptValidator {
+    /// Create the validator with `prefix`, all the prefixes have priority 0.
     pub fn new(prefix: Vec<String>) -> Self {
-        // sort the prefix by length
-        let mut _self = Self(prefix);
+        let mut _self = Self {
+            prefix: vec![],
+            path_prefix: vec![],
+            bare: false,
+        };
 
-        _self.sort_prefix();
+        for prefix in prefix {
+            _self.add_prefix(&prefix);
+        }
         _self
     }
 
+    /// Sort the prefix by priority and length, the order of insertion is kept for others.
     fn sort_prefix(&mut self) {
-        self.0.sort_by_key(|b| std::cmp::Reverse(b.len()));
+        self.prefix
+            .sort_by_key(|(prefix, priority)| std::cmp::Reverse((*priority, prefix.len())));
     }
 
+    /// Add a prefix with priority 0, do nothing if the prefix already exists.
     pub fn add_prefix(&mut self, prefix: &str) -> &mut Self {
-        self.0.push(prefix.to_string());
+        if self.priority(prefix).is_none() {
+            self.prefix.push((prefix.to_string(), 0));
+            self.sort_prefix();
+        }
+        self
+    }
+
+    /// Remove the prefix, the argument begin with it is no longer an option.
+    pub fn remove_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.prefix.retain(|(v, _)| v != prefix);
+        self
+    }
+
+    pub fn with_priority(mut self, prefix: &str, priority: i32) -> Self {
+        self.set_priority(prefix, priority);
+        self
+    }
+
+    /// Set the priority of prefix, the prefix is added if it not exists.
+    /// The prefix with higher priority is matched first.
+    pub fn set_priority(&mut self, prefix: &str, priority: i32) -> &mut Self {
+        match self.prefix.iter_mut().find(|(v, _)| v == prefix) {
+            Some((_, value)) => *value = priority,
+            None => self.prefix.push((prefix.to_string(), priority)),
+        }
         self.sort_prefix();
         self
     }
+
+    pub fn priority(&self, prefix: &str) -> Option<i32> {
+        self.prefix
+            .iter()
+            .find(|(v, _)| v == prefix)
+            .map(|(_, priority)| *priority)
+    }
+
+    /// Return the prefixes in order of matching.
+    pub fn prefix(&self) -> Vec<&str> {
+        self.prefix.iter().map(|(v, _)| v.as_str()).collect()
+    }
+
+    /// Return the first matched prefix of `name`.
+    fn find_prefix(&self, name: &str) -> Option<&str> {
+        self.prefix
+            .iter()
+            .map(|(v, _)| v.as_str())
+            .find(|prefix| name.starts_with(prefix))
+    }
+
+    /// Add a prefix may begin a path, the argument begin with it is treated as option only if
+    /// the set has an option named it. The `prefix` should be added by [`add_prefix`](Self::add_prefix) too.
+    pub fn add_path_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.path_prefix.push(prefix.to_string());
+        self
+    }
+
+    pub fn with_path_prefix(mut self, prefix: Vec<String>) -> Self {
+        self.path_prefix = prefix;
+        self
+    }
+
+    pub fn set_path_prefix(&mut self, prefix: Vec<String>) -> &mut Self {
+        self.path_prefix = prefix;
+        self
+    }
+
+    pub fn path_prefix(&self) -> &[String] {
+        &self.path_prefix
+    }
+
+    /// Enable the `name=value` bare word options.
+    pub fn with_bare(mut self, bare: bool) -> Self {
+        self.bare = bare;
+        self
+    }
+
+    pub fn set_bare(&mut self, bare: bool) -> &mut Self {
+        self.bare = bare;
+        self
+    }
+
+    pub fn bare(&self) -> bool {
+        self.bare
+    }
 }
 
 impl OptValidator for PrefixOptValidator {
     type Error = Error;
 
     fn check(&mut self, name: &str) -> Result<bool, Self::Error> {
-        for prefix in self.0.iter() {
-            if name.starts_with(prefix) {
-                return Ok(true);
-            }
-        }
-        Ok(false)
+        Ok(self.find_prefix(name).is_some())
     }
 
     fn split<'a>(&self, name: &Cow<'a, str>) -> Result<(Cow<'a, str>, Cow<'a, str>), Self::Error> {
-        for prefix in self.0.iter() {
-            if name.starts_with(prefix) {
-                return Ok(name.split_at(prefix.len()));
-            }
+        match self.find_prefix(name) {
+            Some(prefix) => Ok(name.split_at(prefix.len())),
+            None => Err(raise_error!(
+                "can not split the {}: invalid option name string",
+                name
+            )),
         }
-        Err(raise_error!(
-            "can not split the {}: invalid option name string",
-            name
-        ))
+    }
+
+    fn check_bare(&mut self, name: &str) -> Result<bool, Self::Error> {
+        Ok(self.bare && !name.is_empty())
+    }
+
+    /// Return true if the matched prefix of `name` is a path prefix.
+    fn maybe_path(&self, name: &str) -> bool {
+        self.find_prefix(name)
+            .is_some_and(|prefix| self.path_prefix.iter().any(|v| v == prefix))
     }
 }
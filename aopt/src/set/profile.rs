@@ -0,0 +1,165 @@
+use std::ffi::OsString;
+
+use crate::ctx::Ctx;
+use crate::opt::Opt;
+use crate::set::Set;
+use crate::set::SetOpt;
+use crate::set::ValueSources;
+use crate::value::ValueSource;
+use crate::Error;
+
+/// A named bundle of option values, it can be activated by an option such as `--profile release`.
+///
+/// The values of profile are stored with [`ValueSource::Profile`],
+/// so the values supplied by command line are kept, no matter they are before or after the profile.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--opt=i")?.set_value_t(0i64);
+/// parser.add_opt("--lto=b")?;
+/// parser.add_opt("--name=s")?.set_value_t(String::from("aopt"));
+/// parser.add_opt("--profile=s")?.on(profile_handler)?;
+/// parser.add_profile("release", [("--opt", "3"), ("--lto", "true"), ("--name", "fast")])?;
+/// parser.parse(Args::from(["app", "--name=cote", "--profile", "release"]))?;
+///
+/// assert_eq!(parser.find_val::<i64>("--opt")?, &3);
+/// assert_eq!(parser.find_val::<bool>("--lto")?, &true);
+/// assert_eq!(parser.find_val::<String>("--name")?, "cote");
+/// assert_eq!(parser.find_val::<String>("--profile")?, "release");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    name: String,
+
+    values: Vec<(String, Vec<OsString>)>,
+}
+
+impl Profile {
+    /// Create a profile, the values of same option are merged in order.
+    pub fn new<N, I, K, V>(name: N, values: I) -> Self
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<OsString>,
+    {
+        let mut ret: Vec<(String, Vec<OsString>)> = vec![];
+
+        for (opt, val) in values {
+            let opt = opt.into();
+
+            match ret.iter_mut().find(|(name, _)| name == &opt) {
+                Some((_, vals)) => vals.push(val.into()),
+                None => ret.push((opt, vec![val.into()])),
+            }
+        }
+        Self {
+            name: name.into(),
+            values: ret,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn values(&self) -> &[(String, Vec<OsString>)] {
+        &self.values
+    }
+
+    /// Store the values into options, the values supplied by higher priority source are kept.
+    pub fn apply<S>(&self, set: &mut S) -> Result<(), Error>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        for (name, vals) in self.values.iter() {
+            ValueSources::store(set, name, ValueSource::Profile, vals)?;
+        }
+        Ok(())
+    }
+}
+
+/// A handler apply the profile named by the value of option, see [`Profile`].
+pub fn profile_handler<S, Ser>(set: &mut S, _: &mut Ser, ctx: &Ctx) -> Result<Option<String>, Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let name = ctx.value::<String>()?;
+
+    set.apply_profile(&name)?;
+    Ok(Some(name))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_profile() {
+        assert!(testing_profile_main().is_ok());
+    }
+
+    fn testing_profile_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--opt;-O=u")?.set_value_t(0u64);
+        parser.add_opt("--feature=s")?.set_action(Action::App);
+        parser.add_opt("--profile=s")?.on(profile_handler)?;
+        parser.add_profile("dev", [("-O", "1")])?;
+        parser.add_profile(
+            "release",
+            [("-O", "3"), ("--feature", "a"), ("--feature", "b")],
+        )?;
+
+        let opt = parser.find_uid("--opt")?;
+        let feature = parser.find_uid("--feature")?;
+
+        assert_eq!(parser.profile("release").map(|v| v.values().len()), Some(2));
+        assert!(parser.profile("none").is_none());
+
+        parser.parse(Args::from(["app", "--profile=release"]))?;
+        assert_eq!(parser.find_val::<u64>("--opt")?, &3);
+        assert_eq!(parser.value_source(opt)?, ValueSource::Profile);
+        assert_eq!(
+            parser.find_vals::<String>("--feature")?,
+            &vec!["a".to_owned(), "b".to_owned()]
+        );
+
+        // the value from command line is kept
+        for args in [
+            ["app", "--profile=release", "-O", "2"],
+            ["app", "-O", "2", "--profile=release"],
+        ] {
+            parser.reset_all()?;
+            parser.parse(Args::from(args))?;
+            assert_eq!(parser.find_val::<u64>("--opt")?, &2);
+            assert_eq!(parser.value_source(opt)?, ValueSource::Cli);
+            assert_eq!(parser.value_source(feature)?, ValueSource::Profile);
+        }
+
+        // the profile is replaced
+        parser.add_profile("dev", [("-O", "0")])?;
+        parser.reset_all()?;
+        parser.parse(Args::from(["app", "--profile=dev"]))?;
+        assert_eq!(parser.find_val::<u64>("--opt")?, &0);
+        assert_eq!(parser.value_source(opt)?, ValueSource::Profile);
+
+        assert!(!parser
+            .parse(Args::from(["app", "--profile=none"]))?
+            .status());
+        Ok(())
+    }
+}
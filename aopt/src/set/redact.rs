@@ -0,0 +1,113 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::fmt::Debug;
+
+use crate::opt::Opt;
+use crate::opt::OptValueExt;
+use crate::set::Set;
+use crate::set::SetOpt;
+
+/// The transform of [`Redactor`], called with the option name and raw value.
+pub type RedactFn = Box<dyn Fn(&str, &OsStr) -> OsString>;
+
+/// The replacement used by [`Redactor::mask`].
+pub const REDACTED: &str = "******";
+
+/// Collect the raw values of matched options for persisting, such as an audit log.
+///
+/// The values of options marked as [`sensitive`](crate::opt::Opt::sensitive) will pass through
+/// the transform of [`Redactor`] instead of being written as is.
+/// The default transform replace the value with [`REDACTED`],
+/// using [`Redactor::new`] if you want hashing or partial masking.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::set::Redactor;
+/// # use aopt::Error;
+/// # use std::ffi::OsString;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--user=s")?;
+/// parser.add_opt("--token=s")?.set_sensitive(true);
+/// parser.add_opt("--debug=b")?;
+/// parser.parse(Args::from(["app", "--user=alice", "--token", "secret"]))?;
+///
+/// let dump = Redactor::default().redact(parser.optset());
+///
+/// assert_eq!(dump.len(), 2);
+/// assert_eq!(dump[0], ("--user".to_owned(), vec![OsString::from("alice")]));
+/// assert_eq!(dump[1], ("--token".to_owned(), vec![OsString::from("******")]));
+///
+/// let redactor = Redactor::new(|_, val| {
+///     let len = val.len();
+///     OsString::from(format!("<{len} bytes>"))
+/// });
+/// let dump = redactor.redact(parser.optset());
+///
+/// assert_eq!(dump[1].1, vec![OsString::from("<6 bytes>")]);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Redactor {
+    transform: RedactFn,
+}
+
+impl Debug for Redactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Redactor")
+            .field("transform", &"{ ... }")
+            .finish()
+    }
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::mask()
+    }
+}
+
+impl Redactor {
+    /// The transform will be called with the option name and raw value.
+    pub fn new(transform: impl Fn(&str, &OsStr) -> OsString + 'static) -> Self {
+        Self {
+            transform: Box::new(transform),
+        }
+    }
+
+    /// Replace every sensitive value with [`REDACTED`].
+    pub fn mask() -> Self {
+        Self::new(|_, _| OsString::from(REDACTED))
+    }
+
+    pub fn transform(&self, name: &str, val: &OsStr) -> OsString {
+        (self.transform)(name, val)
+    }
+
+    /// Return the name and raw values of options matched in last parsing,
+    /// in the order they were added.
+    pub fn redact<S>(&self, set: &S) -> Vec<(String, Vec<OsString>)>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        set.iter()
+            .filter(|opt| opt.matched())
+            .filter_map(|opt| {
+                let name = opt.name();
+                let vals = opt.rawvals().ok()?;
+                let vals = if opt.sensitive() {
+                    vals.iter().map(|val| self.transform(name, val)).collect()
+                } else {
+                    vals.clone()
+                };
+
+                Some((name.to_string(), vals))
+            })
+            .collect()
+    }
+}
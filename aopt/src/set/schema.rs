@@ -0,0 +1,401 @@
+use std::any::TypeId;
+use std::ffi::OsString;
+use std::io::Stdin;
+use std::path::PathBuf;
+
+use crate::opt::Action;
+use crate::opt::Cmd;
+use crate::opt::ConfigValue;
+use crate::opt::Index;
+use crate::opt::Main;
+use crate::opt::Opt;
+use crate::opt::Pos;
+use crate::opt::Style;
+use crate::raise_error;
+use crate::set::ctor_default_name;
+use crate::set::Ctor;
+use crate::set::Set;
+use crate::set::SetCfg;
+use crate::set::SetExt;
+use crate::set::SetOpt;
+use crate::value::ColorChoice;
+use crate::value::Infer;
+use crate::value::Stop;
+use crate::Error;
+use crate::Uid;
+
+const STYLES: [Style; 7] = [
+    Style::Pos,
+    Style::Cmd,
+    Style::Main,
+    Style::Boolean,
+    Style::Argument,
+    Style::Combined,
+    Style::Flag,
+];
+
+macro_rules! schema_types {
+    ($($name:literal => $ty:ty),+ $(,)?) => {
+        fn type_name_of(type_id: &TypeId) -> Option<&'static str> {
+            $(
+                if type_id == &<$ty as Infer>::infer_type_id() {
+                    return Some($name);
+                }
+            )+
+            None
+        }
+
+        fn fill_info_of<C>(name: &str, cfg: &mut C) -> Result<(), Error>
+        where
+            C: ConfigValue + Default,
+        {
+            match name {
+                $(
+                    $name => <$ty as Infer>::infer_fill_info(cfg),
+                )+
+                _ => Err(raise_error!("unsupported type `{}` in schema", name)),
+            }
+        }
+    };
+}
+
+schema_types!(
+    "bool" => bool,
+    "i8" => i8,
+    "i16" => i16,
+    "i32" => i32,
+    "i64" => i64,
+    "i128" => i128,
+    "u8" => u8,
+    "u16" => u16,
+    "u32" => u32,
+    "u64" => u64,
+    "u128" => u128,
+    "isize" => isize,
+    "usize" => usize,
+    "f32" => f32,
+    "f64" => f64,
+    "String" => String,
+    "OsString" => OsString,
+    "PathBuf" => PathBuf,
+    "Stdin" => Stdin,
+    "ColorChoice" => ColorChoice,
+    "Stop" => Stop,
+    "Cmd" => Cmd,
+    "Main" => Main,
+    "Pos<bool>" => Pos<bool>,
+    "Pos<i32>" => Pos<i32>,
+    "Pos<i64>" => Pos<i64>,
+    "Pos<u32>" => Pos<u32>,
+    "Pos<u64>" => Pos<u64>,
+    "Pos<usize>" => Pos<usize>,
+    "Pos<f32>" => Pos<f32>,
+    "Pos<f64>" => Pos<f64>,
+    "Pos<String>" => Pos<String>,
+    "Pos<OsString>" => Pos<OsString>,
+    "Pos<PathBuf>" => Pos<PathBuf>,
+);
+
+/// The serializable definition of an option.
+///
+/// The handlers, value storers and initializers can not be serialized,
+/// so the options are rebuilt with the default value parser and initializer of their type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OptSchema {
+    name: String,
+
+    #[serde(rename = "type")]
+    ty: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    hint: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    help: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    alias: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<Index>,
+
+    action: Action,
+
+    styles: Vec<Style>,
+
+    #[serde(default)]
+    force: bool,
+
+    #[serde(default)]
+    ignore_name: bool,
+
+    #[serde(default)]
+    ignore_alias: bool,
+
+    #[serde(default)]
+    ignore_index: bool,
+
+    #[serde(default)]
+    global: bool,
+
+    #[serde(default)]
+    sensitive: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    force_if_cmd: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    choices: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    requires: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    conflicts_with: Vec<String>,
+
+    #[serde(default)]
+    priority: i32,
+}
+
+impl OptSchema {
+    /// Dump the definition of `opt`, return an error if the type of option is not supported.
+    pub fn new<O: Opt>(opt: &O) -> Result<Self, Error> {
+        let ty = type_name_of(opt.r#type()).ok_or_else(|| {
+            raise_error!("can not dump option `{}`: unsupported type", opt.name())
+        })?;
+
+        Ok(Self {
+            name: opt.name().to_string(),
+            ty: ty.to_string(),
+            hint: opt.hint().to_string(),
+            help: opt.help().to_string(),
+            alias: opt.alias().cloned().unwrap_or_default(),
+            index: opt.index().cloned(),
+            action: *opt.action(),
+            styles: STYLES.into_iter().filter(|v| opt.mat_style(*v)).collect(),
+            force: opt.force(),
+            ignore_name: opt.ignore_name(),
+            ignore_alias: opt.ignore_alias(),
+            ignore_index: opt.ignore_index(),
+            global: opt.global(),
+            sensitive: opt.sensitive(),
+            force_if_cmd: opt.force_if_cmd().map(String::from),
+            choices: opt.choices().cloned().unwrap_or_default(),
+            requires: opt.requires().to_vec(),
+            conflicts_with: opt.conflicts_with().to_vec(),
+            priority: opt.priority(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of option type, such as `i64` or `Pos<String>`.
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    pub fn help(&self) -> &str {
+        &self.help
+    }
+
+    pub fn alias(&self) -> &[String] {
+        &self.alias
+    }
+
+    pub fn index(&self) -> Option<&Index> {
+        self.index.as_ref()
+    }
+
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    /// Create the configuration of option.
+    pub fn to_config<C>(&self) -> Result<C, Error>
+    where
+        C: ConfigValue + Default,
+    {
+        let mut cfg = C::default();
+
+        cfg.set_name(self.name.clone());
+        cfg.set_ctor(ctor_default_name());
+        cfg.set_force(self.force);
+        cfg.set_action(self.action);
+        cfg.set_style(self.styles.clone());
+        if !self.alias.is_empty() {
+            cfg.set_alias(self.alias.clone());
+        }
+        if !self.hint.is_empty() {
+            cfg.set_hint(self.hint.clone());
+        }
+        if !self.help.is_empty() {
+            cfg.set_help(self.help.clone());
+        }
+        if let Some(index) = &self.index {
+            cfg.set_index(index.clone());
+        }
+        fill_info_of(&self.ty, &mut cfg)?;
+        cfg.set_ignore_name(self.ignore_name);
+        cfg.set_ignore_alias(self.ignore_alias);
+        cfg.set_ignore_index(self.ignore_index);
+        cfg.set_global(self.global);
+        cfg.set_sensitive(self.sensitive);
+        if let Some(cmd) = &self.force_if_cmd {
+            cfg.set_force_if_cmd(cmd.clone());
+        }
+        if !self.choices.is_empty() {
+            cfg.set_choices(self.choices.clone());
+        }
+        if !self.requires.is_empty() {
+            cfg.set_requires(self.requires.clone());
+        }
+        if !self.conflicts_with.is_empty() {
+            cfg.set_conflicts_with(self.conflicts_with.clone());
+        }
+        cfg.set_priority(self.priority);
+        Ok(cfg)
+    }
+}
+
+/// The serializable definition of all options in a [`Set`], it can be dumped as JSON
+/// and rebuilt into another [`Set`] at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--count;-c=i: Set the count")?;
+/// parser.add_opt("--debug=b")?;
+/// parser.add_opt("file=p@1")?.set_pos_type::<String>();
+///
+/// let json = SetSchema::new(parser.optset())?.to_json()?;
+/// let mut other = AFwdParser::default();
+///
+/// SetSchema::from_json(&json)?.apply(other.optset_mut())?;
+/// other.parse(Args::from(["app", "-c", "42", "--debug", "foo.txt"]))?;
+///
+/// assert_eq!(other.find_val::<i64>("--count")?, &42);
+/// assert_eq!(other.find_val::<bool>("--debug")?, &true);
+/// assert_eq!(other.find_val::<String>("file")?, "foo.txt");
+/// assert_eq!(other.find_opt("--count")?.help(), "Set the count");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetSchema {
+    opts: Vec<OptSchema>,
+}
+
+impl SetSchema {
+    /// Dump the definition of all options in `set`.
+    pub fn new<S>(set: &S) -> Result<Self, Error>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        Ok(Self {
+            opts: set.iter().map(OptSchema::new).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn opts(&self) -> &[OptSchema] {
+        &self.opts
+    }
+
+    /// Add the options into `set`, return the [`Uid`]s of them.
+    pub fn apply<S>(&self, set: &mut S) -> Result<Vec<Uid>, Error>
+    where
+        S: Set,
+        SetCfg<S>: ConfigValue + Default,
+    {
+        let mut uids = Vec::with_capacity(self.opts.len());
+
+        for schema in self.opts.iter() {
+            let cfg: SetCfg<S> = schema.to_config()?;
+            let ctor = ctor_default_name();
+            let opt = set.ctor_mut(&ctor)?.new_with(cfg).map_err(Into::into)?;
+
+            uids.push(set.insert(opt));
+        }
+        Ok(uids)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self).map_err(|e| raise_error!("can not dump schema: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|e| raise_error!("can not load schema: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_schema() {
+        assert!(testing_schema_main().is_ok());
+    }
+
+    fn testing_schema_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--jobs;-j=u")?;
+        parser.add_opt("--flag=b")?.add_alias("-f");
+        parser
+            .add_opt("--name=s!: Set the name")?
+            .set_choices(vec!["foo", "bar"]);
+        parser.add_opt("build=c")?;
+        parser.add_opt("files=p@2..")?.set_pos_type::<String>();
+
+        let schema = SetSchema::new(parser.optset())?;
+        let json = schema.to_json()?;
+        let loaded = SetSchema::from_json(&json)?;
+
+        assert_eq!(schema, loaded);
+        assert_eq!(loaded.opts().len(), 5);
+        assert_eq!(loaded.opts()[0].ty(), "u64");
+        assert_eq!(loaded.opts()[1].alias(), ["-f"]);
+        assert_eq!(loaded.opts()[2].choices(), ["foo", "bar"]);
+        assert_eq!(loaded.opts()[3].ty(), "Cmd");
+        assert_eq!(loaded.opts()[4].ty(), "Pos<String>");
+
+        let mut other = AFwdParser::default();
+
+        loaded.apply(other.optset_mut())?;
+        assert_eq!(SetSchema::new(other.optset())?, schema);
+
+        assert!(!other.parse(Args::from(["app", "build"]))?.status());
+
+        other.reset()?;
+        other.parse(Args::from([
+            "app", "build", "a", "-j", "4", "-f", "--name", "foo", "b",
+        ]))?;
+        assert_eq!(other.find_vals::<u64>("--jobs")?, &[4]);
+        assert_eq!(other.find_val::<bool>("--flag")?, &true);
+        assert_eq!(other.find_val::<String>("--name")?, "foo");
+        assert_eq!(other.find_val::<bool>("build")?, &true);
+        assert_eq!(
+            other.find_vals::<String>("files")?,
+            &["a".to_owned(), "b".to_owned()]
+        );
+        assert_eq!(other.find_opt("--name")?.help(), "Set the name");
+
+        assert!(SetSchema::from_json(
+            r#"{"opts":[{"name":"--foo","type":"Foo","action":"App","styles":[]}]}"#
+        )?
+        .apply(other.optset_mut())
+        .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::borrow::Cow;
+use std::ffi::OsString;
+
+use crate::ctx::Ctx;
+use crate::ctx::InnerCtx;
+use crate::opt::Opt;
+use crate::raise_error;
+use crate::set::Set;
+use crate::set::SetOpt;
+use crate::value::ValueSource;
+use crate::Error;
+
+/// Fill the values of options from environment variables and configuration after parsing.
+///
+/// The value of option is taken from the first available source of
+/// command line, environment variable, configuration, and the default value.
+/// The source of value is recorded, you can get it by [`Set::value_source`].
+///
+/// The option is searched by name or alias.
+/// The [`force`](Opt::force) check happens in parsing, so the force option can not be supplied by
+/// the fallback sources.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::set::ValueSources;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--jobs=i")?.set_value_t(1i64);
+/// parser.add_opt("--name=s")?;
+/// parser.add_opt("--dir=s")?;
+/// parser.add_opt("--debug=b")?;
+/// parser.parse(Args::from(["app", "--name=cote"]))?;
+///
+/// std::env::set_var("VALUE_SOURCES_NAME", "aopt");
+/// std::env::set_var("VALUE_SOURCES_JOBS", "8");
+/// ValueSources::default()
+///     .with_env("--name", "VALUE_SOURCES_NAME")
+///     .with_env("--jobs", "VALUE_SOURCES_JOBS")
+///     .with_config("--jobs", ["4"])
+///     .with_config("--dir", ["target"])
+///     .apply(parser.optset_mut())?;
+///
+/// let source = |name: &str| -> Result<ValueSource, Error> {
+///     let uid = parser.find_uid(name)?;
+///
+///     parser.optset().value_source(uid)
+/// };
+///
+/// assert_eq!(parser.find_val::<String>("--name")?, "cote");
+/// assert_eq!(source("--name")?, ValueSource::Cli);
+/// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+/// assert_eq!(source("--jobs")?, ValueSource::Env);
+/// assert_eq!(parser.find_val::<String>("--dir")?, "target");
+/// assert_eq!(source("--dir")?, ValueSource::Config);
+/// assert_eq!(parser.find_val::<bool>("--debug")?, &false);
+/// assert_eq!(source("--debug")?, ValueSource::Default);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ValueSources {
+    envs: Vec<(String, OsString)>,
+
+    configs: Vec<(String, Vec<OsString>)>,
+}
+
+impl ValueSources {
+    /// Read the value of option `name` from environment variable `var`.
+    pub fn with_env(mut self, name: impl Into<String>, var: impl Into<OsString>) -> Self {
+        self.set_env(name, var);
+        self
+    }
+
+    /// Using the `vals` as configuration values of option `name`.
+    pub fn with_config<I, T>(mut self, name: impl Into<String>, vals: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        self.set_config(name, vals);
+        self
+    }
+
+    pub fn set_env(&mut self, name: impl Into<String>, var: impl Into<OsString>) -> &mut Self {
+        self.envs.push((name.into(), var.into()));
+        self
+    }
+
+    pub fn set_config<I, T>(&mut self, name: impl Into<String>, vals: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        self.configs
+            .push((name.into(), vals.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    pub fn envs(&self) -> &[(String, OsString)] {
+        &self.envs
+    }
+
+    pub fn configs(&self) -> &[(String, Vec<OsString>)] {
+        &self.configs
+    }
+
+    /// Store the values of environment variables and configuration into options,
+    /// the values supplied by higher priority source are kept.
+    pub fn apply<S>(&self, set: &mut S) -> Result<(), Error>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        for (name, var) in self.envs.iter() {
+            if let Some(val) = std::env::var_os(var) {
+                Self::store(set, name, ValueSource::Env, &[val])?;
+            }
+        }
+        for (name, vals) in self.configs.iter() {
+            Self::store(set, name, ValueSource::Config, vals)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn store<S>(
+        set: &mut S,
+        name: &str,
+        source: ValueSource,
+        vals: &[OsString],
+    ) -> Result<(), Error>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let opt = set
+            .iter_mut()
+            .find(|v| v.name() == name || v.mat_alias(name))
+            .ok_or_else(|| raise_error!("can not find option `{}` in set", name))?;
+        let uid = opt.uid();
+        let act = *opt.action();
+        let ctx = Ctx::default().with_inner_ctx(
+            InnerCtx::default()
+                .with_uid(uid)
+                .with_name(Some(Cow::Borrowed(name))),
+        );
+
+        opt.accessor_mut()
+            .store_source(source, vals, &ctx, &act)
+            .map_err(|e| {
+                e.cause(raise_error!(
+                    "can not store value of `{name}` from {source}"
+                ))
+                .with_uid(uid)
+            })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use crate::prelude::*;
+    use crate::set::ValueSources;
+    use crate::Error;
+
+    #[test]
+    fn testing_1() {
+        assert!(testing_1_main().is_ok());
+    }
+
+    fn testing_1_main() -> Result<(), Error> {
+        let mut set = ASet::default();
+
+        let files = set.add_opt("--file=s")?.set_action(Action::App).run()?;
+        let level = set.add_opt("--level;-l=i")?.set_value_t(3i64).run()?;
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        assert_eq!(set.value_source(level)?, ValueSource::Default);
+
+        let sources = ValueSources::default()
+            .with_config("--file", ["a.txt", "b.txt"])
+            .with_config("-l", ["5"]);
+
+        sources.apply(&mut set)?;
+        assert_eq!(set.value_source(files)?, ValueSource::Config);
+        assert_eq!(
+            set[files].vals::<String>()?,
+            &vec!["a.txt".to_owned(), "b.txt".to_owned()]
+        );
+        assert_eq!(set[files].rawvals()?.len(), 2);
+        assert_eq!(set[level].val::<i64>()?, &5);
+
+        // the value from command line is kept
+        let ctx = Ctx::default().with_inner_ctx(InnerCtx::default().with_uid(level));
+
+        set[level]
+            .accessor_mut()
+            .store_all(Some(&OsString::from("9")), &ctx, &Action::Set)?;
+        sources.apply(&mut set)?;
+        assert_eq!(set.value_source(level)?, ValueSource::Cli);
+        assert_eq!(set[level].val::<i64>()?, &9);
+
+        set.reset_values()?;
+        assert_eq!(set.value_source(level)?, ValueSource::Default);
+        assert_eq!(set[level].val::<i64>()?, &3);
+
+        assert!(ValueSources::default()
+            .with_config("--level", ["nan"])
+            .apply(&mut set)
+            .is_err());
+        assert!(ValueSources::default()
+            .with_config("--unknown", ["42"])
+            .apply(&mut set)
+            .is_err());
+        Ok(())
+    }
+}
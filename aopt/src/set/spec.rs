@@ -0,0 +1,201 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use crate::ctx::Ctx;
+use crate::ctx::InnerCtx;
+use crate::opt::Cid;
+use crate::opt::ConfigValue;
+use crate::raise_error;
+use crate::set::Commit;
+use crate::set::Set;
+use crate::set::SetCfg;
+use crate::value::RawValParser;
+use crate::value::ValInitializer;
+use crate::Error;
+
+/// A plain description of option, it can be constructed in const context.
+///
+/// Using it with [`add_opts`](crate::set::OptSet::add_opts) to register many options
+/// from a static table.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// const OPTIONS: &[OptSpec] = &[
+///     OptSpec::new("--debug", "b").with_help("Print debug message"),
+///     OptSpec::new("--jobs", "i")
+///         .with_alias(&["-j"])
+///         .with_default("4"),
+///     OptSpec::new("--name", "s").with_default("aopt"),
+///     OptSpec::new("file", "p@1"),
+/// ];
+///
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opts(OPTIONS)?;
+/// parser.parse(Args::from(["app", "-j", "8", "foo"]))?;
+///
+/// assert_eq!(parser.find_val::<bool>("--debug")?, &false);
+/// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+/// assert_eq!(parser.find_val::<String>("--name")?, "aopt");
+/// assert_eq!(parser.find_val::<bool>("file")?, &true);
+/// assert_eq!(parser.find_opt("--debug")?.help(), "Print debug message");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptSpec {
+    name: &'static str,
+
+    ty: &'static str,
+
+    help: &'static str,
+
+    alias: &'static [&'static str],
+
+    default: Option<&'static str>,
+}
+
+impl OptSpec {
+    /// Create an option spec, `ty` is the creator name and the optional index,
+    /// such as `s` or `p@1`.
+    pub const fn new(name: &'static str, ty: &'static str) -> Self {
+        Self {
+            name,
+            ty,
+            help: "",
+            alias: &[],
+            default: None,
+        }
+    }
+
+    pub const fn with_help(mut self, help: &'static str) -> Self {
+        self.help = help;
+        self
+    }
+
+    pub const fn with_alias(mut self, alias: &'static [&'static str]) -> Self {
+        self.alias = alias;
+        self
+    }
+
+    /// Set the default value of option, it will be parsed according to the creator name.
+    /// Only the value of `i`, `u`, `f`, `s`, `b` and `r` can be set in this way.
+    pub const fn with_default(mut self, default: &'static str) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn ty(&self) -> &'static str {
+        self.ty
+    }
+
+    pub fn help(&self) -> &'static str {
+        self.help
+    }
+
+    pub fn alias(&self) -> &'static [&'static str] {
+        self.alias
+    }
+
+    pub fn default(&self) -> Option<&'static str> {
+        self.default
+    }
+
+    /// The option create string, such as `--name=s`.
+    pub fn create_str(&self) -> String {
+        format!("{}={}", self.name, self.ty)
+    }
+
+    /// Apply the help, alias and default value to the commit.
+    pub fn apply<S, C>(&self, mut commit: C) -> Result<C, Error>
+    where
+        S: Set,
+        C: Commit<S>,
+        SetCfg<S>: ConfigValue + Default,
+    {
+        if !self.help.is_empty() {
+            commit = commit.set_help(self.help);
+        }
+        for alias in self.alias {
+            commit = commit.add_alias(*alias);
+        }
+        if let Some(default) = self.default {
+            let ctor = commit
+                .cfg()
+                .ctor()
+                .ok_or_else(|| raise_error!("missing creator of `{}`", self.name))?;
+            let initializer = Self::initializer(ctor, default)
+                .map_err(|e| e.cause(raise_error!("invalid default value of `{}`", self.name)))?;
+
+            commit = commit.set_initializer(initializer);
+        }
+        Ok(commit)
+    }
+
+    fn initializer(ctor: &str, default: &str) -> Result<ValInitializer, Error> {
+        let raw = Some(OsStr::new(default));
+        let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+
+        Ok(match Cid::from(ctor) {
+            Cid::Int => ValInitializer::new_value(i64::parse(raw, &ctx)?),
+            Cid::Str => ValInitializer::new_value(String::parse(raw, &ctx)?),
+            Cid::Flt => ValInitializer::new_value(f64::parse(raw, &ctx)?),
+            Cid::Uint => ValInitializer::new_value(u64::parse(raw, &ctx)?),
+            Cid::Bool => ValInitializer::new_value(bool::parse(raw, &ctx)?),
+            Cid::Raw => ValInitializer::new_value(OsString::parse(raw, &ctx)?),
+            _ => {
+                return Err(raise_error!(
+                    "can not set default value for creator `{}`",
+                    ctor
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_spec() {
+        assert!(testing_spec_main().is_ok());
+    }
+
+    fn testing_spec_main() -> Result<(), Error> {
+        static SPECS: [OptSpec; 3] = [
+            OptSpec::new("--ratio", "f").with_default("0.5"),
+            OptSpec::new("--count", "u").with_alias(&["-c", "--cnt"]),
+            OptSpec::new("run", "c").with_help("Run the program"),
+        ];
+        let mut set = ASet::default();
+        let uids = set.add_opts(&SPECS)?;
+
+        assert_eq!(uids.len(), 3);
+        assert_eq!(set.find_opt("--ratio")?.uid(), uids[0]);
+        assert_eq!(set.find_opt("-c")?.uid(), uids[1]);
+        assert_eq!(set.find_opt("--cnt")?.uid(), uids[1]);
+        assert_eq!(set.find_opt("run")?.help(), "Run the program");
+
+        let mut set = ASet::default();
+
+        assert!(set
+            .add_opts(&[OptSpec::new("--count", "u").with_default("-1")])
+            .is_err());
+        assert!(set
+            .add_opts(&[OptSpec::new("run", "c").with_default("true")])
+            .is_err());
+        Ok(())
+    }
+}
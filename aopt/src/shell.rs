@@ -39,6 +39,8 @@ pub enum Shell {
     Bash,
 
     Fish,
+
+    PowerShell,
 }
 
 impl RawValParser for Shell {
@@ -52,6 +54,7 @@ impl RawValParser for Shell {
             "zsh" => Ok(Shell::Zsh),
             "bash" => Ok(Shell::Bash),
             "fish" => Ok(Shell::Fish),
+            "powershell" | "pwsh" => Ok(Shell::PowerShell),
             _ => Err(crate::raise_failure!("unknow shell type: {}", name).with_uid(ctx.uid()?)),
         }
     }
@@ -92,6 +95,8 @@ pub struct CompleteService<Set, Ser> {
     display_cmd: bool,
 
     incomplete_opt: Option<Uid>,
+
+    incomplete_eq: bool,
 }
 
 impl<Set, Ser> CompleteService<Set, Ser> {
@@ -120,6 +125,7 @@ impl<Set, Ser> CompleteService<Set, Ser> {
         self.avail_cmd.clear();
         self.avail_opt.clear();
         self.incomplete_opt = None;
+        self.incomplete_eq = false;
         self
     }
 }
@@ -154,7 +160,20 @@ where
             writeln!(writer, "local -a subcmds\nsubcmds=(\n")
                 .map_err(|e| crate::raise_error!("can not write data: {:?}", e))?;
         }
-        if self.display_cmd {
+        let incomplete = self.incomplete_opt.map(|uid| set.opt(uid)).transpose()?;
+
+        if let Some((name, choices)) =
+            incomplete.and_then(|opt| opt.choices().map(|v| (opt.name(), v)))
+        {
+            // complete the value of option, such as `--color=auto`
+            for choice in choices {
+                if self.incomplete_eq {
+                    Self::write_to(writer, &format!("{}={}", name, choice), "", shell)?;
+                } else {
+                    Self::write_to(writer, choice, "", shell)?;
+                }
+            }
+        } else if self.display_cmd {
             for uid in self.avail_cmd.iter() {
                 let opt = set.opt(*uid)?;
                 let name = opt.name();
@@ -195,7 +214,13 @@ where
                 let hint = opt.hint();
                 let help = opt.help();
 
-                Self::write_to(writer, hint, help, shell)?;
+                if let Some(choices) = opt.choices() {
+                    for choice in choices {
+                        Self::write_to(writer, choice, help, shell)?;
+                    }
+                } else {
+                    Self::write_to(writer, hint, help, shell)?;
+                }
             }
         }
         if matches!(shell, Shell::Zsh) {
@@ -221,7 +246,7 @@ where
                         .map_err(|e| crate::raise_error!("can not write data: {:?}", e))?;
                 }
             }
-            Shell::Bash => {
+            Shell::Bash | Shell::PowerShell => {
                 writeln!(writer, "{}", hint)
                     .map_err(|e| crate::raise_error!("can not write data: {:?}", e))?;
             }
@@ -251,6 +276,7 @@ where
         if let Some((opt, _)) = crate::str::split_once(arg, '=') {
             win_os_string = Some(opt);
             arg = win_os_string.as_deref().unwrap();
+            self.incomplete_eq = true;
         }
 
         self.set_incomplete_opt(
@@ -373,8 +399,9 @@ pub fn try_get_complete() -> Result<Option<(String, Shell)>, Error> {
     let mut try_parser = AFwdParser::default();
 
     try_parser.add_opt("--_completes: Get complete option or sub command".infer::<String>())?;
-    try_parser
-        .add_opt("--_shell!: Set shell type, support zsh fish bash".infer::<MutOpt<Shell>>())?;
+    try_parser.add_opt(
+        "--_shell!: Set shell type, support zsh fish bash powershell".infer::<MutOpt<Shell>>(),
+    )?;
     try_parser.parse_env()?;
 
     if let Ok(cl) = try_parser.take_val("--_completes") {
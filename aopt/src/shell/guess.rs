@@ -3,6 +3,7 @@ use std::ffi::OsStr;
 
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
+use crate::opt::Opt;
 use crate::opt::Style;
 use crate::opt::BOOL_TRUE;
 use crate::parser::UserStyle;
@@ -11,6 +12,7 @@ use crate::str::CowStrUtils;
 use crate::Error;
 use crate::Uid;
 
+use crate::guess::sort_by_priority;
 use crate::guess::style::*;
 use crate::guess::GuessPolicy;
 use crate::guess::MatchPolicy;
@@ -171,16 +173,16 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<MainStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, true)?;
                     }
                 }
             }
-            UserStyle::Pos => {
+            UserStyle::Pos | UserStyle::Assign => {
                 if let Some(mut policy) =
                     GuessPolicy::<PosStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, true)?;
                     }
                 }
@@ -189,7 +191,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<CmdStyle, SingleNonOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, true)?;
                     }
                 }
@@ -198,7 +200,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, false)?;
                     }
                 }
@@ -208,7 +210,7 @@ where
                     GuessPolicy::<ArgumentStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
                     consume = true;
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, false)?;
                     }
                 }
@@ -217,7 +219,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<EmbeddedValueStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, false)?;
                     }
                 }
@@ -228,7 +230,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, consume)? {
+                    if self.match_multi(style, &mut policy, consume)? {
                         matched = self.apply_multi(&mut policy, false)?;
                     }
                 }
@@ -239,7 +241,7 @@ where
                     MultiOpt<SingleOpt<Set>, Set>,
                 >::guess_policy(self)?
                 {
-                    if self.match_multi(&mut policy, consume)? {
+                    if self.match_multi(style, &mut policy, consume)? {
                         matched = self.apply_multi(&mut policy, false)?;
                     }
                 }
@@ -248,7 +250,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<BooleanStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, false)?;
                     }
                 }
@@ -257,7 +259,7 @@ where
                 if let Some(mut policy) =
                     GuessPolicy::<FlagStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
-                    if self.r#match(&mut policy, consume)? {
+                    if self.r#match(style, &mut policy, consume)? {
                         matched = self.apply(&mut policy, false)?;
                     }
                 }
@@ -572,15 +574,22 @@ where
     Set: crate::set::Set,
     Inv: HandlerCollection<'c, Set, Ser>,
 {
-    pub fn r#match<T>(&mut self, policy: &mut T, consume: bool) -> Result<bool, Error>
+    pub fn r#match<T>(
+        &mut self,
+        style: &UserStyle,
+        policy: &mut T,
+        consume: bool,
+    ) -> Result<bool, Error>
     where
         T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
     {
-        let uids = self.set.keys();
+        let mut uids = self.set.keys();
+
+        sort_by_priority(self.set, &mut uids);
 
         for uid in uids {
             // if overload is true select all the option may match the `policy`
-            if !policy.filter(uid, self.set) {
+            if self.allow_style(uid, style) && !policy.filter(uid, self.set) {
                 if let Err(e) = policy.r#match(uid, self.set, false, consume) {
                     let e = e.into();
 
@@ -593,18 +602,29 @@ where
         Ok(policy.matched())
     }
 
+    /// Return false if the option of `uid` not allow the user `style`.
+    fn allow_style(&self, uid: Uid, style: &UserStyle) -> bool {
+        self.set
+            .get(uid)
+            .map(|opt| opt.mat_user_style(style))
+            .unwrap_or(true)
+    }
+
     fn match_multi(
         &mut self,
+        style: &UserStyle,
         policy: &mut MultiOpt<SingleOpt<Set>, Set>,
         consume: bool,
     ) -> Result<bool, Error> {
-        let uids = self.set.keys();
+        let mut uids = self.set.keys();
+
+        sort_by_priority(self.set, &mut uids);
         let any_match = policy.any_match();
 
         for sub_policy in policy.sub_policys_mut() {
             // process all uids with each policy first
             for uid in uids.iter() {
-                if !sub_policy.filter(*uid, self.set) {
+                if self.allow_style(*uid, style) && !sub_policy.filter(*uid, self.set) {
                     if let Err(e) = sub_policy.r#match(*uid, self.set, false, consume) {
                         if !e.is_failure() {
                             return Err(e);
@@ -1,4 +1,3 @@
-use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -176,16 +175,19 @@ where
             let mut matched = false;
             let mut consume = false;
 
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
+            if let Ok(ArgInfo { name, value }) = opt_styles.parse_arg(opt) {
                 trace!(
                     "guess name: {:?} value: {:?} & next: {:?}",
                     name,
                     value,
                     next
                 );
-                if set.check(&name).map_err(Into::into)? {
+                let like_opt = set.check(&name).map_err(Into::into)?
+                    || (value.is_some() && set.check_bare(&name).map_err(Into::into)?);
+
+                if like_opt {
                     let arg = value.clone();
-                    let next = next.map(|v| Cow::Borrowed(*v));
+                    let next = next.map(|v| opt_styles.next_arg(v));
                     let mut guess = CompleteGuess {
                         idx,
                         arg,
@@ -198,7 +200,7 @@ where
                         name: Some(name.clone()),
                     };
 
-                    for style in opt_styles.iter() {
+                    for style in opt_styles.resolve(&name).iter() {
                         if let Some(ret) = guess.guess_complete(style)? {
                             (matched, consume) = (ret.matched, ret.consume);
                         }
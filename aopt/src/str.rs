@@ -66,6 +66,36 @@ pub fn display_of_osstr(val: Option<&OsStr>) -> String {
     }
 }
 
+/// Return the Levenshtein distance between `lhs` and `rhs`, counted in [`char`]s.
+///
+/// ```rust
+/// # use aopt::str::edit_distance;
+/// assert_eq!(edit_distance("--verbose", "--verbsoe"), 2);
+/// assert_eq!(edit_distance("-v", "-v"), 0);
+/// assert_eq!(edit_distance("", "--all"), 5);
+/// ```
+pub fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut row: Vec<usize> = (0..=rhs.len()).collect();
+
+    for (i, lc) in lhs.chars().enumerate() {
+        let mut prev = row[0];
+
+        row[0] = i + 1;
+        for (j, rc) in rhs.iter().enumerate() {
+            let cur = row[j + 1];
+
+            row[j + 1] = if lc == *rc {
+                prev
+            } else {
+                1 + prev.min(cur).min(row[j])
+            };
+            prev = cur;
+        }
+    }
+    row[rhs.len()]
+}
+
 pub trait CowOsStrUtils<'a> {
     fn split_once(&self, sep: char) -> Option<(Cow<'a, OsStr>, Cow<'a, OsStr>)>;
 
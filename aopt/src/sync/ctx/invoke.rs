@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::ctx::normalize_arg;
 use crate::ctx::wrap_handler;
 use crate::ctx::wrap_handler_action;
 use crate::ctx::wrap_handler_fallback;
@@ -8,6 +9,7 @@ use crate::ctx::wrap_handler_fallback_action;
 use crate::ctx::Ctx;
 use crate::ctx::Store;
 use crate::map::ErasedTy;
+use crate::opt::action::check_once;
 use crate::opt::Opt;
 use crate::set::SetExt;
 use crate::set::SetOpt;
@@ -76,12 +78,15 @@ use crate::Uid;
 /// ```
 pub struct Invoker<'a, Set, Ser> {
     callbacks: HashMap<Uid, InvokeHandler<'a, Set, Ser, Error>>,
+
+    middlewares: Vec<InvokeMiddleware<'a, Set, Ser, Error>>,
 }
 
 impl<'a, Set, Ser> Debug for Invoker<'a, Set, Ser> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Invoker")
             .field("callbacks", &"{ ... }")
+            .field("middlewares", &self.middlewares.len())
             .finish()
     }
 }
@@ -90,6 +95,7 @@ impl<'a, Set, Ser> Default for Invoker<'a, Set, Ser> {
     fn default() -> Self {
         Self {
             callbacks: HashMap::default(),
+            middlewares: vec![],
         }
     }
 }
@@ -98,6 +104,7 @@ impl<'a, Set, Ser> Invoker<'a, Set, Ser> {
     pub fn new() -> Self {
         Self {
             callbacks: HashMap::default(),
+            middlewares: vec![],
         }
     }
 }
@@ -151,6 +158,84 @@ where
     pub fn has(&self, uid: Uid) -> bool {
         self.callbacks.contains_key(&uid)
     }
+
+    /// Add a middleware wrapping the invocation of all handlers, include the [`fallback`](Invoker::fallback).
+    ///
+    /// The middlewares are called in the order of registration, the first one is the outermost.
+    /// The middleware can do something before and after the handler,
+    /// it should call [`run`](InvokeNext::run) of `next` to continue the chain.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// # use std::borrow::Cow;
+    /// # use std::ffi::OsStr;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--name=s")?;
+    /// parser.add_opt("--debug=b")?;
+    /// parser
+    ///     .optset_mut()
+    ///     .invoker_mut()
+    ///     .add_middleware(|set, ser, ctx, next| {
+    ///         // count the options set
+    ///         let ret = next.run(set, ser, ctx)?;
+    ///
+    ///         if ret {
+    ///             *ser.sve_val_mut::<usize>()? += 1;
+    ///         }
+    ///         Ok(ret)
+    ///     })
+    ///     .add_middleware(|set, ser, ctx, next| {
+    ///         // change the value of argument before the handler
+    ///         if let (Style::Argument, Some(arg)) = (ctx.style()?, ctx.arg()?) {
+    ///             let arg = arg.to_string_lossy().to_uppercase();
+    ///
+    ///             ctx.set_arg(Some(Cow::Owned(OsStr::new(&arg).to_os_string())))?;
+    ///         }
+    ///         next.run(set, ser, ctx)
+    ///     });
+    /// parser.set_app_data(0usize)?;
+    /// parser.parse(Args::from(["app", "--name=foo", "--debug"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<String>("--name")?, "FOO");
+    /// assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+    /// assert_eq!(parser.app_data::<usize>()?, &2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_middleware<M>(&mut self, middleware: M) -> &mut Self
+    where
+        M: FnMut(&mut Set, &mut Ser, &mut Ctx, InvokeNext<'a, '_, Set, Ser>) -> Result<bool, Error>
+            + Send
+            + Sync
+            + 'a,
+    {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Add a middleware, see [`add_middleware`](Invoker::add_middleware).
+    pub fn with_middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: FnMut(&mut Set, &mut Ser, &mut Ctx, InvokeNext<'a, '_, Set, Ser>) -> Result<bool, Error>
+            + Send
+            + Sync
+            + 'a,
+    {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Remove all the middlewares.
+    pub fn clear_middlewares(&mut self) -> &mut Self {
+        self.middlewares.clear();
+        self
+    }
 }
 
 impl<'a, Set, Ser> Invoker<'a, Set, Ser>
@@ -173,11 +258,12 @@ where
     /// then save the value to [`ValStorer`](crate::value::ValStorer).
     pub fn fallback(set: &mut Set, _: &mut Ser, ctx: &mut Ctx) -> Result<bool, Error> {
         let uid = ctx.uid()?;
-        let opt = set.get_mut(uid).unwrap();
+        let opt = set.opt_mut(uid)?;
         let arg = ctx.arg()?.map(|v| v.as_ref());
         let act = *opt.action();
 
         trace!("in fallback, call for {}({act}) {{{ctx:?}}}", opt.name());
+        check_once(opt, &act)?;
         opt.accessor_mut().store_all(arg, ctx, &act)
     }
 }
@@ -186,6 +272,60 @@ where
 pub type InvokeHandler<'a, Set, Ser, Error> =
     Box<dyn FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + Send + Sync + 'a>;
 
+/// Middleware type wrapping the invocation of handlers, see [`add_middleware`](Invoker::add_middleware).
+pub type InvokeMiddleware<'a, Set, Ser, Error> = Box<
+    dyn for<'b> FnMut(
+            &mut Set,
+            &mut Ser,
+            &mut Ctx,
+            InvokeNext<'a, 'b, Set, Ser>,
+        ) -> Result<bool, Error>
+        + Send
+        + Sync
+        + 'a,
+>;
+
+/// The rest of middleware chain and the handler.
+pub struct InvokeNext<'a, 'b, Set, Ser> {
+    middlewares: &'b mut [InvokeMiddleware<'a, Set, Ser, Error>],
+
+    handler: &'b mut dyn FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error>,
+}
+
+impl<Set, Ser> Debug for InvokeNext<'_, '_, Set, Ser> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InvokeNext")
+            .field("middlewares", &self.middlewares.len())
+            .field("handler", &"{ ... }")
+            .finish()
+    }
+}
+
+impl<'a, 'b, Set, Ser> InvokeNext<'a, 'b, Set, Ser> {
+    pub fn new(
+        middlewares: &'b mut [InvokeMiddleware<'a, Set, Ser, Error>],
+        handler: &'b mut dyn FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error>,
+    ) -> Self {
+        Self {
+            middlewares,
+            handler,
+        }
+    }
+
+    /// Call the next middleware, or the handler if no middleware left.
+    pub fn run(self, set: &mut Set, ser: &mut Ser, ctx: &mut Ctx) -> Result<bool, Error> {
+        let Self {
+            middlewares,
+            handler,
+        } = self;
+
+        match middlewares.split_first_mut() {
+            Some((middleware, rest)) => (middleware)(set, ser, ctx, Self::new(rest, handler)),
+            None => (handler)(set, ser, ctx),
+        }
+    }
+}
+
 pub trait HandlerCollection<'a, Set, Ser>
 where
     Set: crate::set::Set,
@@ -200,6 +340,9 @@ where
 
     fn get_handler(&mut self, uid: &Uid) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>>;
 
+    /// Remove the handler of given `uid`, return true if the handler exists.
+    fn remove_handler(&mut self, uid: &Uid) -> bool;
+
     /// Invoke the handler saved in [`Invoker`], it will panic if the handler not exist.
     fn invoke(
         &mut self,
@@ -208,7 +351,8 @@ where
         ser: &mut Ser,
         ctx: &mut Ctx,
     ) -> Result<bool, Error> {
-        trace!("invoking callback of {} {:?}", uid, ctx);
+        normalize_arg(set, ctx)?;
+        trace!(uid, style = ?ctx.style().ok(), "invoking callback {ctx:?}");
         if let Some(callback) = self.get_handler(uid) {
             return (callback)(set, ser, ctx);
         }
@@ -226,11 +370,12 @@ where
         ser: &mut Ser,
         ctx: &mut Ctx,
     ) -> Result<bool, Error> {
+        normalize_arg(set, ctx)?;
         if let Some(callback) = self.get_handler(uid) {
-            trace!("invoking(fb) callback of {} {:?}", uid, ctx);
+            trace!(uid, style = ?ctx.style().ok(), "invoking(fb) callback {ctx:?}");
             (callback)(set, ser, ctx)
         } else {
-            trace!("invoking(fb) fallback callback of {} {:?}", uid, ctx);
+            trace!(uid, style = ?ctx.style().ok(), "invoking(fb) fallback {ctx:?}");
             Invoker::fallback(set, ser, ctx)
         }
     }
@@ -253,6 +398,45 @@ where
     fn get_handler(&mut self, uid: &Uid) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>> {
         self.callbacks.get_mut(uid)
     }
+
+    fn remove_handler(&mut self, uid: &Uid) -> bool {
+        self.callbacks.remove(uid).is_some()
+    }
+
+    fn invoke(
+        &mut self,
+        uid: &Uid,
+        set: &mut Set,
+        ser: &mut Ser,
+        ctx: &mut Ctx,
+    ) -> Result<bool, Error> {
+        normalize_arg(set, ctx)?;
+        trace!(uid, style = ?ctx.style().ok(), "invoking callback {ctx:?}");
+        if let Some(callback) = self.callbacks.get_mut(uid) {
+            return InvokeNext::new(&mut self.middlewares, callback).run(set, ser, ctx);
+        }
+        unreachable!(
+            "no callback of {}, call `invoke_fb` or `fallback` instead",
+            set.opt(*uid)?.name()
+        )
+    }
+
+    fn invoke_fb(
+        &mut self,
+        uid: &Uid,
+        set: &mut Set,
+        ser: &mut Ser,
+        ctx: &mut Ctx,
+    ) -> Result<bool, Error> {
+        normalize_arg(set, ctx)?;
+        if let Some(callback) = self.callbacks.get_mut(uid) {
+            trace!(uid, style = ?ctx.style().ok(), "invoking(fb) callback {ctx:?}");
+            InvokeNext::new(&mut self.middlewares, callback).run(set, ser, ctx)
+        } else {
+            trace!(uid, style = ?ctx.style().ok(), "invoking(fb) fallback {ctx:?}");
+            InvokeNext::new(&mut self.middlewares, &mut Invoker::fallback).run(set, ser, ctx)
+        }
+    }
 }
 
 pub struct HandlerEntry<'a, 'b, I, Set, Ser, H, O>
@@ -1,8 +1,14 @@
 pub(crate) mod accessor;
+pub(crate) mod color;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) mod datetime;
 pub(crate) mod infer;
 pub(crate) mod initializer;
+pub(crate) mod normalizer;
 pub(crate) mod parser;
+pub(crate) mod source;
 pub(crate) mod storer;
+pub(crate) mod valenum;
 pub(crate) mod validator;
 
 use std::any::type_name;
@@ -11,15 +17,34 @@ use std::ffi::OsString;
 use std::fmt::Debug;
 
 pub use self::accessor::ValAccessor;
+pub use self::color::ColorChoice;
+pub use self::color::NO_COLOR;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use self::datetime::DateFormat;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use self::datetime::Formatted;
 pub use self::infer::Infer;
 pub use self::infer::Placeholder;
 pub use self::initializer::InitHandler;
 pub use self::initializer::InitializeValue;
 pub use self::initializer::ValInitializer;
+pub use self::normalizer::Normalize;
+pub use self::normalizer::Normalizer;
+pub use self::parser::raw2bytes;
+pub use self::parser::raw2cow;
+pub use self::parser::raw2fromstr;
 pub use self::parser::raw2str;
+pub use self::parser::BoolLiterals;
+pub use self::parser::Decoding;
+pub use self::parser::FloatSyntax;
+pub use self::parser::NumLocale;
 pub use self::parser::RawValParser;
+pub use self::source::ValueSource;
+pub use self::storer::ConvertHandler;
+pub use self::storer::DeferredHandler;
 pub use self::storer::StoreHandler;
 pub use self::storer::ValStorer;
+pub use self::valenum::ValueEnum;
 pub use self::validator::ValValidator;
 pub use self::validator::ValidatorHandler;
 
@@ -75,6 +100,135 @@ use crate::Error;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Stop;
 
+/// A special option value, collect the arguments after `--` verbatim and stop the policy.
+///
+/// The arguments after `--` are still passed to the `NOA`s as [`Stop`] does.
+/// Like other option, need add `--` to no delay option list when using [`DelayPolicy`](crate::parser::DelayPolicy).
+///
+/// # Example
+/// ```
+/// use aopt::prelude::*;
+/// use aopt::value::Passthrough;
+/// use std::ffi::OsString;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut parser = AFwdParser::default();
+///
+///     parser.add_opt("rest".infer::<Passthrough>())?;
+///     parser.add_opt("-w=i")?;
+///
+///     parser.parse(Args::from(["app", "-w=42", "--", "-w", "val"]))?;
+///
+///     let rest = parser.find_val::<Passthrough>("--")?;
+///
+///     assert_eq!(parser.find_val::<i64>("-w")?, &42);
+///     assert_eq!(rest.args(), &[OsString::from("-w"), OsString::from("val")]);
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Passthrough(Vec<OsString>);
+
+impl Passthrough {
+    pub fn new(args: Vec<OsString>) -> Self {
+        Self(args)
+    }
+
+    pub fn args(&self) -> &[OsString] {
+        &self.0
+    }
+
+    pub fn into_args(self) -> Vec<OsString> {
+        self.0
+    }
+}
+
+impl From<Passthrough> for Vec<OsString> {
+    fn from(value: Passthrough) -> Self {
+        value.0
+    }
+}
+
+/// A special option value, the `name=value` NOA such as `CC=clang` of `make build CC=clang`.
+///
+/// The option of this type allow [`Assign`](crate::parser::UserStyle::Assign) style only,
+/// the matched arguments are removed from NOA before matching [`Cmd`](crate::opt::Cmd) and [`Pos`](crate::opt::Pos).
+/// Use `BTreeMap<String, String>` if you want collect them into a map.
+///
+/// # Example
+/// ```
+/// use aopt::prelude::*;
+/// use aopt::value::Assign;
+/// use std::collections::BTreeMap;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut parser = AFwdParser::default();
+///
+///     parser.add_opt("target=p@1")?.set_pos_type::<String>();
+///     parser.add_opt("vars".infer::<BTreeMap<String, String>>())?;
+///
+///     parser.parse(Args::from(["app", "CC=clang", "build", "CFLAGS=-O2"]))?;
+///
+///     let vars = parser.find_vals::<Assign>("vars")?;
+///
+///     assert_eq!(parser.find_val::<String>("target")?, "build");
+///     assert_eq!(vars[0], Assign::new("CC", "clang"));
+///     assert_eq!(vars[1], Assign::new("CFLAGS", "-O2"));
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Assign {
+    name: String,
+
+    value: String,
+}
+
+impl Assign {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn into_pair(self) -> (String, String) {
+        (self.name, self.value)
+    }
+
+    /// Split the argument into name and value if it likes `name=value`.
+    ///
+    /// The name should start with letter or `_`, and contain only letters, digits, `_` or `.`.
+    pub fn split(arg: &OsStr) -> Option<(&str, &str)> {
+        let (name, value) = arg.to_str()?.split_once('=')?;
+        let mut chars = name.chars();
+
+        chars
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+            .then_some(())?;
+        chars
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '.')
+            .then_some((name, value))
+    }
+}
+
+impl From<Assign> for (String, String) {
+    fn from(value: Assign) -> Self {
+        value.into_pair()
+    }
+}
+
 pub trait ErasedValue {
     fn initialize(&mut self) -> Result<(), Error>;
 
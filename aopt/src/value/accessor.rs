@@ -7,6 +7,7 @@ use super::RawValParser;
 use super::ValInitializer;
 use super::ValStorer;
 use super::ValValidator;
+use super::ValueSource;
 
 use crate::ctx::Ctx;
 use crate::map::ErasedTy;
@@ -94,9 +95,13 @@ pub struct ValAccessor {
 
     rawval: Vec<OsString>,
 
+    rawidx: Vec<usize>,
+
     storer: ValStorer,
 
     initializer: ValInitializer,
+
+    source: ValueSource,
 }
 
 impl Default for ValAccessor {
@@ -110,8 +115,10 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            rawidx: vec![],
             storer,
             initializer,
+            source: ValueSource::Default,
         }
     }
 
@@ -122,8 +129,10 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            rawidx: vec![],
             storer: ValStorer::new_validator(validator),
             initializer,
+            source: ValueSource::Default,
         }
     }
 
@@ -131,8 +140,10 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            rawidx: vec![],
             storer: ValStorer::fallback::<U>(),
             initializer: ValInitializer::fallback(),
+            source: ValueSource::Default,
         }
     }
 
@@ -156,6 +167,11 @@ impl ValAccessor {
         self
     }
 
+    pub fn set_source(&mut self, source: ValueSource) -> &mut Self {
+        self.source = source;
+        self
+    }
+
     pub fn storer(&self) -> &ValStorer {
         &self.storer
     }
@@ -164,6 +180,17 @@ impl ValAccessor {
         &self.initializer
     }
 
+    /// Return the index of arguments which the raw values parsed from,
+    /// it is empty if the raw values are not from command line.
+    pub fn arg_indices(&self) -> &[usize] {
+        &self.rawidx
+    }
+
+    /// Return the source which supplied current value.
+    pub fn source(&self) -> ValueSource {
+        self.source
+    }
+
     pub fn storer_mut(&mut self) -> &mut ValStorer {
         &mut self.storer
     }
@@ -172,6 +199,19 @@ impl ValAccessor {
         &mut self.initializer
     }
 
+    /// Invoke the [`deferred`](ValStorer::with_deferred) validator of storer on current values.
+    pub fn validate(&self) -> Result<bool, Error> {
+        self.storer.validate(&self.any_value)
+    }
+
+    /// Clear the values and raw values, the storer and initializer are kept.
+    pub fn clear(&mut self) {
+        self.rawval.clear();
+        self.rawidx.clear();
+        self.any_value.clear();
+        self.source = ValueSource::Default;
+    }
+
     pub fn handlers(&mut self) -> (&mut Vec<OsString>, &mut AnyValue) {
         (&mut self.rawval, &mut self.any_value)
     }
@@ -188,18 +228,47 @@ impl ValAccessor {
             Ok(_) => {
                 if let Some(raw) = arg {
                     self.rawval.push(raw.to_os_string());
+                    self.rawidx.push(ctx.idx().unwrap_or_default());
                 }
+                self.source = ValueSource::Cli;
                 Ok(true)
             }
             Err(e) => Err(e),
         }
     }
+
+    /// Store the raw values supplied by `source`, current values such as the default value
+    /// will be cleared first.
+    ///
+    /// Return `Ok(false)` if current value is supplied by a higher priority source.
+    pub fn store_source(
+        &mut self,
+        source: ValueSource,
+        vals: &[OsString],
+        ctx: &Ctx,
+        act: &Action,
+    ) -> Result<bool, Error> {
+        if self.source > source {
+            return Ok(false);
+        }
+        self.clear();
+        for val in vals {
+            self.store(Some(val), ctx, act)?;
+            self.rawval.push(val.clone());
+        }
+        self.source = source;
+        Ok(true)
+    }
 }
 
 impl ErasedValue for ValAccessor {
+    /// Initialize the value, the [`source`](ValAccessor::source) is reset to [`ValueSource::Default`].
     fn initialize(&mut self) -> Result<(), Error> {
         let handler = &mut self.any_value;
 
+        self.rawval.clear();
+        self.rawidx.clear();
+        self.source = ValueSource::Default;
         self.initializer.invoke(handler)
     }
 
@@ -214,7 +283,7 @@ impl ErasedValue for ValAccessor {
         let value = val;
 
         match act {
-            Action::Set => {
+            Action::Set | Action::SetOnce => {
                 handler.set(vec![value]);
             }
             Action::App => {
@@ -0,0 +1,198 @@
+use std::ffi::OsStr;
+use std::io::IsTerminal;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::opt::ConfigBuildInfer;
+use crate::opt::ConfigBuildWith;
+use crate::opt::ConfigBuilderWith;
+use crate::opt::ConfigValue;
+use crate::opt::Style;
+use crate::opt::BOOL_TRUE;
+use crate::Error;
+
+use super::raw2cow;
+use super::Infer;
+use super::RawValParser;
+use super::ValInitializer;
+use super::ValueEnum;
+
+/// The environment variable disable color output, see <https://no-color.org>.
+pub const NO_COLOR: &str = "NO_COLOR";
+
+/// The value of `--color` option, decide when to use color in the output.
+///
+/// The option accepts `auto`, `always` or `never`, using it without value is same as `always`.
+/// The default value is [`Auto`](ColorChoice::Auto),
+/// which enable color if the stdout is a terminal and [`NO_COLOR`] is not set.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt(ColorChoice::preset())?;
+/// parser.parse(Args::from(["app"]))?;
+/// assert_eq!(parser.find_val::<ColorChoice>("--color")?, &ColorChoice::Auto);
+///
+/// parser.reset()?;
+/// parser.parse(Args::from(["app", "--color=never"]))?;
+/// assert_eq!(parser.find_val::<ColorChoice>("--color")?, &ColorChoice::Never);
+/// assert!(!parser.find_val::<ColorChoice>("--color")?.enabled());
+///
+/// parser.reset()?;
+/// parser.parse(Args::from(["app", "--color"]))?;
+/// assert!(parser.find_val::<ColorChoice>("--color")?.enabled());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Enable color if the output is a terminal and [`NO_COLOR`] is not set.
+    #[default]
+    Auto,
+
+    Always,
+
+    Never,
+}
+
+impl ColorChoice {
+    /// Create the configuration of option `--color`.
+    pub fn preset<C>() -> ConfigBuilderWith<C, &'static str, ColorChoice>
+    where
+        C: ConfigValue + Default,
+    {
+        "--color"
+            .infer::<ColorChoice>()
+            .with_hint("--color[=WHEN]")
+            .with_help("When to use color")
+    }
+
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    /// Return true if the color should be used when writing to stdout.
+    pub fn enabled(&self) -> bool {
+        self.enabled_for(std::io::stdout().is_terminal())
+    }
+
+    /// Return true if the color should be used when writing to an output,
+    /// `is_terminal` is whether the output is a terminal.
+    pub fn enabled_for(&self, is_terminal: bool) -> bool {
+        match self {
+            Self::Auto => is_terminal && std::env::var_os(NO_COLOR).is_none_or(|v| v.is_empty()),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+impl RawValParser for ColorChoice {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        let val = raw2cow(raw, ctx)?;
+
+        match val.as_ref() {
+            "auto" => Ok(Self::Auto),
+            "always" | BOOL_TRUE => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(Error::sp_rawval(raw, "except auto, always or never").with_uid(ctx.uid()?)),
+        }
+    }
+}
+
+impl Infer for ColorChoice {
+    type Val = ColorChoice;
+
+    fn infer_act() -> Action {
+        Action::Set
+    }
+
+    fn infer_style() -> Vec<Style> {
+        vec![Style::Argument, Style::Boolean]
+    }
+
+    /// Default value is [`Auto`](ColorChoice::Auto).
+    fn infer_initializer() -> Option<ValInitializer> {
+        Some(ValInitializer::new_value(ColorChoice::Auto))
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        Self::tweak_choices(cfg);
+        Ok(())
+    }
+}
+
+impl ValueEnum for ColorChoice {
+    fn variants() -> &'static [&'static str] {
+        &["auto", "always", "never"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_color() {
+        assert!(testing_color_main().is_ok());
+    }
+
+    fn testing_color_main() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt(ColorChoice::preset())?;
+        parser.add_opt("file=p@1")?.set_pos_type::<String>();
+
+        for (args, choice) in [
+            (vec!["app", "a"], ColorChoice::Auto),
+            (vec!["app", "--color=always", "a"], ColorChoice::Always),
+            (vec!["app", "--color", "never", "a"], ColorChoice::Never),
+            (vec!["app", "--color=auto", "a"], ColorChoice::Auto),
+            (vec!["app", "a", "--color"], ColorChoice::Always),
+        ] {
+            parser.reset()?;
+            parser.parse(Args::from(args))?;
+            assert_eq!(parser.find_val::<ColorChoice>("--color")?, &choice);
+            assert_eq!(parser.find_val::<String>("file")?, "a");
+        }
+
+        parser.reset()?;
+        assert!(!parser
+            .parse(Args::from(["app", "--color=rainbow", "a"]))?
+            .status());
+
+        assert_eq!(parser.find_opt("--color")?.hint(), "--color[=WHEN]");
+        assert!(ColorChoice::Always.enabled_for(false));
+        assert!(!ColorChoice::Never.enabled_for(true));
+        assert!(!ColorChoice::Auto.enabled_for(false));
+        Ok(())
+    }
+}
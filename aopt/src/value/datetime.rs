@@ -0,0 +1,340 @@
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::ctx::Ctx;
+use crate::map::ErasedTy;
+use crate::value::raw2str;
+use crate::value::Infer;
+use crate::Error;
+
+/// Provide the format string used by [`Formatted`].
+///
+/// For `chrono` types the format is the [`strftime`](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) syntax,
+/// for `time` types it is the [`format description`](https://time-rs.github.io/book/api/format-description.html) syntax.
+pub trait DateFormat {
+    const FORMAT: &'static str;
+}
+
+/// Parsing the date or time value with the format provided by `F`.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::value::DateFormat;
+/// # use aopt::value::Formatted;
+/// # use aopt::Error;
+/// # use chrono::NaiveDate;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// #[derive(Debug)]
+/// pub struct Dmy;
+///
+/// impl DateFormat for Dmy {
+///     const FORMAT: &'static str = "%d/%m/%Y";
+/// }
+///
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--since".infer::<NaiveDate>())?;
+/// parser.add_opt("--until".infer::<Formatted<NaiveDate, Dmy>>())?;
+/// parser.parse(Args::from(["app", "--since=2024-02-29", "--until", "01/03/2024"]))?;
+///
+/// assert_eq!(
+///     parser.find_val::<NaiveDate>("--since")?,
+///     &NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+/// );
+/// assert_eq!(
+///     **parser.find_val::<Formatted<NaiveDate, Dmy>>("--until")?,
+///     NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Formatted<T, F> {
+    inner: T,
+
+    marker: PhantomData<F>,
+}
+
+impl<T, F> Formatted<T, F> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Clone, F> Clone for Formatted<T, F> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<T: Debug, F> Debug for Formatted<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Formatted").field(&self.inner).finish()
+    }
+}
+
+impl<T, F> Infer for Formatted<T, F>
+where
+    Self: ErasedTy,
+{
+    type Val = Self;
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+}
+
+impl<T: PartialEq, F> PartialEq for Formatted<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T, F> Deref for Formatted<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, F> DerefMut for Formatted<T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+fn parse_with<T, E: std::error::Error>(
+    raw: Option<&OsStr>,
+    ctx: &Ctx,
+    name: &str,
+    parser: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, Error> {
+    let val = raw2str(raw)?;
+    let uid = ctx.uid()?;
+
+    parser(val).map_err(|e| {
+        Error::sp_rawval(raw, format!("not a valid value of type {}", name))
+            .with_uid(uid)
+            .cause_by(Error::from(e))
+    })
+}
+
+macro_rules! impl_infer_for_datetime {
+    ($name:ty) => {
+        impl Infer for $name {
+            type Val = $name;
+
+            fn infer_map(val: Self::Val) -> Self {
+                val
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use std::ffi::OsStr;
+    use std::str::FromStr;
+
+    use chrono::DateTime;
+    use chrono::NaiveDate;
+    use chrono::NaiveDateTime;
+    use chrono::Utc;
+
+    use super::parse_with;
+    use super::DateFormat;
+    use super::Formatted;
+    use crate::ctx::Ctx;
+    use crate::value::Infer;
+    use crate::value::RawValParser;
+    use crate::Error;
+
+    /// Parsing the date in `%Y-%m-%d` format.
+    impl RawValParser for NaiveDate {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            parse_with(raw, ctx, "NaiveDate", NaiveDate::from_str)
+        }
+    }
+
+    /// Parsing the date and time in RFC 3339 format, such as `2024-02-29T08:00:00Z`.
+    impl RawValParser for DateTime<Utc> {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            parse_with(raw, ctx, "DateTime<Utc>", DateTime::<Utc>::from_str)
+        }
+    }
+
+    impl<F: DateFormat> RawValParser for Formatted<NaiveDate, F> {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            parse_with(raw, ctx, "NaiveDate", |val| {
+                NaiveDate::parse_from_str(val, F::FORMAT)
+            })
+            .map(Formatted::new)
+        }
+    }
+
+    /// The time zone is optional in the format, the value is treated as UTC if it is missing.
+    impl<F: DateFormat> RawValParser for Formatted<DateTime<Utc>, F> {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            parse_with(raw, ctx, "DateTime<Utc>", |val| {
+                DateTime::parse_from_str(val, F::FORMAT)
+                    .map(|v| v.with_timezone(&Utc))
+                    .or_else(|_| NaiveDateTime::parse_from_str(val, F::FORMAT).map(|v| v.and_utc()))
+            })
+            .map(Formatted::new)
+        }
+    }
+
+    impl_infer_for_datetime!(NaiveDate);
+    impl_infer_for_datetime!(DateTime<Utc>);
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use std::ffi::OsStr;
+
+    use time::format_description;
+    use time::format_description::well_known::Rfc3339;
+    use time::OffsetDateTime;
+
+    use super::parse_with;
+    use super::DateFormat;
+    use super::Formatted;
+    use crate::ctx::Ctx;
+    use crate::value::Infer;
+    use crate::value::RawValParser;
+    use crate::Error;
+
+    /// Parsing the date and time in RFC 3339 format, such as `2024-02-29T08:00:00+08:00`.
+    impl RawValParser for OffsetDateTime {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            parse_with(raw, ctx, "OffsetDateTime", |val| {
+                OffsetDateTime::parse(val, &Rfc3339)
+            })
+        }
+    }
+
+    impl<F: DateFormat> RawValParser for Formatted<OffsetDateTime, F> {
+        type Error = Error;
+
+        fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+            let format = format_description::parse_borrowed::<1>(F::FORMAT)
+                .map_err(|e| crate::raise_error!("invalid time format `{}`: {}", F::FORMAT, e))?;
+
+            parse_with(raw, ctx, "OffsetDateTime", |val| {
+                OffsetDateTime::parse(val, &format)
+            })
+            .map(Formatted::new)
+        }
+    }
+
+    impl_infer_for_datetime!(OffsetDateTime);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::value::DateFormat;
+    use crate::value::Formatted;
+    use crate::Error;
+
+    #[derive(Debug)]
+    pub struct Simple;
+
+    impl DateFormat for Simple {
+        #[cfg(feature = "chrono")]
+        const FORMAT: &'static str = "%Y%m%d %H:%M";
+
+        #[cfg(not(feature = "chrono"))]
+        const FORMAT: &'static str =
+            "[year][month][day] [hour]:[minute] [offset_hour sign:mandatory]";
+    }
+
+    #[test]
+    fn testing_1() {
+        assert!(testing_1_main().is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    fn testing_1_main() -> Result<(), Error> {
+        use chrono::DateTime;
+        use chrono::TimeZone;
+        use chrono::Utc;
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--at".infer::<DateTime<Utc>>())?;
+        parser.add_opt("--on".infer::<Formatted<DateTime<Utc>, Simple>>())?;
+        parser.parse(Args::from([
+            "app",
+            "--at=2024-02-29T08:00:00+08:00",
+            "--on=20240301 12:30",
+        ]))?;
+
+        let expect = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+
+        assert_eq!(parser.find_val::<DateTime<Utc>>("--at")?, &expect);
+        let expect = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+
+        assert_eq!(
+            **parser.find_val::<Formatted<DateTime<Utc>, Simple>>("--on")?,
+            expect
+        );
+        assert!(parser
+            .parse(Args::from(["app", "--at=2024-02-30"]))?
+            .failure()
+            .is_some());
+        Ok(())
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    fn testing_1_main() -> Result<(), Error> {
+        use time::format_description::well_known::Rfc3339;
+        use time::OffsetDateTime;
+
+        let datetime = |val: &str| OffsetDateTime::parse(val, &Rfc3339).unwrap();
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--at".infer::<OffsetDateTime>())?;
+        parser.add_opt("--on".infer::<Formatted<OffsetDateTime, Simple>>())?;
+        parser.parse(Args::from([
+            "app",
+            "--at=2024-02-29T08:00:00+08:00",
+            "--on=20240301 12:30 +08",
+        ]))?;
+
+        assert_eq!(
+            parser.find_val::<OffsetDateTime>("--at")?,
+            &datetime("2024-02-29T08:00:00+08:00")
+        );
+        assert_eq!(
+            **parser.find_val::<Formatted<OffsetDateTime, Simple>>("--on")?,
+            datetime("2024-03-01T12:30:00+08:00")
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::any::TypeId;
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::io::Stdin;
@@ -16,6 +17,8 @@ use crate::opt::Main;
 use crate::opt::MutOpt;
 use crate::opt::Pos;
 use crate::opt::Style;
+use crate::opt::CMD_WILDCARD;
+use crate::parser::UserStyle;
 use crate::trace;
 use crate::typeid;
 use crate::value::ValInitializer;
@@ -23,6 +26,8 @@ use crate::value::ValValidator;
 use crate::Error;
 
 use super::AnyValue;
+use super::Assign;
+use super::Passthrough;
 use super::RawValParser;
 use super::Stop;
 use super::ValStorer;
@@ -188,6 +193,25 @@ impl Infer for Cmd {
         typeid::<Self>()
     }
 
+    /// For wildcard [`Cmd`](crate::opt::CMD_WILDCARD), the raw value is the command name,
+    /// add a storer always set value to `true`.
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        if cfg.name() == Some(CMD_WILDCARD) && !cfg.has_storer() {
+            cfg.set_storer(ValStorer::new(Box::new(
+                |_: Option<&OsStr>, _: &Ctx, act: &Action, handler: &mut AnyValue| {
+                    act.store1(Some(true), handler);
+                    Ok(())
+                },
+            )));
+        }
+        Ok(())
+    }
+
     fn infer_map(val: Self::Val) -> Self {
         Cmd::new(val)
     }
@@ -438,6 +462,131 @@ impl Infer for Stop {
     }
 }
 
+impl Infer for Passthrough {
+    type Val = Passthrough;
+
+    fn infer_act() -> Action {
+        Action::Set
+    }
+
+    /// Default value is empty, so the option has value even if `--` not present.
+    fn infer_initializer() -> Option<ValInitializer> {
+        Some(ValInitializer::new_value(Passthrough::default()))
+    }
+
+    fn infer_style() -> Vec<Style> {
+        vec![Style::Boolean]
+    }
+
+    fn infer_ignore_alias() -> bool {
+        true
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    /// For type Passthrough, swap the name and default alias(`--`) when build configuration.
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        Stop::infer_tweak_info(cfg)
+    }
+}
+
+impl Infer for Assign {
+    type Val = Assign;
+
+    fn infer_style() -> Vec<Style> {
+        vec![Style::Pos]
+    }
+
+    fn infer_index() -> Option<Index> {
+        Some(Index::anywhere())
+    }
+
+    fn infer_ignore_name() -> bool {
+        true
+    }
+
+    fn infer_ignore_alias() -> bool {
+        true
+    }
+
+    fn infer_ignore_index() -> bool {
+        false
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    /// For type Assign, only allow the [`Assign`](UserStyle::Assign) style.
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        if !cfg.has_user_style() {
+            cfg.set_user_style(vec![UserStyle::Assign]);
+        }
+        Ok(())
+    }
+}
+
+/// Collect the `name=value` NOAs into map, the later one overrides the former with the same name.
+impl Infer for BTreeMap<String, String> {
+    type Val = Assign;
+
+    fn infer_style() -> Vec<Style> {
+        Assign::infer_style()
+    }
+
+    fn infer_index() -> Option<Index> {
+        Assign::infer_index()
+    }
+
+    fn infer_ignore_name() -> bool {
+        Assign::infer_ignore_name()
+    }
+
+    fn infer_ignore_alias() -> bool {
+        Assign::infer_ignore_alias()
+    }
+
+    fn infer_ignore_index() -> bool {
+        Assign::infer_ignore_index()
+    }
+
+    /// The map is empty if no assignment present.
+    fn infer_initializer() -> Option<ValInitializer> {
+        Some(ValInitializer::new_values(Vec::<Assign>::new()))
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        BTreeMap::from([val.into_pair()])
+    }
+
+    fn infer_mutable(&mut self, val: Self::Val) {
+        let (name, value) = val.into_pair();
+
+        self.insert(name, value);
+    }
+
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        Assign::infer_tweak_info(cfg)
+    }
+}
+
 macro_rules! impl_infer_for {
     ($name:path) => {
         impl Infer for $name {
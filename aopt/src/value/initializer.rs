@@ -1,9 +1,13 @@
+use std::ffi::OsString;
 use std::fmt::Debug;
 
+use crate::ctx::Ctx;
+use crate::ctx::InnerCtx;
 use crate::map::ErasedTy;
 use crate::Error;
 
 use super::AnyValue;
+use super::RawValParser;
 
 #[cfg(feature = "sync")]
 mod __initializer {
@@ -91,6 +95,59 @@ impl ValInitializer {
         }))
     }
 
+    /// Read the value from environment variable `var` when initializing the option,
+    /// the value is parsed by [`RawValParser`]. Do nothing if the variable is not present.
+    ///
+    /// Unlike [`ValueSources::with_env`](crate::set::ValueSources::with_env) which fills the value after parsing,
+    /// the value is used as default value of option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// std::env::set_var("VAL_INITIALIZER_JOBS", "8");
+    /// parser
+    ///     .add_opt("--jobs=i")?
+    ///     .set_initializer(ValInitializer::new_env::<i64>("VAL_INITIALIZER_JOBS"));
+    /// parser
+    ///     .add_opt("--name=s")?
+    ///     .set_initializer(ValInitializer::new_env::<String>("VAL_INITIALIZER_NOT_EXIST"));
+    /// parser.parse(Args::from(["app"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+    /// assert!(parser.find_val::<String>("--name").is_err());
+    ///
+    /// parser.parse(Args::from(["app", "--jobs=2"]))?;
+    /// assert_eq!(parser.find_val::<i64>("--jobs")?, &2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_env<U: RawValParser + ErasedTy>(var: impl Into<OsString>) -> Self {
+        let var = var.into();
+
+        Self(Box::new(move |erased_val| {
+            if let Some(val) = std::env::var_os(&var) {
+                let ctx = Ctx::default()
+                    .with_inner_ctx(InnerCtx::default().with_name(Some(var.to_string_lossy())));
+                let val = U::parse(Some(&val), &ctx).map_err(|e| {
+                    e.into().cause(crate::raise_error!(
+                        "can not initialize value from environment variable `{}`",
+                        var.to_string_lossy()
+                    ))
+                })?;
+
+                erased_val.set(vec![val]);
+            }
+            Ok(())
+        }))
+    }
+
     /// Default value initializer, do nothing.
     pub fn fallback() -> Self {
         Self(Box::new(|_| Ok(())))
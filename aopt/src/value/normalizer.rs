@@ -0,0 +1,202 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use crate::raise_failure;
+use crate::str::display_of_osstr;
+use crate::Error;
+
+/// A step of [`Normalizer`] applied to the raw value.
+#[derive(Debug, Clone, Copy)]
+pub enum Normalize {
+    /// Remove the leading and trailing whitespace.
+    Trim,
+
+    /// Convert the value to lowercase.
+    Lowercase,
+
+    /// Convert the value to uppercase.
+    Uppercase,
+
+    /// Convert the path to absolute form with all intermediate components normalized,
+    /// see [`canonicalize`](std::fs::canonicalize).
+    Canonicalize,
+
+    /// Normalize the value with custom function.
+    Custom(fn(&OsStr) -> Result<OsString, Error>),
+}
+
+impl Normalize {
+    pub fn apply(&self, raw: &OsStr) -> Result<OsString, Error> {
+        let to_str = || {
+            raw.to_str().ok_or_else(|| {
+                raise_failure!(
+                    "can not normalize non UTF-8 value `{}`",
+                    display_of_osstr(Some(raw))
+                )
+            })
+        };
+
+        match self {
+            Normalize::Trim => Ok(OsString::from(to_str()?.trim())),
+            Normalize::Lowercase => Ok(OsString::from(to_str()?.to_lowercase())),
+            Normalize::Uppercase => Ok(OsString::from(to_str()?.to_uppercase())),
+            Normalize::Canonicalize => {
+                std::fs::canonicalize(raw).map(OsString::from).map_err(|e| {
+                    raise_failure!(
+                        "can not canonicalize path `{}`: {}",
+                        display_of_osstr(Some(raw)),
+                        e
+                    )
+                })
+            }
+            Normalize::Custom(func) => func(raw),
+        }
+    }
+}
+
+/// The normalizer applied to the raw value before the handler and
+/// [`ValStorer`](crate::value::ValStorer) invoked, so they always see the canonical value.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--mode=s")?
+///     .set_normalizer(Normalizer::new([Normalize::Trim, Normalize::Lowercase]))
+///     .set_storer(ValStorer::new_validator::<String>(ValValidator::contains2(
+///         vec!["fast", "slow"],
+///     )));
+///
+/// parser.parse(Args::from(["app", "--mode", " FAST "]))?;
+/// assert_eq!(parser.find_val::<String>("--mode")?, "fast");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Normalizer {
+    steps: Vec<Normalize>,
+}
+
+impl Normalizer {
+    pub fn new(steps: impl IntoIterator<Item = Normalize>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+        }
+    }
+
+    pub fn with_step(mut self, step: Normalize) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn add_step(&mut self, step: Normalize) -> &mut Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(&self) -> &[Normalize] {
+        &self.steps
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Apply the steps in order to the raw value.
+    pub fn normalize(&self, raw: &OsStr) -> Result<OsString, Error> {
+        let mut val = raw.to_os_string();
+
+        for step in self.steps.iter() {
+            val = step.apply(&val)?;
+        }
+        Ok(val)
+    }
+}
+
+impl From<Normalize> for Normalizer {
+    fn from(value: Normalize) -> Self {
+        Self::new([value])
+    }
+}
+
+impl From<Vec<Normalize>> for Normalizer {
+    fn from(value: Vec<Normalize>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+    use std::ffi::OsString;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn testing_normalizer() {
+        assert!(testing_normalizer_main().is_ok());
+    }
+
+    fn testing_normalizer_main() -> Result<(), Error> {
+        let normalizer = Normalizer::new([Normalize::Trim, Normalize::Uppercase]);
+
+        assert_eq!(normalizer.normalize(OsStr::new(" info\t"))?, "INFO");
+        assert_eq!(
+            Normalizer::from(Normalize::Custom(|v| Ok(OsString::from(
+                v.len().to_string()
+            ))))
+            .normalize(OsStr::new("four"))?,
+            "4"
+        );
+
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--level=s")?
+            .set_normalizer(vec![Normalize::Trim, Normalize::Lowercase]);
+        parser
+            .add_opt("--path=s")?
+            .set_normalizer(Normalize::Canonicalize);
+        // the handler see the normalized value
+        let tag = parser
+            .add_opt("--tag=s")?
+            .set_normalizer(Normalize::Trim)
+            .run()?;
+
+        parser
+            .entry(tag)?
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                assert_eq!(ctx.arg()?.map(|v| v.as_ref()), Some(OsStr::new("v1")));
+                Ok(Some(ctx.value::<String>()?))
+            });
+
+        parser.parse(Args::from([
+            "app",
+            "--level",
+            " DEBUG ",
+            "--path=.",
+            "--tag= v1",
+        ]))?;
+        assert_eq!(parser.find_val::<String>("--level")?, "debug");
+        assert_eq!(
+            OsStr::new(parser.find_val::<String>("--path")?),
+            std::fs::canonicalize(".").unwrap().as_os_str()
+        );
+        assert_eq!(parser.find_val::<String>("--tag")?, "v1");
+
+        // failed normalization is a failure of option
+        parser.reset()?;
+        let ret = parser.parse(Args::from(["app", "--path=/not/exist/path"]))?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+}
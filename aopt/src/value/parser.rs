@@ -1,9 +1,12 @@
+use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::io::Stdin;
 use std::path::PathBuf;
 
 use crate::ctx::Ctx;
+use crate::value::Assign;
+use crate::value::Passthrough;
 use crate::value::Stop;
 use crate::Error;
 
@@ -28,6 +31,459 @@ pub fn raw2str(raw: Option<&OsStr>) -> Result<&str, Error> {
         .ok_or_else(|| Error::sp_rawval(raw, "can not convert OsStr to str"))
 }
 
+/// The decoding policy used when converting raw value to string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Decoding {
+    /// Return an error if the raw value is not valid UTF-8.
+    #[default]
+    Strict,
+
+    /// Replace the invalid UTF-8 sequences with [`U+FFFD`](std::char::REPLACEMENT_CHARACTER).
+    Lossy,
+
+    /// Skip the UTF-8 validation, the parser should get the value with [`raw2bytes`].
+    /// The string based parsers fallback to lossy conversion in this mode.
+    Bytes,
+}
+
+impl Decoding {
+    pub fn is_strict(&self) -> bool {
+        matches!(self, Self::Strict)
+    }
+
+    pub fn is_lossy(&self) -> bool {
+        matches!(self, Self::Lossy)
+    }
+
+    pub fn is_bytes(&self) -> bool {
+        matches!(self, Self::Bytes)
+    }
+
+    /// Convert the raw value to string according to the decoding policy.
+    pub fn decode<'a>(&self, raw: Option<&'a OsStr>) -> Result<Cow<'a, str>, Error> {
+        match self {
+            Decoding::Strict => raw2str(raw).map(Cow::Borrowed),
+            Decoding::Lossy | Decoding::Bytes => Ok(ok_or_else(raw)?.to_string_lossy()),
+        }
+    }
+}
+
+/// The locale format of numbers accepted by the integer and float value parsers.
+///
+/// The value is normalized only if it is well formed in the locale,
+/// i.e. the group separators split the integer part into groups of three digits.
+/// Otherwise the parser falls back to plain parsing.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::value::NumLocale;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().set_num_locale(NumLocale::DotGroup);
+/// parser.add_opt("--size=i")?;
+/// parser.add_opt("--ratio=f")?;
+/// parser.parse(Args::from(["app", "--size=-1.000.000", "--ratio", "1.000,5"]))?;
+///
+/// assert_eq!(parser.find_val::<i64>("--size")?, &-1_000_000);
+/// assert_eq!(parser.find_val::<f64>("--ratio")?, &1000.5);
+///
+/// parser.parse(Args::from(["app", "--size=42", "--ratio", "0.5"]))?;
+///
+/// assert_eq!(parser.find_val::<i64>("--size")?, &42);
+/// assert_eq!(parser.find_val::<f64>("--ratio")?, &0.5);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NumLocale {
+    /// Parse the number using [`FromStr`](std::str::FromStr) only.
+    #[default]
+    Plain,
+
+    /// Number such as `1,000.5`.
+    CommaGroup,
+
+    /// Number such as `1.000,5`.
+    DotGroup,
+
+    /// Number using the given group separator and decimal separator.
+    Custom { group: char, decimal: char },
+}
+
+impl NumLocale {
+    pub fn is_plain(&self) -> bool {
+        matches!(self, Self::Plain)
+    }
+
+    /// Return the group separator and decimal separator.
+    pub fn separators(&self) -> Option<(char, char)> {
+        match self {
+            NumLocale::Plain => None,
+            NumLocale::CommaGroup => Some((',', '.')),
+            NumLocale::DotGroup => Some(('.', ',')),
+            NumLocale::Custom { group, decimal } => Some((*group, *decimal)),
+        }
+    }
+
+    /// Convert the locale formatted number to plain format,
+    /// return `None` if the value is not well formed in the locale.
+    pub fn normalize(&self, val: &str) -> Option<String> {
+        let (group, decimal) = self.separators()?;
+        let (int, frac) = match val.split_once(decimal) {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (val, None),
+        };
+
+        if frac.is_some_and(|v| v.contains([group, decimal])) {
+            return None;
+        }
+
+        let (sign, digits) = match int.strip_prefix(['+', '-']) {
+            Some(digits) => (&int[..1], digits),
+            None => ("", int),
+        };
+        let mut ret = String::from(sign);
+
+        for (i, chunk) in digits.split(group).enumerate() {
+            let valid = if i == 0 {
+                (1..=3).contains(&chunk.len()) || !digits.contains(group)
+            } else {
+                chunk.len() == 3
+            };
+
+            if !valid || !chunk.bytes().all(|v| v.is_ascii_digit()) {
+                return None;
+            }
+            ret.push_str(chunk);
+        }
+        if let Some(frac) = frac {
+            ret.push('.');
+            ret.push_str(frac);
+        }
+        Some(ret)
+    }
+}
+
+/// The extra syntax accepted by the float value parsers.
+///
+/// By default only the plain number such as `1.5`, `-2` or `1e3` is accepted,
+/// the special values and the underscores are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--ratio=f")?;
+/// assert!(!parser.parse(Args::from(["app", "--ratio=inf"]))?.status());
+///
+/// parser.policy_mut().set_float_syntax(FloatSyntax::relaxed());
+/// parser.parse(Args::from(["app", "--ratio=1_000.5"]))?;
+/// assert_eq!(parser.find_val::<f64>("--ratio")?, &1000.5);
+///
+/// parser.parse(Args::from(["app", "--ratio=-Inf"]))?;
+/// assert_eq!(parser.find_val::<f64>("--ratio")?, &f64::NEG_INFINITY);
+///
+/// parser.parse(Args::from(["app", "--ratio=NaN"]))?;
+/// assert!(parser.find_val::<f64>("--ratio")?.is_nan());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FloatSyntax {
+    special: bool,
+
+    underscore: bool,
+}
+
+impl FloatSyntax {
+    /// Accept both the special values and the underscores.
+    pub fn relaxed() -> Self {
+        Self {
+            special: true,
+            underscore: true,
+        }
+    }
+
+    /// Accept the special values `inf`, `infinity` and `nan`, case insensitive.
+    pub fn with_special(mut self, special: bool) -> Self {
+        self.special = special;
+        self
+    }
+
+    /// Accept the underscores between digits, such as `1_000.5`.
+    pub fn with_underscore(mut self, underscore: bool) -> Self {
+        self.underscore = underscore;
+        self
+    }
+
+    pub fn special(&self) -> bool {
+        self.special
+    }
+
+    pub fn underscore(&self) -> bool {
+        self.underscore
+    }
+
+    /// Return true if the value is a special value such as `-inf` or `NaN`.
+    pub fn is_special(val: &str) -> bool {
+        let val = val.strip_prefix(['+', '-']).unwrap_or(val);
+
+        ["inf", "infinity", "nan"]
+            .iter()
+            .any(|v| val.eq_ignore_ascii_case(v))
+    }
+
+    /// Remove the underscores between digits,
+    /// return `None` if the value has no underscore or any underscore is not between digits.
+    pub fn strip_underscore(val: &str) -> Option<String> {
+        let bytes = val.as_bytes();
+
+        if !val.contains('_') {
+            return None;
+        }
+        for (idx, ch) in bytes.iter().enumerate() {
+            if *ch == b'_' {
+                let prev = idx.checked_sub(1).and_then(|v| bytes.get(v));
+                let next = bytes.get(idx + 1);
+
+                if !prev.is_some_and(u8::is_ascii_digit) || !next.is_some_and(u8::is_ascii_digit) {
+                    return None;
+                }
+            }
+        }
+        Some(val.replace('_', ""))
+    }
+}
+
+/// The literals accepted by the `bool` value parser in addition to `true` and `false`.
+///
+/// The literals are fixed strings, they are not affected by the locale of system.
+/// The `true` and `false` are always accepted, they are passed to the parser by
+/// [`Boolean`](crate::opt::Style::Boolean) style options such as `--debug`.
+/// The literals are useful for options accept `bool` argument,
+/// such as positional of `Pos<bool>` or option using [`set_value_type::<bool>`](crate::set::SetCommit::set_value_type).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::value::BoolLiterals;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.policy_mut().set_bool_literals(BoolLiterals::common());
+/// parser.add_opt("--color=s")?.set_value_type::<bool>();
+/// parser.add_opt("--pager=s")?.set_value_type::<bool>();
+/// parser.add_opt("--debug=b")?;
+/// parser.add_opt("cache=p@1")?.set_pos_type::<bool>();
+/// parser.parse(Args::from(["app", "--color=on", "--pager", "No", "--debug", "1"]))?;
+///
+/// assert_eq!(parser.find_val::<bool>("--color")?, &true);
+/// assert_eq!(parser.find_val::<bool>("--pager")?, &false);
+/// assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+/// assert_eq!(parser.find_val::<bool>("cache")?, &true);
+///
+/// parser.reset()?;
+/// parser.policy_mut().set_bool_literals(BoolLiterals::default());
+/// assert!(parser.parse(Args::from(["app", "--color=on"]))?.failure().is_some());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BoolLiterals {
+    truthy: Vec<String>,
+
+    falsey: Vec<String>,
+
+    ignore_case: bool,
+}
+
+impl BoolLiterals {
+    pub fn new<S: Into<String>>(
+        truthy: impl IntoIterator<Item = S>,
+        falsey: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            truthy: truthy.into_iter().map(Into::into).collect(),
+            falsey: falsey.into_iter().map(Into::into).collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// The literals `yes`/`no`, `on`/`off`, `1`/`0` and `y`/`n`, case insensitive.
+    pub fn common() -> Self {
+        Self::new(["yes", "on", "1", "y"], ["no", "off", "0", "n"]).with_ignore_case(true)
+    }
+
+    /// Add a pair of literals, such as `yes` and `no`.
+    pub fn with_literal(mut self, truthy: impl Into<String>, falsey: impl Into<String>) -> Self {
+        self.add_literal(truthy, falsey);
+        self
+    }
+
+    /// Compare the literals ignore ASCII case.
+    pub fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    pub fn add_literal(
+        &mut self,
+        truthy: impl Into<String>,
+        falsey: impl Into<String>,
+    ) -> &mut Self {
+        self.truthy.push(truthy.into());
+        self.falsey.push(falsey.into());
+        self
+    }
+
+    pub fn set_ignore_case(&mut self, ignore_case: bool) -> &mut Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    pub fn truthy(&self) -> &[String] {
+        &self.truthy
+    }
+
+    pub fn falsey(&self) -> &[String] {
+        &self.falsey
+    }
+
+    pub fn ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    fn matches(&self, literals: &[String], val: &str) -> bool {
+        literals.iter().any(|v| {
+            if self.ignore_case {
+                v.eq_ignore_ascii_case(val)
+            } else {
+                v == val
+            }
+        })
+    }
+
+    /// Parse the value into `bool`, return `None` if the value is not a literal.
+    pub fn parse(&self, val: &str) -> Option<bool> {
+        if val == crate::opt::BOOL_TRUE || self.matches(&self.truthy, val) {
+            Some(true)
+        } else if val == crate::opt::BOOL_FALSE || self.matches(&self.falsey, val) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// The description of accepted literals used in error message.
+    pub fn describe(&self) -> String {
+        let join = |default: &str, literals: &[String]| {
+            std::iter::once(default)
+                .chain(literals.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+
+        format!(
+            "{} or {}",
+            join(crate::opt::BOOL_TRUE, &self.truthy),
+            join(crate::opt::BOOL_FALSE, &self.falsey)
+        )
+    }
+}
+
+/// Convert raw value to string using the [`Decoding`] of [`Ctx`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::value::raw2cow;
+/// # use aopt::value::Decoding;
+/// # use aopt::Error;
+/// # use std::ffi::OsStr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let raw = Some(OsStr::new("foo"));
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+///
+/// assert_eq!(raw2cow(raw, &ctx)?, "foo");
+///
+/// let ctx = ctx.with_decoding(Decoding::Lossy);
+///
+/// assert_eq!(raw2cow(raw, &ctx)?, "foo");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn raw2cow<'a>(raw: Option<&'a OsStr>, ctx: &Ctx) -> Result<Cow<'a, str>, Error> {
+    ctx.decoding().decode(raw)
+}
+
+/// Get the raw value as bytes without any UTF-8 validation.
+///
+/// The bytes are in the platform's encoding of [`OsStr`],
+/// see [`OsStr::as_encoded_bytes`].
+pub fn raw2bytes(raw: Option<&OsStr>) -> Result<&[u8], Error> {
+    Ok(ok_or_else(raw)?.as_encoded_bytes())
+}
+
+/// Parse the raw value into `T` using the [`FromStr`](std::str::FromStr) implementation of `T`.
+///
+/// It is useful for the types which implemented [`FromStr`](std::str::FromStr) but not [`RawValParser`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::value::raw2fromstr;
+/// # use aopt::Error;
+/// # use std::ffi::OsStr;
+/// # use std::net::Ipv4Addr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+/// let addr: Ipv4Addr = raw2fromstr(Some(OsStr::new("127.0.0.1")), &ctx)?;
+///
+/// assert_eq!(addr, Ipv4Addr::LOCALHOST);
+/// assert!(raw2fromstr::<Ipv4Addr>(Some(OsStr::new("localhost")), &ctx).is_err());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn raw2fromstr<T>(raw: Option<&OsStr>, ctx: &Ctx) -> Result<T, Error>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let val = raw2cow(raw, ctx)?;
+    let uid = ctx.uid()?;
+
+    val.parse::<T>().map_err(|e| {
+        Error::sp_rawval(
+            raw,
+            format!("not a valid value of type {}", std::any::type_name::<T>()),
+        )
+        .with_uid(uid)
+        .cause_by(Error::raise_error(e.to_string()))
+    })
+}
+
 impl RawValParser for () {
     type Error = Error;
 
@@ -42,16 +498,19 @@ macro_rules! impl_raw_val_parser {
             type Error = Error;
 
             fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<$int, Self::Error> {
-                let val = $crate::value::parser::raw2str(raw)?;
+                let val = $crate::value::parser::raw2cow(raw, ctx)?;
                 let uid = ctx.uid()?;
 
+                // fallback to plain parsing if the value is not in locale format
+                if let Some(val) = ctx
+                    .num_locale()
+                    .normalize(&val)
+                    .and_then(|v| v.parse::<$int>().ok())
+                {
+                    return Ok(val);
+                }
                 val.parse::<$int>().map_err(|e| {
-                    $crate::err::Error::sp_rawval(
-                        raw,
-                        format!("not a valid value of type {}", stringify!($int)),
-                    )
-                    .with_uid(uid)
-                    .cause_by(e.into())
+                    $crate::err::Error::sp_parse_val(raw, stringify!($int), e).with_uid(uid)
                 })
             }
         }
@@ -68,16 +527,58 @@ impl_raw_val_parser!(u16);
 impl_raw_val_parser!(u32);
 impl_raw_val_parser!(u64);
 impl_raw_val_parser!(u128);
-impl_raw_val_parser!(f32);
-impl_raw_val_parser!(f64);
 impl_raw_val_parser!(isize);
 impl_raw_val_parser!(usize);
 
+macro_rules! impl_float_parser {
+    ($float:ty) => {
+        impl $crate::value::parser::RawValParser for $float {
+            type Error = Error;
+
+            fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<$float, Self::Error> {
+                let val = $crate::value::parser::raw2cow(raw, ctx)?;
+                let uid = ctx.uid()?;
+                let syntax = ctx.float_syntax();
+
+                if !syntax.special() && FloatSyntax::is_special(&val) {
+                    return Err($crate::err::Error::sp_rawval(
+                        raw,
+                        "special float value is not accepted, see FloatSyntax",
+                    )
+                    .with_uid(uid));
+                }
+
+                let val = match syntax.underscore() {
+                    true => FloatSyntax::strip_underscore(&val)
+                        .map(Cow::Owned)
+                        .unwrap_or(val),
+                    false => val,
+                };
+
+                // fallback to plain parsing if the value is not in locale format
+                if let Some(val) = ctx
+                    .num_locale()
+                    .normalize(&val)
+                    .and_then(|v| v.parse::<$float>().ok())
+                {
+                    return Ok(val);
+                }
+                val.parse::<$float>().map_err(|e| {
+                    $crate::err::Error::sp_parse_val(raw, stringify!($float), e).with_uid(uid)
+                })
+            }
+        }
+    };
+}
+
+impl_float_parser!(f32);
+impl_float_parser!(f64);
+
 impl RawValParser for String {
     type Error = Error;
 
-    fn parse(raw: Option<&OsStr>, _ctx: &Ctx) -> Result<Self, Self::Error> {
-        Ok(raw2str(raw)?.to_string())
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        Ok(raw2cow(raw, ctx)?.into_owned())
     }
 }
 
@@ -95,12 +596,15 @@ impl RawValParser for bool {
     type Error = Error;
 
     fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
-        let val = raw2str(raw)?;
+        let val = raw2cow(raw, ctx)?;
+        let literals = ctx.bool_literals();
 
-        match val {
-            crate::opt::BOOL_TRUE => Ok(true),
-            crate::opt::BOOL_FALSE => Ok(false),
-            _ => Err(Error::sp_rawval(raw, "except true or false").with_uid(ctx.uid()?)),
+        match literals.parse(&val) {
+            Some(val) => Ok(val),
+            None => Err(
+                Error::sp_rawval(raw, format!("except {}", literals.describe()))
+                    .with_uid(ctx.uid()?),
+            ),
         }
     }
 }
@@ -173,3 +677,223 @@ impl RawValParser for Stop {
         }
     }
 }
+
+impl RawValParser for Passthrough {
+    type Error = Error;
+
+    /// Take the arguments after current option and stop the policy.
+    fn parse(_: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        let args = ctx.remainder()?.iter().map(|v| v.to_os_string());
+
+        ctx.set_policy_act(crate::parser::Action::Stop);
+        Ok(Passthrough::new(args.collect()))
+    }
+}
+
+impl RawValParser for Assign {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        match raw.and_then(Assign::split) {
+            Some((name, value)) => Ok(Assign::new(name, value)),
+            None => {
+                Err(Error::sp_rawval(raw, "except `name=value` for Assign").with_uid(ctx.uid()?))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod test {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    use crate::prelude::*;
+    use crate::value::raw2bytes;
+    use crate::Error;
+
+    #[test]
+    fn testing_decoding() {
+        assert!(testing_decoding_main().is_ok());
+    }
+
+    fn testing_decoding_main() -> Result<(), Error> {
+        let name = OsStr::from_bytes(b"fo\xffo");
+        let raw = Some(name);
+
+        assert!(Decoding::Strict.decode(raw).is_err());
+        assert_eq!(Decoding::Lossy.decode(raw)?, "fo\u{FFFD}o");
+        assert_eq!(Decoding::Bytes.decode(raw)?, "fo\u{FFFD}o");
+        assert_eq!(raw2bytes(raw)?, b"fo\xffo");
+
+        let args = || Args::from([OsStr::new("app"), OsStr::new("-n"), name]);
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("-n=s")?;
+        assert!(!parser.parse(args())?.status());
+
+        parser.reset()?;
+        parser.policy_mut().set_decoding(Decoding::Lossy);
+        assert!(parser.parse(args())?.status());
+        assert_eq!(parser.find_val::<String>("-n")?, "fo\u{FFFD}o");
+
+        Ok(())
+    }
+
+    #[test]
+    fn testing_num_locale() {
+        assert!(testing_num_locale_main().is_ok());
+    }
+
+    fn testing_num_locale_main() -> Result<(), Error> {
+        let custom = NumLocale::Custom {
+            group: '\'',
+            decimal: '.',
+        };
+
+        assert_eq!(NumLocale::Plain.normalize("1,000"), None);
+        assert_eq!(
+            NumLocale::CommaGroup.normalize("+1,000.5").as_deref(),
+            Some("+1000.5")
+        );
+        assert_eq!(NumLocale::CommaGroup.normalize("10,00"), None);
+        assert_eq!(
+            NumLocale::DotGroup.normalize("12.345,6").as_deref(),
+            Some("12345.6")
+        );
+        assert_eq!(NumLocale::DotGroup.normalize("1.000,5,0"), None);
+        assert_eq!(custom.normalize("1'234'567").as_deref(), Some("1234567"));
+
+        let mut parser = AFwdParser::default();
+
+        parser.policy_mut().set_num_locale(NumLocale::CommaGroup);
+        parser.add_opt("--int=u")?;
+        parser.add_opt("--float=f")?;
+
+        parser.parse(Args::from(["app", "--int=1,024", "--float=-2,048.25"]))?;
+        assert_eq!(parser.find_val::<u64>("--int")?, &1024);
+        assert_eq!(parser.find_val::<f64>("--float")?, &-2048.25);
+
+        // fallback to plain parsing
+        parser.reset()?;
+        parser.parse(Args::from(["app", "--int=1024", "--float=1e3"]))?;
+        assert_eq!(parser.find_val::<u64>("--int")?, &1024);
+        assert_eq!(parser.find_val::<f64>("--float")?, &1000.0);
+
+        parser.reset()?;
+        assert!(!parser.parse(Args::from(["app", "--int=1,02"]))?.status());
+
+        parser.reset()?;
+        parser.policy_mut().set_num_locale(NumLocale::Plain);
+        assert!(!parser.parse(Args::from(["app", "--int=1,024"]))?.status());
+        Ok(())
+    }
+
+    #[test]
+    fn testing_float_syntax() {
+        assert!(testing_float_syntax_main().is_ok());
+    }
+
+    fn testing_float_syntax_main() -> Result<(), Error> {
+        assert!(FloatSyntax::is_special("-Infinity"));
+        assert!(FloatSyntax::is_special("+nan"));
+        assert!(!FloatSyntax::is_special("1e3"));
+        assert_eq!(
+            FloatSyntax::strip_underscore("-1_000.000_1").as_deref(),
+            Some("-1000.0001")
+        );
+        assert_eq!(FloatSyntax::strip_underscore("1000.5"), None);
+        assert_eq!(FloatSyntax::strip_underscore("_1"), None);
+        assert_eq!(FloatSyntax::strip_underscore("1__0"), None);
+        assert_eq!(FloatSyntax::strip_underscore("1_.5"), None);
+        assert_eq!(FloatSyntax::strip_underscore("1e_3"), None);
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--float=f")?;
+        parser.add_opt("--small=s")?.set_value_type::<f32>();
+
+        let ret = parser.parse(Args::from(["app", "--float=1_000"]))?;
+        let error = ret.failure().unwrap().find_kind(ErrorKind::RawValParse);
+
+        assert_eq!(error.and_then(|v| v.token()), Some("1_000"));
+        assert_eq!(
+            error.and_then(|v| v.caused_by()).and_then(|v| v.desp()),
+            Some("invalid float literal")
+        );
+
+        parser.reset()?;
+        let ret = parser.parse(Args::from(["app", "--small=NaN"]))?;
+        let error = ret.failure().unwrap().find_kind(ErrorKind::RawValParse);
+
+        assert_eq!(error.and_then(|v| v.token()), Some("NaN"));
+
+        parser.reset()?;
+        parser
+            .policy_mut()
+            .set_float_syntax(FloatSyntax::default().with_underscore(true));
+        parser.parse(Args::from(["app", "--float=1_000", "--small=-0.5"]))?;
+        assert_eq!(parser.find_val::<f64>("--float")?, &1000.0);
+        assert_eq!(parser.find_val::<f32>("--small")?, &-0.5);
+        assert!(!parser.parse(Args::from(["app", "--float=inf"]))?.status());
+
+        // underscores work together with the locale format
+        parser.reset()?;
+        parser
+            .policy_mut()
+            .set_float_syntax(FloatSyntax::relaxed())
+            .set_num_locale(NumLocale::CommaGroup);
+        parser.parse(Args::from(["app", "--float=1,000.000_5", "--small=inf"]))?;
+        assert_eq!(parser.find_val::<f64>("--float")?, &1000.0005);
+        assert_eq!(parser.find_val::<f32>("--small")?, &f32::INFINITY);
+        Ok(())
+    }
+
+    #[test]
+    fn testing_bool_literals() {
+        assert!(testing_bool_literals_main().is_ok());
+    }
+
+    fn testing_bool_literals_main() -> Result<(), Error> {
+        let literals = BoolLiterals::common();
+
+        assert_eq!(literals.parse("true"), Some(true));
+        assert_eq!(literals.parse("ON"), Some(true));
+        assert_eq!(literals.parse("n"), Some(false));
+        assert_eq!(literals.parse("maybe"), None);
+        assert_eq!(
+            BoolLiterals::default().with_literal("Y", "N").parse("y"),
+            None
+        );
+        assert_eq!(BoolLiterals::default().parse("false"), Some(false));
+        assert_eq!(
+            BoolLiterals::default().with_literal("yes", "no").describe(),
+            "true|yes or false|no"
+        );
+
+        let mut parser = APreParser::default();
+
+        parser.enable_combined();
+        parser.policy_mut().set_bool_literals(literals.clone());
+        parser.add_opt("--opt=s")?.set_value_type::<bool>();
+        parser.add_opt("-a=b")?;
+        parser.add_opt("-b=b")?;
+        parser.parse(Args::from(["app", "--opt=off", "-ab"]))?;
+        assert_eq!(parser.find_val::<bool>("--opt")?, &false);
+        assert_eq!(parser.find_val::<bool>("-a")?, &true);
+        assert_eq!(parser.find_val::<bool>("-b")?, &true);
+
+        let mut parser = ADelayParser::default();
+
+        parser.policy_mut().set_bool_literals(literals);
+        parser.add_opt("--opt=s")?.set_value_type::<bool>();
+        parser.add_opt("pos=p@1")?.set_pos_type::<bool>();
+        parser.parse(Args::from(["app", "--opt", "Yes", "n"]))?;
+        assert_eq!(parser.find_val::<bool>("--opt")?, &true);
+        assert_eq!(parser.find_val::<bool>("pos")?, &false);
+        parser.reset()?;
+        assert!(parser.parse(Args::from(["app", "--opt=maybe"])).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+/// The source which supplied the value of option.
+///
+/// The variants are ordered by priority, a value from higher priority source
+/// will not be overwritten by the lower one, i.e. `Cli > Env > Config > Profile > Default`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValueSource {
+    /// The value is set by initializer of option, or the option has no value.
+    #[default]
+    Default,
+
+    /// The value is set by a [`Profile`](crate::set::Profile).
+    Profile,
+
+    /// The value is read from configuration, such as a configuration file.
+    Config,
+
+    /// The value is read from environment variable.
+    Env,
+
+    /// The value is parsed from command line arguments.
+    Cli,
+}
+
+impl ValueSource {
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Default)
+    }
+
+    pub fn is_profile(&self) -> bool {
+        matches!(self, Self::Profile)
+    }
+
+    pub fn is_config(&self) -> bool {
+        matches!(self, Self::Config)
+    }
+
+    pub fn is_env(&self) -> bool {
+        matches!(self, Self::Env)
+    }
+
+    pub fn is_cli(&self) -> bool {
+        matches!(self, Self::Cli)
+    }
+}
+
+impl Display for ValueSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueSource::Default => write!(f, "default"),
+            ValueSource::Profile => write!(f, "profile"),
+            ValueSource::Config => write!(f, "config"),
+            ValueSource::Env => write!(f, "environment"),
+            ValueSource::Cli => write!(f, "command line"),
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use std::ffi::OsStr;
 use std::fmt::Debug;
+use std::fmt::Display;
 
+use crate::args::ArgFile;
 use crate::ctx::Ctx;
 use crate::map::ErasedTy;
 use crate::opt::Action;
+use crate::raise_failure;
 use crate::trace;
 use crate::Error;
 
 use super::AnyValue;
+use super::BoolLiterals;
+use super::Decoding;
 use super::RawValParser;
 use super::ValValidator;
 
@@ -19,29 +24,390 @@ pub type StoreHandler<T> =
 pub type StoreHandler<T> =
     Box<dyn FnMut(Option<&OsStr>, &Ctx, &Action, &mut T) -> Result<(), Error>>;
 
+#[cfg(feature = "sync")]
+pub type ConvertHandler<T, R> = Box<dyn FnMut(T) -> Result<R, String> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type ConvertHandler<T, R> = Box<dyn FnMut(T) -> Result<R, String>>;
+
+#[cfg(feature = "sync")]
+pub type DeferredHandler<T> = Box<dyn Fn(&T) -> Result<bool, Error> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type DeferredHandler<T> = Box<dyn Fn(&T) -> Result<bool, Error>>;
+
 /// [`ValStorer`] perform the value storing action.
-pub struct ValStorer(StoreHandler<AnyValue>);
+///
+/// The [`deferred`](ValStorer::with_deferred) validator runs once after all the values are stored,
+/// the complete value list is available to it.
+pub struct ValStorer {
+    handler: StoreHandler<AnyValue>,
+
+    deferred: Option<DeferredHandler<AnyValue>>,
+
+    value_file: bool,
+
+    dedup: Option<fn(&mut AnyValue)>,
+}
 
 impl Debug for ValStorer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("WriterHandler").field(&"{...}").finish()
+        f.debug_struct("ValStorer")
+            .field("handler", &"{...}")
+            .field("deferred", &self.deferred.is_some())
+            .field("value_file", &self.value_file)
+            .field("dedup", &self.dedup.is_some())
+            .finish()
     }
 }
 
 impl ValStorer {
     pub fn new(handler: StoreHandler<AnyValue>) -> Self {
-        Self(handler)
+        Self {
+            handler,
+            deferred: None,
+            value_file: false,
+            dedup: None,
+        }
     }
 
     pub fn fallback<U: ErasedTy + RawValParser>() -> Self {
-        Self(Self::fallback_handler::<U>())
+        Self::new(Self::fallback_handler::<U>())
+    }
+
+    /// Create a [`ValStorer`] with a deferred validator, see [`with_deferred`](ValStorer::with_deferred).
+    pub fn new_deferred<U: ErasedTy + RawValParser>(validator: ValValidator<Vec<U>>) -> Self {
+        Self::fallback::<U>().with_deferred(validator)
+    }
+
+    /// Add a validator checking all the values of option.
+    ///
+    /// The validator is invoked by [`SetChecker`](crate::set::SetChecker) after all the values stored,
+    /// the option check will return a [`failure`](Error::is_failure) if it return false.
+    /// It is not invoked if the option has no value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// // the sum of all weights must be 100
+    /// parser
+    ///     .add_opt("--weight;-w=u")?
+    ///     .set_action(Action::App)
+    ///     .set_storer(ValStorer::new_deferred(ValValidator::from_fn(|v: &Vec<u64>| {
+    ///         v.iter().sum::<u64>() == 100
+    ///     })));
+    ///
+    /// parser.parse(Args::from(["app", "-w=20", "-w=80"]))?.ok()?;
+    /// assert_eq!(parser.find_vals::<u64>("-w")?, &[20, 80]);
+    ///
+    /// parser.reset()?;
+    /// assert!(!parser.parse(Args::from(["app", "-w=20", "-w=70"]))?.status());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_deferred<U: ErasedTy>(mut self, validator: ValValidator<Vec<U>>) -> Self {
+        self.deferred = Some(Self::deferred_handler(validator));
+        self
+    }
+
+    pub fn set_deferred<U: ErasedTy>(&mut self, validator: ValValidator<Vec<U>>) -> &mut Self {
+        self.deferred = Some(Self::deferred_handler(validator));
+        self
+    }
+
+    pub fn has_deferred(&self) -> bool {
+        self.deferred.is_some()
+    }
+
+    /// Convert the values of type `T` stored by current storer with `f`,
+    /// the option will store the value of type `R`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--name=s")?
+    ///     .set_storer(ValStorer::fallback::<String>().map_value(|v: String| v.len()));
+    ///
+    /// parser.parse(Args::from(["app", "--name", "alice"]))?;
+    /// assert_eq!(parser.find_val::<usize>("--name")?, &5);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    pub fn map_value<T: ErasedTy, R: ErasedTy>(
+        self,
+        mut f: impl FnMut(T) -> R + Send + Sync + 'static,
+    ) -> Self {
+        self.convert(Box::new(move |val| Ok(f(val))))
+    }
+
+    /// Convert the values of type `T` stored by current storer with `f`,
+    /// the option will store the value of type `R`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--name=s")?
+    ///     .set_storer(ValStorer::fallback::<String>().map_value(|v: String| v.len()));
+    ///
+    /// parser.parse(Args::from(["app", "--name", "alice"]))?;
+    /// assert_eq!(parser.find_val::<usize>("--name")?, &5);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "sync"))]
+    pub fn map_value<T: ErasedTy, R: ErasedTy>(self, mut f: impl FnMut(T) -> R + 'static) -> Self {
+        self.convert(Box::new(move |val| Ok(f(val))))
+    }
+
+    /// Same as [`map_value`](ValStorer::map_value), but the conversion may fail,
+    /// the [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure) if `f` return an error.
+    #[cfg(feature = "sync")]
+    pub fn and_then_value<T: ErasedTy, R: ErasedTy, E: Display>(
+        self,
+        mut f: impl FnMut(T) -> Result<R, E> + Send + Sync + 'static,
+    ) -> Self {
+        self.convert(Box::new(move |val| f(val).map_err(|e| e.to_string())))
+    }
+
+    /// Same as [`map_value`](ValStorer::map_value), but the conversion may fail,
+    /// the [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure) if `f` return an error.
+    #[cfg(not(feature = "sync"))]
+    pub fn and_then_value<T: ErasedTy, R: ErasedTy, E: Display>(
+        self,
+        mut f: impl FnMut(T) -> Result<R, E> + 'static,
+    ) -> Self {
+        self.convert(Box::new(move |val| f(val).map_err(|e| e.to_string())))
+    }
+
+    /// Wrap the handler, store the values into a temporary [`AnyValue`] and convert them.
+    fn convert<T: ErasedTy, R: ErasedTy>(mut self, mut f: ConvertHandler<T, R>) -> Self {
+        let mut inner = self.handler;
+
+        self.handler = Box::new(
+            move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let uid = ctx.uid()?;
+                let mut vals = AnyValue::new();
+
+                inner(raw, ctx, &Action::App, &mut vals)?;
+                for val in vals.remove::<T>().unwrap_or_default() {
+                    let val = f(val).map_err(|e| {
+                        raise_failure!("can not convert value `{:?}`: {}", raw, e).with_uid(uid)
+                    })?;
+
+                    trace!("in converting value storer, {:?} -> {:?}", raw, val);
+                    act.store1(Some(val), handler);
+                }
+                Ok(())
+            },
+        );
+        self
+    }
+
+    /// Expand the value begin with `@` into one value per line of the file it referenced,
+    /// such as `--include @list.txt`. Use `@@` to pass a value begin with literal `@`.
+    ///
+    /// The empty lines are ignored, and the file is decoded like [`ArgFile`](crate::args::ArgFile).
+    /// It is useful for the option with [`Action::App`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let path = std::env::temp_dir().join("aopt_value_file_doc.txt");
+    ///
+    /// std::fs::write(&path, "src/lib.rs\nsrc/main.rs\n\n").unwrap();
+    ///
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--include=s")?
+    ///     .set_action(Action::App)
+    ///     .set_storer(ValStorer::fallback::<String>().with_value_file(true));
+    ///
+    /// let file = format!("@{}", path.display());
+    ///
+    /// parser.parse(Args::from(["app", "--include", &file, "--include", "@@home"]))?;
+    /// assert_eq!(
+    ///     parser.find_vals::<String>("--include")?,
+    ///     &["src/lib.rs", "src/main.rs", "@home"]
+    /// );
+    /// # std::fs::remove_file(&path).unwrap();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_value_file(mut self, value_file: bool) -> Self {
+        self.value_file = value_file;
+        self
+    }
+
+    pub fn set_value_file(&mut self, value_file: bool) -> &mut Self {
+        self.value_file = value_file;
+        self
+    }
+
+    pub fn value_file(&self) -> bool {
+        self.value_file
+    }
+
+    /// Collapse the identical values of type `U` into one when storing with [`Action::App`],
+    /// the first occurrence is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--feature=s")?
+    ///     .set_action(Action::App)
+    ///     .set_storer(ValStorer::fallback::<String>().with_dedup::<String>());
+    ///
+    /// parser.parse(Args::from([
+    ///     "app", "--feature", "x", "--feature", "y", "--feature", "x",
+    /// ]))?;
+    /// assert_eq!(parser.find_vals::<String>("--feature")?, &["x", "y"]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_dedup<U: ErasedTy + PartialEq>(mut self) -> Self {
+        self.dedup = Some(Self::dedup_values::<U>);
+        self
+    }
+
+    pub fn set_dedup<U: ErasedTy + PartialEq>(&mut self) -> &mut Self {
+        self.dedup = Some(Self::dedup_values::<U>);
+        self
+    }
+
+    pub fn has_dedup(&self) -> bool {
+        self.dedup.is_some()
+    }
+
+    /// Remove the values equal to a previous value.
+    fn dedup_values<U: ErasedTy + PartialEq>(handler: &mut AnyValue) {
+        if let Ok(vals) = handler.vals_mut::<U>() {
+            let mut idx = 0;
+
+            while idx < vals.len() {
+                if vals[..idx].contains(&vals[idx]) {
+                    vals.remove(idx);
+                } else {
+                    idx += 1;
+                }
+            }
+        }
     }
 
     /// Create a [`ValStorer`] with a value validator.
     /// The [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure)
     /// if value check failed.
     pub fn new_validator<U: ErasedTy + RawValParser>(validator: ValValidator<U>) -> Self {
-        Self(Self::validator(validator))
+        Self::new(Self::validator(validator))
+    }
+
+    /// Create a [`ValStorer`] parsing the value with given [`Decoding`],
+    /// it will override the [`Decoding`] of policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::value::Decoding;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--name=s")?
+    ///     .set_storer(ValStorer::new_decoding::<String>(Decoding::Lossy));
+    ///
+    /// #[cfg(unix)]
+    /// {
+    ///     use std::ffi::OsStr;
+    ///     use std::os::unix::ffi::OsStrExt;
+    ///
+    ///     let name = OsStr::from_bytes(b"fo\xffo");
+    ///
+    ///     parser.parse(Args::from([OsStr::new("app"), OsStr::new("--name"), name]))?;
+    ///     assert_eq!(parser.find_val::<String>("--name")?, "fo\u{FFFD}o");
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_decoding<U: ErasedTy + RawValParser>(decoding: Decoding) -> Self {
+        Self::new(Self::decoding_handler::<U>(decoding))
+    }
+
+    /// Create a [`ValStorer`] parsing the value with given [`BoolLiterals`],
+    /// it will override the [`BoolLiterals`] of policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::value::BoolLiterals;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt("--wrap=s")?
+    ///     .set_value_type::<bool>()
+    ///     .set_storer(ValStorer::new_bool_literals::<bool>(
+    ///         BoolLiterals::default().with_literal("enable", "disable"),
+    ///     ));
+    /// parser.add_opt("--color=s")?.set_value_type::<bool>();
+    ///
+    /// parser.parse(Args::from(["app", "--wrap", "disable", "--color=true"]))?;
+    /// assert_eq!(parser.find_val::<bool>("--wrap")?, &false);
+    /// assert_eq!(parser.find_val::<bool>("--color")?, &true);
+    ///
+    /// parser.reset()?;
+    /// let ret = parser.parse(Args::from(["app", "--color=disable"]))?;
+    ///
+    /// assert!(ret.failure().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_bool_literals<U: ErasedTy + RawValParser>(literals: BoolLiterals) -> Self {
+        Self::new(Self::bool_literals_handler::<U>(literals))
     }
 
     /// Invoke the inner value store handler on [`AnyValue`].
@@ -51,9 +417,60 @@ impl ValStorer {
         ctx: &Ctx,
         act: &Action,
         arg: &mut AnyValue,
+    ) -> Result<(), Error> {
+        self.invoke_handler(raw, ctx, act, arg)?;
+        if let Some(dedup) = self.dedup.filter(|_| act.is_app()) {
+            dedup(arg);
+        }
+        Ok(())
+    }
+
+    fn invoke_handler(
+        &mut self,
+        raw: Option<&OsStr>,
+        ctx: &Ctx,
+        act: &Action,
+        arg: &mut AnyValue,
     ) -> Result<(), Error> {
         crate::trace!("saving raw value({:?}) for {}", raw, ctx.uid()?);
-        (self.0)(raw, ctx, act, arg)
+        if self.value_file {
+            if let Some(val) = raw.and_then(|v| v.to_str()) {
+                if let Some(val) = val.strip_prefix("@@") {
+                    let val = format!("@{}", val);
+
+                    return (self.handler)(Some(OsStr::new(&val)), ctx, act, arg);
+                } else if let Some(path) = val.strip_prefix('@').filter(|v| !v.is_empty()) {
+                    for val in Self::read_value_file(path)? {
+                        (self.handler)(Some(OsStr::new(&val)), ctx, act, arg)?;
+                    }
+                    return Ok(());
+                }
+            }
+        }
+        (self.handler)(raw, ctx, act, arg)
+    }
+
+    /// Read the values of value file, one value per line.
+    fn read_value_file(path: &str) -> Result<Vec<String>, Error> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| raise_failure!("can not read value file `{}`: {}", path, e))?;
+        let content = ArgFile::default()
+            .decode(&bytes)
+            .map_err(|e| raise_failure!("invalid value file `{}`", path).cause_by(e))?;
+
+        Ok(content
+            .lines()
+            .filter(|v| !v.trim().is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Invoke the deferred validator on [`AnyValue`], return true if no deferred validator.
+    pub fn validate(&self, arg: &AnyValue) -> Result<bool, Error> {
+        match &self.deferred {
+            Some(deferred) => deferred(arg),
+            None => Ok(true),
+        }
     }
 
     pub fn validator<U: ErasedTy + RawValParser>(
@@ -88,6 +505,57 @@ impl ValStorer {
         )
     }
 
+    pub fn deferred_handler<U: ErasedTy>(
+        validator: ValValidator<Vec<U>>,
+    ) -> DeferredHandler<AnyValue> {
+        Box::new(move |handler: &AnyValue| {
+            let ret = handler.vals::<U>().map(|vals| validator.invoke(vals));
+
+            trace!("in deferred validator, checking values -> {:?}", ret);
+            Ok(ret.unwrap_or(true))
+        })
+    }
+
+    pub fn decoding_handler<U: ErasedTy + RawValParser>(
+        decoding: Decoding,
+    ) -> StoreHandler<AnyValue> {
+        Box::new(
+            move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let ctx = ctx.clone().with_decoding(decoding);
+                let val = U::parse(raw, &ctx).map_err(Into::into);
+
+                trace!(
+                    "in decoding value storer({:?}), parsing {:?} -> {:?}",
+                    decoding,
+                    raw,
+                    val
+                );
+                act.store1(Some(val?), handler);
+                Ok(())
+            },
+        )
+    }
+
+    pub fn bool_literals_handler<U: ErasedTy + RawValParser>(
+        literals: BoolLiterals,
+    ) -> StoreHandler<AnyValue> {
+        Box::new(
+            move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let ctx = ctx.clone().with_bool_literals(literals.clone());
+                let val = U::parse(raw, &ctx).map_err(Into::into);
+
+                trace!(
+                    "in bool literals value storer({:?}), parsing {:?} -> {:?}",
+                    literals,
+                    raw,
+                    val
+                );
+                act.store1(Some(val?), handler);
+                Ok(())
+            },
+        )
+    }
+
     pub fn fallback_handler<U: ErasedTy + RawValParser>() -> StoreHandler<AnyValue> {
         Box::new(
             |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
@@ -0,0 +1,73 @@
+use crate::opt::ConfigValue;
+
+use super::RawValParser;
+use super::ValValidator;
+
+/// The value type has a fixed set of variants, such as the enum derived by `CoteVal`.
+///
+/// The [`variants`](ValueEnum::variants) are used as the choices of option,
+/// they are displayed in the help message and used by the completion.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt(ColorChoice::preset())?;
+/// parser
+///     .add_opt("--when".infer::<String>())?
+///     .set_validator(ColorChoice::validator());
+///
+/// assert_eq!(ColorChoice::variants(), ["auto", "always", "never"]);
+/// assert_eq!(
+///     parser.find_opt("--color")?.choices(),
+///     Some(&vec!["auto".to_owned(), "always".to_owned(), "never".to_owned()])
+/// );
+/// assert!(parser.parse(Args::from(["app", "--when=never"]))?.status());
+///
+/// parser.reset()?;
+/// assert!(!parser.parse(Args::from(["app", "--when=rainbow"]))?.status());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub trait ValueEnum: RawValParser {
+    /// The names of variants accepted by the [`RawValParser`].
+    fn variants() -> &'static [&'static str];
+
+    /// Return true if the variants are matched case insensitively.
+    fn ignore_case() -> bool {
+        false
+    }
+
+    /// Return true if the `name` is one of the variants.
+    fn contains(name: &str) -> bool {
+        if Self::ignore_case() {
+            let name = name.to_lowercase();
+
+            Self::variants().iter().any(|v| v.to_lowercase() == name)
+        } else {
+            Self::variants().contains(&name)
+        }
+    }
+
+    /// Set the [`choices`](ConfigValue::choices) of option to the variants if it is not set,
+    /// it can be called in the [`infer_tweak_info`](crate::value::Infer::infer_tweak_info).
+    fn tweak_choices<C: ConfigValue>(cfg: &mut C) {
+        if !cfg.has_choices() {
+            cfg.set_choices(Self::variants().to_vec());
+        }
+    }
+
+    /// Create a validator check if the raw string is one of the variants.
+    fn validator() -> ValValidator<String>
+    where
+        Self: 'static,
+    {
+        ValValidator::from_fn(|val: &String| Self::contains(val))
+    }
+}
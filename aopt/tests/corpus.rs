@@ -0,0 +1,330 @@
+//! Run the regression cases in `tests/corpus/*.toml` against the policies.
+//!
+//! Each file contains an array of `[[case]]` tables:
+//!
+//! ```toml
+//! [[case]]
+//! name = "option with value"            # required, used in report
+//! policies = ["fwd", "pre", "delay"]    # optional, default is all the policies
+//! styles = ["combined"]                 # optional, `combined`, `embedded_plus` or `flag`
+//! options = [
+//!     "--foo=s",                        # create string of option
+//!     { create = "-c=i", alias = ["--count"], action = "app" },
+//!     { create = "files=p@1..", type = "s" },  # value type of positional
+//! ]
+//! args = ["--foo", "bar", "-c=1"]       # arguments without program name
+//! status = true                         # optional, the status of `Return`, default is true
+//! error = false                         # optional, true if `parse` return an error
+//! message = "bar"                       # optional, substring of error or failure message in cause chain
+//! values = { "--foo" = ["bar"], "-c" = ["1"] } # optional, all the values of option
+//! ```
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::path::Path;
+use std::path::PathBuf;
+
+use aopt::prelude::*;
+use aopt::Error;
+use serde::Deserialize;
+
+const POLICIES: [&str; 3] = ["fwd", "pre", "delay"];
+
+#[test]
+fn corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("corpus");
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|v| v.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    let mut failures = vec![];
+    let mut count = 0;
+
+    files.sort();
+    for file in files {
+        let content = std::fs::read_to_string(&file).unwrap();
+        let corpus: Corpus = toml::from_str(&content)
+            .unwrap_or_else(|e| panic!("can not parse {}: {e}", file.display()));
+
+        for case in corpus.case {
+            case.check()
+                .unwrap_or_else(|e| panic!("invalid case in {}: {e}", file.display()));
+
+            for policy in case.policies.iter() {
+                count += 1;
+                if let Err(e) = case.run(policy) {
+                    failures.push(format!(
+                        "{}: case `{}` with policy `{policy}`: {e}",
+                        file.display(),
+                        case.name
+                    ));
+                }
+            }
+        }
+    }
+    assert!(count > 0, "no case found in {}", dir.display());
+    assert!(
+        failures.is_empty(),
+        "{} of {count} cases failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Corpus {
+    #[serde(default)]
+    case: Vec<Case>,
+}
+
+/// The scalar value of arguments and values, converted to string when comparing.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Str(String),
+
+    Int(i64),
+
+    Bool(bool),
+}
+
+impl Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(v) => write!(f, "{v}"),
+            Self::Int(v) => write!(f, "{v}"),
+            Self::Bool(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OptCase {
+    Create(String),
+
+    Table(OptTable),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OptTable {
+    create: String,
+
+    #[serde(default)]
+    alias: Vec<String>,
+
+    action: Option<String>,
+
+    r#type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Case {
+    name: String,
+
+    #[serde(default = "Case::default_policies")]
+    policies: Vec<String>,
+
+    #[serde(default)]
+    styles: Vec<String>,
+
+    #[serde(default)]
+    options: Vec<OptCase>,
+
+    #[serde(default)]
+    args: Vec<Scalar>,
+
+    #[serde(default = "Case::default_status")]
+    status: bool,
+
+    #[serde(default)]
+    error: bool,
+
+    message: Option<String>,
+
+    #[serde(default)]
+    values: BTreeMap<String, Vec<Scalar>>,
+}
+
+impl Case {
+    fn default_policies() -> Vec<String> {
+        POLICIES.map(String::from).to_vec()
+    }
+
+    fn default_status() -> bool {
+        true
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("case must have a name".to_string());
+        }
+        if let Some(policy) = self
+            .policies
+            .iter()
+            .find(|v| !POLICIES.contains(&v.as_str()))
+        {
+            return Err(format!("unknown policy `{policy}`"));
+        }
+        Ok(())
+    }
+
+    fn action(action: &str) -> Result<Action, Error> {
+        Ok(match action {
+            "set" => Action::Set,
+            "once" => Action::SetOnce,
+            "app" => Action::App,
+            "pop" => Action::Pop,
+            "cnt" => Action::Cnt,
+            "clr" => Action::Clr,
+            "null" => Action::Null,
+            action => return Err(aopt::raise_error!("unknown action `{action}`")),
+        })
+    }
+
+    fn run(&self, policy: &str) -> Result<(), String> {
+        match policy {
+            "fwd" => self.run_with(AFwdParser::default()),
+            "pre" => self.run_with(APreParser::default()),
+            "delay" => self.run_with(ADelayParser::default()),
+            _ => unreachable!("policy checked in Case::check"),
+        }
+    }
+
+    fn run_with<P>(&self, mut parser: Parser<'_, P>) -> Result<(), String>
+    where
+        P: Policy<Set = ASet, Ret = Return> + PolicySettings,
+    {
+        self.setup(&mut parser)
+            .map_err(|e| format!("setup failed: {e}"))?;
+
+        let args = Args::from(
+            std::iter::once("app".to_string()).chain(self.args.iter().map(ToString::to_string)),
+        );
+        let ret = match parser.parse(args) {
+            Ok(ret) => ret,
+            Err(e) => {
+                let e: Error = e;
+
+                return if self.error {
+                    self.check_message(&e)
+                } else {
+                    Err(format!("parse failed: {e}"))
+                };
+            }
+        };
+
+        if self.error {
+            return Err("except parse failed, but it succeeded".to_string());
+        }
+        if ret.status() != self.status {
+            return Err(format!(
+                "except status {}, found {}: {:?}",
+                self.status,
+                ret.status(),
+                ret.failure().map(|v| v.to_string())
+            ));
+        }
+        if let Some(failure) = ret.failure() {
+            self.check_message(failure)?;
+        }
+        for (name, expect) in self.values.iter() {
+            let opt = parser
+                .find_opt(name.as_str())
+                .map_err(|e| format!("can not find `{name}`: {e}"))?;
+            let found = Self::values(opt);
+            let expect: Vec<String> = expect.iter().map(ToString::to_string).collect();
+
+            if found != expect {
+                return Err(format!(
+                    "except values of `{name}` is {expect:?}, found {found:?}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn setup<P>(&self, parser: &mut Parser<'_, P>) -> Result<(), Error>
+    where
+        P: Policy<Set = ASet, Ret = Return> + PolicySettings,
+    {
+        for style in self.styles.iter() {
+            match style.as_str() {
+                "combined" => parser.enable_combined(),
+                "embedded_plus" => parser.enable_embedded_plus(),
+                "flag" => parser.enable_flag(),
+                _ => return Err(aopt::raise_error!("unknown style `{style}`")),
+            };
+        }
+        for opt in self.options.iter() {
+            let opt = match opt {
+                OptCase::Create(create) => {
+                    parser.add_opt(create.as_str())?;
+                    continue;
+                }
+                OptCase::Table(opt) => opt,
+            };
+            let mut commit = parser.add_opt(opt.create.as_str())?;
+
+            for alias in opt.alias.iter() {
+                commit = commit.add_alias(alias);
+            }
+            if let Some(action) = &opt.action {
+                commit = commit.set_action(Self::action(action)?);
+            }
+            match opt.r#type.as_deref() {
+                None => commit.run()?,
+                Some("s") => commit.set_pos_type::<String>().run()?,
+                Some("i") => commit.set_pos_type::<i64>().run()?,
+                Some("u") => commit.set_pos_type::<u64>().run()?,
+                Some("f") => commit.set_pos_type::<f64>().run()?,
+                Some(ty) => return Err(aopt::raise_error!("unknown type `{ty}`")),
+            };
+        }
+        Ok(())
+    }
+
+    fn check_message(&self, e: &Error) -> Result<(), String> {
+        match &self.message {
+            Some(message) if !e.chain().any(|v| v.to_string().contains(message.as_str())) => {
+                Err(format!("except message contains `{message}`, found `{e}`"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Return the values of option in string, the value type is not the type of option,
+    /// such as `Pos<String>` has values of `String`, so try the common value types in order.
+    /// The count of [`Action::Cnt`] is saved as `u64`.
+    fn values(opt: &AOpt) -> Vec<String> {
+        fn display<T: ErasedTy + ToString>(opt: &AOpt) -> Option<Vec<String>> {
+            opt.vals::<T>()
+                .ok()
+                .map(|vals| vals.iter().map(ToString::to_string).collect())
+        }
+
+        if opt.action() == &Action::Cnt {
+            return display::<u64>(opt).unwrap_or_default();
+        }
+        display::<String>(opt)
+            .or_else(|| display::<bool>(opt))
+            .or_else(|| display::<i64>(opt))
+            .or_else(|| display::<u64>(opt))
+            .or_else(|| display::<f64>(opt))
+            .or_else(|| display::<usize>(opt))
+            .or_else(|| {
+                opt.vals::<OsString>().ok().map(|vals| {
+                    vals.iter()
+                        .map(|v| v.to_string_lossy().to_string())
+                        .collect()
+                })
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,162 @@
+//! Property tests of the matching engine, run with the default policies.
+//!
+//! - `Index` is rendered and parsed back to the same value;
+//! - `Token` is rendered back to the same argument;
+//! - `reset_all` and dry-run mode restore the state of option set;
+//! - strict mode of `FwdPolicy` and `DelayPolicy` never silently drops an argument looks like option,
+//!   the `PrePolicy` discards it by design.
+use std::ffi::OsString;
+
+use aopt::prelude::*;
+use aopt::Error;
+use proptest::prelude::*;
+
+fn index() -> impl Strategy<Value = Index> {
+    let list = || prop::collection::vec(0..100usize, 1..5);
+
+    prop_oneof![
+        (0..100usize).prop_map(Index::forward),
+        (0..100usize).prop_map(Index::backward),
+        list().prop_map(Index::list),
+        list().prop_map(Index::except),
+        (0..100usize, prop::option::of(0..100usize))
+            .prop_map(|(beg, end)| Index::range(Some(beg), end)),
+        Just(Index::anywhere()),
+    ]
+}
+
+/// The arguments can be parsed by the option set created by [`options`].
+fn valid_arg() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z]{1,6}".prop_map(|v| format!("--foo={v}")),
+        Just(String::from("--all")),
+        (0..1000i64).prop_map(|v| format!("--count={v}")),
+        "[a-z][a-z0-9]{0,5}",
+    ]
+}
+
+fn options<P>(parser: &mut Parser<'_, P>) -> Result<(), Error>
+where
+    P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+{
+    parser.add_opt("--foo=s")?;
+    parser.add_opt("--all=b")?;
+    parser.add_opt("--count=i")?.set_value_t(4i64);
+    Ok(())
+}
+
+fn check_initial_state<P>(parser: &Parser<'_, P>) -> Result<(), TestCaseError>
+where
+    P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+{
+    prop_assert!(parser.find_val::<String>("--foo").is_err());
+    prop_assert_eq!(parser.find_val::<i64>("--count").ok(), Some(&4));
+    for name in ["--foo", "--all", "--count"] {
+        prop_assert!(!parser.find_opt(name).unwrap().matched());
+    }
+    Ok(())
+}
+
+fn check_reset<P>(mut parser: Parser<'_, P>, args: &[String]) -> Result<(), TestCaseError>
+where
+    P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+    for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+{
+    options(&mut parser).unwrap();
+
+    let ret = parser
+        .parse(Args::from(
+            std::iter::once("app").chain(args.iter().map(String::as_str)),
+        ))
+        .unwrap();
+
+    prop_assert!(ret.status());
+    parser.optset_mut().reset_all().unwrap();
+    check_initial_state(&parser)
+}
+
+fn check_dry_run<P>(mut parser: Parser<'_, P>, args: &[String]) -> Result<(), TestCaseError>
+where
+    P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error>,
+    for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+{
+    options(&mut parser).unwrap();
+    parser.init().unwrap();
+
+    let ret = parser
+        .parse(Args::from(
+            std::iter::once("app").chain(args.iter().map(String::as_str)),
+        ))
+        .unwrap();
+
+    prop_assert!(ret.status());
+    check_initial_state(&parser)
+}
+
+fn check_strict<P>(mut parser: Parser<'_, P>, args: &[String]) -> Result<(), TestCaseError>
+where
+    P: Policy<Set = ASet, Ser = ASer, Ret = Return, Error = Error> + PolicySettings,
+    for<'a> P: Policy<Inv<'a> = Invoker<'a, ASet, ASer>>,
+{
+    parser.set_strict(true);
+    parser.add_opt("--all=b").unwrap();
+    parser.add_opt("-v=b").unwrap();
+
+    let ret = parser.parse(Args::from(
+        std::iter::once("app").chain(args.iter().map(String::as_str)),
+    ));
+
+    prop_assert!(
+        !matches!(ret, Ok(ref ret) if ret.status()),
+        "the unknown option is dropped silently: {:?}",
+        args
+    );
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn index_round_trip(index in index()) {
+        prop_assert_eq!(Index::parse(&index.to_string()).unwrap(), index);
+    }
+
+    #[test]
+    fn token_round_trip(args in prop::collection::vec("[-a-z0-9=/]{0,10}", 0..8)) {
+        let args = Args::from(args);
+        let tokenizer = Tokenizer::default();
+        let rendered: Vec<OsString> = tokenizer
+            .tokenize_args(&args)
+            .unwrap()
+            .iter()
+            .map(Token::to_os_string)
+            .collect();
+
+        prop_assert_eq!(&rendered, &*args);
+    }
+
+    #[test]
+    fn reset_restore_state(args in prop::collection::vec(valid_arg(), 0..8)) {
+        check_reset(AFwdParser::default(), &args)?;
+        check_reset(APreParser::default(), &args)?;
+        check_reset(ADelayParser::default(), &args)?;
+    }
+
+    #[test]
+    fn dry_run_keep_state(args in prop::collection::vec(valid_arg(), 0..8)) {
+        check_dry_run(AFwdParser::new_policy(AFwdPolicy::default().with_dry_run(true)), &args)?;
+        check_dry_run(APreParser::new_policy(APrePolicy::default().with_dry_run(true)), &args)?;
+        check_dry_run(ADelayParser::new_policy(ADelayPolicy::default().with_dry_run(true)), &args)?;
+    }
+
+    #[test]
+    fn strict_not_drop_option(
+        lefts in prop::collection::vec(prop_oneof!["--all", "-v", "[a-z]{1,6}"], 0..4),
+        unknown in "--x[a-z]{0,5}",
+        rights in prop::collection::vec(prop_oneof!["--all", "-v", "[a-z]{1,6}"], 0..4),
+    ) {
+        let args: Vec<String> = lefts.into_iter().chain(Some(unknown)).chain(rights).collect();
+
+        check_strict(AFwdParser::default(), &args)?;
+        check_strict(ADelayParser::default(), &args)?;
+    }
+}
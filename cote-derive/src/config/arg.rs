@@ -42,6 +42,38 @@ pub enum ArgKind {
 
     Count,
 
+    Once,
+
+    Global,
+
+    Sensitive,
+
+    ForceIfCmd,
+
+    Requires,
+
+    ConflictsWith,
+
+    Priority,
+
+    Bind,
+
+    Passthrough,
+
+    ValueName,
+
+    Nested,
+
+    Normalize,
+
+    DefaultFromEnv,
+
+    ValueFile,
+
+    Dedup,
+
+    Choices,
+
     MethodCall(String),
 }
 
@@ -71,6 +103,22 @@ impl Kind for ArgKind {
                 "fetch" => (Self::Fetch, true),
                 "append" => (Self::Append, false),
                 "count" => (Self::Count, false),
+                "once" => (Self::Once, false),
+                "global" => (Self::Global, false),
+                "sensitive" => (Self::Sensitive, false),
+                "force_if_cmd" => (Self::ForceIfCmd, true),
+                "requires" => (Self::Requires, true),
+                "conflicts_with" => (Self::ConflictsWith, true),
+                "priority" => (Self::Priority, true),
+                "bind" => (Self::Bind, true),
+                "passthrough" => (Self::Passthrough, false),
+                "value_name" => (Self::ValueName, true),
+                "nested" => (Self::Nested, false),
+                "normalize" => (Self::Normalize, true),
+                "default_from_env" => (Self::DefaultFromEnv, true),
+                "value_file" => (Self::ValueFile, false),
+                "dedup" => (Self::Dedup, false),
+                "choices" => (Self::Choices, true),
                 method => (Self::MethodCall(method.to_owned()), true),
             })
         } else {
@@ -122,6 +170,30 @@ impl ArgKind {
             ArgKind::Count => Ok(quote! {
                 cote::prelude::ConfigValue::set_action(&mut #ident, cote::prelude::Action::Cnt);
             }),
+            ArgKind::Once => Ok(quote! {
+                cote::prelude::ConfigValue::set_action(&mut #ident, cote::prelude::Action::SetOnce);
+            }),
+            ArgKind::Global => Ok(quote! {
+                cote::prelude::ConfigValue::set_global(&mut #ident, true);
+            }),
+            ArgKind::Sensitive => Ok(quote! {
+                cote::prelude::ConfigValue::set_sensitive(&mut #ident, true);
+            }),
+            ArgKind::ForceIfCmd => Ok(quote! {
+                cote::prelude::ConfigValue::set_force_if_cmd(&mut #ident, #val);
+            }),
+            ArgKind::Requires => Ok(quote! {
+                cote::prelude::ConfigValue::add_requires(&mut #ident, #val);
+            }),
+            ArgKind::ConflictsWith => Ok(quote! {
+                cote::prelude::ConfigValue::add_conflicts_with(&mut #ident, #val);
+            }),
+            ArgKind::Priority => Ok(quote! {
+                cote::prelude::ConfigValue::set_priority(&mut #ident, #val);
+            }),
+            ArgKind::Choices => Ok(quote! {
+                cote::prelude::ConfigValue::set_choices(&mut #ident, Vec::from(#val));
+            }),
             _ => Err(crate::error(ident.span(), "")),
         }
     }
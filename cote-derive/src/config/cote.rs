@@ -1,5 +1,6 @@
 use quote::ToTokens;
 use syn::Path;
+use syn::Token;
 
 use super::Kind;
 
@@ -17,12 +18,36 @@ pub enum CoteKind {
 
     Foot,
 
+    Author,
+
+    Version,
+
+    About,
+
     HelpWidth,
 
     UsageWidth,
 
+    Usage,
+
+    CompactUsage,
+
+    IndexHint,
+
+    Color,
+
+    HelpStyle,
+
+    Topic,
+
     AbortHelp,
 
+    LongHelp,
+
+    SubFallback,
+
+    DefaultSub,
+
     On,
 
     Fallback,
@@ -39,6 +64,8 @@ pub enum CoteKind {
 
     Overload,
 
+    Profile,
+
     MethodCall(String),
 }
 
@@ -56,9 +83,21 @@ impl Kind for CoteKind {
                 "helpopt" => (Self::HelpOpt, true),
                 "head" => (Self::Head, true),
                 "foot" => (Self::Foot, true),
+                "author" => (Self::Author, input.peek(Token![=])),
+                "version" => (Self::Version, input.peek(Token![=])),
+                "about" => (Self::About, input.peek(Token![=])),
                 "width" => (Self::HelpWidth, true),
                 "usagew" => (Self::UsageWidth, true),
+                "usage" => (Self::Usage, true),
+                "compact_usage" => (Self::CompactUsage, false),
+                "index_hint" => (Self::IndexHint, true),
+                "color" => (Self::Color, false),
+                "help_style" => (Self::HelpStyle, true),
+                "topic" => (Self::Topic, true),
                 "aborthelp" => (Self::AbortHelp, false),
+                "long_help" => (Self::LongHelp, false),
+                "sub_fallback" => (Self::SubFallback, true),
+                "default_sub" => (Self::DefaultSub, true),
                 "on" => (Self::On, true),
                 "fallback" => (Self::Fallback, true),
                 "then" => (Self::Then, true),
@@ -67,6 +106,7 @@ impl Kind for CoteKind {
                 "embedded" => (Self::EmbeddedPlus, false),
                 "flag" => (Self::Flag, false),
                 "overload" => (Self::Overload, false),
+                "profile" => (Self::Profile, true),
                 method => (Self::MethodCall(method.to_owned()), true),
             })
         } else {
@@ -37,6 +37,8 @@ pub enum InferKind {
 
     Fill,
 
+    Choices,
+
     Override,
 }
 
@@ -62,6 +64,7 @@ impl Kind for InferKind {
             "mutable" => Ok((Self::Mutable, true)),
             "tweak" => Ok((Self::Tweak, true)),
             "fill" => Ok((Self::Fill, true)),
+            "choices" => Ok((Self::Choices, false)),
             "override" => Ok((Self::Override, false)),
             _ => Err(error(
                 input.span(),
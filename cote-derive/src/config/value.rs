@@ -15,9 +15,13 @@ pub enum ValueKind {
 
     MapStr,
 
+    FromStr,
+
     Name,
 
     Alias,
+
+    Lookup,
 }
 
 impl Kind for ValueKind {
@@ -30,9 +34,11 @@ impl Kind for ValueKind {
             "map" => Ok((Self::Map, true)),
             "mapraw" => Ok((Self::MapRaw, true)),
             "mapstr" => Ok((Self::MapStr, true)),
+            "fromstr" => Ok((Self::FromStr, false)),
             "igcase" => Ok((Self::IgCase, false)),
             "name" => Ok((Self::Name, true)),
             "alias" => Ok((Self::Alias, true)),
+            "lookup" => Ok((Self::Lookup, true)),
             _ => Err(error(
                 input.span(),
                 format!(
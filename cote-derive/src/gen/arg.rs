@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{spanned::Spanned, Field, Ident, Type};
+use syn::{spanned::Spanned, Expr, Field, Ident, Member, Type};
 
 use crate::{config::ArgKind, error};
 
@@ -17,6 +17,8 @@ pub struct ArgGenerator<'a> {
 
     index: Option<usize>,
 
+    long_help: bool,
+
     config: FieldCfg<'a, ArgKind>,
 }
 
@@ -24,40 +26,71 @@ impl<'a> ArgGenerator<'a> {
     pub fn new(field: &'a Field, id: u64, kind: AttrKind) -> syn::Result<Self> {
         let config = FieldCfg::new(id, field, kind)?;
         let index = config.has_cfg(ArgKind::Index);
+        let nested = config.has_cfg(ArgKind::Nested);
         let ident = Utils::id2opt_ident(id, field.span());
         let uid_ident = Utils::id2opt_uid_ident(id, field.span());
-        let name = config
-            .find_value(ArgKind::Name)
-            .map(|v| v.to_token_stream())
-            .unwrap_or_else(|| {
-                let ident_str = config.ident().to_string();
-
+        let name = match (config.find_value(ArgKind::Name), config.ident_str()) {
+            (Some(name), _) => name.to_token_stream(),
+            // the key path of nested field, such as `server` of `--server.port`
+            (None, Some(ident_str)) if nested => ident_str.replace('_', "-").to_token_stream(),
+            (None, Some(ident_str)) => {
                 if kind.is_cmd() || kind.is_pos() || kind.is_main() || index {
                     ident_str.to_token_stream()
                 } else {
                     Utils::ident2opt_name(&ident_str).to_token_stream()
                 }
-            });
+            }
+            // the positional of tuple struct is named by its position, such as `arg0`
+            (None, None) if kind.is_pos() || kind.is_main() => {
+                format!("arg{}", id).to_token_stream()
+            }
+            (None, None) => {
+                return Err(error(
+                    field.span(),
+                    "field of tuple struct need a name, such as `#[arg(name = \"--foo\")]`",
+                ))
+            }
+        };
 
         if (kind.is_cmd() || kind.is_main()) && config.has_cfg(ArgKind::Index) {
+            let member = config.member();
+
             Err(error(
                 field.span(),
                 format!(
-                    "`cmd` has default index, please remove the `index` attribute from `{:?}`",
-                    config.ident()
+                    "`cmd` has default index, please remove the `index` attribute from `{}`",
+                    quote!(#member)
                 ),
             ))
+        } else if nested && !matches!(kind, AttrKind::Arg) {
+            Err(error(
+                field.span(),
+                "`nested` only available for option, please use `#[arg(nested)]`",
+            ))
+        } else if nested
+            && config
+                .configs()
+                .iter()
+                .any(|v| !matches!(v.kind(), ArgKind::Nested | ArgKind::Name | ArgKind::Help))
+        {
+            Err(error(
+                field.span(),
+                "only `name` and `help` are available with `nested`",
+            ))
         } else if config.has_cfg(ArgKind::Action)
-            && (config.has_cfg(ArgKind::Append) || config.has_cfg(ArgKind::Count))
+            && (config.has_cfg(ArgKind::Append)
+                || config.has_cfg(ArgKind::Count)
+                || config.has_cfg(ArgKind::Once))
         {
             Err(error(
                 field.span(),
-                "`app` and `cnt` are alias of `action`, please remove one from attributes",
+                "`append`, `count` and `once` are alias of `action`, please remove one from attributes",
             ))
         } else {
             Ok(Self {
                 name,
                 index: None,
+                long_help: false,
                 config,
                 ident,
                 uid_ident,
@@ -77,8 +110,8 @@ impl<'a> ArgGenerator<'a> {
         self.config.ty()
     }
 
-    pub fn orig_ident(&self) -> &'a Ident {
-        self.config.ident()
+    pub fn orig_ident(&self) -> &Member {
+        self.config.member()
     }
 
     pub fn ident(&self) -> &Ident {
@@ -89,6 +122,33 @@ impl<'a> ArgGenerator<'a> {
         &self.uid_ident
     }
 
+    /// The field is configured with `nested`, its options are added under the key path.
+    pub fn is_nested(&self) -> bool {
+        self.config.has_cfg(ArgKind::Nested)
+    }
+
+    /// Return the key path of `nested` field, join it with the `path` in nested mode.
+    pub fn gen_key_path(&self, in_nested: bool) -> TokenStream {
+        let name = &self.name;
+
+        if in_nested {
+            quote! { &format!("{}.{}", path, #name) }
+        } else {
+            quote! { #name }
+        }
+    }
+
+    /// Return the uid of option, find it by the name under key path in nested mode.
+    pub fn gen_uid(&self, in_nested: bool) -> TokenStream {
+        let name = &self.name;
+
+        if in_nested {
+            quote! { cote::prelude::key_path_uid(set, path, #name)? }
+        } else {
+            Utils::id2uid_literal(self.uid()).to_token_stream()
+        }
+    }
+
     pub fn need_pos_index(&self) -> bool {
         self.kind().is_pos() && !self.config.has_cfg(ArgKind::Index)
     }
@@ -103,10 +163,68 @@ impl<'a> ArgGenerator<'a> {
         self.index
     }
 
+    // split the doc comments into short help and long help
+    pub fn set_long_help(&mut self, long_help: bool) -> &mut Self {
+        self.long_help = long_help;
+        self
+    }
+
+    pub fn gen_long_help(&self) -> Option<TokenStream> {
+        let uid_literal = Utils::id2uid_literal(self.uid());
+
+        if !self.long_help || self.config.has_cfg(ArgKind::Help) {
+            return None;
+        }
+        self.config.collect_long_help_msgs().map(|help| {
+            let help = self.gen_help_with_default(help);
+
+            quote! { .with_long_help(#uid_literal, #help) }
+        })
+    }
+
+    /// Append the default value to help, such as `[42]`,
+    /// or the name of environment variable for `default_from_env`, such as `[env: JOBS]`.
+    pub fn gen_help_with_default(&self, help: TokenStream) -> TokenStream {
+        let value = self
+            .config
+            .find_value(ArgKind::Value)
+            .or_else(|| self.config.find_value(ArgKind::Values));
+
+        if let Some(value) = value {
+            // using Debug for default value, better?
+            quote! { format!("{} [{:?}]", #help, #value) }
+        } else if let Some(var) = self.config.find_value(ArgKind::DefaultFromEnv) {
+            quote! { format!("{} [env: {}]", #help, #var) }
+        } else {
+            help
+        }
+    }
+
     pub fn gen_opt_update(&self) -> syn::Result<OptUpdate> {
-        let c = self.gen_opt_create()?;
+        let c = self.gen_opt_create(false)?;
         let i = self.gen_opt_insert()?;
-        let h = self.gen_opt_handler()?;
+        let h = self.gen_opt_handler(false)?;
+
+        Ok(OptUpdate {
+            h,
+            ..Default::default()
+        }
+        .with_create(c)
+        .with_insert(i))
+    }
+
+    /// Generate the option update in nested mode, the uid of option is not fixed.
+    pub fn gen_nested_update(&self) -> syn::Result<OptUpdate> {
+        let ident = self.ident();
+        let uid_ident = self.uid_ident();
+        let c = self.gen_opt_create(true)?;
+        let h = self.gen_opt_handler(true)?;
+        // the uid only used in handler of option
+        let i = if h.is_some() && !self.is_nested() {
+            quote! { let #uid_ident = set.insert(#ident); }
+        } else {
+            quote! { set.insert(#ident); }
+        };
 
         Ok(OptUpdate {
             h,
@@ -124,7 +242,15 @@ impl<'a> ArgGenerator<'a> {
         Utils::gen_opt_insert(ident, uid_ident, &uid_literal)
     }
 
-    pub fn gen_opt_handler(&self) -> syn::Result<Option<TokenStream>> {
+    pub fn gen_opt_handler(&self, in_nested: bool) -> syn::Result<Option<TokenStream>> {
+        if self.is_nested() {
+            let ty = self.ty();
+            let path = self.gen_key_path(in_nested);
+
+            return Ok(Some(quote! {
+                <#ty as cote::IntoParserDerive<'inv, Set, Ser>>::update_nested(parser, #path)?;
+            }));
+        }
         let on = self.config.find_cfg(ArgKind::On);
         let fallback = self.config.find_cfg(ArgKind::Fallback);
         let then = self.config.find_cfg(ArgKind::Then);
@@ -133,7 +259,93 @@ impl<'a> ArgGenerator<'a> {
         Utils::gen_opt_handler(uid_ident, on, fallback, then)
     }
 
-    pub fn gen_opt_create(&self) -> syn::Result<TokenStream> {
+    /// Generate the hint such as `-c, --config <FILE>` if user not set hint,
+    /// the value name is enclosed in `[]` if the option is optional.
+    pub fn gen_value_name_hint(cfg_ident: &Ident, value_name: impl ToTokens) -> TokenStream {
+        quote! {
+            if !cote::prelude::ConfigValue::has_hint(&#cfg_ident) {
+                let mut names = vec![cote::prelude::ConfigValue::name(&#cfg_ident).unwrap_or_default()];
+
+                if let Some(alias) = cote::prelude::ConfigValue::alias(&#cfg_ident) {
+                    names.extend(alias.iter().map(|v| v.as_str()));
+                }
+                names.sort_by_key(|v| v.len());
+
+                let hint = if cote::prelude::ConfigValue::force(&#cfg_ident).unwrap_or_default() {
+                    format!("{} <{}>", names.join(", "), #value_name)
+                } else {
+                    format!("{} [{}]", names.join(", "), #value_name)
+                };
+
+                cote::prelude::ConfigValue::set_hint(&mut #cfg_ident, hint);
+            }
+        }
+    }
+
+    /// Generate the option holds the place of `nested` field,
+    /// it has [`Null`](cote::prelude::Style::Null) style and never be matched.
+    pub fn gen_nested_create(&self, in_nested: bool) -> syn::Result<TokenStream> {
+        let cfg_ident = Ident::new("cfg", self.ident().span());
+        let path = self.gen_key_path(in_nested);
+        let help = self
+            .config
+            .find_value(ArgKind::Help)
+            .map(|v| quote! { String::from(#v.trim()) })
+            .or_else(|| self.config.collect_help_msgs())
+            .map(|help| ArgKind::Help.simple(&cfg_ident, help))
+            .transpose()?;
+
+        Utils::gen_opt_create(
+            self.ident(),
+            Some(quote! {
+                cote::prelude::ConfigValue::set_name(&mut #cfg_ident, #path);
+                #help
+                <() as cote::prelude::Infer>::infer_fill_info(&mut #cfg_ident)?;
+                cote::prelude::ConfigValue::set_style(&mut #cfg_ident, vec![cote::prelude::Style::Null]);
+                cote::prelude::ConfigValue::set_force(&mut #cfg_ident, false);
+            }),
+        )
+    }
+
+    /// Generate the normalizer from `normalize = [trim, lowercase]`, the builtin steps are
+    /// `trim`, `lowercase`, `uppercase` and `canonicalize`, other functions are custom steps.
+    /// Any other expression should be convertible into `Normalizer`.
+    pub fn gen_normalizer(value: TokenStream) -> syn::Result<TokenStream> {
+        let expr: Expr = syn::parse2(value)?;
+        let step = |expr: &Expr| {
+            let name = match expr {
+                Expr::Path(path) => path.path.get_ident().map(|v| v.to_string()),
+                _ => None,
+            };
+
+            match name.as_deref() {
+                Some("trim") => quote! { cote::prelude::Normalize::Trim },
+                Some("lowercase") => quote! { cote::prelude::Normalize::Lowercase },
+                Some("uppercase") => quote! { cote::prelude::Normalize::Uppercase },
+                Some("canonicalize") => quote! { cote::prelude::Normalize::Canonicalize },
+                _ => quote! { cote::prelude::Normalize::Custom(#expr) },
+            }
+        };
+
+        Ok(match &expr {
+            Expr::Array(array) => {
+                let steps = array.elems.iter().map(step);
+
+                quote! { cote::prelude::Normalizer::new([#(#steps),*]) }
+            }
+            Expr::Path(_) => {
+                let step = step(&expr);
+
+                quote! { cote::prelude::Normalizer::from(#step) }
+            }
+            _ => quote! { #expr },
+        })
+    }
+
+    pub fn gen_opt_create(&self, in_nested: bool) -> syn::Result<TokenStream> {
+        if self.is_nested() {
+            return self.gen_nested_create(in_nested);
+        }
         let field_span = self.ident().span();
         let field_ty = self.ty();
         let field_cfg = &self.config;
@@ -141,6 +353,18 @@ impl<'a> ArgGenerator<'a> {
         let mut codes = vec![];
         let mut value = None;
 
+        if field_cfg.has_cfg(ArgKind::DefaultFromEnv)
+            && (field_cfg.has_cfg(ArgKind::Value) || field_cfg.has_cfg(ArgKind::Values))
+        {
+            return Err(error(
+                field_span,
+                format!(
+                    "Can not have both `default_from_env` and `value` on field `{}`",
+                    self.ident,
+                ),
+            ));
+        }
+
         codes.push(ArgKind::Name.simple(&cfg_ident, self.name.clone())?);
         for cfg in field_cfg.configs().iter() {
             let kind = cfg.kind();
@@ -152,7 +376,15 @@ impl<'a> ArgGenerator<'a> {
                 | ArgKind::Force
                 | ArgKind::Action
                 | ArgKind::Count
+                | ArgKind::Once
                 | ArgKind::Index
+                | ArgKind::Global
+                | ArgKind::Sensitive
+                | ArgKind::ForceIfCmd
+                | ArgKind::Requires
+                | ArgKind::ConflictsWith
+                | ArgKind::Priority
+                | ArgKind::Choices
                 | ArgKind::Append => {
                     let value = cfg_value.to_token_stream();
 
@@ -166,15 +398,25 @@ impl<'a> ArgGenerator<'a> {
                 }
                 ArgKind::On | ArgKind::Fallback | ArgKind::Then => {}
 
-                ArgKind::Validator => codes.push(kind.simple(
-                    &cfg_ident,
-                    quote! {{
-                        let validator = cote::prelude::ValValidator::from_fn( |value| {
-                            cote::valid::Validate::check(& #cfg_value, value)
-                        });
-                        cote::prelude::ValStorer::new_validator::<InferedOptVal<#field_ty>>(validator)
-                    }},
-                )?),
+                ArgKind::Validator => {
+                    // the choices set by `choices` are preferred
+                    codes.push(quote! {
+                        if let Some(choices) = cote::valid::Validate::<InferedOptVal<#field_ty>>::choices(& #cfg_value) {
+                            if !cote::prelude::ConfigValue::has_choices(&#cfg_ident) {
+                                cote::prelude::ConfigValue::set_choices(&mut #cfg_ident, choices);
+                            }
+                        }
+                    });
+                    codes.push(kind.simple(
+                        &cfg_ident,
+                        quote! {{
+                            let validator = cote::prelude::ValValidator::from_fn( |value| {
+                                cote::valid::Validate::check(& #cfg_value, value)
+                            });
+                            cote::prelude::ValStorer::new_validator::<InferedOptVal<#field_ty>>(validator)
+                        }},
+                    )?);
+                }
                 ArgKind::MethodCall(method) => {
                     let method = Ident::new(method.as_str(), field_span);
                     let value = cfg_value.clone();
@@ -207,11 +449,38 @@ impl<'a> ArgGenerator<'a> {
                 ArgKind::NoDelay => {
                     // will process in policy settings 
                 },
-                ArgKind::Fetch => {
+                ArgKind::Fetch | ArgKind::Bind | ArgKind::Passthrough => {
                     // will process in try extract
                 },
+                ArgKind::ValueName | ArgKind::ValueFile | ArgKind::Dedup => {
+                    // process after infer
+                }
+                ArgKind::Nested => {
+                    // process in nested create
+                }
+                ArgKind::Normalize => {
+                    let normalizer = Self::gen_normalizer(cfg_value.to_token_stream())?;
+
+                    codes.push(quote! {
+                        cote::prelude::ConfigValue::set_normalizer(&mut #cfg_ident, #normalizer);
+                    });
+                }
+                ArgKind::DefaultFromEnv => {
+                    value = Some(cfg_value.clone());
+                    codes.push(quote! {
+                        cote::prelude::ConfigValue::set_initializer(
+                            &mut #cfg_ident,
+                            cote::prelude::ValInitializer::new_env::<InferedOptVal<#field_ty>>(#cfg_value)
+                        );
+                    });
+                }
             }
         }
+        if in_nested {
+            codes.push(quote! {
+                cote::prelude::apply_key_path(&mut #cfg_ident, path);
+            });
+        }
         // if we have value, set the force to false
         if value.is_some() {
             codes.push(ArgKind::Force.simple(&cfg_ident, false.to_token_stream())?);
@@ -219,19 +488,15 @@ impl<'a> ArgGenerator<'a> {
         if let Some(help) = field_cfg
             .find_value(ArgKind::Help)
             .map(|v| quote! { String::from(#v.trim()) })
-            .or_else(|| field_cfg.collect_help_msgs())
-        {
-            codes.push(ArgKind::Help.simple(
-                &cfg_ident,
-                if let Some(value) = value.as_ref() {
-                    let value = value.to_token_stream();
-
-                    // using Debug for default value, better?
-                    quote! { format!("{} [{:?}]", #help, #value) }
+            .or_else(|| {
+                if self.long_help {
+                    field_cfg.collect_short_help_msgs()
                 } else {
-                    help
-                },
-            )?);
+                    field_cfg.collect_help_msgs()
+                }
+            })
+        {
+            codes.push(ArgKind::Help.simple(&cfg_ident, self.gen_help_with_default(help))?);
         }
         if let Some(index) = self.pos_index() {
             if !self.config.has_cfg(ArgKind::Index) {
@@ -248,7 +513,11 @@ impl<'a> ArgGenerator<'a> {
                 ));
             }
         }
-        codes.push(if let Some(ty) = self.config.find_value(ArgKind::Type) {
+        codes.push(if self.config.has_cfg(ArgKind::Passthrough) {
+            quote! {
+                <cote::prelude::Passthrough as cote::prelude::Infer>::infer_fill_info(&mut #cfg_ident)?;
+            }
+        } else if let Some(ty) = self.config.find_value(ArgKind::Type) {
             quote! {
                 <#ty as cote::prelude::InferOverride>::infer_fill_info(&mut #cfg_ident)?;
                 <#ty as cote::prelude::Infer>::infer_fill_info(&mut #cfg_ident)?;
@@ -256,34 +525,115 @@ impl<'a> ArgGenerator<'a> {
         } else {
             self.kind().gen_infer(&cfg_ident, field_ty)?
         });
+        if let Some(value_name) = self.config.find_value(ArgKind::ValueName) {
+            if !matches!(self.kind(), AttrKind::Arg) {
+                return Err(error(
+                    field_span,
+                    format!(
+                        "`value_name` only available for option, not on field `{}`",
+                        self.ident,
+                    ),
+                ));
+            }
+            codes.push(Self::gen_value_name_hint(&cfg_ident, value_name));
+        }
+        if self.config.has_cfg(ArgKind::ValueFile) {
+            codes.push(quote! {
+                if let Some(storer) = cote::prelude::ConfigValue::storer_mut(&mut #cfg_ident) {
+                    storer.set_value_file(true);
+                }
+            });
+        }
+        if self.config.has_cfg(ArgKind::Dedup) {
+            let ty = match self.config.find_value(ArgKind::Type) {
+                Some(ty) => ty.to_token_stream(),
+                None => field_ty.to_token_stream(),
+            };
+
+            codes.push(quote! {
+                if let Some(storer) = cote::prelude::ConfigValue::storer_mut(&mut #cfg_ident) {
+                    storer.set_dedup::<<#ty as cote::prelude::Infer>::Val>();
+                }
+            });
+        }
         Utils::gen_opt_create(self.ident(), Some(quote! { #(#codes)* }))
     }
 
-    pub fn gen_try_extract(&self) -> syn::Result<(bool, TokenStream)> {
-        let ident = self.orig_ident();
+    pub fn gen_fetch_value(&self, in_nested: bool) -> TokenStream {
         let field_ty = self.ty();
         let fetch = self.config.find_cfg(ArgKind::Fetch);
-        let uid_literal = Utils::id2uid_literal(self.uid());
+        let uid_literal = self.gen_uid(in_nested);
         // let spec_ty = self.config.find_cfg(ArgKind::Type);
         // don't use spec_ty here, let user choose how to fetch value
 
-        if let Some(fetch) = fetch {
+        if self.is_nested() {
+            let path = self.gen_key_path(in_nested);
+
+            quote! {
+                <#field_ty as cote::ExtractFromSetDerive<'set, Set>>::try_extract_nested(set, #path)?
+            }
+        } else if let Some(fetch) = fetch {
             let func = fetch.value();
 
-            Ok((
-                false,
-                quote! {
-                    #ident: #func::<#field_ty, Set>(#uid_literal, set)?
-                },
-            ))
+            quote! {
+                #func::<#field_ty, Set>(#uid_literal, set)?
+            }
+        } else if self.config.has_cfg(ArgKind::Passthrough) {
+            quote! {
+                <cote::prelude::Passthrough as cote::prelude::Fetch<Set>>::fetch_uid(#uid_literal, set)?.into()
+            }
         } else {
-            Ok((
-                false,
+            quote! {
+                cote::prelude::Fetch::<Set>::fetch_uid(#uid_literal, set)?
+            }
+        }
+    }
+
+    pub fn gen_try_extract(&self, in_nested: bool) -> syn::Result<(bool, TokenStream)> {
+        let ident = self.orig_ident();
+        let fetch_value = self.gen_fetch_value(in_nested);
+
+        Ok((
+            false,
+            quote! {
+                #ident: #fetch_value
+            },
+        ))
+    }
+
+    /// Overwrite the field only if the option matched in current parsing.
+    pub fn gen_update_from(&self, in_nested: bool) -> syn::Result<TokenStream> {
+        let ident = self.orig_ident();
+        let uid_literal = self.gen_uid(in_nested);
+        let fetch_value = self.gen_fetch_value(in_nested);
+
+        if self.is_nested() {
+            let field_ty = self.ty();
+            let path = self.gen_key_path(in_nested);
+
+            return Ok(quote! {
+                <#field_ty as cote::ExtractFromSetDerive<'set, Set>>::update_from_set_nested(&mut self.#ident, set, #path)?;
+            });
+        }
+        Ok(quote! {
+            if cote::prelude::Opt::matched(cote::prelude::SetExt::opt(set, #uid_literal)?) {
+                self.#ident = #fetch_value;
+            }
+        })
+    }
+
+    /// Return the matched flag of option and the call of method bound to it.
+    pub fn gen_bind(&self) -> Option<(TokenStream, TokenStream)> {
+        self.config.find_value(ArgKind::Bind).map(|method| {
+            let uid_literal = Utils::id2uid_literal(self.uid());
+
+            (
                 quote! {
-                    #ident: cote::prelude::Fetch::<Set>::fetch_uid(#uid_literal, set)?
+                    cote::prelude::Opt::matched(cote::prelude::SetExt::opt(set, #uid_literal)?)
                 },
-            ))
-        }
+                method.to_token_stream(),
+            )
+        })
     }
 
     pub fn gen_nodelay_setting(&self) -> syn::Result<Option<TokenStream>> {
@@ -1,4 +1,5 @@
 use proc_macro2::Ident;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::quote;
 use quote::ToTokens;
@@ -10,6 +11,7 @@ use syn::DeriveInput;
 use syn::Field;
 use syn::Fields;
 use syn::GenericParam;
+use syn::Member;
 use syn::Type;
 use syn::{Data, Generics};
 
@@ -17,6 +19,7 @@ use crate::config::Configs;
 use crate::config::CoteKind;
 use crate::error;
 use crate::gen::GenericsModifier;
+use crate::value::Value;
 
 use super::arg::ArgGenerator;
 use super::sub::SubGenerator;
@@ -28,8 +31,10 @@ use super::CONFIG_CMD;
 use super::CONFIG_POS;
 use super::CONFIG_SUB;
 use super::HELP_OPTION;
+use super::POLICY_DELAY;
 use super::POLICY_FWD;
 use super::POLICY_PRE;
+use super::POLICY_RUNTIME;
 
 #[derive(Debug)]
 pub struct CoteGenerator<'a> {
@@ -39,6 +44,8 @@ pub struct CoteGenerator<'a> {
 
     field_generators: Vec<FieldGenerator<'a>>,
 
+    newtype: Option<&'a Type>,
+
     generics: &'a Generics,
 
     configs: Configs<CoteKind>,
@@ -61,22 +68,56 @@ impl<'a> CoteGenerator<'a> {
         Self::check_parameters(&generics.params)?;
 
         let mut fgs = vec![];
+        let mut newtype = None;
 
         match input.data {
             Data::Struct(DataStruct {
-                fields: Fields::Named(ref fields),
+                fields: Fields::Unnamed(ref fields),
+                ..
+            }) if Self::is_newtype(fields.unnamed.iter()) => {
+                if !generics.params.is_empty() {
+                    return Err(error(
+                        generics,
+                        "Cote not support newtype struct with generics currently",
+                    ));
+                }
+                if !configs.is_empty() {
+                    return Err(error(
+                        input,
+                        "newtype struct delegates to the inner type, please configure the inner type",
+                    ));
+                }
+                newtype = fields.unnamed.first().map(|v| &v.ty);
+            }
+            Data::Struct(DataStruct {
+                fields:
+                    Fields::Named(syn::FieldsNamed {
+                        named: ref fields, ..
+                    }),
+                ..
+            })
+            | Data::Struct(DataStruct {
+                fields:
+                    Fields::Unnamed(syn::FieldsUnnamed {
+                        unnamed: ref fields,
+                        ..
+                    }),
                 ..
             }) => {
                 let mut sub_index = 0;
                 let mut pos_index = 1;
+                let long_help = configs.has_cfg(CoteKind::LongHelp);
+                let fallback_pos = Self::check_sub_fallback(&configs)?;
 
-                for (id, field) in fields.named.iter().enumerate() {
+                for (id, field) in fields.iter().enumerate() {
                     let id = id as u64;
                     let kind = Self::detect_attr_kind(field)?;
                     let fg = if kind.is_sub() {
                         FieldGenerator::Sub({
-                            let sg = SubGenerator::new(field, id, sub_index)?;
+                            let mut sg = SubGenerator::new(field, id, sub_index)?;
 
+                            sg.set_long_help(long_help);
+                            sg.set_fallback_pos(fallback_pos);
                             sub_index += 1;
                             sg
                         })
@@ -84,6 +125,7 @@ impl<'a> CoteGenerator<'a> {
                         FieldGenerator::Arg({
                             let mut ag = ArgGenerator::new(field, id, kind)?;
 
+                            ag.set_long_help(long_help);
                             if ag.need_pos_index() {
                                 ag.set_pos_index(pos_index);
                                 pos_index += 1;
@@ -103,6 +145,7 @@ impl<'a> CoteGenerator<'a> {
 
         Ok(Self {
             field_generators: fgs,
+            newtype,
             name,
             orig_ident,
             generics,
@@ -112,6 +155,45 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
+    /// Return true if the struct has only one unnamed field without any configuration,
+    /// such as `struct Cli(Inner);`.
+    pub fn is_newtype<'b>(mut fields: impl Iterator<Item = &'b Field>) -> bool {
+        match (fields.next(), fields.next()) {
+            (Some(field), None) => {
+                !field.attrs.iter().any(|v| {
+                    [CONFIG_ARG, CONFIG_SUB, CONFIG_CMD, CONFIG_POS]
+                        .iter()
+                        .any(|name| v.path().is_ident(name))
+                }) && !["Pos", "Main", "Cmd"]
+                    .iter()
+                    .any(|name| Utils::check_in_ty(&field.ty, name).unwrap_or_default())
+            }
+            _ => false,
+        }
+    }
+
+    /// Return true if the unknown sub command can fallback to positional.
+    pub fn check_sub_fallback(configs: &Configs<CoteKind>) -> syn::Result<bool> {
+        match configs.find_cfg(CoteKind::SubFallback) {
+            Some(cfg) => {
+                let value = cfg.value().to_token_stream();
+
+                if value.to_string() == "pos" {
+                    Ok(true)
+                } else {
+                    Err(error(
+                        value.span(),
+                        format!(
+                            "unsupported value `{}` of `sub_fallback`, expect `pos`",
+                            value
+                        ),
+                    ))
+                }
+            }
+            None => Ok(false),
+        }
+    }
+
     pub fn check_parameters(paras: &Punctuated<GenericParam, Comma>) -> syn::Result<()> {
         for para in paras {
             match para {
@@ -164,6 +246,9 @@ impl<'a> CoteGenerator<'a> {
             Ok(AttrKind::Main)
         } else if Utils::check_in_ty(&field.ty, "Cmd")? {
             Ok(AttrKind::Cmd)
+        } else if field.ident.is_none() {
+            // the field of tuple struct is positional in default
+            Ok(AttrKind::Pos)
         } else {
             Ok(AttrKind::Arg)
         }
@@ -182,6 +267,9 @@ impl<'a> CoteGenerator<'a> {
     }
 
     pub fn gen_impl_for_struct(&mut self) -> syn::Result<TokenStream> {
+        if let Some(inner_ty) = self.newtype {
+            return Ok(self.gen_impl_for_newtype(inner_ty));
+        }
         let generics = self.generics.clone();
         let orig_ident = self.orig_ident;
         let used_generics = Self::find_generics_t(&generics, &self.field_generators)?;
@@ -193,7 +281,32 @@ impl<'a> CoteGenerator<'a> {
         let mut pi_generics = GenericsModifier::new(generics.clone());
         let (impl_pi, _, where_pi) = pi_generics.split_for_impl_pi(&used_generics);
         let parser_update = self.gen_parser_update()?;
-        let try_extract = self.gen_try_extract()?;
+        let try_extract = self.gen_try_extract(false)?;
+        let update_from = self.gen_update_from(false)?;
+        let (nested_update, nested_extract) = if self.support_nested() {
+            let nested_update = self.gen_nested_update()?;
+            let try_extract = self.gen_try_extract(true)?;
+            let update_from = self.gen_update_from(true)?;
+
+            (
+                Some(quote! {
+                    fn update_nested(parser: &mut cote::prelude::Parser<'inv, Set, Ser>, path: &str) -> cote::Result<()> {
+                        #nested_update
+                    }
+                }),
+                Some(quote! {
+                    fn try_extract_nested(set: &mut Set, path: &str) -> cote::Result<Self> where Self: Sized {
+                        #try_extract
+                    }
+
+                    fn update_from_set_nested(&mut self, set: &mut Set, path: &str) -> cote::Result<()> {
+                        #update_from
+                    }
+                }),
+            )
+        } else {
+            (None, None)
+        };
         let parser_interface = self.gen_parser_interface(&used_generics)?;
 
         Ok(quote! {
@@ -202,6 +315,8 @@ impl<'a> CoteGenerator<'a> {
                 fn update(parser: &mut cote::prelude::Parser<'inv, Set, Ser>) -> cote::Result<()> {
                     #parser_update
                 }
+
+                #nested_update
             }
 
             #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
@@ -209,6 +324,12 @@ impl<'a> CoteGenerator<'a> {
                 fn try_extract(set: &'set mut Set) -> cote::Result<Self> where Self: Sized {
                     #try_extract
                 }
+
+                fn update_from_set(&mut self, set: &'set mut Set) -> cote::Result<()> {
+                    #update_from
+                }
+
+                #nested_extract
             }
 
             #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
@@ -218,12 +339,131 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
-    pub fn gen_try_extract(&mut self) -> syn::Result<TokenStream> {
+    /// Generate the implementations delegate to the inner type of newtype struct.
+    pub fn gen_impl_for_newtype(&self, inner_ty: &Type) -> TokenStream {
+        let orig_ident = self.orig_ident;
+        let set_bounds = quote! {
+            Set: cote::prelude::Set + cote::prelude::OptParser + cote::prelude::OptValidator + cote::prelude::SetValueFindExt + Default + 'inv,
+            Ser: cote::prelude::ServicesValExt + Default + 'inv,
+            cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default,
+            <Set as cote::prelude::OptParser>::Output: cote::prelude::Information,
+        };
+        let where_clause = quote! {
+            P::Error: Into<cote::Error>,
+            P::Ret: cote::prelude::Status,
+            #set_bounds
+            P: cote::prelude::Policy<
+                Set = cote::prelude::Parser<'inv, Set, Ser>,
+                Ser = Ser,
+                Inv<'inv> = cote::prelude::Invoker<'inv, cote::prelude::Parser<'inv, Set, Ser>, Ser>
+            > + cote::prelude::APolicyExt<P> + cote::prelude::PolicySettings + Default,
+        };
+
+        quote! {
+            #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
+            impl<'inv, Set, Ser> cote::IntoParserDerive<'inv, Set, Ser> for #orig_ident
+            where
+                #set_bounds
+                #inner_ty: cote::IntoParserDerive<'inv, Set, Ser> {
+                fn update(parser: &mut cote::prelude::Parser<'inv, Set, Ser>) -> cote::Result<()> {
+                    <#inner_ty as cote::IntoParserDerive<'inv, Set, Ser>>::update(parser)
+                }
+            }
+
+            #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
+            impl<'set, Set> cote::ExtractFromSetDerive<'set, Set> for #orig_ident
+            where
+                Set: cote::prelude::SetValueFindExt,
+                cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default,
+                #inner_ty: cote::ExtractFromSetDerive<'set, Set> {
+                fn try_extract(set: &'set mut Set) -> cote::Result<Self> where Self: Sized {
+                    <#inner_ty as cote::ExtractFromSetDerive<'set, Set>>::try_extract(set).map(Self)
+                }
+
+                fn update_from_set(&mut self, set: &'set mut Set) -> cote::Result<()> {
+                    <#inner_ty as cote::ExtractFromSetDerive<'set, Set>>::update_from_set(&mut self.0, set)
+                }
+            }
+
+            #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
+            impl #orig_ident {
+                pub fn app_info() -> cote::prelude::AppInfo {
+                    <#inner_ty>::app_info()
+                }
+
+                pub fn help_context() -> cote::prelude::HelpContext {
+                    <#inner_ty>::help_context()
+                }
+
+                #[doc(hidden)]
+                pub fn new_help_context() -> cote::prelude::HelpContext {
+                    <#inner_ty>::new_help_context()
+                }
+
+                #[doc(hidden)]
+                pub fn inject_default_sub<Set: cote::prelude::Set>(set: &Set, args: cote::prelude::Args) -> cote::prelude::Args {
+                    <#inner_ty>::inject_default_sub(set, args)
+                }
+
+                #[doc(hidden)]
+                pub fn sync_rctx<'a, Set, Ret>(rctx: &'a mut cote::prelude::RunningCtx, ret: &cote::Result<Ret>, set: &Set, sub_parser: bool)
+                -> cote::Result<&'a mut cote::prelude::RunningCtx>
+                    where Set: cote::prelude::SetValueFindExt, Ret: cote::prelude::Status,
+                        cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default {
+                    <#inner_ty>::sync_rctx(rctx, ret, set, sub_parser)
+                }
+
+                pub fn into_parser<'inv>() -> cote::Result<cote::prelude::Parser<'inv, cote::prelude::ASet, cote::prelude::ASer>> {
+                    <#inner_ty>::into_parser()
+                }
+
+                pub fn into_parser_with<'inv, Set, Ser>() -> cote::Result<cote::prelude::Parser<'inv, Set, Ser>>
+                where #set_bounds {
+                    <#inner_ty>::into_parser_with::<Set, Ser>()
+                }
+
+                /// See the `apply_to` of inner type.
+                pub fn apply_to<'inv, Set, Ser>(parser: &mut cote::prelude::Parser<'inv, Set, Ser>) -> cote::Result<()>
+                where #set_bounds {
+                    <#inner_ty>::apply_to(parser)
+                }
+
+                pub fn apply_policy_settings(policy: &mut impl cote::prelude::PolicySettings) {
+                    <#inner_ty>::apply_policy_settings(policy)
+                }
+
+                pub fn parse_args_with<'inv, Set, Ser, P>(args: cote::prelude::Args, policy: &mut P)
+                    -> cote::Result<cote::prelude::CoteRes<&mut P, P>> where #where_clause {
+                    <#inner_ty>::parse_args_with(args, policy)
+                }
+
+                pub fn parse_args_with_parser<'inv, 'p, Set, Ser, P>(parser: cote::prelude::Parser<'inv, Set, Ser>, args: cote::prelude::Args, policy: &'p mut P)
+                    -> cote::Result<cote::prelude::CoteRes<&'p mut P, P>> where #where_clause {
+                    <#inner_ty>::parse_args_with_parser(parser, args, policy)
+                }
+
+                pub fn parse_env_args_with<'inv, Set, Ser, P>(policy: &mut P) -> cote::Result<cote::prelude::CoteRes<&mut P, P>>
+                    where #where_clause {
+                    <#inner_ty>::parse_env_args_with(policy)
+                }
+
+                pub fn parse(args: cote::prelude::Args) -> cote::Result<Self> {
+                    <#inner_ty>::parse(args).map(Self)
+                }
+
+                pub fn parse_env() -> cote::Result<Self> {
+                    Self::parse(cote::prelude::Args::from_env())
+                }
+            }
+        }
+    }
+
+    pub fn gen_try_extract(&mut self, in_nested: bool) -> syn::Result<TokenStream> {
         let mut mut_field = vec![];
         let mut ref_field = vec![];
 
         for fg in self.field_generators.iter_mut() {
-            let (is_refopt, extract) = fg.gen_try_extract()?;
+            let (is_refopt, extract) = fg.gen_try_extract(in_nested)?;
 
             if is_refopt {
                 ref_field.push(extract);
@@ -232,11 +472,68 @@ impl<'a> CoteGenerator<'a> {
             }
         }
 
-        Ok(quote! {
-            Ok(Self {
-                #(#mut_field),*
-                #(#ref_field),*
+        let (matched, calls) = self.gen_bind_calls(quote! { &mut ret });
+
+        if calls.is_empty() {
+            Ok(quote! {
+                Ok(Self {
+                    #(#mut_field),*
+                    #(#ref_field),*
+                })
             })
+        } else {
+            // check the matched flag before the ref field borrow the set
+            Ok(quote! {
+                #(#matched)*
+                let mut ret = Self {
+                    #(#mut_field),*
+                    #(#ref_field),*
+                };
+                #(#calls)*
+                Ok(ret)
+            })
+        }
+    }
+
+    /// Generate the matched flags and deferred method calls of `bind` options.
+    pub fn gen_bind_calls(&self, recv: TokenStream) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        let mut matched = vec![];
+        let mut calls = vec![];
+
+        for (idx, (flag, method)) in self
+            .field_generators
+            .iter()
+            .filter_map(|fg| fg.gen_bind())
+            .enumerate()
+        {
+            let ident = Ident::new(&format!("__bind_matched_{}", idx), Span::call_site());
+
+            matched.push(quote! { let #ident = #flag; });
+            calls.push(quote! {
+                if #ident {
+                    #method(#recv)?;
+                }
+            });
+        }
+        (matched, calls)
+    }
+
+    pub fn gen_update_from(&mut self, in_nested: bool) -> syn::Result<TokenStream> {
+        let mut updates = vec![];
+
+        for fg in self.field_generators.iter_mut() {
+            if let Some(update) = fg.gen_update_from(in_nested)? {
+                updates.push(update);
+            }
+        }
+
+        let (matched, calls) = self.gen_bind_calls(quote! { self });
+
+        Ok(quote! {
+            #(#matched)*
+            #(#updates)*
+            #(#calls)*
+            Ok(())
         })
     }
 
@@ -276,6 +573,46 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
+    /// Return true if the struct can be used as `nested` field,
+    /// it should only have options without sub command, positional or main.
+    pub fn support_nested(&self) -> bool {
+        !self.configs.has_cfg(CoteKind::On)
+            && !self.configs.has_cfg(CoteKind::Fallback)
+            && self.field_generators.iter().all(|fg| match fg {
+                FieldGenerator::Sub(_) => false,
+                FieldGenerator::Arg(ag) => {
+                    matches!(ag.kind(), AttrKind::Arg) && ag.gen_bind().is_none()
+                }
+            })
+    }
+
+    /// Generate the options under key path `path`, the main and help option are not added.
+    pub fn gen_nested_update(&mut self) -> syn::Result<TokenStream> {
+        let mut creates = vec![];
+        let mut inserts = vec![];
+        let mut handlers = vec![];
+
+        for fg in self.field_generators.iter() {
+            let OptUpdate { c, i, h } = fg.as_arg().gen_nested_update()?;
+
+            creates.extend(c);
+            inserts.extend(i);
+            handlers.extend(h);
+        }
+        Ok(quote! {
+            // a convenient type for option value
+            type InferedOptVal<T> = <T as cote::prelude::Infer>::Val;
+
+            let set = parser.optset_mut();
+            let ctor_name = cote::prelude::ctor_default_name();
+
+            #(#creates)*
+            #(#inserts)*
+            #(#handlers)*
+            Ok(())
+        })
+    }
+
     pub fn gen_main_and_help_uid(&mut self) -> syn::Result<()> {
         // we need help uid in handler of sub
         if self.configs.has_cfg(CoteKind::Fallback) || self.configs.has_cfg(CoteKind::On) {
@@ -360,6 +697,10 @@ impl<'a> CoteGenerator<'a> {
                         let index = ctx.idx()?;
 
                         ser.sve_insert::<std::ffi::OsString>(args[index].to_os_string());
+                        // the argument following help option may be a help topic
+                        if let Some(topic) = args.get(index + 1) {
+                            ser.sve_insert(cote::prelude::HelpTopic(topic.to_string_lossy().into_owned()));
+                        }
                         Ok(Some(true))
                     }
                 );
@@ -376,18 +717,62 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
+    /// Generate the value of `author`, `version` or `about`, take the Cargo package information if no value given.
+    pub fn gen_app_meta(&self, kind: CoteKind) -> Option<TokenStream> {
+        let value = self.configs.find_value(kind.clone())?;
+
+        Some(match (value, kind) {
+            (Value::Null, CoteKind::Author) => quote! { env!("CARGO_PKG_AUTHORS") },
+            (Value::Null, CoteKind::Version) => quote! { env!("CARGO_PKG_VERSION") },
+            (Value::Null, _) => quote! { env!("CARGO_PKG_DESCRIPTION") },
+            (value, _) => quote! { #value },
+        })
+    }
+
+    pub fn gen_app_info(&self) -> TokenStream {
+        let name = &self.name;
+        let author = self
+            .gen_app_meta(CoteKind::Author)
+            .unwrap_or_else(|| quote! { env!("CARGO_PKG_AUTHORS") });
+        let version = self
+            .gen_app_meta(CoteKind::Version)
+            .unwrap_or_else(|| quote! { env!("CARGO_PKG_VERSION") });
+        let about = self
+            .gen_app_meta(CoteKind::About)
+            .unwrap_or_else(|| quote! { env!("CARGO_PKG_DESCRIPTION") });
+
+        quote! {
+            cote::prelude::AppInfo::default()
+                .with_name(#name)
+                .with_author(#author)
+                .with_version(#version)
+                .with_about(#about)
+        }
+    }
+
     pub fn gen_help_context(&self) -> syn::Result<TokenStream> {
         let head = self
             .configs
             .find_value(CoteKind::Head)
             .map(|v| quote! { String::from(#v) })
+            .or_else(|| {
+                self.gen_app_meta(CoteKind::About)
+                    .map(|v| quote! { String::from(#v) })
+            })
             .unwrap_or_else(|| quote! { String::from(env!("CARGO_PKG_DESCRIPTION")) });
+        let author = self.gen_app_meta(CoteKind::Author);
+        let version = self.gen_app_meta(CoteKind::Version);
         let foot = self
             .configs
             .find_value(CoteKind::Foot)
             .map(|v| quote! { String::from(#v) })
-            .unwrap_or_else(|| quote! {
-                format!("Create by {} v{}", env!("CARGO_PKG_AUTHORS"), env!("CARGO_PKG_VERSION"))
+            .unwrap_or_else(|| match (author, version) {
+                (Some(author), Some(version)) => quote! { format!("Create by {} v{}", #author, #version) },
+                (Some(author), None) => quote! { format!("Create by {}", #author) },
+                (None, Some(version)) => quote! { format!("v{}", #version) },
+                (None, None) => quote! {
+                    format!("Create by {} v{}", env!("CARGO_PKG_AUTHORS"), env!("CARGO_PKG_VERSION"))
+                },
             });
         let width = self
             .configs
@@ -399,6 +784,35 @@ impl<'a> CoteGenerator<'a> {
             .find_value(CoteKind::UsageWidth)
             .map(|v| quote! { #v })
             .unwrap_or(quote! { 10usize });
+        let index_hint = self
+            .configs
+            .find_value(CoteKind::IndexHint)
+            .map(|v| quote! { #v })
+            .unwrap_or(quote! { cote::prelude::IndexHint::Raw });
+        let long_helps = self
+            .field_generators
+            .iter()
+            .filter_map(|v| v.gen_long_help());
+        let usage = self
+            .configs
+            .find_value(CoteKind::Usage)
+            .map(|v| quote! { .with_usage(#v) });
+        let compact_usage = self
+            .configs
+            .has_cfg(CoteKind::CompactUsage)
+            .then(|| quote! { .with_compact_usage(true) });
+        let color = self.configs.has_cfg(CoteKind::Color).then(|| {
+            quote! { .with_color(cote::prelude::ColorChoice::Auto.enabled()) }
+        });
+        let help_style = self
+            .configs
+            .find_value(CoteKind::HelpStyle)
+            .map(|v| quote! { .with_style(#v) });
+        let topics = self
+            .configs
+            .find_values(CoteKind::Topic)
+            .into_iter()
+            .map(|v| quote! { .with_topic(#v) });
         let name = &self.name;
 
         Ok(quote! {
@@ -408,16 +822,68 @@ impl<'a> CoteGenerator<'a> {
                 .with_foot(#foot)
                 .with_width(#width)
                 .with_usagew(#usage_width)
+                .with_index_hint(#index_hint)
+                #usage
+                #compact_usage
+                #color
+                #help_style
+                #(#topics)*
+                #(#long_helps)*
+        })
+    }
+
+    /// Generate the code insert default sub command to `args` if no sub command or help option present.
+    pub fn gen_default_sub(&self) -> syn::Result<TokenStream> {
+        Ok(match self.configs.find_cfg(CoteKind::DefaultSub) {
+            Some(cfg) => {
+                let default_sub = cfg.value();
+                let help_uid = self.help_uid().map(|id| {
+                    let uid_literal = Utils::id2uid_literal(id);
+
+                    quote! {
+                        if let Some(opt) = cote::prelude::Set::get(set, #uid_literal) {
+                            if cote::prelude::Opt::mat_name(opt, Some(arg)) || cote::prelude::Opt::mat_alias(opt, arg) {
+                                return true;
+                            }
+                        }
+                    }
+                });
+
+                quote! {
+                    let present = args.iter().skip(1).filter_map(|v|v.to_str()).any(|arg| {
+                        #help_uid
+                        cote::prelude::Set::iter(set).any(|opt| {
+                            cote::prelude::Opt::mat_style(opt, cote::prelude::Style::Cmd) &&
+                                (cote::prelude::Opt::mat_name(opt, Some(arg)) || cote::prelude::Opt::mat_alias(opt, arg))
+                        })
+                    });
+
+                    if present {
+                        args
+                    }
+                    else {
+                        let mut args = args.unwrap_or_clone();
+
+                        args.insert(1.min(args.len()), std::ffi::OsString::from(#default_sub));
+                        cote::prelude::Args::from(args)
+                    }
+                }
+            }
+            None => quote! { args },
         })
     }
 
     pub fn gen_parser_interface(&self, used: &[&Ident]) -> syn::Result<TokenStream> {
         let sub_parsers = self.gen_sub_parsers()?;
+        let default_sub = self.gen_default_sub()?;
         let help_context = self.gen_help_context()?;
+        let app_info = self.gen_app_info();
         let policy_def_ty = self.gen_policy_ty(true)?;
         let policy_ret_ty = self.gen_policy_ty(false)?;
         let policy_setting_mod = self.gen_policy_setting_mod()?;
         let method_calls = self.gen_method_call()?;
+        let profiles = self.gen_profiles();
+        let is_set = self.field_generators.iter().map(|v| v.gen_is_set());
         let parser_name = &self.name;
         let abort = self.configs.find_cfg(CoteKind::AbortHelp);
         let help = self.configs.find_cfg(CoteKind::Help);
@@ -428,6 +894,8 @@ impl<'a> CoteGenerator<'a> {
 
             quote! { #infer_override  #fetch }
         };
+        let parse = self.gen_parse(&fetch_code)?;
+        let set_help_context = Utils::gen_help_context(quote! { Self::new_help_context() });
         let sync_rctx_from_ret = Utils::gen_sync_ret(
             self.has_sub_command(),
             abort.is_some(),
@@ -450,11 +918,27 @@ impl<'a> CoteGenerator<'a> {
         };
 
         Ok(quote! {
+            /// Return the name, author, version and about of the application.
+            pub fn app_info() -> cote::prelude::AppInfo {
+                #app_info
+            }
+
+            /// Return the [`HelpContext`](cote::prelude::HelpContext) generated from the `cote` attribute,
+            /// it can be modified and passed to [`display_help_ctx`](cote::prelude::Parser::display_help_ctx).
+            pub fn help_context() -> cote::prelude::HelpContext {
+                Self::new_help_context()
+            }
+
             #[doc(hidden)]
             pub fn new_help_context() -> cote::prelude::HelpContext {
                 #help_context
             }
 
+            #[doc(hidden)]
+            pub fn inject_default_sub<Set: cote::prelude::Set>(set: &Set, args: cote::prelude::Args) -> cote::prelude::Args {
+                #default_sub
+            }
+
             #[doc(hidden)]
             pub fn sync_rctx<'a, Set, Ret>(rctx: &'a mut cote::prelude::RunningCtx, ret: &cote::Result<Ret>, set: &Set, sub_parser: bool)
             -> cote::Result<&'a mut cote::prelude::RunningCtx>
@@ -476,11 +960,31 @@ impl<'a> CoteGenerator<'a> {
                 <Set as cote::prelude::OptParser>::Output: cote::prelude::Information,
                 Set: cote::prelude::Set + cote::prelude::OptParser + cote::prelude::OptValidator + cote::prelude::SetValueFindExt + Default + 'inv,
                 #fetch_generics {
-                let mut parser = <Self as cote::IntoParserDerive<'inv, Set, Ser>>::into_parser()?;
+                let mut parser = cote::prelude::Parser::default();
+
+                Self::apply_to(&mut parser)?;
+                Ok(parser)
+            }
 
+            /// Add the options and sub parsers of current struct to the `parser`,
+            /// and set the name of `parser`.
+            ///
+            /// The generated code depends on the uid of options, so the `parser` should not contain any option.
+            /// You can add more options after this call.
+            pub fn apply_to<'inv, Set, Ser>(parser: &mut cote::prelude::Parser<'inv, Set, Ser>) -> cote::Result<()>
+            where
+                Ser: cote::prelude::ServicesValExt + Default + 'inv,
+                cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default,
+                <Set as cote::prelude::OptParser>::Output: cote::prelude::Information,
+                Set: cote::prelude::Set + cote::prelude::OptParser + cote::prelude::OptValidator + cote::prelude::SetValueFindExt + Default + 'inv,
+                #fetch_generics {
+                <Self as cote::IntoParserDerive<'inv, Set, Ser>>::update(parser)?;
+
+                #(#profiles)*
                 #sub_parsers
 
-                Ok(parser.with_name(#parser_name))
+                parser.set_name(#parser_name);
+                Ok(())
             }
 
             pub fn into_policy<'inv>() -> #policy_def_ty {
@@ -500,8 +1004,13 @@ impl<'a> CoteGenerator<'a> {
 
             pub fn parse_args_with<'inv, Set, Ser, P>(args: cote::prelude::Args, policy: &mut P)
                 -> cote::Result<cote::prelude::CoteRes<&mut P, P>> where #where_clause {
-                let mut parser = Self::into_parser_with::<'inv, Set, Ser>()?;
+                Self::parse_args_with_parser(Self::into_parser_with::<'inv, Set, Ser>()?, args, policy)
+            }
 
+            /// Parsing the `args` with the `parser` created by [`into_parser`](Self::into_parser)
+            /// or [`into_parser_with`](Self::into_parser_with), which may be customized by user.
+            pub fn parse_args_with_parser<'inv, 'p, Set, Ser, P>(mut parser: cote::prelude::Parser<'inv, Set, Ser>, args: cote::prelude::Args, policy: &'p mut P)
+                -> cote::Result<cote::prelude::CoteRes<&'p mut P, P>> where #where_clause {
                 // call on parser or policy set by user
                 #(#method_calls)* // todo! do we need apply this in sub handler ?
 
@@ -511,6 +1020,7 @@ impl<'a> CoteGenerator<'a> {
                 rctx.add_name(#parser_name);
                 parser.set_rctx(rctx);
 
+                let args = Self::inject_default_sub(parser.optset(), args);
                 let ret = cote::prelude::PolicyParser::parse_policy(&mut parser, args, policy);
                 let mut rctx = parser.take_rctx()?;
 
@@ -518,7 +1028,7 @@ impl<'a> CoteGenerator<'a> {
                 if !rctx.display_help() {
                     Self::sync_rctx::<Set, _>(&mut rctx, &ret, parser.optset(), false)?;
                     if rctx.display_help() {
-                        rctx.set_help_context(Self::new_help_context());
+                        #set_help_context
                     }
                 }
 
@@ -550,50 +1060,7 @@ impl<'a> CoteGenerator<'a> {
                 Ok(cote::prelude::CoteRes{ ret, parser, policy })
             }
 
-            pub fn parse(args: cote::prelude::Args) -> cote::Result<Self>
-            where #fetch_code {
-                let cote::prelude::CoteRes { mut ret, mut parser, .. } = Self::parse_args(args)?;
-
-                if let Some(mut error) = ret.take_failure() {
-                    let mut rctx = parser.take_rctx()?;
-
-                    if let Some(chain_error) = rctx.chain_error() {
-                        error = error.cause_by(chain_error);
-                    }
-                    let mut failed_info = rctx.take_failed_info();
-                    let (command, ret) = failed_info.last_mut()
-                        .map(|v|(Some(v.name.as_str()), &mut v.retval))
-                        .unwrap_or((None, &mut ret));
-                    let e = {
-                        let ctx = ret.take_ctx();
-                        let args = ctx.orig[1..].iter()
-                                    .map(|v|std::path::Path::new(v).display())
-                                    .map(|v|v.to_string())
-                                    .collect::<Vec<_>>()
-                                    .join(", ");
-                        let guess = ctx.guess;
-                        let failed_msg = if let Some(command) = command {
-                            format!("Parsing command `{}`", command)
-                        }
-                        else {
-                            format!("Parsing arguments `{}`", args)
-                        };
-                        let guess = if let Some(guess) = guess {
-                            format!("{:?}", guess)
-                        } else {
-                            "None".to_owned()
-                        };
-
-                        // return failure with more detail error message
-                        cote::prelude::raise_failure!("{} failed: {}", failed_msg, guess).cause_by(error)
-                    };
-
-                    Err(e)
-                }
-                else {
-                    <Self as cote::ExtractFromSetDerive::<cote::prelude::ASet>>::try_extract(parser.optset_mut())
-                }
-            }
+            #parse
 
             pub fn parse_env_args_with<'inv, Set, Ser, P>(policy: &mut P) -> cote::Result<cote::prelude::CoteRes<&mut P, P>>
                 where #where_clause {
@@ -609,9 +1076,123 @@ impl<'a> CoteGenerator<'a> {
             where #fetch_code {
                 Self::parse(cote::prelude::Args::from_env())
             }
+
+            #(#is_set)*
+        })
+    }
+
+    /// Generate the `parse` function, and `parse_with_policy` if the policy is `runtime`.
+    pub fn gen_parse(&self, fetch_code: &TokenStream) -> syn::Result<TokenStream> {
+        let extract = quote! {
+            if let Some(mut error) = ret.take_failure() {
+                let mut rctx = parser.take_rctx()?;
+
+                if let Some(chain_error) = rctx.chain_error() {
+                    error = error.cause_by(chain_error);
+                }
+                let mut failed_info = rctx.take_failed_info();
+                let (command, ret) = failed_info.last_mut()
+                    .map(|v|(Some(v.name.as_str()), &mut v.retval))
+                    .unwrap_or((None, &mut ret));
+                let e = {
+                    let ctx = ret.take_ctx();
+                    let args = ctx.orig[1..].iter()
+                                .map(|v|std::path::Path::new(v).display())
+                                .map(|v|v.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                    let guess = ctx.guess;
+                    let guess = if let Some(guess) = guess {
+                        format!("{:?}", guess)
+                    } else {
+                        "None".to_owned()
+                    };
+
+                    // return failure with more detail error message,
+                    // the kind of original error is kept in the cause chain
+                    if let Some(command) = command {
+                        cote::Error::sp_sub_command(command, guess).cause_by(error)
+                    }
+                    else {
+                        cote::prelude::raise_failure!("Parsing arguments `{}` failed: {}", args, guess).cause_by(error)
+                    }
+                };
+
+                Err(e)
+            }
+            else {
+                <Self as cote::ExtractFromSetDerive::<cote::prelude::ASet>>::try_extract(parser.optset_mut())
+            }
+        };
+
+        Ok(if self.is_runtime_policy() {
+            let parse_with = |policy_ty: TokenStream| {
+                quote! {
+                    {
+                        let mut policy = <#policy_ty>::default();
+
+                        Self::apply_policy_settings(&mut policy);
+                        let cote::prelude::CoteRes { ret, parser, .. } = Self::parse_args_with_parser(parser, args, &mut policy)?;
+
+                        (ret, parser)
+                    }
+                }
+            };
+            let fwd = parse_with(Utils::gen_policy_default_ty(POLICY_FWD).unwrap());
+            let pre = parse_with(Utils::gen_policy_default_ty(POLICY_PRE).unwrap());
+            let delay = parse_with(Utils::gen_policy_default_ty(POLICY_DELAY).unwrap());
+
+            quote! {
+                /// Parsing the `args` with the policy selected by [`PolicyKind::select`](cote::prelude::PolicyKind::select).
+                pub fn parse(args: cote::prelude::Args) -> cote::Result<Self>
+                where #fetch_code {
+                    let parser = Self::into_parser()?;
+                    let policy = cote::prelude::PolicyKind::select(parser.optset(), &args);
+
+                    Self::parse_with_parser_policy(parser, args, policy)
+                }
+
+                /// Parsing the `args` with the built-in policy specified by `policy`.
+                pub fn parse_with_policy(args: cote::prelude::Args, policy: cote::prelude::PolicyKind) -> cote::Result<Self>
+                where #fetch_code {
+                    Self::parse_with_parser_policy(Self::into_parser()?, args, policy)
+                }
+
+                #[doc(hidden)]
+                pub fn parse_with_parser_policy<'inv>(
+                    parser: cote::prelude::Parser<'inv, cote::prelude::ASet, cote::prelude::ASer>,
+                    args: cote::prelude::Args,
+                    policy: cote::prelude::PolicyKind
+                ) -> cote::Result<Self>
+                where #fetch_code {
+                    let (mut ret, mut parser) = match policy {
+                        cote::prelude::PolicyKind::Fwd => #fwd,
+                        cote::prelude::PolicyKind::Pre => #pre,
+                        cote::prelude::PolicyKind::Delay => #delay,
+                    };
+
+                    #extract
+                }
+            }
+        } else {
+            quote! {
+                pub fn parse(args: cote::prelude::Args) -> cote::Result<Self>
+                where #fetch_code {
+                    let cote::prelude::CoteRes { mut ret, mut parser, .. } = Self::parse_args(args)?;
+
+                    #extract
+                }
+            }
         })
     }
 
+    pub fn is_runtime_policy(&self) -> bool {
+        self.configs
+            .find_cfg(CoteKind::Policy)
+            .map(|v| v.value().to_token_stream().to_string() == POLICY_RUNTIME)
+            .unwrap_or_default()
+    }
+
     /// Insert sub parsers to main parser.
     pub fn gen_sub_parsers(&self) -> syn::Result<Option<TokenStream>> {
         let mut sub_parsers = vec![];
@@ -641,6 +1222,9 @@ impl<'a> CoteGenerator<'a> {
             Utils::gen_policy_ty
         };
 
+        // `runtime` using the default policy type in `parse_args` and `into_policy`
+        let policy_cfg = policy_cfg.filter(|_| !self.is_runtime_policy());
+
         Ok(if let Some(policy_cfg) = policy_cfg {
             let policy_name = policy_cfg.value().to_token_stream().to_string();
             let policy_ty = policy_cfg.value();
@@ -699,6 +1283,19 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
+    /// Generate the code add profiles, such as `profile("release", [("--opt", "3")])`.
+    pub fn gen_profiles(&self) -> Vec<TokenStream> {
+        self.configs
+            .find_values(CoteKind::Profile)
+            .into_iter()
+            .map(|value| {
+                quote! {
+                    cote::prelude::Set::add_profile(parser, #value)?;
+                }
+            })
+            .collect()
+    }
+
     pub fn gen_method_call(&self) -> syn::Result<Vec<TokenStream>> {
         let span = self.orig_ident.span();
         let mut ret = vec![];
@@ -757,6 +1354,7 @@ impl<'a> CoteGenerator<'a> {
 }
 
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum FieldGenerator<'a> {
     Sub(SubGenerator<'a>),
     Arg(ArgGenerator<'a>),
@@ -799,10 +1397,52 @@ impl<'a> FieldGenerator<'a> {
         }
     }
 
-    pub fn gen_try_extract(&mut self) -> syn::Result<(bool, TokenStream)> {
+    pub fn gen_try_extract(&mut self, in_nested: bool) -> syn::Result<(bool, TokenStream)> {
         match self {
             FieldGenerator::Sub(sg) => sg.gen_try_extract(),
-            FieldGenerator::Arg(ag) => ag.gen_try_extract(),
+            FieldGenerator::Arg(ag) => ag.gen_try_extract(in_nested),
+        }
+    }
+
+    pub fn gen_update_from(&mut self, in_nested: bool) -> syn::Result<Option<TokenStream>> {
+        match self {
+            FieldGenerator::Sub(sg) => sg.gen_update_from(),
+            FieldGenerator::Arg(ag) => ag.gen_update_from(in_nested).map(Some),
+        }
+    }
+
+    pub fn gen_bind(&self) -> Option<(TokenStream, TokenStream)> {
+        match self {
+            FieldGenerator::Sub(_) => None,
+            FieldGenerator::Arg(ag) => ag.gen_bind(),
+        }
+    }
+
+    pub fn gen_long_help(&self) -> Option<TokenStream> {
+        match self {
+            FieldGenerator::Sub(sg) => sg.gen_long_help(),
+            FieldGenerator::Arg(ag) => ag.gen_long_help(),
+        }
+    }
+
+    /// Generate the `is_set_<field>` function check if the value of field is supplied by user.
+    pub fn gen_is_set(&self) -> TokenStream {
+        let (uid, member) = match self {
+            FieldGenerator::Sub(sg) => (sg.uid(), sg.orig_ident()),
+            FieldGenerator::Arg(ag) => (ag.uid(), ag.orig_ident()),
+        };
+        let uid_literal = Utils::id2uid_literal(uid);
+        let name = match member {
+            Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_owned(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let method = Ident::new(&format!("is_set_{}", name), member.span());
+
+        quote! {
+            #[doc=concat!("Return true if the value of field `", #name, "` is supplied by user, see [`is_user_set`](cote::prelude::Set::is_user_set).")]
+            pub fn #method<Set: cote::prelude::Set>(set: &Set) -> cote::Result<bool> {
+                cote::prelude::Set::is_user_set(set, #uid_literal)
+            }
         }
     }
 }
@@ -34,6 +34,12 @@ impl<'a> InferGenerator<'a> {
         let (impl_inf, type_inf, where_inf) = self.generics.split_for_impl();
         let mut codes = vec![];
 
+        if self.configs.has_cfg(InferKind::Choices) && self.configs.has_cfg(InferKind::Tweak) {
+            return Err(crate::error(
+                ident.span(),
+                "`CoteOpt` error: `choices` can not using with `tweak`",
+            ));
+        }
         for config in self.configs.iter() {
             let value = config.value();
 
@@ -131,6 +137,17 @@ impl<'a> InferGenerator<'a> {
                         Ok(())
                     }
                 },
+                InferKind::Choices => quote! {
+                    fn infer_tweak_info<C>(cfg: &mut C) -> cote::Result<()>
+                    where
+                        Self: Sized + 'static,
+                        Self::Val: cote::prelude::RawValParser,
+                        C: cote::prelude::ConfigValue + Default,
+                    {
+                        <Self as cote::prelude::ValueEnum>::tweak_choices(cfg);
+                        Ok(())
+                    }
+                },
                 InferKind::Fill => quote! {
                     fn infer_fill_info<C>(cfg: &mut C) -> cote::Result<()>
                     where
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{spanned::Spanned, Field, GenericArgument, Ident, PathArguments, Type};
+use syn::{spanned::Spanned, Field, GenericArgument, Ident, Member, PathArguments, Type};
 
 use crate::{config::SubKind, error};
 
@@ -16,7 +16,13 @@ pub struct SubGenerator<'a> {
 
     uid_ident: Ident,
 
-    inner_ty: Type, // type without option, sub is always wrapped with Option
+    inner_ty: Type, // type without option, sub is always wrapped with Option or Vec
+
+    repeat: bool, // the sub command can be invoked repeatedly if it is wrapped with Vec
+
+    long_help: bool,
+
+    fallback_pos: bool,
 
     config: FieldCfg<'a, SubKind>,
 }
@@ -26,11 +32,17 @@ impl<'a> SubGenerator<'a> {
         let config = FieldCfg::new(id, field, super::AttrKind::Sub)?;
         let ident = Utils::id2opt_ident(id, field.span());
         let uid_ident = Utils::id2opt_uid_ident(id, field.span());
-        let inner_ty = Self::gen_inner_ty(&field.ty)?;
-        let name = config
-            .find_value(SubKind::Name)
-            .map(|v| v.to_token_stream())
-            .unwrap_or_else(|| config.ident().to_string().to_token_stream());
+        let (inner_ty, repeat) = Self::gen_inner_ty(&field.ty)?;
+        let name = match (config.find_value(SubKind::Name), config.ident_str()) {
+            (Some(name), _) => name.to_token_stream(),
+            (None, Some(ident_str)) => ident_str.to_token_stream(),
+            (None, None) => {
+                return Err(error(
+                    field.span(),
+                    "field of tuple struct need a name, such as `#[sub(name = \"foo\")]`",
+                ))
+            }
+        };
 
         Ok(Self {
             index,
@@ -38,7 +50,10 @@ impl<'a> SubGenerator<'a> {
             config,
             ident,
             inner_ty,
+            repeat,
             uid_ident,
+            long_help: false,
+            fallback_pos: false,
         })
     }
 
@@ -50,14 +65,37 @@ impl<'a> SubGenerator<'a> {
         self.config.ty()
     }
 
-    pub fn orig_ident(&self) -> &'a Ident {
-        self.config.ident()
+    pub fn orig_ident(&self) -> &Member {
+        self.config.member()
     }
 
     pub fn sub_index(&self) -> usize {
         self.index
     }
 
+    // split the doc comments into short help and long help
+    pub fn set_long_help(&mut self, long_help: bool) -> &mut Self {
+        self.long_help = long_help;
+        self
+    }
+
+    // the sub command is not force required if it can fallback to positional
+    pub fn set_fallback_pos(&mut self, fallback_pos: bool) -> &mut Self {
+        self.fallback_pos = fallback_pos;
+        self
+    }
+
+    pub fn gen_long_help(&self) -> Option<TokenStream> {
+        let uid_literal = Utils::id2uid_literal(self.uid());
+
+        if !self.long_help || self.config.has_cfg(SubKind::Help) {
+            return None;
+        }
+        self.config
+            .collect_long_help_msgs()
+            .map(|help| quote! { .with_long_help(#uid_literal, #help) })
+    }
+
     pub fn name(&self) -> &TokenStream {
         &self.name
     }
@@ -74,6 +112,10 @@ impl<'a> SubGenerator<'a> {
         &self.inner_ty
     }
 
+    pub fn repeat(&self) -> bool {
+        self.repeat
+    }
+
     pub fn gen_opt_update(&self, help_uid: Option<u64>) -> syn::Result<OptUpdate> {
         let c = self.gen_opt_create()?;
         let i = self.gen_opt_insert()?;
@@ -114,6 +156,13 @@ impl<'a> SubGenerator<'a> {
             }
         });
 
+        let set_help_context = Utils::gen_help_context(quote! { <#inner_ty>::new_help_context() });
+
+        if self.repeat() {
+            return self
+                .gen_repeat_handler(pass_help_to, set_help_context)
+                .map(Some);
+        }
         Ok(Some(quote! {
             parser.entry(#uid_ident)?.on(
                 move |set: &mut cote::prelude::Parser<'inv, Set, Ser>, ser: &mut Ser, ctx: &cote::prelude::Ctx| {
@@ -129,12 +178,17 @@ impl<'a> SubGenerator<'a> {
 
                     let args = cote::prelude::Args::from(args);
                     let parser = set.parser_mut(#sub_index)?;
+                    let args = <#inner_ty>::inject_default_sub(parser.optset(), args);
                     let mut policy = <#policy_ty>::default();
                     let name = parser.name().clone();
 
                     // setup running ctx
                     parser.set_rctx(ser.sve_take_val::<cote::prelude::RunningCtx>()?);
-                    parser.rctx_mut()?.add_name(name);
+
+                    let offset = parser.rctx()?.offset();
+
+                    // the sub command is removed, shift the index of sub parser
+                    parser.rctx_mut()?.add_name(name).set_offset(offset + index);
 
                     // apply policy settings
                     <#inner_ty>::apply_policy_settings(&mut policy);
@@ -142,12 +196,21 @@ impl<'a> SubGenerator<'a> {
                     // parsing
                     let ret = cote::prelude::PolicyParser::parse_policy(parser, args, &mut policy);
                     let mut rctx = parser.take_rctx()?;
+                    // map the index in failure to the top level parser
+                    let ret = ret.map(|mut ret| {
+                        if let Some(failure) = ret.take_failure() {
+                            ret.set_failure(rctx.orig_failure(&failure));
+                        }
+                        ret
+                    });
+
+                    rctx.set_offset(offset);
 
                     // check if we need display help for sub parser
                     if !rctx.display_help() {
                         <#inner_ty>::sync_rctx(&mut rctx, &ret, parser.optset(), true)?;
                         if rctx.display_help() {
-                            rctx.set_help_context(<#inner_ty>::new_help_context());
+                            #set_help_context
                         }
                         else {
                             rctx.pop_name(); // pop current name if not need display help
@@ -175,6 +238,119 @@ impl<'a> SubGenerator<'a> {
         }))
     }
 
+    /// Split the arguments by the sub command, invoke the sub parser for every part of arguments,
+    /// then collect the results into a [`Vec`].
+    pub fn gen_repeat_handler(
+        &self,
+        pass_help_to: Option<TokenStream>,
+        set_help_context: TokenStream,
+    ) -> syn::Result<TokenStream> {
+        let inner_ty = self.inner_ty();
+        let policy_ty = self.gen_sub_policy_ty()?;
+        let uid_ident = self.uid_ident();
+        let sub_index = syn::Index::from(self.sub_index());
+
+        Ok(quote! {
+            parser.entry(#uid_ident)?.on(
+                move |set: &mut cote::prelude::Parser<'inv, Set, Ser>, ser: &mut Ser, ctx: &cote::prelude::Ctx| {
+                    let index = ctx.idx()?;
+                    let mut args: Vec<_> = ctx.args().iter().map(|v|v.to_os_string()).collect();
+                    let cmd = args.remove(index);
+                    let cmd = cmd.to_str();
+                    let cmd = cmd.ok_or_else(|| cote::prelude::raise_error!("can not convert `{:?}` to &str", cmd))?;
+
+                    // process help pass
+                    // if we are jump into current handler, then we need pass original help option
+                    #pass_help_to
+
+                    // split the arguments by the sub command, the start is the index of sub command
+                    let (prefix, rest) = args.split_at(index);
+                    let mut chunks = vec![];
+                    let mut chunk = prefix.to_vec();
+                    let mut start = index;
+
+                    for (idx, arg) in rest.iter().enumerate() {
+                        if arg.to_str() == Some(cmd) {
+                            chunks.push((start, std::mem::replace(&mut chunk, prefix.to_vec())));
+                            start = index + idx + 1;
+                        } else {
+                            chunk.push(arg.clone());
+                        }
+                    }
+                    chunks.push((start, chunk));
+
+                    let parser = set.parser_mut(#sub_index)?;
+                    let mut values = vec![];
+
+                    for (start, args) in chunks {
+                        let args = cote::prelude::Args::from(args);
+                        let args = <#inner_ty>::inject_default_sub(parser.optset(), args);
+                        let mut policy = <#policy_ty>::default();
+                        let name = parser.name().clone();
+
+                        // clear the matched state of previous invocation
+                        parser.reset()?;
+                        // setup running ctx
+                        parser.set_rctx(ser.sve_take_val::<cote::prelude::RunningCtx>()?);
+
+                        let offset = parser.rctx()?.offset();
+
+                        // the sub command is removed, shift the index of sub parser
+                        parser.rctx_mut()?.add_name(name).set_offset(offset + start);
+
+                        // apply policy settings
+                        <#inner_ty>::apply_policy_settings(&mut policy);
+
+                        // parsing
+                        let ret = cote::prelude::PolicyParser::parse_policy(parser, args, &mut policy);
+                        let mut rctx = parser.take_rctx()?;
+                        // map the index in failure to the top level parser
+                        let ret = ret.map(|mut ret| {
+                            if let Some(failure) = ret.take_failure() {
+                                ret.set_failure(rctx.orig_failure(&failure));
+                            }
+                            ret
+                        });
+
+                        rctx.set_offset(offset);
+
+                        // check if we need display help for sub parser
+                        if !rctx.display_help() {
+                            <#inner_ty>::sync_rctx(&mut rctx, &ret, parser.optset(), true)?;
+                            if rctx.display_help() {
+                                #set_help_context
+                            }
+                            else {
+                                rctx.pop_name(); // pop current name if not need display help
+                            }
+                        }
+                        // indicate we have accessed sub parser
+                        rctx.set_sub_parser(true);
+                        // insert back to owned parser
+                        ser.sve_insert(rctx);
+
+                        let ret = ret?;
+
+                        if !ret.status() {
+                            ser.sve_val_mut::<cote::prelude::RunningCtx>()?
+                                .add_failed_info(cote::prelude::FailedInfo{ name: cmd.to_owned(), retval: ret });
+                            return Ok(None);
+                        }
+                        ser.sve_val_mut::<cote::prelude::RunningCtx>()?.clear_failed_info();
+                        match <#inner_ty as cote::ExtractFromSetDerive::<Set>>::try_extract(parser.optset_mut()) {
+                            Ok(value) => values.push(value),
+                            Err(_) => return Ok(None),
+                        }
+                        if ser.sve_val::<cote::prelude::RunningCtx>()?.display_help() {
+                            break;
+                        }
+                    }
+                    Ok(Some(values))
+                }
+            );
+        })
+    }
+
     pub fn gen_opt_create(&self) -> syn::Result<TokenStream> {
         let field_span = self.ident().span();
         let field_cfg = &self.config;
@@ -208,10 +384,19 @@ impl<'a> SubGenerator<'a> {
                 _ => {}
             }
         }
+        if self.fallback_pos && !field_cfg.has_cfg(SubKind::Force) {
+            codes.push(SubKind::Force.simple(&cfg_ident, &false.to_token_stream())?);
+        }
         if let Some(help) = field_cfg
             .find_value(SubKind::Help)
             .map(|v| quote! { String::from(#v.trim()) })
-            .or_else(|| field_cfg.collect_help_msgs())
+            .or_else(|| {
+                if self.long_help {
+                    field_cfg.collect_short_help_msgs()
+                } else {
+                    field_cfg.collect_help_msgs()
+                }
+            })
         {
             codes.push(SubKind::Help.simple(&cfg_ident, &help)?);
         }
@@ -225,10 +410,27 @@ impl<'a> SubGenerator<'a> {
         let uid_literal = Utils::id2uid_literal(self.uid());
         let ident = self.orig_ident();
 
-        if is_refopt && is_mutopt {
+        if self.repeat() && (is_refopt || is_mutopt) {
             Err(error(
                 ident.span(),
-                format!("Can not set both mut and ref on field `{}`", ident),
+                format!(
+                    "Can not set mut or ref on repeatable sub command `{}`",
+                    quote!(#ident)
+                ),
+            ))
+        } else if self.repeat() {
+            let inner_ty = self.inner_ty();
+
+            Ok((
+                false,
+                quote! {
+                    #ident: cote::prelude::fetch_uid_impl::<Vec<#inner_ty>, _>(#uid_literal, set).unwrap_or_default()
+                },
+            ))
+        } else if is_refopt && is_mutopt {
+            Err(error(
+                ident.span(),
+                format!("Can not set both mut and ref on field `{}`", quote!(#ident)),
             ))
         } else if is_refopt {
             Ok((
@@ -247,15 +449,41 @@ impl<'a> SubGenerator<'a> {
         }
     }
 
-    pub fn gen_inner_ty(ty: &Type) -> syn::Result<Type> {
+    /// Overwrite the sub command field only if the sub command matched in current parsing,
+    /// the field of `ref` sub command is not updated.
+    pub fn gen_update_from(&self) -> syn::Result<Option<TokenStream>> {
+        let uid_literal = Utils::id2uid_literal(self.uid());
+        let ident = self.orig_ident();
+
+        if self.config.find_cfg(SubKind::Ref).is_some() {
+            Ok(None)
+        } else if self.repeat() {
+            let inner_ty = self.inner_ty();
+
+            Ok(Some(quote! {
+                if cote::prelude::Opt::matched(cote::prelude::SetExt::opt(set, #uid_literal)?) {
+                    self.#ident = cote::prelude::fetch_uid_impl::<Vec<#inner_ty>, _>(#uid_literal, set).unwrap_or_default();
+                }
+            }))
+        } else {
+            Ok(Some(quote! {
+                if cote::prelude::Opt::matched(cote::prelude::SetExt::opt(set, #uid_literal)?) {
+                    self.#ident = cote::prelude::fetch_uid_impl(#uid_literal, set).ok();
+                }
+            }))
+        }
+    }
+
+    /// Return the inner type and whether the sub command is repeatable.
+    pub fn gen_inner_ty(ty: &Type) -> syn::Result<(Type, bool)> {
         if let Type::Path(path) = ty {
             if let Some(segment) = path.path.segments.last() {
                 let ident_str = segment.ident.to_string();
 
-                if ident_str == "Option" {
+                if ident_str == "Option" || ident_str == "Vec" {
                     if let PathArguments::AngleBracketed(ab) = &segment.arguments {
                         if let Some(GenericArgument::Type(next_ty)) = ab.args.first().as_ref() {
-                            return Ok(next_ty.clone());
+                            return Ok((next_ty.clone(), ident_str == "Vec"));
                         }
                     }
                 }
@@ -263,7 +491,7 @@ impl<'a> SubGenerator<'a> {
         }
         Err(error(
             ty,
-            "`sub` configuration only support `Option<T>`".to_owned(),
+            "`sub` configuration only support `Option<T>` or `Vec<T>`".to_owned(),
         ))
     }
 
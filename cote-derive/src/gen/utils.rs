@@ -2,8 +2,8 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
     parse_quote, spanned::Spanned, Attribute, Field, GenericArgument, Generics, Ident,
-    ImplGenerics, Lifetime, LifetimeParam, Lit, PathArguments, Type, TypeGenerics, TypeParam,
-    WhereClause,
+    ImplGenerics, Index, Lifetime, LifetimeParam, Lit, Member, PathArguments, Type, TypeGenerics,
+    TypeParam, WhereClause,
 };
 
 use crate::{
@@ -20,6 +20,7 @@ pub const CONFIG_DOC: &str = "doc";
 pub const POLICY_PRE: &str = "pre";
 pub const POLICY_FWD: &str = "fwd";
 pub const POLICY_DELAY: &str = "delay";
+pub const POLICY_RUNTIME: &str = "runtime";
 pub const HELP_OPTION: &str = "--help;-h=b: Display help message";
 
 #[derive(Debug, Clone, Copy)]
@@ -99,7 +100,7 @@ pub struct FieldCfg<'a, T> {
 
     kind: AttrKind,
 
-    ident: &'a Ident,
+    member: Member,
 
     docs: Vec<Lit>,
 
@@ -109,8 +110,14 @@ pub struct FieldCfg<'a, T> {
 impl<'a, T: config::Kind + PartialEq> FieldCfg<'a, T> {
     pub fn new(id: u64, field: &'a Field, kind: AttrKind) -> syn::Result<Self> {
         let ty = &field.ty;
-        let ident = field.ident.as_ref();
-        let ident = ident.ok_or_else(|| error(field.span(), "Not support unnamed field"))?;
+        // the unnamed field of tuple struct is addressed by its position
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index {
+                index: id as u32,
+                span: field.span(),
+            }),
+        };
         let configs = Configs::<T>::parse_attrs(kind.name(), &field.attrs);
         let docs = Self::filter_comment_doc(&field.attrs);
 
@@ -118,7 +125,7 @@ impl<'a, T: config::Kind + PartialEq> FieldCfg<'a, T> {
             id,
             ty,
             kind,
-            ident,
+            member,
             configs,
             docs,
         })
@@ -180,8 +187,16 @@ impl<'a, T: config::Kind + PartialEq> FieldCfg<'a, T> {
         self.ty
     }
 
-    pub fn ident(&self) -> &'a Ident {
-        self.ident
+    pub fn member(&self) -> &Member {
+        &self.member
+    }
+
+    /// Return the name of field, or [`None`] if the field is unnamed.
+    pub fn ident_str(&self) -> Option<String> {
+        match &self.member {
+            Member::Named(ident) => Some(ident.to_string()),
+            Member::Unnamed(_) => None,
+        }
     }
 
     pub fn has_cfg(&self, kind: T) -> bool {
@@ -207,6 +222,26 @@ impl<'a, T: config::Kind + PartialEq> FieldCfg<'a, T> {
             })
         }
     }
+
+    /// Using the first line of doc comments as help message.
+    pub fn collect_short_help_msgs(&self) -> Option<TokenStream> {
+        self.docs()
+            .first()
+            .map(|doc| quote! { String::from(#doc.trim()) })
+    }
+
+    /// Join all the non-empty lines of doc comments, return [`None`] if it is same as short help.
+    pub fn collect_long_help_msgs(&self) -> Option<TokenStream> {
+        if self.docs().len() > 1 {
+            let docs = self.docs.iter();
+
+            Some(quote! {
+                [ #(#docs),* ].into_iter().map(|v|v.trim()).filter(|v|!v.is_empty()).collect::<Vec<_>>().join(" ")
+            })
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -354,6 +389,23 @@ impl Utils {
     }
 
     // variable name: `ret`, `rctx`, and `parser`
+    /// Create the help context, display long help if the help option is a long option, i.e. `--help`.
+    pub fn gen_help_context(new_help_context: TokenStream) -> TokenStream {
+        quote! {
+            let mut help_context = #new_help_context;
+
+            if let Ok(help_arg) = cote::prelude::ServicesValExt::sve_val::<std::ffi::OsString>(parser.service()) {
+                help_context.set_long(help_arg.to_str().map(|v| v.starts_with("--")).unwrap_or_default());
+            }
+            if let Ok(topic) = cote::prelude::ServicesValExt::sve_val::<cote::prelude::HelpTopic>(parser.service()) {
+                if help_context.topic(&topic.0).is_some() {
+                    help_context.set_show_topic(Some(topic.0.clone()));
+                }
+            }
+            rctx.set_help_context(help_context);
+        }
+    }
+
     pub fn gen_sync_ret(
         has_sub: bool,
         enable_abort: bool,
@@ -444,7 +496,7 @@ impl GenericsModifier {
     pub fn split_for_impl_ipd(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_ipd(used);
         self.0.split_for_impl()
     }
@@ -469,7 +521,7 @@ impl GenericsModifier {
     pub fn split_for_impl_esd(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_esd(used);
         self.0.split_for_impl()
     }
@@ -490,7 +542,7 @@ impl GenericsModifier {
     pub fn split_for_impl_pi(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_pi(used);
         self.0.split_for_impl()
     }
@@ -515,7 +567,7 @@ impl GenericsModifier {
     pub fn split_for_impl_fetch(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_fetch(used);
         self.0.split_for_impl()
     }
@@ -51,8 +51,29 @@ impl<'a> ValueGenerator<'a> {
         let map_raw_cfg = self.configs.find_value(ValueKind::MapRaw);
         let map_str_cfg = self.configs.find_value(ValueKind::MapStr);
         let igcase = self.configs.has_cfg(ValueKind::IgCase);
-        let impl_code = if let (Some(forward), Some(map)) = (forward_cfg, map_cfg) {
-            if map_raw_cfg.is_some() || map_str_cfg.is_some() || igcase {
+        let fromstr = self.configs.has_cfg(ValueKind::FromStr);
+        let lookup_cfg = self.configs.find_value(ValueKind::Lookup);
+        // the names of variants, only available if matching the variants by name
+        let mut variants = None;
+        let impl_code = if fromstr {
+            if forward_cfg.is_some()
+                || lookup_cfg.is_some()
+                || map_cfg.is_some()
+                || map_raw_cfg.is_some()
+                || map_str_cfg.is_some()
+                || igcase
+            {
+                return Err(error(
+                    span,
+                    "`CoteVal` error: `fromstr` can not using with other configurations",
+                ));
+            }
+            // parsing value using the FromStr implementation
+            quote! {
+                cote::prelude::raw2fromstr(raw, ctx)
+            }
+        } else if let (Some(forward), Some(map)) = (forward_cfg, map_cfg) {
+            if map_raw_cfg.is_some() || map_str_cfg.is_some() || igcase || lookup_cfg.is_some() {
                 return Err(error(
                     span,
                     "`CoteVal` error: `forward` can only using pair with `map`",
@@ -77,6 +98,12 @@ impl<'a> ValueGenerator<'a> {
             };
             let ty_name = ident.to_string();
 
+            if lookup_cfg.is_some() && (map_raw_cfg.is_some() || map_str_cfg.is_some()) {
+                return Err(error(
+                    span,
+                    "`CoteVal` error: `lookup` can not using with `mapraw` or `mapstr`",
+                ));
+            }
             if let Some(value) = map_raw_cfg {
                 // map raw value to Self
                 quote! { #value(raw, ctx) }
@@ -85,6 +112,7 @@ impl<'a> ValueGenerator<'a> {
                 quote! { #value( #str_convert ) }
             } else {
                 let mut branches = vec![];
+                let mut names = vec![];
 
                 for (var, config) in self.variants.iter().zip(self.var_configs.iter()) {
                     let var_ident = &var.ident;
@@ -100,23 +128,63 @@ impl<'a> ValueGenerator<'a> {
                     let alias_cfg = config.find_values(ValueKind::Alias);
 
                     branches.push(quote! {
-                        #name_cfg #(| #alias_cfg)* => Ok(#ident::#var_ident),
-                    })
+                        #name_cfg #(| #alias_cfg)* => Some(#ident::#var_ident),
+                    });
+                    names.push(name_cfg);
                 }
+                variants = (!self.variants.is_empty()).then_some(names);
+
+                // consult the lookup if the name not match any variant,
+                // the name returned by lookup is matched again
+                let lookup = lookup_cfg.map(|lookup| {
+                    let convert = igcase.then(|| quote! { .map(|v| v.to_lowercase()) });
+
+                    quote! {
+                        .or_else(|| {
+                            #lookup(#ty_name, name.as_ref())
+                                .map(Into::<String>::into)
+                                #convert
+                                .and_then(|v| mat(&v))
+                        })
+                    }
+                });
 
                 quote! {
                     let name = #str_convert;
                     let uid = ctx.uid()?;
-
-                    match name.as_ref() {
+                    let mat = |name: &str| match name {
                         #(#branches)*
 
-                        _ => Err(cote::prelude::raise_failure!("Unknow value for enum type `{}`: {}", #ty_name, name).with_uid(uid)),
-                    }
+                        _ => None,
+                    };
+
+                    mat(name.as_ref())
+                        #lookup
+                        .ok_or_else(|| cote::prelude::raise_failure!("Unknow value for enum type `{}`: {}", #ty_name, name).with_uid(uid))
                 }
             }
         };
 
+        let value_enum_impl = variants.map(|names| {
+            let ignore_case = igcase.then(|| {
+                quote! {
+                    fn ignore_case() -> bool {
+                        true
+                    }
+                }
+            });
+
+            quote! {
+                impl cote::prelude::ValueEnum for #ident {
+                    fn variants() -> &'static [&'static str] {
+                        &[#(#names),*]
+                    }
+
+                    #ignore_case
+                }
+            }
+        });
+
         Ok(quote! {
             impl cote::prelude::RawValParser for #ident {
                 type Error = cote::Error;
@@ -125,6 +193,8 @@ impl<'a> ValueGenerator<'a> {
                     #impl_code
                 }
             }
+
+            #value_enum_impl
         })
     }
 
@@ -13,7 +13,7 @@ use syn::Token;
 
 use crate::error;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum Value {
     Literal(Lit),
 
@@ -21,6 +21,7 @@ pub enum Value {
 
     Call(Vec<Expr>),
 
+    #[default]
     Null,
 }
 
@@ -54,12 +55,6 @@ impl ToTokens for Value {
     }
 }
 
-impl Default for Value {
-    fn default() -> Self {
-        Self::Null
-    }
-}
-
 impl Parse for Value {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(Token![=]) {
@@ -13,6 +13,7 @@
 //!     2. [Positionals](#positionals)
 //!     3. [Command Flags](#command-flags)
 //!     4. [Sub Commands](#sub-commands)
+//!     5. [Tuple Struct](#tuple-struct)
 //! 4. [Configurating Options, Command flags and Positionals](#configurating-options-command-flags-and-positionals)
 //!     1. [Configurating the name and alias](#configurating-the-name-and-alias)
 //!     2. [Configurating the hint, help and default value](#configurating-the-hint-help-and-default-value)
@@ -125,6 +126,23 @@
 //! Run with RUST_BACKTRACE=full to include source snippets.
 //! ```
 //!
+//! ### Customize the parser
+//!
+//! Use `into_parser` or `apply_to` get the parser generated by `Cote`, you can add more options or
+//! change the validator before parsing, then parsing the arguments with `parse_args_with_parser`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/23_parser.rs")]
+//! ```
+//!
+//! When parsing with the parser directly, use `ok_or_print_help` check the result:
+//! it displays the help message if the help option is set or parsing failed,
+//! and returns the failure with the arguments.
+//!
+//! ```rust
+#![doc = include_str!("../tests/40_ok_or_print_help.rs")]
+//! ```
+//!
 //! ## Configurating Struct
 //!
 //! ### Configurating Policy
@@ -186,6 +204,13 @@
 //! The foot message display in help message
 //! ```
 //!
+//! The generated `help_context` returns the help settings of struct,
+//! modify it at runtime and display the help with [`display_help_ctx`](crate::prelude::Parser::display_help_ctx).
+//!
+//! ```rust
+#![doc = include_str!("../tests/59_help_context.rs")]
+//! ```
+//!
 //! ### Configurating User Style
 //!
 //! The option styles support by default are:
@@ -254,6 +279,13 @@
 #![doc = include_str!("../examples/07_positional_demo.rs")]
 //! ```
 //!
+//! The `make` style assignments such as `CC=gcc` can be collected into a `BTreeMap<String, String>` field,
+//! they are removed before matching the positionals, see [`Assign`](crate::prelude::Assign).
+//!
+//! ```rust
+#![doc = include_str!("../tests/60_assign.rs")]
+//! ```
+//!
 //! ### Command Flags
 //!
 //! Specific the attribute `cmd` will let you create a sub command flag.
@@ -270,6 +302,18 @@
 #![doc = include_str!("../examples/09_sub_command_demo.rs")]
 //! ```
 //!
+//! ### Tuple Struct
+//!
+//! The fields of tuple struct are positionals in default, they are named by the position of field,
+//! such as `arg0`. The options, command flags and sub commands of tuple struct need a `name`.
+//!
+//! The newtype struct which has only one field without configuration, such as `struct Cli(Inner)`,
+//! delegates to the inner type.
+//!
+//! ```rust
+#![doc = include_str!("../tests/39_tuple_struct.rs")]
+//! ```
+//!
 //! ## Configurating Options, Command flags and Positionals
 //!
 //! ### Configurating the name and alias
@@ -571,6 +615,46 @@
 //! You age is set to 8
 //! ```
 //!
+//! ### Matching the kind of errors
+//!
+//! The error returned by `parse` wraps the original error, such as an invalid value.
+//! The failure of sub command has kind [`SubCommandFailed`](crate::ErrorKind::SubCommandFailed).
+//! Using [`find_kind`](crate::Error::find_kind) search the kind in the cause chain.
+//!
+//! ```rust
+#![doc = include_str!("../tests/33_error_kind.rs")]
+//! ```
+//!
+//! ### Registering sub parsers at runtime
+//!
+//! The sub commands contributed by plugins can not be known by the derive.
+//! Using [`register_sub_parser`](crate::prelude::Parser::register_sub_parser) add a [`Parser`](crate::prelude::Parser)
+//! as sub command at runtime, it is listed in the help message and the arguments after it are parsed by the sub parser.
+//! The return value of handler is saved as the value of sub command.
+//!
+//! ```rust
+#![doc = include_str!("../tests/48_sub_parser.rs")]
+//! ```
+//!
+//! ## Updating an existing struct
+//!
+//! Besides extracting a new struct, [`update_from_set`](crate::ExtractFromSetDerive::update_from_set)
+//! only overwrites the fields whose options are matched in current parsing.
+//! It is useful for layered configuration, such as defaults → config file → command line.
+//!
+//! ```rust
+#![doc = include_str!("../tests/22_update.rs")]
+//! ```
+//!
+//! ## Describing the grammar
+//!
+//! [`grammar`](crate::prelude::Parser::grammar) renders the options, sub commands and positionals
+//! into an EBNF like description, which can be put in the documentation or used to review the design of CLI.
+//!
+//! ```rust
+#![doc = include_str!("../tests/25_grammar.rs")]
+//! ```
+//!
 //! ## How it works
 //!
 //! ### Traits
@@ -626,7 +710,7 @@
 //!
 //!| name      | need value | available value |
 //!|-----------|------------|-----------|
-//!| `policy`  |  true      | `"pre"`, `"fwd"`, `"delay"`, or type |
+//!| `policy`  |  true      | `"pre"`, `"fwd"`, `"delay"`, `"runtime"`, or type |
 //!| `name`    |  true      | string literal |
 //!| `help`    |  false     | |
 //!| `helpopt` |  true      | string literal |
@@ -634,7 +718,16 @@
 //!| `foot`    |  true      | string literal |
 //!| `width`   |  true      | integer |
 //!| `usagew`  |  true      | integer |
+//!| `usage`   |  true      | string literal |
+//!|`compact_usage`| false  | |
+//!|`index_hint`| true      | [`IndexHint`](crate::prelude::IndexHint) |
+//!| `color`   |  false     | |
+//!|`help_style`| true      | [`HelpStyle`](crate::prelude::HelpStyle) |
+//!| `topic`   |  true      | `("name", "body")` |
 //!|`aborthelp`|  false     | |
+//!|`long_help`|  false     | |
+//!|`sub_fallback`| true    | `pos` |
+//!|`default_sub`| true     | string literal |
 //!| `on`      |  true      | function or closure |
 //!| `fallback`|  true      | function or closure |
 //!| `then`    |  true      | function or closure |
@@ -642,6 +735,7 @@
 //!| `combine` |  false     | |
 //!| `embedded`|  false     | |
 //!| `flag`    |  false     | |
+//!| `profile` |  true      | name and values of profile |
 //! * `policy`
 //!
 //! Configure the policy of current struct, its value should be `fwd`, `pre` or `delay`.
@@ -650,6 +744,14 @@
 #![doc = include_str!("../tests/01_policy.rs")]
 //! ```
 //!
+//! With `policy = runtime`, the policy is selected when parsing: [`pre`](crate::PrePolicy) if any
+//! argument matched the sub command, otherwise [`fwd`](crate::FwdPolicy).
+//! The generated `parse_with_policy` can parsing the arguments with the given [`PolicyKind`](crate::PolicyKind).
+//! The `parse_args` and `into_policy` still using the default policy type.
+//! ```rust
+#![doc = include_str!("../tests/37_runtime_policy.rs")]
+//! ```
+//!
 //! * `name`
 //!
 //! The name is display in usage information.
@@ -674,11 +776,143 @@
 #![doc = include_str!("../tests/02_head_foot.rs")]
 //! ```
 //!
+//! * `author`, `version`, `about`
+//!
+//! Set the metadata of application, take the Cargo package information if no value given, i.e. `#[cote(version)]`.
+//! The `about` is displayed as head, and the `author`, `version` are displayed in foot if `head` or `foot` not set.
+//! The metadata can be accessed by `app_info`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/52_app_info.rs")]
+//! ```
+//!
 //! * `width`, `usagew`
 //!
 //! `width` set the maximum length of option help message. `usagew` set the maximum count of options in usage.
 //! See [`Configurating Help`](#configurating-help).
 //!
+//! * `usage`
+//!
+//! Override the generated usage line of help message with a template.
+//! The placeholders `{name}`, `{options}`, `{command}` and `{args}` are replaced with
+//! the name and the generated part of usage.
+//!
+//! ```rust
+#![doc = include_str!("../tests/32_usage.rs")]
+//! ```
+//!
+//! * `compact_usage`
+//!
+//! Only display the shortest and longest name of options in usage, such as `[-h|--help]`.
+//! The options with custom hint are not changed.
+//!
+//! ```rust
+#![doc = include_str!("../tests/35_compact_usage.rs")]
+//! ```
+//!
+//! * `index_hint`
+//!
+//! Set how the index of positional arguments is displayed in help message.
+//! With [`IndexHint::Human`](crate::prelude::IndexHint::Human), `files@2..` will be displayed as `files` with
+//! the description `second and later arguments`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/21_index_hint.rs")]
+//! ```
+//!
+//! * `color` and `help_style`
+//!
+//! Highlight the help message if the stdout is a terminal and `NO_COLOR` is not set:
+//! the section headers are colored, the names of option are bold and the default values are dimmed.
+//! Customize the styles with `help_style`, see [`HelpStyle`](crate::prelude::HelpStyle).
+//!
+//! ```rust
+#![doc = include_str!("../tests/41_help_style.rs")]
+//! ```
+//!
+//! * `topic`
+//!
+//! Register a help topic with its own help page, such as `topic("formats", "...")`.
+//! The topics are listed at the bottom of help message with the first line of page,
+//! and `--help formats` displays the page of topic instead of the help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/54_help_topic.rs")]
+//! ```
+//!
+//! * `help_model`
+//!
+//! Build the help message as structured data with [`help_model`](crate::prelude::Parser::help_model),
+//! the [`HelpModel`](crate::prelude::HelpModel) has the usage line, sections and the models of sub commands,
+//! so GUI apps and web docs can render the help message themselves.
+//!
+//! ```rust
+#![doc = include_str!("../tests/45_help_model.rs")]
+//! ```
+//!
+//! * `render_markdown`
+//!
+//! Render the markdown document of command line with [`render_markdown`](crate::prelude::Parser::render_markdown),
+//! it is generated from the [`HelpModel`](crate::prelude::HelpModel) and can be pasted to `README.md`.
+//! The environment variables of [`ValueSources`](crate::prelude::ValueSources) are listed in the option tables,
+//! and the sub commands have their own pages linked by anchors.
+//!
+//! ```rust
+#![doc = include_str!("../tests/55_markdown.rs")]
+//! ```
+//!
+//! * `Router`
+//!
+//! Route the command strings such as the commands of chat bots to closures with [`Router`](crate::prelude::Router),
+//! each route is a sub parser built at runtime, and the closure extracts its result from the sub parser.
+//!
+//! ```rust
+#![doc = include_str!("../tests/57_router.rs")]
+//! ```
+//!
+//! * `nested`
+//!
+//! Configure the field with `#[arg(nested)]` to add the options of nested struct under the key path,
+//! such as `--server.port` for the field `port` of field `server`, so the configuration merged over
+//! the command line can keep its nested layout.
+//! The key path is the field name in default, using `name` to change it.
+//! The nested struct can only have options, and the options are displayed in sections grouped by key path.
+//!
+//! ```rust
+#![doc = include_str!("../tests/46_key_path.rs")]
+//! ```
+//!
+//! * `long_help`
+//!
+//! Split the doc comments of fields into short help and long help.
+//! The first line is the short help, it is displayed when user pass the short help option such as `-h`.
+//! All the lines of doc comments make up the long help, which is displayed with the long help option such as `--help`.
+//! It has no effect on the fields that have `help` configured.
+//!
+//! ```rust
+#![doc = include_str!("../tests/29_long_help.rs")]
+//! ```
+//!
+//! * `sub_fallback`
+//!
+//! By default the sub commands are force required, parsing will fail if the first argument is not a sub command.
+//! With `sub_fallback = pos`, the sub commands are no longer force required,
+//! the unknown sub command will be matched by the positional arguments.
+//!
+//! ```rust
+#![doc = include_str!("../tests/30_sub_fallback.rs")]
+//! ```
+//!
+//! * `default_sub`
+//!
+//! Set the default sub command, which is inserted as the first argument when neither
+//! sub command nor help option present in the command line.
+//! The default sub command then processed as if user passed it, include the options after it.
+//!
+//! ```rust
+#![doc = include_str!("../tests/31_default_sub.rs")]
+//! ```
+//!
 //! * `on`, `fallback`, `then`
 //!
 //! Using `then` you can configure a handler which is responsible for storing the option value
@@ -712,6 +946,16 @@
 //!
 //! Enable some extra [`user style`](crate::UserStyle) of policy. See also [`Configurating User Style`](#configurating-user-style).
 //!
+//! * `profile`
+//!
+//! Add a [`Profile`](crate::prelude::Profile), which is a named bundle of option values.
+//! The profile is activated by an option using [`profile_handler`](crate::prelude::profile_handler),
+//! the values supplied by command line are kept.
+//!
+//! ```rust
+#![doc = include_str!("../tests/38_profile.rs")]
+//! ```
+//!
 //! #### `arg`, `pos`, `cmd`
 //!
 //!| name      | need value | available value |
@@ -734,6 +978,16 @@
 //!| `fetch`   |  true      | function |
 //!| `append`  |  false     | |
 //!| `count`   |  false     | |
+//!| `once`    |  false     | |
+//!| `global`  |  false     | |
+//!| `sensitive`| false     | |
+//!| `force_if_cmd`| true  | string literal |
+//!| `requires`| true      | string literal |
+//!| `conflicts_with`| true | string literal |
+//!| `priority`| true      | integer literal |
+//!| `bind`    |  true      | method of struct |
+//!| `passthrough`| false   | |
+//!| `value_name`| true     | string literal |
 //!
 //! * `name`, `alias`
 //!
@@ -744,6 +998,16 @@
 //! Configure the name and help message of option.
 //! See also [`Configurating the hint, help and default value`](#configurating-the-hint-help-and-default-value).
 //!
+//! * `value_name`
+//!
+//! Append the value name to the generated hint of option, such as `-c, --config <FILE>`.
+//! The value name is enclosed in `[]` if the option is not force required.
+//! It has no effect if `hint` is configured.
+//!
+//! ```rust
+#![doc = include_str!("../tests/36_value_name.rs")]
+//! ```
+//!
 //! * `value`, `values`
 //!
 //! Configure the default value of option, `cote-derive` using [`From`] convert given value to option value.
@@ -752,6 +1016,52 @@
 #![doc = include_str!("../tests/06_value.rs")]
 //! ```
 //!
+//! `cote-derive` generates an `is_set_<field>` function for every field,
+//! check if the value of field is supplied by user rather than the default value,
+//! see [`Set::is_user_set`](crate::prelude::Set::is_user_set).
+//!
+//! ```rust
+#![doc = include_str!("../tests/42_is_set.rs")]
+//! ```
+//!
+//! * `default_from_env`
+//!
+//! Read the default value from environment variable when parsing, see [`ValInitializer::new_env`](crate::prelude::ValInitializer::new_env).
+//! The help message displays the name of variable such as `[env: JOBS]` instead of its value.
+//! It is different from [`ValueSources`](crate::prelude::ValueSources) which fills the value after parsing.
+//!
+//! ```rust
+#![doc = include_str!("../tests/49_default_from_env.rs")]
+//! ```
+//!
+//! * `value_file`
+//!
+//! Expand the value beginning with `@` into one value per line of the file when storing,
+//! blank lines are skipped. Use `@@` to pass a value beginning with a literal `@`,
+//! see [`ValStorer::with_value_file`](crate::prelude::ValStorer::with_value_file).
+//!
+//! ```rust
+#![doc = include_str!("../tests/50_value_file.rs")]
+//! ```
+//!
+//! * `dedup`
+//!
+//! Collapse the repeated identical values such as `--feature x --feature x` into one when storing,
+//! the first occurrence is kept, see [`ValStorer::with_dedup`](crate::prelude::ValStorer::with_dedup).
+//!
+//! ```rust
+#![doc = include_str!("../tests/58_dedup.rs")]
+//! ```
+//!
+//! * `choices`
+//!
+//! Set the possible values of option, they are displayed in the help message and used by the completion,
+//! such as the `possible_values` of `CliSpec` exported with feature `serde`. Use `valid` check the value.
+//!
+//! ```rust
+#![doc = include_str!("../tests/51_choices.rs")]
+//! ```
+//!
 //! * `index`
 //!
 //! Configure the index of option, it is using for `pos`([`Pos`](crate::prelude::Pos)) attribute generally.
@@ -768,7 +1078,7 @@
 #![doc = include_str!("../tests/08_force.rs")]
 //! ```
 //!
-//! * `action`, `ty`, `append`, `count`
+//! * `action`, `ty`, `append`, `count`, `once`
 //!
 //! `action` can configure the [`Action`](crate::prelude::Action) which responsible for saving value of option.
 //! Using `ty` specify the option type when using [`Action::Cnt`](crate::prelude::Action::Cnt).
@@ -778,7 +1088,13 @@
 //! ```
 //!
 //! `append` is an alias of "action = [`Action::App`](crate::prelude::Action::App)",
-//! `count` is an alias of "action = [`Action::Cnt`](crate::prelude::Action::Cnt)"
+//! `count` is an alias of "action = [`Action::Cnt`](crate::prelude::Action::Cnt)",
+//! `once` is an alias of "action = [`Action::SetOnce`](crate::prelude::Action::SetOnce)",
+//! which returns an error if the option is specified more than once.
+//!
+//! ```rust
+#![doc = include_str!("../tests/56_once.rs")]
+//! ```
 //!
 //! * `fetch`
 //!
@@ -796,6 +1112,16 @@
 #![doc = include_str!("../tests/11_valid.rs")]
 //! ```
 //!
+//! * `normalize`
+//!
+//! Normalize the raw value before the handler and validator invoked, such as `normalize = [trim, lowercase]`.
+//! The builtin steps are `trim`, `lowercase`, `uppercase` and `canonicalize`,
+//! any other function with signature `fn(&OsStr) -> Result<OsString, Error>` is a custom step.
+//!
+//! ```rust
+#![doc = include_str!("../tests/47_normalize.rs")]
+//! ```
+//!
 //! * `on`, `fallback`, `then`
 //!
 //! Using `then` you can configure a handler which is responsible for storing the option value.
@@ -817,6 +1143,75 @@
 //! Only work for [`DelayPolicy`](crate::DelayPolicy) currently.
 //! See also [`Add "no delay" option`](#add-no-delay-option).
 //!
+//! * `global`
+//!
+//! Make the option can be set after the sub command, the value is saved in the parent parser.
+//! Once any option is `global`, the other options of parent must be set before sub command.
+//! The argument after sub command must match the name or alias of global option exactly,
+//! such as `--verbose-level` will not match the global option `--verbose`.
+//! Only work for [`PrePolicy`](crate::PrePolicy) currently.
+//!
+//! ```rust
+#![doc = include_str!("../tests/20_global.rs")]
+//! ```
+//!
+//! * `sensitive`
+//!
+//! Mark the value of option as sensitive, the [`Redactor`](crate::prelude::Redactor) will transform
+//! the value instead of writing the raw value when dumping the options.
+//!
+//! ```rust
+#![doc = include_str!("../tests/24_sensitive.rs")]
+//! ```
+//!
+//! * `force_if_cmd`
+//!
+//! Make the option force required only when the sub command is matched,
+//! it is checked after all the arguments are processed.
+//!
+//! ```rust
+#![doc = include_str!("../tests/44_force_if_cmd.rs")]
+//! ```
+//!
+//! * `requires`, `conflicts_with`
+//!
+//! The option requires or conflicts with another option, they can be repeated.
+//! The relations are checked for the options set by user after all the arguments are processed.
+//!
+//! ```rust
+#![doc = include_str!("../tests/61_requires.rs")]
+//! ```
+//!
+//! * `priority`
+//!
+//! When an argument may match more than one options, such as they have same alias,
+//! the option with higher priority is matched first, default is 0.
+//! The options have same priority are matched in the order of fields.
+//!
+//! ```rust
+#![doc = include_str!("../tests/63_priority.rs")]
+//! ```
+//!
+//! * `bind`
+//!
+//! Bind the option to a method `fn(&mut self) -> cote::Result<()>` of the struct.
+//! Unlike the `on` handler, the method is called after the struct extracted,
+//! so it can access all the fields. It is called only if the option matched,
+//! in the order of fields.
+//!
+//! ```rust
+#![doc = include_str!("../tests/26_bind.rs")]
+//! ```
+//!
+//! * `passthrough`
+//!
+//! Collect the arguments after `--` verbatim into the field, such as `Vec<OsString>`.
+//! The option is named `--`, and the policy stops processing options once it matched.
+//!
+//! ```rust
+#![doc = include_str!("../tests/27_passthrough.rs")]
+//! ```
+//!
 //! #### `sub`
 //!
 //!| name      | need value | available value |
@@ -830,6 +1225,14 @@
 //!| `alias`   |  true      | string literal |
 //!| `force`   |  true      | boolean |
 //!
+//! The type of sub command field should be `Option<T>`.
+//! With `Vec<T>`, the sub command can be invoked repeatedly in one command line, such as `app add a add b`.
+//! The arguments are split by the name of sub command, and the results are collected into the field.
+//!
+//! ```rust
+#![doc = include_str!("../tests/43_repeat_sub.rs")]
+//! ```
+//!
 //! * `policy`
 //!
 //! Override the `policy` of sub command.
@@ -862,6 +1265,18 @@
 #![doc = include_str!("../tests/15_force.rs")]
 //! ```
 //!
+//! * index of sub parser
+//!
+//! The sub command is removed from the arguments passed to sub parser,
+//! so `@1` of sub parser always means the first argument after the sub command.
+//! Using [`RunningCtx::orig_index`](crate::prelude::RunningCtx::orig_index) map the index to the top level parser.
+//! The index in the failures of `Pos` and `Cmd` returned by sub parser, such as `target@1`,
+//! is mapped to the top level parser by [`RunningCtx::orig_failure`](crate::prelude::RunningCtx::orig_failure).
+//!
+//! ```rust
+#![doc = include_str!("../tests/28_sub_offset.rs")]
+//! ```
+//!
 //! ### `CoteOpt` Configurations list
 //!
 //! `CoteOpt` derive the default behavior of [`Infer`](crate::prelude::Infer), [`Fetch`](crate::prelude::Fetch`);
@@ -884,10 +1299,16 @@
 //!| `ty`      |  true      | [`TypeId`](std::any::TypeId) |
 //!| `tweak`   |  true      | function |
 //!| `fill`    |  true      | function |
+//!| `choices` |  false     | |
 //!| `override`|  false
 //!
 //! `infer` can configure the behavior of [`Infer`](crate::prelude::Infer), the configures are mostly using to providing default value.
 //!
+//! ##### choices
+//!
+//! Set the [`variants`](crate::prelude::ValueEnum::variants) of type as the choices of option,
+//! the type must implement [`ValueEnum`](crate::prelude::ValueEnum), it can not using with `tweak`.
+//!
 //! ##### override
 //!
 //! In default, `CoteOpt` will generate a default implementation of [`InferOverride`](crate::prelude::InferOverride),
@@ -930,9 +1351,11 @@
 //!| `map`     |  true      |  function |
 //!| `mapraw`  |  true      |  function |
 //!| `mapstr`  |  true      |  function |
+//!| `fromstr` |  false     | |
 //!| `igcase`  |  false     | |
 //!| `name`    |  true      | string literal |
 //!| `alias`   |  true      | string literal |
+//!| `lookup`  |  true      |  function |
 //!
 //! `coteval` can configure the behavior of [`RawValParser`](crate::prelude::RawValParser).
 //!
@@ -942,6 +1365,8 @@
 //! `CoteVal` also support generate default parsing code for simple enum type.
 //! For enum type, you can use `igcase` ignore case when matching, `name` configure the name of matching
 //! or use `alias` add other names of matching.
+//! The names of variants are available through the generated [`ValueEnum`](crate::prelude::ValueEnum),
+//! the aliases are not included.
 //!
 //! ##### Example 1
 //!
@@ -949,8 +1374,37 @@
 #![doc = include_str!("../tests/18_value.rs")]
 //! ```
 //!
+//! ##### Example of `lookup`
+//!
+//! Using `lookup` on enum type add the names loaded at runtime, such as localized names.
+//! The function is called with the name of type and the value if it not match any variant,
+//! it should return the name of variant or `alias`, such as `fn(&str, &str) -> Option<String>`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/53_value_lookup.rs")]
+//! ```
+//!
+//! ##### Example of `ValueEnum`
+//!
+//! Using `#[infer(choices)]` display the variants in help message and completion,
+//! [`Variants`](crate::valid::Variants) check a string value is one of them, the case is ignored if the enum is `igcase`,
+//! and the variants are set as the choices of option.
+//!
+//! ```rust
+#![doc = include_str!("../tests/62_value_enum.rs")]
+//! ```
+//!
 //! ##### Example of `mapraw` and `mapstr`
 //!
 //! ```rust
 #![doc = include_str!("../tests/19_map.rs")]
 //! ```
+//!
+//! ##### Example of `fromstr`
+//!
+//! Using `fromstr` parse the value with the [`FromStr`](std::str::FromStr) implementation of type,
+//! see also [`raw2fromstr`](crate::prelude::raw2fromstr).
+//!
+//! ```rust
+#![doc = include_str!("../tests/34_fromstr.rs")]
+//! ```
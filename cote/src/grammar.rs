@@ -0,0 +1,159 @@
+use aopt::opt::Opt;
+use aopt::opt::Style;
+use aopt::prelude::Set;
+
+/// Render the options, sub commands and positionals of `set` into an EBNF like grammar.
+///
+/// The generated rules are named after `name`:
+///
+/// * `name` is the top rule, which is options, then command, then positional arguments.
+/// * `name_option` list the options, the one with value is followed by `? value ?`.
+/// * `name_command` list the [`Cmd`](crate::prelude::Cmd) of the set.
+/// * `name_arg` list the [`Pos`](crate::prelude::Pos) with the description of index.
+///
+/// The required items are marked with `required` in the comment.
+/// Using [`Parser::grammar`](crate::prelude::Parser::grammar) if you want the rules of sub commands.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// # fn main() -> cote::Result<()> {
+/// let mut set = ASet::default();
+///
+/// set.add_opt("--verbose;-v=b")?.run()?;
+/// set.add_opt("--name=s!")?.run()?;
+/// set.add_opt("file=p@1")?.run()?;
+///
+/// assert_eq!(
+///     set_grammar(&set, "app"),
+///     r#"app = { app_option }, { app_arg } ;
+/// app_option = ( "--verbose" | "-v" )
+///            | "--name", ? value ? (* required *) ;
+/// app_arg = ? file ? (* first argument *) ;
+/// "#
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn set_grammar<T: Set>(set: &T, name: &str) -> String {
+    let mut grammar = String::default();
+
+    write_set_grammar(set, name, &mut grammar, |_| false);
+    grammar
+}
+
+/// Write the rules of `set` to `grammar`,
+/// the `is_sub` return true if the command has a rule named after it.
+pub(crate) fn write_set_grammar<T: Set>(
+    set: &T,
+    name: &str,
+    grammar: &mut String,
+    is_sub: impl Fn(&str) -> bool,
+) {
+    let rule = ident(name);
+    let mut opts = vec![];
+    let mut cmds = vec![];
+    let mut args = vec![];
+    let mut cmd_required = false;
+
+    for opt in set.iter() {
+        if opt.mat_style(Style::Pos) {
+            let alt = format!("? {} ?", opt.name());
+            let desc = opt.index().map(|v| v.to_desc());
+
+            args.push(annotate(alt, desc, opt.force()));
+        } else if opt.mat_style(Style::Cmd) {
+            let mut alt = names(opt);
+
+            if is_sub(opt.name()) {
+                alt.push_str(&format!(", {}", ident(opt.name())));
+            }
+            cmd_required = cmd_required || opt.force();
+            cmds.push(alt);
+        } else if opt.mat_style(Style::Argument) {
+            let alt = format!("{}, ? value ?", names(opt));
+
+            opts.push(annotate(alt, None, opt.force()));
+        } else if opt.mat_style(Style::Boolean)
+            || opt.mat_style(Style::Combined)
+            || opt.mat_style(Style::Flag)
+        {
+            opts.push(annotate(names(opt), None, opt.force()));
+        }
+    }
+
+    let mut top = vec![];
+
+    if !opts.is_empty() {
+        top.push(format!("{{ {rule}_option }}"));
+    }
+    if !cmds.is_empty() {
+        if cmd_required {
+            top.push(format!("{rule}_command"));
+        } else {
+            top.push(format!("[ {rule}_command ]"));
+        }
+    }
+    if !args.is_empty() {
+        top.push(format!("{{ {rule}_arg }}"));
+    }
+    write_rule(grammar, &rule, &[top.join(", ")]);
+    write_rule(grammar, &format!("{rule}_option"), &opts);
+    write_rule(grammar, &format!("{rule}_command"), &cmds);
+    write_rule(grammar, &format!("{rule}_arg"), &args);
+}
+
+fn write_rule(grammar: &mut String, rule: &str, alts: &[String]) {
+    if !alts.is_empty() {
+        let indent = " ".repeat(rule.len() + 1);
+
+        grammar.push_str(rule);
+        grammar.push_str(" = ");
+        for (idx, alt) in alts.iter().enumerate() {
+            if idx > 0 {
+                grammar.push('\n');
+                grammar.push_str(&indent);
+                grammar.push_str("| ");
+            }
+            grammar.push_str(alt);
+        }
+        grammar.push_str(" ;\n");
+    }
+}
+
+fn names<O: Opt>(opt: &O) -> String {
+    let mut names = vec![format!("{:?}", opt.name())];
+
+    if let Some(alias) = opt.alias() {
+        names.extend(alias.iter().map(|v| format!("{:?}", v)));
+    }
+    if names.len() > 1 {
+        format!("( {} )", names.join(" | "))
+    } else {
+        names.pop().unwrap()
+    }
+}
+
+/// Append the description and `required` as comment.
+fn annotate(alt: String, desc: Option<String>, force: bool) -> String {
+    let notes: Vec<_> = desc
+        .into_iter()
+        .chain(force.then(|| String::from("required")))
+        .collect();
+
+    if notes.is_empty() {
+        alt
+    } else {
+        format!("{} (* {} *)", alt, notes.join(", "))
+    }
+}
+
+/// Make the name can be used as rule name.
+fn ident(name: &str) -> String {
+    name.chars()
+        .map(|ch| if ch.is_alphanumeric() { ch } else { '_' })
+        .collect()
+}
@@ -1,9 +1,265 @@
+use aopt::opt::Index;
 use aopt::opt::Opt;
 use aopt::opt::Style;
 use aopt::set::Set;
+use aopt::set::ValueSources;
+use aopt::Uid;
 use aopt_help::block::Block;
+use aopt_help::format::DefaultAppPolicy;
 use aopt_help::store::Store;
+use aopt_help::AppHelp;
+
+use crate::keypath::key_path_of;
 use std::borrow::Cow;
+use std::io::Write;
+
+/// Control how the index of positional arguments is displayed in the help message.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum IndexHint {
+    /// Keep the raw index syntax, such as `format@1`.
+    #[default]
+    Raw,
+
+    /// Describe the position in human terms, such as `third and later arguments`.
+    /// See [`Index::to_desc`].
+    Human,
+
+    /// Describe the position with a custom template.
+    Custom(fn(&Index) -> String),
+}
+
+impl IndexHint {
+    /// Return the description of given index, or [`None`] if the raw syntax should be kept.
+    pub fn describe(&self, index: &Index) -> Option<String> {
+        match self {
+            IndexHint::Raw => None,
+            IndexHint::Human => Some(index.to_desc()),
+            IndexHint::Custom(template) => Some(template(index)),
+        }
+    }
+}
+
+/// The styles used to highlight the help message when [`color`](HelpContext::color) is enabled.
+///
+/// Every style is the parameters of ANSI SGR sequence, such as `1` for bold or `1;32` for bold green,
+/// the text is not highlighted if the style is empty.
+#[derive(Debug, Clone)]
+pub struct HelpStyle {
+    header: String,
+
+    name: String,
+
+    value: String,
+}
+
+impl Default for HelpStyle {
+    /// Colored section headers, bold option names and dimmed default values.
+    fn default() -> Self {
+        Self {
+            header: String::from("1;32"),
+            name: String::from("1"),
+            value: String::from("2"),
+        }
+    }
+}
+
+impl HelpStyle {
+    /// Create a style not highlight anything.
+    pub fn plain() -> Self {
+        Self {
+            header: String::default(),
+            name: String::default(),
+            value: String::default(),
+        }
+    }
+
+    pub fn with_header(mut self, header: impl Into<String>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn set_header(&mut self, header: impl Into<String>) -> &mut Self {
+        self.header = header.into();
+        self
+    }
+
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn set_value(&mut self, value: impl Into<String>) -> &mut Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Highlight the section header, such as `Options:`.
+    pub fn paint_header<'a>(&self, header: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+        paint(&self.header, header.into())
+    }
+
+    /// Highlight the name of option, command or positional argument.
+    pub fn paint_name<'a>(&self, name: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+        paint(&self.name, name.into())
+    }
+
+    /// Highlight the default value at the end of `help`, such as `[42]` appended by derive macro.
+    pub fn paint_value<'a>(&self, help: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+        let help = help.into();
+
+        match value_start(&help) {
+            Some(start) if !self.value.is_empty() => Cow::from(format!(
+                "{}{}",
+                &help[..start],
+                paint(&self.value, Cow::from(&help[start..]))
+            )),
+            _ => help,
+        }
+    }
+}
+
+fn paint<'a>(sgr: &str, text: Cow<'a, str>) -> Cow<'a, str> {
+    if sgr.is_empty() || text.is_empty() {
+        text
+    } else {
+        Cow::from(format!("\x1b[{}m{}\x1b[0m", sgr, text))
+    }
+}
+
+/// Find the start of trailing `[...]` in help message, the brackets in value are balanced.
+fn value_start(help: &str) -> Option<usize> {
+    let mut depth = 0usize;
+
+    if !help.ends_with(']') {
+        return None;
+    }
+    for (idx, ch) in help.char_indices().rev() {
+        match ch {
+            ']' => depth += 1,
+            '[' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return (idx > 0 && help[..idx].ends_with(' ')).then_some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the help message into the description and the default value,
+/// such as `Set the count` and `42` of `Set the count [42]`.
+pub(crate) fn split_default(help: &str) -> (String, Option<String>) {
+    match value_start(help) {
+        Some(start) => (
+            help[..start].trim_end().to_string(),
+            Some(help[start + 1..help.len() - 1].to_string()),
+        ),
+        None => (help.to_string(), None),
+    }
+}
+
+/// The metadata of application, generated by `cote-derive` from the `name`, `author`,
+/// `version` and `about` configurations, which default to the Cargo package information.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppInfo {
+    name: String,
+
+    author: String,
+
+    version: String,
+
+    about: String,
+}
+
+impl AppInfo {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    pub fn with_about(mut self, about: impl Into<String>) -> Self {
+        self.about = about.into();
+        self
+    }
+
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn set_author(&mut self, author: impl Into<String>) -> &mut Self {
+        self.author = author.into();
+        self
+    }
+
+    pub fn set_version(&mut self, version: impl Into<String>) -> &mut Self {
+        self.version = version.into();
+        self
+    }
+
+    pub fn set_about(&mut self, about: impl Into<String>) -> &mut Self {
+        self.about = about.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The authors of application, separated by `:` if it comes from Cargo.
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn about(&self) -> &str {
+        &self.about
+    }
+}
+
+/// The argument following the help option, such as `formats` of `--help formats`.
+///
+/// It is saved to services by the help option handler of generated code,
+/// and used as [`show_topic`](HelpContext::show_topic) if such topic is registered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HelpTopic(pub String);
 
 #[derive(Debug, Clone, Default)]
 pub struct HelpContext {
@@ -16,6 +272,24 @@ pub struct HelpContext {
     width: usize,
 
     usagew: usize,
+
+    index_hint: IndexHint,
+
+    long: bool,
+
+    long_helps: Vec<(Uid, String)>,
+
+    usage: Option<String>,
+
+    compact_usage: bool,
+
+    color: bool,
+
+    style: HelpStyle,
+
+    topics: Vec<(String, String)>,
+
+    show_topic: Option<String>,
 }
 
 impl HelpContext {
@@ -44,6 +318,135 @@ impl HelpContext {
         self
     }
 
+    pub fn with_index_hint(mut self, index_hint: IndexHint) -> Self {
+        self.index_hint = index_hint;
+        self
+    }
+
+    /// Display the long help of options instead of [`help`](Opt::help).
+    pub fn with_long(mut self, long: bool) -> Self {
+        self.long = long;
+        self
+    }
+
+    /// Add the long help of option `uid`, it is used when [`long`](Self::long) is true.
+    pub fn with_long_help(mut self, uid: Uid, help: impl Into<String>) -> Self {
+        self.set_long_help(uid, help);
+        self
+    }
+
+    /// Override the generated usage line, the placeholders `{name}`, `{options}`,
+    /// `{command}` and `{args}` are expanded when displaying help.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// # fn main() -> cote::Result<()> {
+    /// let mut set = ASet::default();
+    ///
+    /// set.add_opt("--verbose;-v=b: Print more message")?.run()?;
+    ///
+    /// let ctx = HelpContext::default()
+    ///     .with_name("app")
+    ///     .with_usage("Usage: {name} [OPTIONS] <INPUT> -- [ARGS]");
+    /// let help = render_set_help_ctx(&set, &ctx).unwrap();
+    ///
+    /// assert!(help.starts_with("Usage: app [OPTIONS] <INPUT> -- [ARGS]"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    /// Display the shortest and longest name of options in usage, such as `[-h|--help]`.
+    /// The other aliases are still displayed in the options table.
+    pub fn with_compact_usage(mut self, compact_usage: bool) -> Self {
+        self.compact_usage = compact_usage;
+        self
+    }
+
+    /// Highlight the help message with the [`HelpStyle`].
+    ///
+    /// Use [`ColorChoice::enabled`](aopt::value::ColorChoice::enabled) to disable it when the stdout is not a terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// # fn main() -> cote::Result<()> {
+    /// let mut parser = Parser::<ASet, ASer>::default();
+    /// let mut policy = FwdPolicy::default();
+    ///
+    /// parser.add_opt(ColorChoice::preset())?;
+    /// parser.parse_policy(Args::from(["app", "--color=always"]), &mut policy)?;
+    ///
+    /// let color = parser.find_val::<ColorChoice>("--color")?;
+    /// let ctx = HelpContext::default().with_name("app").with_color(color.enabled());
+    /// let help = render_set_help_ctx(parser.optset(), &ctx).unwrap();
+    ///
+    /// assert!(help.contains("\x1b[1;32mOptions:\x1b[0m"));
+    /// assert!(help.contains("\x1b[1m--color[=WHEN]\x1b[0m"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_style(mut self, style: HelpStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a help topic, such as `formats`, with its own help page `body`.
+    /// The topics are listed at the bottom of help message, with the first line of `body`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// # fn main() -> cote::Result<()> {
+    /// let mut set = ASet::default();
+    ///
+    /// set.add_opt("--verbose;-v=b: Print more message")?.run()?;
+    ///
+    /// let ctx = HelpContext::default()
+    ///     .with_name("app")
+    ///     .with_topic("formats", "The supported formats\n\n  json, toml and yaml");
+    /// let help = render_set_help_ctx(&set, &ctx).unwrap();
+    ///
+    /// assert!(help.contains("Topics:"));
+    /// assert!(help.contains("The supported formats"));
+    /// assert!(!help.contains("json, toml and yaml"));
+    ///
+    /// let help = render_set_help_ctx(&set, &ctx.with_show_topic("formats")).unwrap();
+    ///
+    /// assert_eq!(help, "The supported formats\n\n  json, toml and yaml\n");
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_topic(mut self, name: impl Into<String>, body: impl Into<String>) -> Self {
+        self.set_topic(name, body);
+        self
+    }
+
+    /// Display the help page of topic `name` instead of the help message,
+    /// it is ignored if no such topic.
+    pub fn with_show_topic(mut self, name: impl Into<String>) -> Self {
+        self.show_topic = Some(name.into());
+        self
+    }
+
     pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
         self.name = name.into();
         self
@@ -69,6 +472,61 @@ impl HelpContext {
         self
     }
 
+    pub fn set_index_hint(&mut self, index_hint: IndexHint) -> &mut Self {
+        self.index_hint = index_hint;
+        self
+    }
+
+    pub fn set_long(&mut self, long: bool) -> &mut Self {
+        self.long = long;
+        self
+    }
+
+    pub fn set_long_help(&mut self, uid: Uid, help: impl Into<String>) -> &mut Self {
+        let help = help.into();
+
+        match self.long_helps.iter_mut().find(|(id, _)| *id == uid) {
+            Some((_, value)) => *value = help,
+            None => self.long_helps.push((uid, help)),
+        }
+        self
+    }
+
+    pub fn set_usage(&mut self, usage: impl Into<String>) -> &mut Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    pub fn set_compact_usage(&mut self, compact_usage: bool) -> &mut Self {
+        self.compact_usage = compact_usage;
+        self
+    }
+
+    pub fn set_color(&mut self, color: bool) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn set_style(&mut self, style: HelpStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    pub fn set_topic(&mut self, name: impl Into<String>, body: impl Into<String>) -> &mut Self {
+        let (name, body) = (name.into(), body.into());
+
+        match self.topics.iter_mut().find(|(v, _)| *v == name) {
+            Some((_, value)) => *value = body,
+            None => self.topics.push((name, body)),
+        }
+        self
+    }
+
+    pub fn set_show_topic(&mut self, name: Option<String>) -> &mut Self {
+        self.show_topic = name;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -88,6 +546,81 @@ impl HelpContext {
     pub fn usagew(&self) -> usize {
         self.usagew
     }
+
+    pub fn index_hint(&self) -> IndexHint {
+        self.index_hint
+    }
+
+    pub fn long(&self) -> bool {
+        self.long
+    }
+
+    pub fn usage(&self) -> Option<&str> {
+        self.usage.as_deref()
+    }
+
+    pub fn compact_usage(&self) -> bool {
+        self.compact_usage
+    }
+
+    pub fn color(&self) -> bool {
+        self.color
+    }
+
+    pub fn style(&self) -> &HelpStyle {
+        &self.style
+    }
+
+    /// Return the title of help block, it is highlighted if [`color`](Self::color) is true.
+    pub fn title<'a>(&self, title: &'a str) -> Cow<'a, str> {
+        if self.color {
+            self.style.paint_header(title)
+        } else {
+            Cow::from(title)
+        }
+    }
+
+    /// Return the hint of store and the help with default value highlighted
+    /// if [`color`](Self::color) is true.
+    pub fn highlight<'a>(
+        &self,
+        hint: Cow<'a, str>,
+        help: Cow<'a, str>,
+    ) -> (Cow<'a, str>, Cow<'a, str>) {
+        if self.color {
+            (self.style.paint_name(hint), self.style.paint_value(help))
+        } else {
+            (hint, help)
+        }
+    }
+
+    pub fn long_helps(&self) -> &[(Uid, String)] {
+        &self.long_helps
+    }
+
+    pub fn topics(&self) -> &[(String, String)] {
+        &self.topics
+    }
+
+    /// Return the help page of topic `name`.
+    pub fn topic(&self, name: &str) -> Option<&str> {
+        self.topics
+            .iter()
+            .find(|(v, _)| v == name)
+            .map(|(_, body)| body.as_str())
+    }
+
+    pub fn show_topic(&self) -> Option<&str> {
+        self.show_topic.as_deref()
+    }
+
+    /// Return the long help of option `uid`.
+    pub fn long_help(&self, uid: Uid) -> Option<&str> {
+        self.long_helps
+            .iter()
+            .find(|(id, _)| *id == uid)
+            .map(|(_, help)| help.as_str())
+    }
 }
 
 pub fn display_set_help<'a, T: Set>(
@@ -98,67 +631,672 @@ pub fn display_set_help<'a, T: Set>(
     max_width: usize,
     usage_width: usize,
 ) -> Result<(), aopt_help::Error> {
-    let mut app_help = aopt_help::AppHelp::new(
-        name.into(),
-        head.into(),
-        foot.into(),
+    let ctx = HelpContext::default()
+        .with_name(name.into())
+        .with_head(head.into())
+        .with_foot(foot.into())
+        .with_width(max_width)
+        .with_usagew(usage_width);
+
+    display_set_help_ctx(set, &ctx)
+}
+
+/// Display help message of given set with the settings of [`HelpContext`].
+///
+/// The long help of options are displayed if [`HelpContext::long`] is true.
+pub fn display_set_help_ctx<T: Set>(set: &T, ctx: &HelpContext) -> Result<(), aopt_help::Error> {
+    write_set_help_ctx(set, ctx, std::io::stdout())
+}
+
+/// Render the help message of given set into a string instead of printing it,
+/// useful in the environment that has no stdout such as browser.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// # fn main() -> cote::Result<()> {
+/// let mut set = ASet::default();
+///
+/// set.add_opt("--verbose;-v=b: Print more message")?.run()?;
+///
+/// let ctx = HelpContext::default().with_name("app").with_width(40);
+/// let help = render_set_help_ctx(&set, &ctx).unwrap();
+///
+/// assert!(help.starts_with("Usage: app"));
+/// assert!(help.contains("Print more message"));
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_set_help_ctx<T: Set>(set: &T, ctx: &HelpContext) -> Result<String, aopt_help::Error> {
+    let mut buffer = vec![];
+
+    write_set_help_ctx(set, ctx, &mut buffer)?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+/// Write help message of given set to `writer` with the settings of [`HelpContext`].
+///
+/// The help page of [`show_topic`](HelpContext::show_topic) is written instead if it is registered.
+pub fn write_set_help_ctx<T: Set, W: Write>(
+    set: &T,
+    ctx: &HelpContext,
+    mut writer: W,
+) -> Result<(), aopt_help::Error> {
+    if let Some(body) = ctx.show_topic().and_then(|name| ctx.topic(name)) {
+        return writeln!(writer, "{}", body)
+            .map_err(|e| aopt_help::Error::Error(format!("can not write help topic: {:?}", e)));
+    }
+    let mut app_help = new_app_help(set, ctx, writer)?;
+
+    app_help.display(true)?;
+
+    Ok(())
+}
+
+fn new_app_help<'a, T: Set, W: Write>(
+    set: &'a T,
+    ctx: &'a HelpContext,
+    writer: W,
+) -> Result<AppHelp<'a, W>, aopt_help::Error> {
+    let index_hint = ctx.index_hint();
+    let mut app_help = AppHelp::new(
+        Cow::from(ctx.name()),
+        Cow::from(ctx.head()),
+        Cow::from(ctx.foot()),
         aopt_help::prelude::Style::default(),
-        std::io::stdout(),
-        max_width,
-        usage_width,
+        writer,
+        ctx.width(),
+        ctx.usagew(),
     );
+
+    if let Some(usage) = ctx.usage() {
+        app_help.set_usage(usage);
+    }
     let global = app_help.global_mut();
 
-    global.add_block(Block::new("command", "<COMMAND>", "", "Commands:", ""))?;
-    global.add_block(Block::new("option", "", "", "Options:", ""))?;
-    global.add_block(Block::new("args", "[ARGS]", "", "Args:", ""))?;
-    for opt in set.iter() {
-        if opt.mat_style(Style::Pos) {
-            global.add_store(
-                "args",
-                Store::new(
-                    Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
+    let block = |name, hint, title| {
+        Block::new(
+            Cow::from(name),
+            Cow::from(hint),
+            Cow::default(),
+            ctx.title(title),
+            Cow::default(),
+        )
+    };
+
+    for section in SECTIONS {
+        if section.0 == "args" {
+            // the options under key path are displayed after other options
+            for (name, title) in groups_of(set) {
+                global.add_block(Block::new(
+                    Cow::from(name),
                     Cow::default(),
-                    !opt.force(),
-                    true,
-                ),
-            )?;
-        } else if opt.mat_style(Style::Cmd) {
-            global.add_store(
-                "command",
-                Store::new(
-                    Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
                     Cow::default(),
-                    !opt.force(),
-                    true,
-                ),
-            )?;
-        } else if opt.mat_style(Style::Argument)
-            || opt.mat_style(Style::Boolean)
-            || opt.mat_style(Style::Combined)
-            || opt.mat_style(Style::Flag)
-        {
-            global.add_store(
-                "option",
-                Store::new(
+                    Cow::from(ctx.title(&title).into_owned()),
+                    Cow::default(),
+                ))?;
+            }
+        }
+        global.add_block(block(section.0, section.1, section.2))?;
+    }
+    if !ctx.topics().is_empty() {
+        global.add_block(block("topic", "", "Topics:"))?;
+        for (name, body) in ctx.topics() {
+            let summary = body.lines().next().unwrap_or_default();
+            let mut store = new_store(
+                ctx,
+                Cow::from(format!("topic.{}", name)),
+                Cow::from(name.as_str()),
+                Cow::from(summary),
+                true,
+                false,
+            );
+
+            // topics are not displayed in usage
+            store.set_usage(Cow::default());
+            global.add_store("topic", store)?;
+        }
+    }
+    for opt in set.iter() {
+        let help = choices_help(opt, help_of(opt, ctx));
+
+        match section_name(opt).as_deref() {
+            Some("args") => {
+                let (hint, help) = pos_hint_help(opt, help, index_hint);
+
+                global.add_store(
+                    "args",
+                    new_store(ctx, Cow::from(opt.name()), hint, help, !opt.force(), true),
+                )?;
+            }
+            Some("command") => {
+                global.add_store(
+                    "command",
+                    new_store(
+                        ctx,
+                        Cow::from(opt.name()),
+                        Cow::from(opt.hint()),
+                        help,
+                        !opt.force(),
+                        true,
+                    ),
+                )?;
+            }
+            Some(section) => {
+                let mut store = new_store(
+                    ctx,
                     Cow::from(opt.name()),
                     Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
-                    Cow::default(),
+                    help,
                     !opt.force(),
                     false,
-                ),
-            )?;
+                );
+
+                if ctx.compact_usage() {
+                    if let Some(usage) = compact_usage(opt) {
+                        store.set_usage(usage);
+                    }
+                }
+                global.add_store(section.to_string(), store)?;
+            }
+            None => {}
         }
     }
+    Ok(app_help)
+}
 
-    app_help.display(true)?;
+/// The name, hint and title of help sections.
+const SECTIONS: [(&str, &str, &str); 3] = [
+    ("command", "<COMMAND>", "Commands:"),
+    ("option", "", "Options:"),
+    ("args", "[ARGS]", "Args:"),
+];
 
-    Ok(())
+/// Return the section name of option, or `None` if the option is not displayed.
+fn section_of<O: Opt>(opt: &O) -> Option<&'static str> {
+    if opt.mat_style(Style::Pos) {
+        Some("args")
+    } else if opt.mat_style(Style::Cmd) {
+        Some("command")
+    } else if opt.mat_style(Style::Argument)
+        || opt.mat_style(Style::Boolean)
+        || opt.mat_style(Style::Combined)
+        || opt.mat_style(Style::Flag)
+    {
+        Some("option")
+    } else {
+        None
+    }
+}
+
+/// Return the section name of option, the option under key path is displayed
+/// in section `option.<path>`, such as `option.server` of `--server.port`.
+fn section_name<O: Opt>(opt: &O) -> Option<Cow<'static, str>> {
+    match (section_of(opt), key_path_of(opt.name())) {
+        (Some("option"), Some(path)) => Some(Cow::from(format!("option.{}", path))),
+        (section, _) => section.map(Cow::from),
+    }
+}
+
+/// Return the name and title of sections for key paths in the order of appearance.
+fn groups_of<T: Set>(set: &T) -> Vec<(String, String)> {
+    let mut groups: Vec<(String, String)> = vec![];
+
+    for opt in set.iter() {
+        if let (Some("option"), Some(path)) = (section_of(opt), key_path_of(opt.name())) {
+            let name = format!("option.{}", path);
+
+            if !groups.iter().any(|(v, _)| v == &name) {
+                groups.push((name, format!("Options of {}:", path)));
+            }
+        }
+    }
+    groups
+}
+
+/// Return the long help of option if [`long`](HelpContext::long) is true, otherwise [`help`](Opt::help).
+pub(crate) fn help_of<'a, O: Opt>(opt: &'a O, ctx: &'a HelpContext) -> Cow<'a, str> {
+    ctx.long()
+        .then(|| ctx.long_help(opt.uid()))
+        .flatten()
+        .map(Cow::from)
+        .unwrap_or_else(|| Cow::from(opt.help()))
+}
+
+/// Append the [`choices`](Opt::choices) of option to the help, before the default value,
+/// such as `Set the level [possible values: debug, info] [info]`.
+fn choices_help<'a, O: Opt>(opt: &O, help: Cow<'a, str>) -> Cow<'a, str> {
+    match opt.choices().filter(|v| !v.is_empty()) {
+        Some(choices) => {
+            let choices = format!("[possible values: {}]", choices.join(", "));
+
+            match value_start(&help) {
+                Some(start) => Cow::from(format!(
+                    "{} {} {}",
+                    help[..start].trim_end(),
+                    choices,
+                    &help[start..]
+                )),
+                None if help.is_empty() => Cow::from(choices),
+                None => Cow::from(format!("{} {}", help, choices)),
+            }
+        }
+        None => help,
+    }
+}
+
+/// A row of help section, such as an option, a sub command or a positional argument.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpRow {
+    uid: Uid,
+
+    name: String,
+
+    alias: Vec<String>,
+
+    hint: String,
+
+    help: String,
+
+    default: Option<String>,
+
+    optional: bool,
+}
+
+impl HelpRow {
+    pub fn uid(&self) -> Uid {
+        self.uid
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn alias(&self) -> &[String] {
+        &self.alias
+    }
+
+    /// The hint displayed in the first column, such as `-v, --verbose`.
+    pub fn hint(&self) -> &str {
+        &self.hint
+    }
+
+    /// The help message without default value.
+    pub fn help(&self) -> &str {
+        &self.help
+    }
+
+    /// The default value appended to help message, such as `42` of `Set the count [42]`.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    pub fn optional(&self) -> bool {
+        self.optional
+    }
+}
+
+/// A section of help message, such as `Options:`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpSection {
+    name: String,
+
+    title: String,
+
+    rows: Vec<HelpRow>,
+}
+
+impl HelpSection {
+    /// The name of section, one of `command`, `option` and `args`,
+    /// or `option.<path>` for the options under key path, such as `option.server`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The title of section, such as `Commands:`.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn rows(&self) -> &[HelpRow] {
+        &self.rows
+    }
+}
+
+/// The structured data of help message, it can be rendered by GUI or web docs
+/// without parsing the text output.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// # fn main() -> cote::Result<()> {
+/// let mut set = ASet::default();
+///
+/// set.add_opt("--count;-c=i: Set the count [42]")?.run()?;
+/// set.add_opt("list=c: List the files")?.run()?;
+///
+/// let ctx = HelpContext::default().with_name("app");
+/// let model = HelpModel::new(&set, &ctx).unwrap();
+///
+/// assert_eq!(model.usage(), "Usage: app [-c, --count] <COMMAND>");
+///
+/// let commands = model.section("command").unwrap();
+///
+/// assert_eq!(commands.title(), "Commands:");
+/// assert_eq!(commands.rows()[0].name(), "list");
+///
+/// let count = &model.section("option").unwrap().rows()[0];
+///
+/// assert_eq!(count.hint(), "-c, --count");
+/// assert_eq!(count.alias(), ["-c"]);
+/// assert_eq!(count.help(), "Set the count");
+/// assert_eq!(count.default(), Some("42"));
+/// assert!(count.optional());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpModel {
+    name: String,
+
+    head: String,
+
+    foot: String,
+
+    usage: String,
+
+    sections: Vec<HelpSection>,
+
+    subs: Vec<HelpModel>,
+}
+
+impl HelpModel {
+    /// Build the help model of given set with the settings of [`HelpContext`],
+    /// the color settings are ignored.
+    pub fn new<T: Set>(set: &T, ctx: &HelpContext) -> Result<Self, aopt_help::Error> {
+        let app_help = new_app_help(set, ctx, std::io::sink())?;
+        let policy = DefaultAppPolicy::new(vec![], ctx.width(), true, ctx.usagew());
+        let usage = policy.get_app_usage(&app_help).trim_end().to_string();
+        let mut sections: Vec<_> = SECTIONS
+            .iter()
+            .map(|(name, _, title)| (name.to_string(), title.to_string()))
+            .collect();
+        let args = sections
+            .iter()
+            .position(|(name, _)| name == "args")
+            .unwrap_or(sections.len());
+
+        sections.splice(args..args, groups_of(set));
+
+        let mut sections: Vec<_> = sections
+            .into_iter()
+            .map(|(name, title)| HelpSection {
+                name,
+                title,
+                rows: vec![],
+            })
+            .collect();
+
+        for opt in set.iter() {
+            if let Some(section) = section_name(opt) {
+                let help = help_of(opt, ctx);
+                let (hint, help) = if section == "args" {
+                    pos_hint_help(opt, help, ctx.index_hint())
+                } else {
+                    (Cow::from(opt.hint()), help)
+                };
+                let (help, default) = split_default(&help);
+
+                if let Some(section) = sections.iter_mut().find(|v| v.name == section) {
+                    section.rows.push(HelpRow {
+                        uid: opt.uid(),
+                        name: opt.name().to_string(),
+                        alias: opt.alias().cloned().unwrap_or_default(),
+                        hint: hint.to_string(),
+                        help,
+                        default,
+                        optional: !opt.force(),
+                    });
+                }
+            }
+        }
+        Ok(Self {
+            name: ctx.name().clone(),
+            head: ctx.head().clone(),
+            foot: ctx.foot().clone(),
+            usage,
+            sections,
+            subs: vec![],
+        })
+    }
+
+    pub fn with_sub(mut self, sub: HelpModel) -> Self {
+        self.subs.push(sub);
+        self
+    }
+
+    pub fn add_sub(&mut self, sub: HelpModel) -> &mut Self {
+        self.subs.push(sub);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn head(&self) -> &str {
+        &self.head
+    }
+
+    pub fn foot(&self) -> &str {
+        &self.foot
+    }
+
+    /// The usage line same as displayed, such as `Usage: app [-v, --verbose]`.
+    pub fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    /// The sections in display order, the empty sections are included.
+    pub fn sections(&self) -> &[HelpSection] {
+        &self.sections
+    }
+
+    /// Return the section `name`, see [`HelpSection::name`].
+    pub fn section(&self, name: &str) -> Option<&HelpSection> {
+        self.sections.iter().find(|v| v.name == name)
+    }
+
+    /// The help models of sub commands.
+    pub fn subs(&self) -> &[HelpModel] {
+        &self.subs
+    }
+
+    /// Find the sub model by the name of sub command, such as `build` of `app build`.
+    pub fn find_sub(&self, name: &str) -> Option<&HelpModel> {
+        self.subs
+            .iter()
+            .find(|v| v.name.rsplit(' ').next() == Some(name))
+    }
+
+    /// Render the model as markdown document, such as a section of `README.md`.
+    ///
+    /// Each command has a heading with an anchor named after the command path,
+    /// such as `app-build` of `app build`, the rows of `Commands` link to them.
+    pub fn render_markdown(&self) -> String {
+        self.render_markdown_with(&ValueSources::default())
+    }
+
+    /// Same as [`render_markdown`](Self::render_markdown), but the environment variables
+    /// of options are listed in the `Env` column.
+    ///
+    /// The variables are matched by the name or alias of option in all the commands.
+    pub fn render_markdown_with(&self, sources: &ValueSources) -> String {
+        let mut out = String::new();
+
+        self.write_markdown(&mut out, sources, 2);
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    fn write_markdown(&self, out: &mut String, sources: &ValueSources, level: usize) {
+        let level = level.min(6);
+
+        out.push_str(&format!("<a id=\"{}\"></a>\n\n", anchor_of(&self.name)));
+        out.push_str(&format!("{} {}\n\n", "#".repeat(level), self.name));
+        if !self.head.is_empty() {
+            out.push_str(&format!("{}\n\n", self.head.trim()));
+        }
+        out.push_str(&format!("```text\n{}\n```\n\n", self.usage));
+        for section in self.sections.iter().filter(|v| !v.rows.is_empty()) {
+            let title = section.title.trim().trim_end_matches(':');
+
+            out.push_str(&format!("{} {}\n\n", "#".repeat((level + 1).min(6)), title));
+            if section.name == "command" {
+                out.push_str("| Command | Alias | Description |\n| --- | --- | --- |\n");
+                for row in section.rows.iter() {
+                    let name = match self.find_sub(&row.name) {
+                        Some(sub) => format!("[`{}`](#{})", row.name, anchor_of(&sub.name)),
+                        None => format!("`{}`", row.name),
+                    };
+
+                    out.push_str(&format!(
+                        "| {} | {} | {} |\n",
+                        name,
+                        row.alias
+                            .iter()
+                            .map(|v| format!("`{v}`"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        cell_of(&row.help, true),
+                    ));
+                }
+            } else {
+                out.push_str("| Name | Description | Default | Env |\n| --- | --- | --- | --- |\n");
+                for row in section.rows.iter() {
+                    let env = sources
+                        .envs()
+                        .iter()
+                        .filter(|(name, _)| name == &row.name || row.alias.contains(name))
+                        .map(|(_, var)| format!("`{}`", var.to_string_lossy()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    out.push_str(&format!(
+                        "| `{}` | {} | {} | {} |\n",
+                        row.hint.replace('|', "\\|"),
+                        cell_of(&row.help, row.optional),
+                        row.default
+                            .as_ref()
+                            .map(|v| format!("`{}`", v.replace('|', "\\|")))
+                            .unwrap_or_default(),
+                        env,
+                    ));
+                }
+            }
+            out.push('\n');
+        }
+        if !self.foot.is_empty() {
+            out.push_str(&format!("{}\n\n", self.foot.trim()));
+        }
+        for sub in self.subs.iter() {
+            sub.write_markdown(out, sources, level + 1);
+        }
+    }
+}
+
+/// Return the anchor of command path, such as `app-build` of `app build`.
+fn anchor_of(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+/// Escape the help message for markdown table, mark the required rows.
+fn cell_of(help: &str, optional: bool) -> String {
+    let help = help.trim().replace('|', "\\|").replace('\n', "<br>");
+
+    if optional {
+        help
+    } else if help.is_empty() {
+        String::from("**Required**")
+    } else {
+        format!("{help} **Required**")
+    }
+}
+
+/// Create the store of help, the usage line is not highlighted.
+fn new_store<'a>(
+    ctx: &HelpContext,
+    name: Cow<'a, str>,
+    hint: Cow<'a, str>,
+    help: Cow<'a, str>,
+    optional: bool,
+    position: bool,
+) -> Store<'a> {
+    let usage = hint.clone();
+    let (hint, help) = ctx.highlight(hint, help);
+    let mut store = Store::new(name, hint, help, Cow::default(), optional, position);
+
+    store.set_usage(usage);
+    store
+}
+
+/// Join the shortest and longest name of option with `|`.
+///
+/// Return None if the option has no alias or the hint is set by user.
+fn compact_usage<O: Opt>(opt: &O) -> Option<String> {
+    let alias = opt.alias().filter(|v| !v.is_empty())?;
+    let mut names: Vec<&str> = std::iter::once(opt.name())
+        .chain(alias.iter().map(|v| v.as_str()))
+        .collect();
+
+    names.sort_by_key(|v| v.len());
+    // same as the hint generated by aopt
+    if opt.hint() != names.join(", ") {
+        return None;
+    }
+    match (names.first(), names.last()) {
+        (Some(short), Some(long)) if names.len() > 1 => Some(format!("{}|{}", short, long)),
+        _ => None,
+    }
+}
+
+/// Replace the generated `name@index` hint with the description of index.
+///
+/// The hint is kept if user set it manually.
+fn pos_hint_help<'a, O: Opt>(
+    opt: &'a O,
+    help: Cow<'a, str>,
+    index_hint: IndexHint,
+) -> (Cow<'a, str>, Cow<'a, str>) {
+    let hint = opt.hint();
+
+    if let Some(index) = opt.index() {
+        let raw = format!("@{}", index.to_help());
+
+        if let (Some(prefix), Some(desc)) = (hint.strip_suffix(&raw), index_hint.describe(index)) {
+            if desc.is_empty() {
+                return (Cow::from(prefix), help);
+            } else if help.is_empty() {
+                return (Cow::from(prefix), Cow::from(desc));
+            } else {
+                return (Cow::from(prefix), Cow::from(format!("{} ({})", help, desc)));
+            }
+        }
+    }
+    (Cow::from(hint), help)
 }
 
 /// Using for cote-derive display help message.
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::io::Stdin;
 use std::path::PathBuf;
@@ -36,6 +37,7 @@ infer_override!(crate::prelude::Cmd);
 infer_override!(Stdin);
 infer_override!(crate::aopt::value::Stop);
 infer_override!(crate::aopt::value::Placeholder);
+infer_override!(crate::aopt::value::Assign);
 
 impl<T: InferOverride> InferOverride for crate::prelude::Pos<T> {
     fn infer_force() -> bool {
@@ -99,6 +101,15 @@ infer_override!(OsString);
 
 infer_override!(());
 
+#[cfg(feature = "chrono")]
+infer_override!(chrono::NaiveDate);
+#[cfg(feature = "chrono")]
+infer_override!(chrono::DateTime<chrono::Utc>);
+#[cfg(feature = "time")]
+infer_override!(time::OffsetDateTime);
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T, F> InferOverride for crate::aopt::value::Formatted<T, F> {}
+
 impl<T> InferOverride for Option<T> {
     fn infer_force() -> bool {
         false
@@ -112,3 +123,9 @@ impl<T, E> InferOverride for Result<T, E> {
 }
 
 impl<T> InferOverride for Vec<T> {}
+
+impl InferOverride for BTreeMap<String, String> {
+    fn infer_force() -> bool {
+        false
+    }
+}
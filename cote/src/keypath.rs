@@ -0,0 +1,65 @@
+use aopt::prelude::ConfigValue;
+use aopt::prelude::Opt;
+use aopt::prelude::Set;
+use aopt::raise_error;
+use aopt::set::SetOpt;
+use aopt::Uid;
+
+/// Return the key path of option name, such as `server` of `--server.port`
+/// and `server.tls` of `--server.tls.cert`.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// assert_eq!(key_path_of("--server.port"), Some("server"));
+/// assert_eq!(key_path_of("--server.tls.cert"), Some("server.tls"));
+/// assert_eq!(key_path_of("--port"), None);
+/// ```
+pub fn key_path_of(name: &str) -> Option<&str> {
+    name.trim_start_matches('-')
+        .rsplit_once('.')
+        .map(|(path, _)| path)
+        .filter(|path| !path.is_empty())
+}
+
+/// Join the key path and the name of option, such as `--server.port` of `server` and `--port`.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// assert_eq!(key_path_name("server", "--port"), "--server.port");
+/// assert_eq!(key_path_name("server.tls", "-c"), "--server.tls.c");
+/// ```
+pub fn key_path_name(path: &str, name: &str) -> String {
+    format!("--{}.{}", path, name.trim_start_matches('-'))
+}
+
+/// Move the name and aliases of option configuration under the key path.
+pub fn apply_key_path<C: ConfigValue>(cfg: &mut C, path: &str) {
+    if let Some(name) = cfg.name_mut() {
+        *name = key_path_name(path, name);
+    }
+    if let Some(alias) = cfg.alias_mut() {
+        for alias in alias.iter_mut() {
+            *alias = key_path_name(path, alias);
+        }
+    }
+}
+
+/// Find the uid of option `name` under the key path.
+pub fn key_path_uid<S>(set: &S, path: &str, name: &str) -> Result<Uid, aopt::Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let name = key_path_name(path, name);
+
+    set.iter()
+        .find(|opt| opt.mat_name(Some(&name)))
+        .map(|opt| opt.uid())
+        .ok_or_else(|| raise_error!("can not find option `{}` of key path `{}`", name, path))
+}
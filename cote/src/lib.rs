@@ -1,16 +1,26 @@
 #![doc = include_str!("../README.md")]
 pub mod _reference;
+pub(crate) mod grammar;
 pub(crate) mod help;
 pub(crate) mod infer;
+pub(crate) mod keypath;
 pub(crate) mod meta;
 pub(crate) mod parser;
 pub(crate) mod rctx;
+pub(crate) mod router;
+#[cfg(feature = "serde")]
+pub(crate) mod schema;
+#[cfg(feature = "serde")]
+pub(crate) mod spec;
 pub(crate) mod value;
+#[cfg(feature = "shell")]
+pub(crate) mod wrapper;
 
 pub mod valid;
 
 pub use aopt;
 pub use aopt::Error;
+pub use aopt::ErrorKind;
 pub use aopt_help;
 pub use cote_derive;
 
@@ -25,13 +35,19 @@ pub mod prelude {
     pub use aopt::opt::Pos;
     pub use aopt::parser::UserStyle;
     pub use aopt::prelude::ctor_default_name;
+    pub use aopt::prelude::diff_set;
+    pub use aopt::prelude::profile_handler;
+    pub use aopt::prelude::tokenize;
     pub use aopt::prelude::AOpt;
     pub use aopt::prelude::APolicyExt;
     pub use aopt::prelude::ARef;
     pub use aopt::prelude::ASer;
     pub use aopt::prelude::ASet;
     pub use aopt::prelude::Action;
+    pub use aopt::prelude::ArgFile;
+    pub use aopt::prelude::ArgFileEncoding;
     pub use aopt::prelude::Args;
+    pub use aopt::prelude::CancelToken;
     pub use aopt::prelude::Commit;
     pub use aopt::prelude::ConfigBuild;
     pub use aopt::prelude::ConfigBuildInfer;
@@ -39,43 +55,85 @@ pub mod prelude {
     pub use aopt::prelude::ConfigBuilder;
     pub use aopt::prelude::ConfigBuilderWith;
     pub use aopt::prelude::ConfigValue;
+    pub use aopt::prelude::Conflict;
     pub use aopt::prelude::Ctor;
     pub use aopt::prelude::Ctx;
     pub use aopt::prelude::DefaultSetChecker;
     pub use aopt::prelude::ErasedTy;
     pub use aopt::prelude::ErasedValue;
+    pub use aopt::prelude::ErrorKind;
+    #[cfg(feature = "miette")]
+    pub use aopt::prelude::FailureReport;
     pub use aopt::prelude::FilterMatcher;
     pub use aopt::prelude::HandlerCollection;
     pub use aopt::prelude::Index;
     pub use aopt::prelude::Infer;
     pub use aopt::prelude::Information;
     pub use aopt::prelude::InitializeValue;
+    pub use aopt::prelude::InvokeNext;
     pub use aopt::prelude::Invoker;
+    #[cfg(feature = "lazy")]
+    pub use aopt::prelude::Lazy;
+    #[cfg(feature = "lexer")]
+    pub use aopt::prelude::Lexeme;
+    #[cfg(feature = "lexer")]
+    pub use aopt::prelude::Lexer;
+    pub use aopt::prelude::Matched;
+    pub use aopt::prelude::Normalize;
+    pub use aopt::prelude::Normalizer;
+    pub use aopt::prelude::ObserverRef;
     pub use aopt::prelude::Opt;
+    pub use aopt::prelude::OptDiff;
     pub use aopt::prelude::OptParser;
+    pub use aopt::prelude::OptSpec;
     pub use aopt::prelude::OptValidator;
     pub use aopt::prelude::OptValueExt;
+    pub use aopt::prelude::Phase;
     pub use aopt::prelude::Policy;
+    pub use aopt::prelude::PolicyChain;
+    pub use aopt::prelude::PolicyObserver;
     pub use aopt::prelude::PolicyParser;
     pub use aopt::prelude::PolicySettings;
+    pub use aopt::prelude::PosCount;
     pub use aopt::prelude::PrefixOptValidator;
+    pub use aopt::prelude::Profile;
     pub use aopt::prelude::RawValParser;
+    pub use aopt::prelude::Redactor;
     pub use aopt::prelude::Return;
+    pub use aopt::prelude::RuleChecker;
     pub use aopt::prelude::ServicesValExt;
     pub use aopt::prelude::Set;
     pub use aopt::prelude::SetCfg;
     pub use aopt::prelude::SetChecker;
     pub use aopt::prelude::SetExt;
+    #[cfg(feature = "serde")]
+    pub use aopt::prelude::SetSchema;
     pub use aopt::prelude::SetValueFindExt;
     pub use aopt::prelude::Store;
     pub use aopt::prelude::Style;
+    pub use aopt::prelude::Token;
+    pub use aopt::prelude::Tokenizer;
     pub use aopt::prelude::ValInitializer;
     pub use aopt::prelude::ValStorer;
     pub use aopt::prelude::ValValidator;
+    pub use aopt::prelude::ValueEnum;
+    pub use aopt::prelude::ValueRule;
+    pub use aopt::prelude::ValueSource;
+    pub use aopt::prelude::ValueSources;
     pub use aopt::prelude::VecStore;
     pub use aopt::raise_error;
     pub use aopt::raise_failure;
+    pub use aopt::value::raw2bytes;
+    pub use aopt::value::raw2cow;
+    pub use aopt::value::raw2fromstr;
     pub use aopt::value::raw2str;
+    pub use aopt::value::Assign;
+    pub use aopt::value::BoolLiterals;
+    pub use aopt::value::ColorChoice;
+    pub use aopt::value::Decoding;
+    pub use aopt::value::FloatSyntax;
+    pub use aopt::value::NumLocale;
+    pub use aopt::value::Passthrough;
     pub use aopt::value::Placeholder;
     pub use aopt::GetoptRes;
     pub use aopt::Uid;
@@ -83,13 +141,39 @@ pub mod prelude {
     pub use cote_derive::CoteOpt;
     pub use cote_derive::CoteVal;
 
+    pub use crate::grammar::set_grammar;
     pub use crate::help::display_set_help;
+    pub use crate::help::display_set_help_ctx;
+    pub use crate::help::render_set_help_ctx;
+    pub use crate::help::write_set_help_ctx;
+    pub use crate::help::AppInfo;
     pub use crate::help::HelpContext;
+    pub use crate::help::HelpModel;
+    pub use crate::help::HelpRow;
+    pub use crate::help::HelpSection;
+    pub use crate::help::HelpStyle;
+    pub use crate::help::HelpTopic;
+    pub use crate::help::IndexHint;
     pub use crate::infer::InferOverride;
+    pub use crate::keypath::apply_key_path;
+    pub use crate::keypath::key_path_name;
+    pub use crate::keypath::key_path_of;
+    pub use crate::keypath::key_path_uid;
     pub use crate::meta::OptionMeta;
     pub use crate::parser::Parser;
     pub use crate::rctx::FailedInfo;
     pub use crate::rctx::RunningCtx;
+    pub use crate::router::Router;
+    #[cfg(feature = "serde")]
+    pub use crate::schema::ParserSchema;
+    #[cfg(feature = "serde")]
+    pub use crate::spec::ArgSpec;
+    #[cfg(feature = "serde")]
+    pub use crate::spec::CliSpec;
+    #[cfg(feature = "serde")]
+    pub use crate::spec::CommandSpec;
+    #[cfg(feature = "serde")]
+    pub use crate::spec::CLI_SPEC_VERSION;
     pub use crate::valid;
     pub use crate::value::fetch_uid_impl;
     pub use crate::value::fetch_vec_uid_impl;
@@ -100,6 +184,7 @@ pub mod prelude {
     pub use crate::FwdPolicy;
     pub use crate::IntoParserDerive;
     pub use crate::NullPolicy;
+    pub use crate::PolicyKind;
     pub use crate::PrePolicy;
     pub use crate::Status;
 }
@@ -134,6 +219,17 @@ where
         Ok(parser)
     }
     fn update(parser: &mut Parser<'inv, Set, Ser>) -> Result<()>;
+
+    /// Add the options under the key path `path`, such as `--server.port` for `path` `server`.
+    ///
+    /// It is used by the field configured with `#[arg(nested)]`,
+    /// the default implementation returns an error.
+    fn update_nested(_parser: &mut Parser<'inv, Set, Ser>, path: &str) -> Result<()> {
+        Err(aopt::raise_error!(
+            "can not add options of key path `{}`: type not support nested",
+            path
+        ))
+    }
 }
 
 pub trait ExtractFromSetDerive<'set, Set: SetValueFindExt>
@@ -143,6 +239,39 @@ where
     fn try_extract(set: &'set mut Set) -> Result<Self>
     where
         Self: Sized;
+
+    /// Extract the value from options under the key path `path`, see [`IntoParserDerive::update_nested`].
+    fn try_extract_nested(_set: &mut Set, path: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Err(aopt::raise_error!(
+            "can not extract options of key path `{}`: type not support nested",
+            path
+        ))
+    }
+
+    /// Update the fields whose options are matched in current parsing, keep other fields unchanged.
+    ///
+    /// The default implementation overwrites all the fields with [`try_extract`](Self::try_extract).
+    fn update_from_set(&mut self, set: &'set mut Set) -> Result<()>
+    where
+        Self: Sized,
+    {
+        *self = Self::try_extract(set)?;
+        Ok(())
+    }
+
+    /// Update the fields under the key path `path`, see [`update_from_set`](Self::update_from_set).
+    ///
+    /// The default implementation overwrites all the fields with [`try_extract_nested`](Self::try_extract_nested).
+    fn update_from_set_nested(&mut self, set: &mut Set, path: &str) -> Result<()>
+    where
+        Self: Sized,
+    {
+        *self = Self::try_extract_nested(set, path)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -185,6 +314,46 @@ pub type DelayPolicy<'inv, Set, Ser> = aopt::prelude::DelayPolicy<
     DefaultSetChecker<Parser<'inv, Set, Ser>>,
 >;
 
+/// The built-in policy selected at runtime, used by the struct configured with `policy = runtime`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PolicyKind {
+    /// Using [`FwdPolicy`].
+    #[default]
+    Fwd,
+
+    /// Using [`PrePolicy`].
+    Pre,
+
+    /// Using [`DelayPolicy`].
+    Delay,
+}
+
+impl PolicyKind {
+    /// Select [`PolicyKind::Pre`] if any argument matched the [`Cmd`](crate::prelude::Cmd) in `set`,
+    /// otherwise select [`PolicyKind::Fwd`].
+    /// The first argument is skipped, it is the name of program.
+    pub fn select<S>(set: &S, args: &[std::ffi::OsString]) -> Self
+    where
+        S: crate::prelude::Set,
+        aopt::set::SetOpt<S>: crate::prelude::Opt,
+    {
+        use crate::prelude::Opt;
+        use crate::prelude::Style;
+
+        let has_cmd = args.iter().skip(1).filter_map(|v| v.to_str()).any(|arg| {
+            set.iter().any(|opt| {
+                opt.mat_style(Style::Cmd) && (opt.mat_name(Some(arg)) || opt.mat_alias(arg))
+            })
+        });
+
+        if has_cmd {
+            Self::Pre
+        } else {
+            Self::Fwd
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NullPolicy<'inv, Set, Ser> {
     style_manager: OptStyleManager,
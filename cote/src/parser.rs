@@ -16,15 +16,20 @@ use aopt::prelude::OptParser;
 use aopt::prelude::OptValidator;
 use aopt::prelude::Policy;
 use aopt::prelude::PolicyParser;
+use aopt::prelude::Return;
 use aopt::prelude::SetCfg;
 use aopt::prelude::SetOpt;
 use aopt::raise_error;
 use aopt::ser::ServicesValExt;
+use aopt::set::Profile;
 use aopt::set::SetValueFindExt;
+use aopt::set::ValueSources;
 use aopt::Error;
 use aopt::Uid;
 
+use crate::grammar::write_set_grammar;
 use crate::prelude::HelpContext;
+use crate::prelude::HelpModel;
 use crate::prelude::RunningCtx;
 use crate::ExtractFromSetDerive;
 
@@ -227,6 +232,26 @@ where
         }
         Ok(())
     }
+
+    /// Render the grammar of current parser and all the sub parsers,
+    /// see [`set_grammar`](crate::prelude::set_grammar).
+    ///
+    /// The rules of sub parser are named after its name.
+    pub fn grammar(&self) -> String {
+        let mut grammar = String::default();
+
+        self.write_grammar(&mut grammar);
+        grammar
+    }
+
+    fn write_grammar(&self, grammar: &mut String) {
+        write_set_grammar(self.optset(), self.name(), grammar, |name| {
+            self.find_parser(name).is_ok()
+        });
+        for parser in self.parsers() {
+            parser.write_grammar(grammar);
+        }
+    }
 }
 
 impl<'a, Set, Ser> Parser<'a, Set, Ser>
@@ -260,6 +285,224 @@ where
     }
 }
 
+impl<'a, Set, Ser> Parser<'a, Set, Ser>
+where
+    Set: aopt::set::Set + OptParser + OptValidator + SetValueFindExt + 'a,
+    SetOpt<Set>: Opt,
+    SetCfg<Set>: ConfigValue + Default,
+    <Set as OptParser>::Output: Information,
+    Ser: ServicesValExt + Default + 'a,
+{
+    /// Register the `parser` as a sub command named `name` at runtime,
+    /// such as the sub commands contributed by plugins discovered from a directory.
+    ///
+    /// The sub command is listed in the help of current parser,
+    /// and the arguments after it are parsed by `parser` with [`FwdPolicy`](crate::FwdPolicy).
+    /// The `handler` is invoked with `parser` if the parsing success,
+    /// and its return value is saved as the value of sub command.
+    /// Help of `parser` is displayed if its `--help` option is set.
+    ///
+    /// Like the derived type with sub commands, current parser should be parsed with
+    /// [`PrePolicy`](crate::PrePolicy) so the options of sub command are not rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// # fn main() -> cote::Result<()> {
+    /// let mut parser = Parser::<ASet, ASer>::default().with_name("app");
+    /// let mut plugin = Parser::<ASet, ASer>::default();
+    ///
+    /// plugin.add_opt("--count=i")?.set_value_t(1i64);
+    /// parser.register_sub_parser("hello", plugin, |plugin, _| {
+    ///     let count = *plugin.find_val::<i64>("--count")?;
+    ///
+    ///     Ok(Some(vec!["hello"; count as usize].join(" ")))
+    /// })?;
+    /// parser.find_opt_mut("hello")?.set_help("Say hello from plugin");
+    ///
+    /// let ret = parser.parse_policy(
+    ///     Args::from(["app", "hello", "--count", "2"]),
+    ///     &mut PrePolicy::default(),
+    /// )?;
+    ///
+    /// assert!(ret.status());
+    /// assert_eq!(parser.find_val::<String>("hello")?, "hello hello");
+    /// assert!(parser.find_parser("hello").is_ok());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    pub fn register_sub_parser<O, H>(
+        &mut self,
+        name: impl Into<String>,
+        parser: Self,
+        handler: H,
+    ) -> Result<Uid, Error>
+    where
+        O: ErasedTy,
+        H: FnMut(&mut Self, &mut Ser) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    {
+        let name = name.into();
+        let uid = self.add_sub_parser_impl(name.clone(), parser)?;
+
+        self.entry(uid)?.on(Self::sub_parser_handler(name, handler));
+        Ok(uid)
+    }
+
+    /// Register the `parser` as a sub command named `name` at runtime,
+    /// such as the sub commands contributed by plugins discovered from a directory.
+    ///
+    /// The sub command is listed in the help of current parser,
+    /// and the arguments after it are parsed by `parser` with [`FwdPolicy`](crate::FwdPolicy).
+    /// The `handler` is invoked with `parser` if the parsing success,
+    /// and its return value is saved as the value of sub command.
+    /// Help of `parser` is displayed if its `--help` option is set.
+    ///
+    /// Like the derived type with sub commands, current parser should be parsed with
+    /// [`PrePolicy`](crate::PrePolicy) so the options of sub command are not rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// # fn main() -> cote::Result<()> {
+    /// let mut parser = Parser::<ASet, ASer>::default().with_name("app");
+    /// let mut plugin = Parser::<ASet, ASer>::default();
+    ///
+    /// plugin.add_opt("--count=i")?.set_value_t(1i64);
+    /// parser.register_sub_parser("hello", plugin, |plugin, _| {
+    ///     let count = *plugin.find_val::<i64>("--count")?;
+    ///
+    ///     Ok(Some(vec!["hello"; count as usize].join(" ")))
+    /// })?;
+    /// parser.find_opt_mut("hello")?.set_help("Say hello from plugin");
+    ///
+    /// let ret = parser.parse_policy(
+    ///     Args::from(["app", "hello", "--count", "2"]),
+    ///     &mut PrePolicy::default(),
+    /// )?;
+    ///
+    /// assert!(ret.status());
+    /// assert_eq!(parser.find_val::<String>("hello")?, "hello hello");
+    /// assert!(parser.find_parser("hello").is_ok());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "sync"))]
+    pub fn register_sub_parser<O, H>(
+        &mut self,
+        name: impl Into<String>,
+        parser: Self,
+        handler: H,
+    ) -> Result<Uid, Error>
+    where
+        O: ErasedTy,
+        H: FnMut(&mut Self, &mut Ser) -> Result<Option<O>, Error> + 'a,
+    {
+        let name = name.into();
+        let uid = self.add_sub_parser_impl(name.clone(), parser)?;
+
+        self.entry(uid)?.on(Self::sub_parser_handler(name, handler));
+        Ok(uid)
+    }
+
+    /// Add the sub command option and the sub parser, return the uid of sub command.
+    fn add_sub_parser_impl(&mut self, name: String, mut parser: Self) -> Result<Uid, Error> {
+        if self.find_parser(&name).is_ok() {
+            return Err(raise_error!("sub parser named `{}` already exists", name));
+        }
+        let mut cfg = SetCfg::<Set>::default();
+
+        cfg.set_name(name.clone());
+        <aopt::opt::Cmd as aopt::prelude::Infer>::infer_fill_info(&mut cfg)?;
+
+        let ctor_name = aopt::prelude::ctor_default_name();
+        let opt = aopt::prelude::Ctor::new_with(
+            aopt::prelude::SetExt::ctor_mut(&mut self.set, &ctor_name)?,
+            cfg,
+        )
+        .map_err(Into::into)?;
+        let uid = self.set.insert(opt);
+
+        parser.set_name(name);
+        parser.ser.get_or_insert_with(Ser::default);
+        parser.inv.get_or_insert_with(Invoker::default);
+        self.add_parser(parser);
+        Ok(uid)
+    }
+
+    /// Return the handler of sub command which parsing the arguments with sub parser `name`.
+    fn sub_parser_handler<O, H>(
+        name: String,
+        mut handler: H,
+    ) -> impl FnMut(&mut Self, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a
+    where
+        O: ErasedTy,
+        H: FnMut(&mut Self, &mut Ser) -> Result<Option<O>, Error> + 'a,
+    {
+        move |set: &mut Self, ser: &mut Ser, ctx: &Ctx| {
+            let index = ctx.idx()?;
+            let mut args: Vec<_> = ctx.args().iter().map(|v| v.to_os_string()).collect();
+
+            args.remove(index);
+
+            let parser = set.find_parser_mut(&name)?;
+            let mut policy = crate::FwdPolicy::<'a, Set, Ser>::default();
+
+            // setup running ctx, the parent may not have one if it is not parsed by derived type
+            parser.set_rctx(ser.sve_take_val::<RunningCtx>().unwrap_or_default());
+
+            let offset = parser.rctx()?.offset();
+
+            // the sub command is removed, shift the index of sub parser
+            parser
+                .rctx_mut()?
+                .add_name(name.clone())
+                .set_offset(offset + index);
+
+            let ret = PolicyParser::parse_policy(parser, Args::from(args), &mut policy);
+            let mut rctx = parser.take_rctx()?;
+            // map the index in failure to the top level parser
+            let ret = ret.map(|mut ret| {
+                if let Some(failure) = ret.take_failure() {
+                    ret.set_failure(rctx.orig_failure(&failure));
+                }
+                ret
+            });
+
+            rctx.set_offset(offset);
+            if !rctx.display_help() {
+                if parser.find_val::<bool>("--help").ok() == Some(&true) {
+                    rctx.set_display_help(true);
+                    rctx.set_exit(true);
+                    rctx.set_help_context(HelpContext::default());
+                } else {
+                    rctx.pop_name(); // pop current name if not need display help
+                }
+            }
+            // indicate we have accessed sub parser
+            rctx.set_sub_parser(true);
+            ser.sve_insert(rctx);
+
+            let ret = ret?;
+
+            if ret.status() {
+                ser.sve_val_mut::<RunningCtx>()?.clear_failed_info();
+                handler(parser, ser)
+            } else {
+                ser.sve_val_mut::<RunningCtx>()?
+                    .add_failed_info(crate::prelude::FailedInfo::new(name.clone(), ret));
+                Ok(None)
+            }
+        }
+    }
+}
+
 impl<Set, Ser> Parser<'_, Set, Ser>
 where
     Set: crate::prelude::Set,
@@ -292,6 +535,16 @@ where
 
         T::try_extract(set)
     }
+
+    /// Update the fields of `value` whose options are matched in current parsing.
+    pub fn update_type<T>(&'b mut self, value: &mut T) -> Result<(), Error>
+    where
+        T: ExtractFromSetDerive<'b, Set>,
+    {
+        let set = self.optset_mut();
+
+        value.update_from_set(set)
+    }
 }
 
 impl<Set, Ser> aopt::set::Set for Parser<'_, Set, Ser>
@@ -331,6 +584,30 @@ where
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         Set::insert(&mut self.set, opt)
     }
+
+    fn candidates(&mut self, name: &str) -> Vec<Uid> {
+        Set::candidates(&mut self.set, name)
+    }
+
+    fn insert_profile(&mut self, profile: Profile) -> Result<(), Error> {
+        Set::insert_profile(&mut self.set, profile)
+    }
+
+    fn profile(&self, name: &str) -> Option<&Profile> {
+        Set::profile(&self.set, name)
+    }
+
+    fn get(&self, uid: Uid) -> Option<&SetOpt<Self>> {
+        Set::get(&self.set, uid)
+    }
+
+    fn get_mut(&mut self, uid: Uid) -> Option<&mut SetOpt<Self>> {
+        Set::get_mut(&mut self.set, uid)
+    }
+
+    fn replace(&mut self, uid: Uid, opt: SetOpt<Self>) -> Option<SetOpt<Self>> {
+        Set::replace(&mut self.set, uid, opt)
+    }
 }
 
 impl<Set, Ser> OptParser for Parser<'_, Set, Ser>
@@ -359,6 +636,14 @@ where
     fn split<'b>(&self, name: &Cow<'b, str>) -> Result<(Cow<'b, str>, Cow<'b, str>), Self::Error> {
         OptValidator::split(&self.set, name)
     }
+
+    fn check_bare(&mut self, name: &str) -> Result<bool, Self::Error> {
+        OptValidator::check_bare(&mut self.set, name)
+    }
+
+    fn maybe_path(&self, name: &str) -> bool {
+        OptValidator::maybe_path(&self.set, name)
+    }
 }
 
 impl<Set, Ser> SetValueFindExt for Parser<'_, Set, Ser>
@@ -687,60 +972,132 @@ where
     }
 
     pub fn display_help_ctx(&self, ctx: HelpContext) -> Result<(), Error> {
-        let set = self.optset();
+        crate::help::display_set_help_ctx(self.optset(), &ctx)
+            .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))
+    }
 
-        crate::display_help!(
-            set,
-            ctx.name(),
-            ctx.head(),
-            ctx.foot(),
-            ctx.width(),
-            ctx.usagew()
-        )
+    /// Render the help message into a string, see [`render_set_help_ctx`](crate::help::render_set_help_ctx).
+    pub fn render_help_ctx(&self, ctx: &HelpContext) -> Result<String, Error> {
+        crate::help::render_set_help_ctx(self.optset(), ctx)
+            .map_err(|e| aopt::raise_error!("can not render help message: {:?}", e))
+    }
+
+    /// Build the [`HelpModel`] of parser, the models of sub parsers are added recursively,
+    /// the name of sub model is the names of commands joined by space, such as `app build`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// #
+    /// #[derive(Debug, Cote)]
+    /// #[cote(name = "app")]
+    /// pub struct Cli {
+    ///     /// Print more message
+    ///     verbose: bool,
+    ///
+    ///     #[sub(alias = "b", help = "Build the project")]
+    ///     build: Option<Build>,
+    /// }
+    ///
+    /// #[derive(Debug, Cote)]
+    /// pub struct Build {
+    ///     /// The number of jobs
+    ///     #[arg(value = 4u64)]
+    ///     jobs: u64,
+    /// }
+    ///
+    /// # fn main() -> cote::Result<()> {
+    /// let parser = Cli::into_parser()?;
+    /// let model = parser.help_model(&HelpContext::default().with_name("app"))?;
+    /// let build = &model.section("command").unwrap().rows()[0];
+    ///
+    /// assert_eq!(build.name(), "build");
+    /// assert_eq!(build.alias(), ["b"]);
+    /// assert_eq!(build.help(), "Build the project");
+    ///
+    /// let build = model.find_sub("build").unwrap();
+    /// let jobs = &build.section("option").unwrap().rows()[0];
+    ///
+    /// assert_eq!(build.name(), "app build");
+    /// assert_eq!(jobs.help(), "The number of jobs");
+    /// assert_eq!(jobs.default(), Some("4"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn help_model(&self, ctx: &HelpContext) -> Result<HelpModel, Error> {
+        let mut model = HelpModel::new(self.optset(), ctx)
+            .map_err(|e| aopt::raise_error!("can not build help model: {:?}", e))?;
+
+        for parser in self.parsers() {
+            let name = format!("{} {}", ctx.name(), parser.name());
+
+            model.add_sub(parser.help_model(&ctx.clone().with_name(name))?);
+        }
+        Ok(model)
+    }
+
+    /// Render the markdown document of parser and its sub parsers,
+    /// see [`HelpModel::render_markdown_with`].
+    pub fn render_markdown(
+        &self,
+        ctx: &HelpContext,
+        sources: &ValueSources,
+    ) -> Result<String, Error> {
+        Ok(self.help_model(ctx)?.render_markdown_with(sources))
     }
 
     pub fn display_sub_help(&self, names: Vec<&str>, ctx: &HelpContext) -> Result<(), Error> {
-        self.display_sub_help_impl(names, ctx, 0)
+        let (parser, ctx) = self.find_sub_help(&names, ctx)?;
+
+        crate::help::display_set_help_ctx(parser.optset(), &ctx)
+            .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))
     }
 
-    fn display_sub_help_impl(
+    /// Same as [`display_sub_help`](Self::display_sub_help), but return the help message as string.
+    pub fn render_sub_help(&self, names: Vec<&str>, ctx: &HelpContext) -> Result<String, Error> {
+        let (parser, ctx) = self.find_sub_help(&names, ctx)?;
+
+        crate::help::render_set_help_ctx(parser.optset(), &ctx)
+            .map_err(|e| aopt::raise_error!("can not render help message: {:?}", e))
+    }
+
+    fn find_sub_help(
         &self,
-        names: Vec<&str>,
+        names: &[&str],
         ctx: &HelpContext,
-        i: usize,
-    ) -> Result<(), Error> {
+    ) -> Result<(&Self, HelpContext), Error> {
+        self.find_sub_help_impl(names, 0)
+            .map(|parser| (parser, ctx.clone().with_name(names.join(" "))))
+            .ok_or_else(|| {
+                raise_error!(
+                    "can not display help message for names `{names:?}` with context: {ctx:?}"
+                )
+            })
+    }
+
+    fn find_sub_help_impl(&self, names: &[&str], i: usize) -> Option<&Self> {
         if !names.is_empty() {
             let max = names.len() - 1;
 
             if let Some(name) = names.get(i) {
-                if i == max && (i > 0 || name == self.name()) {
-                    let name = names.join(" ");
-                    let optset = self.optset();
-
-                    return crate::display_help!(
-                        optset,
-                        &name,
-                        ctx.head(),
-                        ctx.foot(),
-                        ctx.width(),
-                        ctx.usagew()
-                    );
-                } else if i < max && name == self.name() {
+                if i == max && (i > 0 || *name == self.name()) {
+                    return Some(self);
+                } else if i < max && *name == self.name() {
                     if let Some(name) = names.get(i + 1) {
                         let sub_parsers = self.parsers();
 
                         for sub_parser in sub_parsers {
                             if sub_parser.name() == name {
-                                return sub_parser.display_sub_help_impl(names, ctx, i + 1);
+                                return sub_parser.find_sub_help_impl(names, i + 1);
                             }
                         }
                     }
                 }
             }
         }
-        Err(raise_error!(
-            "can not display help message for names `{names:?}` with context: {ctx:?}"
-        ))
+        None
     }
 }
 
@@ -777,15 +1134,8 @@ where
             if *help_option {
                 let set = self.optset();
 
-                crate::help::display_set_help(
-                    set,
-                    ctx.name(),
-                    ctx.head(),
-                    ctx.foot(),
-                    ctx.width(),
-                    ctx.usagew(),
-                )
-                .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))?;
+                crate::help::display_set_help_ctx(set, ctx)
+                    .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))?;
                 return Ok(true);
             }
         }
@@ -821,4 +1171,34 @@ where
         }
         Ok(false)
     }
+
+    /// Check the result of parsing, display the help message if the help `option` is set or parsing failed.
+    ///
+    /// Return `true` if parsing successes and no help displayed,
+    /// `false` if help is requested, or the failure with the arguments if parsing failed.
+    pub fn ok_or_print_help(
+        &self,
+        ret: &Return,
+        option: impl ConfigBuild<SetCfg<Set>>,
+        ctx: &HelpContext,
+    ) -> Result<bool, Error> {
+        if self.display_help_if_ctx(option, ctx)? {
+            Ok(false)
+        } else if let Some(failure) = ret.failure() {
+            let args: Vec<_> = ret
+                .orig_args()
+                .iter()
+                .map(|v| v.to_string_lossy())
+                .collect();
+
+            crate::help::display_set_help_ctx(self.optset(), ctx)
+                .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))?;
+            Err(
+                raise_error!("failed to parse arguments `{}`", args.join(" "))
+                    .cause_by(failure.clone()),
+            )
+        } else {
+            Ok(true)
+        }
+    }
 }
@@ -1,4 +1,6 @@
 use crate::prelude::HelpContext;
+use crate::Error;
+use crate::ErrorKind;
 use crate::Return;
 use std::ops::Deref;
 use std::ops::DerefMut;
@@ -51,6 +53,8 @@ pub struct RunningCtx {
     failed_info: Vec<FailedInfo>,
 
     help_context: Option<HelpContext>,
+
+    offset: usize,
 }
 
 impl RunningCtx {
@@ -79,6 +83,11 @@ impl RunningCtx {
         self
     }
 
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     pub fn set_names(&mut self, names: Vec<String>) -> &mut Self {
         self.names = names;
         self
@@ -104,6 +113,11 @@ impl RunningCtx {
         self
     }
 
+    pub fn set_offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = offset;
+        self
+    }
+
     pub fn add_failed_info(&mut self, failed_info: FailedInfo) -> &mut Self {
         self.failed_info.push(failed_info);
         self
@@ -121,8 +135,11 @@ impl RunningCtx {
         self.sub_parser
     }
 
+    /// Return true if the process should exit after displaying help message.
+    ///
+    /// It is always false with feature `wasm`, the process can not exit in browser.
     pub fn exit(&self) -> bool {
-        self.exit
+        self.exit && !cfg!(feature = "wasm")
     }
 
     pub fn failed_info(&self) -> &[FailedInfo] {
@@ -133,6 +150,75 @@ impl RunningCtx {
         self.help_context.as_ref()
     }
 
+    /// The number of `NOA` removed before the arguments of current parser.
+    ///
+    /// The sub command is removed when passing arguments to sub parser,
+    /// so the `@1` of sub parser is the first argument after the sub command.
+    /// It is zero for the top level parser.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Map the `NOA` index of current parser to the index in the top level parser.
+    pub fn orig_index(&self, index: usize) -> usize {
+        if index == 0 {
+            0
+        } else {
+            index + self.offset
+        }
+    }
+
+    /// Map the `NOA` index in the [`PosRequired`](ErrorKind::PosRequired) and
+    /// [`CmdRequired`](ErrorKind::CmdRequired) failures to the index in the top level parser,
+    /// such as `target@1` of sub command `run` will be `target@2` in `app run x86`.
+    ///
+    /// Only the fixed index such as `@1` is mapped, the range and list are kept.
+    pub fn orig_failure(&self, failure: &Error) -> Error {
+        let mut ret = failure.clone();
+
+        if self.offset > 0
+            && matches!(
+                failure.kind(),
+                ErrorKind::PosRequired | ErrorKind::CmdRequired
+            )
+        {
+            if let Some(desp) = failure.desp() {
+                ret = ret.with_desp(self.orig_hint(desp));
+            }
+            if let Some(field) = failure.field() {
+                ret = ret.with_field(self.orig_hint(field));
+            }
+        }
+        match failure.caused_by() {
+            Some(cause) => ret.cause_by(self.orig_failure(cause)),
+            None => ret,
+        }
+    }
+
+    // replace the `@N` in hint with the original index
+    fn orig_hint(&self, hint: &str) -> String {
+        let mut ret = String::with_capacity(hint.len());
+        let mut parts = hint.split('@');
+
+        ret.push_str(parts.next().unwrap_or_default());
+        for part in parts {
+            let end = part
+                .find(|ch: char| !ch.is_ascii_digit())
+                .unwrap_or(part.len());
+            let (index, rest) = part.split_at(end);
+
+            ret.push('@');
+            match index.parse::<usize>() {
+                Ok(index) if !rest.starts_with('.') => {
+                    ret.push_str(&self.orig_index(index).to_string());
+                }
+                _ => ret.push_str(index),
+            }
+            ret.push_str(rest);
+        }
+        ret
+    }
+
     pub fn take_failed_info(&mut self) -> Vec<FailedInfo> {
         std::mem::take(&mut self.failed_info)
     }
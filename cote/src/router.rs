@@ -0,0 +1,208 @@
+use std::marker::PhantomData;
+
+use aopt::prelude::ArgFile;
+use aopt::prelude::Args;
+use aopt::prelude::ConfigValue;
+use aopt::prelude::ErasedTy;
+use aopt::prelude::Information;
+use aopt::prelude::Opt;
+use aopt::prelude::OptParser;
+use aopt::prelude::OptValidator;
+use aopt::prelude::PolicyParser;
+use aopt::prelude::SetCfg;
+use aopt::prelude::SetOpt;
+use aopt::raise_error;
+use aopt::ser::ServicesValExt;
+use aopt::set::SetValueFindExt;
+use aopt::Error;
+
+use crate::prelude::Parser;
+use crate::prelude::RunningCtx;
+use crate::PrePolicy;
+
+/// Route the command strings to the registered closures, such as the commands received by chat bots or RPC.
+///
+/// Each route is a sub parser registered by [`register_sub_parser`](Parser::register_sub_parser),
+/// the closure of matched route is invoked with the sub parser after the arguments parsed,
+/// and its return value is returned by [`dispatch`](Router::dispatch).
+/// No derive is needed, the options are added to the sub parser directly.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// # fn main() -> cote::Result<()> {
+/// let mut router = Router::<ASet, ASer, String>::new("bot");
+/// let mut greet = Parser::<ASet, ASer>::default();
+/// let mut roll = Parser::<ASet, ASer>::default();
+///
+/// greet.add_opt("--loud=b")?;
+/// greet.add_opt("name=p@1")?.set_pos_type::<String>();
+/// roll.add_opt("--sides;-s=i")?.set_value_t(6i64);
+///
+/// router.route("greet", greet, |parser, _| {
+///     let name = parser.find_val::<String>("name")?;
+///
+///     Ok(match parser.find_val::<bool>("--loud")? {
+///         true => format!("HELLO {}!", name.to_uppercase()),
+///         false => format!("hello {name}"),
+///     })
+/// })?;
+/// router.route("roll", roll, |parser, _| {
+///     Ok(format!("rolling d{}", parser.find_val::<i64>("--sides")?))
+/// })?;
+///
+/// assert_eq!(router.dispatch_line("greet --loud 'cote rs'")?, "HELLO COTE RS!");
+/// assert_eq!(router.dispatch_line("roll -s 20")?, "rolling d20");
+/// assert_eq!(router.dispatch(["bot", "greet", "alice"])?, "hello alice");
+/// assert!(router.dispatch_line("").is_err());
+/// assert!(router.dispatch_line("roll -s twenty").is_err());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Router<'a, Set, Ser, R> {
+    parser: Parser<'a, Set, Ser>,
+
+    routes: Vec<String>,
+
+    marker: PhantomData<R>,
+}
+
+impl<Set, Ser, R> std::fmt::Debug for Router<'_, Set, Ser, R>
+where
+    Set: std::fmt::Debug,
+    Ser: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field("parser", &self.parser)
+            .field("routes", &self.routes)
+            .finish()
+    }
+}
+
+impl<'a, Set, Ser, R> Router<'a, Set, Ser, R>
+where
+    Set: Default,
+    Ser: Default,
+{
+    /// Create a router with a parser named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::from_parser(Parser::<Set, Ser>::default().with_name(name))
+    }
+}
+
+impl<'a, Set, Ser, R> Router<'a, Set, Ser, R> {
+    /// Create a router with given parser, the global options can be added to it.
+    pub fn from_parser(parser: Parser<'a, Set, Ser>) -> Self {
+        Self {
+            parser,
+            routes: vec![],
+            marker: PhantomData,
+        }
+    }
+
+    pub fn parser(&self) -> &Parser<'a, Set, Ser> {
+        &self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<'a, Set, Ser> {
+        &mut self.parser
+    }
+
+    /// The names of routes in order of registration.
+    pub fn routes(&self) -> &[String] {
+        &self.routes
+    }
+}
+
+impl<'a, Set, Ser, R> Router<'a, Set, Ser, R>
+where
+    R: ErasedTy,
+    Set: aopt::set::Set + OptParser + OptValidator + SetValueFindExt + 'a,
+    SetOpt<Set>: Opt,
+    SetCfg<Set>: ConfigValue + Default,
+    <Set as OptParser>::Output: Information,
+    Ser: ServicesValExt + Default + 'a,
+{
+    /// Add a route named `name`, the `handler` is invoked with `parser` if the route matched.
+    #[cfg(feature = "sync")]
+    pub fn route<H>(
+        &mut self,
+        name: impl Into<String>,
+        parser: Parser<'a, Set, Ser>,
+        mut handler: H,
+    ) -> Result<&mut Self, Error>
+    where
+        H: FnMut(&mut Parser<'a, Set, Ser>, &mut Ser) -> Result<R, Error> + Send + Sync + 'a,
+    {
+        let name = name.into();
+
+        self.parser
+            .register_sub_parser(name.clone(), parser, move |parser, ser| {
+                handler(parser, ser).map(Some)
+            })?;
+        self.routes.push(name);
+        Ok(self)
+    }
+
+    /// Add a route named `name`, the `handler` is invoked with `parser` if the route matched.
+    #[cfg(not(feature = "sync"))]
+    pub fn route<H>(
+        &mut self,
+        name: impl Into<String>,
+        parser: Parser<'a, Set, Ser>,
+        mut handler: H,
+    ) -> Result<&mut Self, Error>
+    where
+        H: FnMut(&mut Parser<'a, Set, Ser>, &mut Ser) -> Result<R, Error> + 'a,
+    {
+        let name = name.into();
+
+        self.parser
+            .register_sub_parser(name.clone(), parser, move |parser, ser| {
+                handler(parser, ser).map(Some)
+            })?;
+        self.routes.push(name);
+        Ok(self)
+    }
+
+    /// Parse the arguments and return the value of matched route,
+    /// the first argument is the name of program.
+    ///
+    /// Return an error if no route matched or the arguments of route are invalid.
+    pub fn dispatch(&mut self, args: impl Into<Args>) -> Result<R, Error> {
+        let mut policy = PrePolicy::<'a, Set, Ser>::default();
+
+        // clear the matched state of previous dispatching
+        self.parser.reset()?;
+        self.parser.set_rctx(RunningCtx::default());
+
+        let ret = self.parser.parse_policy(args.into(), &mut policy);
+        let mut rctx = self.parser.take_rctx()?;
+
+        if let Some(error) = rctx.chain_error() {
+            return Err(error);
+        }
+        ret?.ok()?;
+        for name in self.routes.iter() {
+            if self.parser.find_opt(name.as_str())?.matched() {
+                return self.parser.take_val::<R>(name.as_str());
+            }
+        }
+        Err(raise_error!(
+            "no route matched in router `{}`",
+            self.parser.name()
+        ))
+    }
+
+    /// Split the `line` into arguments like shell and [`dispatch`](Router::dispatch) them,
+    /// the quoted part such as `'hello world'` is kept as one argument.
+    pub fn dispatch_line(&mut self, line: &str) -> Result<R, Error> {
+        let args = ArgFile::default().with_comments(vec![]).split(line);
+
+        self.dispatch(std::iter::once(self.parser.name().clone()).chain(args))
+    }
+}
@@ -0,0 +1,120 @@
+use aopt::prelude::ConfigValue;
+use aopt::prelude::Opt;
+use aopt::prelude::SetCfg;
+use aopt::prelude::SetOpt;
+use aopt::raise_error;
+use aopt::set::SetSchema;
+use aopt::Error;
+
+use crate::prelude::Parser;
+
+/// The serializable definition of [`Parser`], including the options and the sub parser tree.
+///
+/// The handlers of options are not part of schema, they need to be registered after rebuilding the parser.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// # use cote::Result;
+/// #
+/// #[derive(Debug, Cote)]
+/// pub struct Cli {
+///     /// Print debug message
+///     debug: bool,
+///
+///     #[sub()]
+///     build: Option<Build>,
+/// }
+///
+/// #[derive(Debug, Cote)]
+/// pub struct Build {
+///     #[arg(alias = "-j")]
+///     jobs: Option<u64>,
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let json = ParserSchema::new(&Cli::into_parser()?)?.to_json()?;
+/// let mut parser = ParserSchema::from_json(&json)?.to_parser::<ASet, ASer>()?;
+///
+/// assert_eq!(parser.find_opt("--debug")?.help(), "Print debug message");
+///
+/// let mut policy = Cli::into_policy();
+///
+/// parser.parse_policy(Args::from(["app", "--debug", "build"]), &mut policy)?;
+/// assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+/// assert_eq!(parser.find_val::<bool>("build")?, &true);
+///
+/// let build = parser.find_parser_mut("build")?;
+/// let mut policy = Build::into_policy();
+///
+/// build.parse_policy(Args::from(["build", "-j", "4"]), &mut policy)?;
+/// assert_eq!(build.find_val::<u64>("--jobs")?, &4);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ParserSchema {
+    name: String,
+
+    set: SetSchema,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    parsers: Vec<ParserSchema>,
+}
+
+impl ParserSchema {
+    /// Dump the definition of `parser` and its sub parsers.
+    pub fn new<Set, Ser>(parser: &Parser<'_, Set, Ser>) -> Result<Self, Error>
+    where
+        Set: aopt::set::Set,
+        SetOpt<Set>: Opt,
+    {
+        Ok(Self {
+            name: parser.name().clone(),
+            set: SetSchema::new(parser.optset())?,
+            parsers: parser
+                .parsers()
+                .iter()
+                .map(Self::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set(&self) -> &SetSchema {
+        &self.set
+    }
+
+    pub fn parsers(&self) -> &[ParserSchema] {
+        &self.parsers
+    }
+
+    /// Rebuild the [`Parser`] and its sub parsers.
+    pub fn to_parser<'a, Set, Ser>(&self) -> Result<Parser<'a, Set, Ser>, Error>
+    where
+        Set: aopt::set::Set + Default,
+        Ser: Default,
+        SetCfg<Set>: ConfigValue + Default,
+    {
+        let mut parser = Parser::<Set, Ser>::default().with_name(self.name.clone());
+
+        self.set.apply(parser.optset_mut())?;
+        for sub in self.parsers.iter() {
+            parser.add_parser(sub.to_parser()?);
+        }
+        Ok(parser)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self).map_err(|e| raise_error!("can not dump schema: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|e| raise_error!("can not load schema: {}", e))
+    }
+}
@@ -0,0 +1,386 @@
+use aopt::prelude::Action;
+use aopt::prelude::Opt;
+use aopt::prelude::SetOpt;
+use aopt::prelude::Style;
+use aopt::raise_error;
+use aopt::Error;
+
+use crate::help::help_of;
+use crate::help::split_default;
+use crate::prelude::HelpContext;
+use crate::prelude::Parser;
+
+/// The version of [`CliSpec`] format, it is increased when the format changed incompatibly.
+pub const CLI_SPEC_VERSION: u32 = 1;
+
+/// The machine-readable description of command line interface, it is using for
+/// documentation generators or completion tools.
+///
+/// Different from [`ParserSchema`](crate::prelude::ParserSchema), the spec can not be loaded back into a parser.
+/// The field names follow the convention of `clap`, such as `about`, `long`, `short` and `possible_values`.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// # use cote::Result;
+/// #
+/// #[derive(Debug, Cote)]
+/// pub struct Cli {
+///     /// Set the log level
+///     #[arg(alias = "-l", value = "info", choices = ["debug", "info", "warn"])]
+///     level: String,
+///
+///     /// Build the project
+///     #[sub(alias = "b")]
+///     build: Option<Build>,
+/// }
+///
+/// #[derive(Debug, Cote)]
+/// pub struct Build {
+///     /// The files need to build
+///     #[pos()]
+///     files: Vec<String>,
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let spec = CliSpec::new(&Cli::into_parser()?, &HelpContext::default().with_name("app"))?;
+/// let level = spec.command().find_arg("--level").unwrap();
+///
+/// assert_eq!(level.long(), Some("level"));
+/// assert_eq!(level.short(), Some('l'));
+/// assert_eq!(level.help(), "Set the log level");
+/// assert_eq!(level.default(), Some("\"info\""));
+/// assert_eq!(level.possible_values(), ["debug", "info", "warn"]);
+///
+/// let build = spec.command().find_sub("build").unwrap();
+///
+/// assert_eq!(build.about(), "Build the project");
+/// assert_eq!(build.aliases(), ["b"]);
+/// assert_eq!(build.find_arg("files").unwrap().index(), Some("1"));
+/// assert!(spec.to_json()?.starts_with("{\"version\":1,"));
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CliSpec {
+    version: u32,
+
+    command: CommandSpec,
+}
+
+impl CliSpec {
+    /// Describe the `parser` and its sub parsers, the name and about of root command are
+    /// taken from [`name`](HelpContext::name) and [`head`](HelpContext::head) of `ctx`.
+    pub fn new<Set, Ser>(parser: &Parser<'_, Set, Ser>, ctx: &HelpContext) -> Result<Self, Error>
+    where
+        Set: aopt::set::Set,
+        SetOpt<Set>: Opt,
+    {
+        let name = if ctx.name().is_empty() {
+            parser.name().clone()
+        } else {
+            ctx.name().clone()
+        };
+
+        Ok(Self {
+            version: CLI_SPEC_VERSION,
+            command: CommandSpec::new(parser, ctx, name, ctx.head().clone(), vec![]),
+        })
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn command(&self) -> &CommandSpec {
+        &self.command
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self).map_err(|e| raise_error!("can not dump spec: {}", e))
+    }
+
+    pub fn to_json_pretty(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| raise_error!("can not dump spec: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let spec: Self =
+            serde_json::from_str(json).map_err(|e| raise_error!("can not load spec: {}", e))?;
+
+        if spec.version != CLI_SPEC_VERSION {
+            return Err(raise_error!(
+                "unsupported spec version `{}`, expect `{}`",
+                spec.version,
+                CLI_SPEC_VERSION
+            ));
+        }
+        Ok(spec)
+    }
+}
+
+/// The description of a command, the sub commands are described recursively.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CommandSpec {
+    name: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    about: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    args: Vec<ArgSpec>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    subcommands: Vec<CommandSpec>,
+}
+
+impl CommandSpec {
+    fn new<Set, Ser>(
+        parser: &Parser<'_, Set, Ser>,
+        ctx: &HelpContext,
+        name: String,
+        about: String,
+        aliases: Vec<String>,
+    ) -> Self
+    where
+        Set: aopt::set::Set,
+        SetOpt<Set>: Opt,
+    {
+        let mut args = vec![];
+        let mut subcommands = vec![];
+
+        for opt in parser.optset().iter() {
+            if opt.mat_style(Style::Cmd) {
+                let about = split_default(&help_of(opt, ctx)).0;
+                let aliases = opt.alias().cloned().unwrap_or_default();
+
+                // the command may not have a sub parser, such as `list=c`
+                subcommands.push(match parser.find_parser(opt.name()) {
+                    Ok(sub) => Self::new(sub, ctx, opt.name().to_string(), about, aliases),
+                    Err(_) => Self {
+                        name: opt.name().to_string(),
+                        about,
+                        aliases,
+                        ..Default::default()
+                    },
+                });
+            } else if let Some(arg) = ArgSpec::new(opt, ctx) {
+                args.push(arg);
+            }
+        }
+        Self {
+            name,
+            about,
+            aliases,
+            args,
+            subcommands,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn about(&self) -> &str {
+        &self.about
+    }
+
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// The options and positional arguments in the order of definition.
+    pub fn args(&self) -> &[ArgSpec] {
+        &self.args
+    }
+
+    pub fn subcommands(&self) -> &[CommandSpec] {
+        &self.subcommands
+    }
+
+    /// Find the argument by its name, such as `--level` or `files`.
+    pub fn find_arg(&self, id: &str) -> Option<&ArgSpec> {
+        self.args.iter().find(|v| v.id == id)
+    }
+
+    /// Find the sub command by its name or aliases.
+    pub fn find_sub(&self, name: &str) -> Option<&CommandSpec> {
+        self.subcommands
+            .iter()
+            .find(|v| v.name == name || v.aliases.iter().any(|v| v == name))
+    }
+}
+
+/// The description of an option or a positional argument.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ArgSpec {
+    id: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    long: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    short: Option<char>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    hint: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    help: String,
+
+    action: Action,
+
+    #[serde(default)]
+    required: bool,
+
+    #[serde(default)]
+    takes_value: bool,
+
+    #[serde(default)]
+    multiple: bool,
+
+    #[serde(default)]
+    global: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    possible_values: Vec<String>,
+}
+
+impl ArgSpec {
+    /// Describe the option, return None if the option is not displayed in help, such as [`Main`](aopt::opt::Main).
+    fn new<O: Opt>(opt: &O, ctx: &HelpContext) -> Option<Self> {
+        let positional = opt.mat_style(Style::Pos);
+        let takes_value = positional || opt.mat_style(Style::Argument);
+
+        if !takes_value
+            && !opt.mat_style(Style::Boolean)
+            && !opt.mat_style(Style::Combined)
+            && !opt.mat_style(Style::Flag)
+        {
+            return None;
+        }
+
+        let (help, default) = split_default(&help_of(opt, ctx));
+        let mut spec = Self {
+            id: opt.name().to_string(),
+            index: opt.index().map(|v| v.to_help()),
+            hint: opt.hint().to_string(),
+            help,
+            action: *opt.action(),
+            required: opt.force(),
+            takes_value,
+            multiple: opt.action().is_app() && takes_value,
+            global: opt.global(),
+            default,
+            possible_values: opt.choices().cloned().unwrap_or_default(),
+            ..Default::default()
+        };
+
+        if !positional {
+            let names = std::iter::once(opt.name())
+                .chain(opt.alias().into_iter().flatten().map(String::as_str));
+
+            for name in names {
+                let short = name
+                    .strip_prefix('-')
+                    .filter(|v| !v.starts_with('-'))
+                    .and_then(|v| {
+                        let mut chars = v.chars();
+
+                        chars.next().filter(|_| chars.next().is_none())
+                    });
+
+                if let (Some(long), None) = (name.strip_prefix("--"), &spec.long) {
+                    spec.long = Some(long.to_string());
+                } else if let (Some(short), None) = (short, &spec.short) {
+                    spec.short = Some(short);
+                } else {
+                    spec.aliases.push(name.to_string());
+                }
+            }
+        }
+        Some(spec)
+    }
+
+    /// The name of option, such as `--level`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The first long name without prefix, such as `level` of `--level`.
+    pub fn long(&self) -> Option<&str> {
+        self.long.as_deref()
+    }
+
+    /// The first short name without prefix, such as `l` of `-l`.
+    pub fn short(&self) -> Option<char> {
+        self.short
+    }
+
+    /// The other names with prefix.
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// The index of positional argument, such as `1` or `1..`.
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_deref()
+    }
+
+    pub fn hint(&self) -> &str {
+        &self.hint
+    }
+
+    /// The help message without default value.
+    pub fn help(&self) -> &str {
+        &self.help
+    }
+
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    pub fn takes_value(&self) -> bool {
+        self.takes_value
+    }
+
+    /// If the argument accept multiple values.
+    pub fn multiple(&self) -> bool {
+        self.multiple
+    }
+
+    pub fn global(&self) -> bool {
+        self.global
+    }
+
+    /// The default value displayed in help, such as `42` of `Set the count [42]`.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    pub fn possible_values(&self) -> &[String] {
+        &self.possible_values
+    }
+
+    pub fn is_positional(&self) -> bool {
+        self.index.is_some()
+    }
+}
@@ -4,12 +4,18 @@ use std::ops::RangeBounds;
 use aopt::prelude::ErasedTy;
 use aopt::value::ValValidator;
 use aopt::value::ValidatorHandler;
+use aopt::value::ValueEnum;
 
 pub trait Validate<T>
 where
     T: ErasedTy,
 {
     fn check(&self, value: &T) -> bool;
+
+    /// The valid values displayed in help message and completion, set as the choices of option.
+    fn choices(&self) -> Option<Vec<String>> {
+        None
+    }
 }
 
 pub struct Value<K>(K);
@@ -124,6 +130,36 @@ where
     }
 }
 
+/// Check if the value is one of the [`variants`](ValueEnum::variants) of `E`,
+/// ignore case if the [`ignore_case`](ValueEnum::ignore_case) of `E` return true.
+pub struct Variants<E>(PhantomData<E>);
+
+impl<E: ValueEnum> Variants<E> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: ValueEnum> Default for Variants<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Validate<T> for Variants<E>
+where
+    T: ErasedTy + AsRef<str>,
+    E: ValueEnum,
+{
+    fn check(&self, value: &T) -> bool {
+        E::contains(value.as_ref())
+    }
+
+    fn choices(&self) -> Option<Vec<String>> {
+        Some(E::variants().iter().map(|v| v.to_string()).collect())
+    }
+}
+
 pub struct Validator<T>(ValidatorHandler<T>);
 
 impl<T> Validator<T>
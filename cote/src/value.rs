@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
+
 use aopt::opt::ConfigBuild;
 use aopt::opt::ConfigValue;
 use aopt::opt::OptValueExt;
 use aopt::set::SetCfg;
+use aopt::value::Assign;
 
 use crate::prelude::raise_error;
 use crate::prelude::ErasedTy;
@@ -129,8 +132,30 @@ impl_fetch!(std::io::Stdin);
 
 impl_fetch!(aopt::value::Stop);
 
+impl_fetch!(aopt::value::Passthrough);
+
+impl_fetch!(aopt::value::Assign);
+
 impl_fetch!(crate::prelude::Cmd);
 
+#[cfg(feature = "chrono")]
+impl_fetch!(chrono::NaiveDate);
+
+#[cfg(feature = "chrono")]
+impl_fetch!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "time")]
+impl_fetch!(time::OffsetDateTime);
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<S, T, F> Fetch<S> for aopt::value::Formatted<T, F>
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+    Self: ErasedTy,
+{
+}
+
 impl<S, T: Infer + ErasedTy> Fetch<S> for crate::prelude::AnyOpt<T>
 where
     S: SetValueFindExt,
@@ -212,3 +237,14 @@ where
             .map(|v| v.into_iter().map(<T as Infer>::infer_map).collect())
     }
 }
+
+impl<S> Fetch<S> for BTreeMap<String, String>
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+{
+    fn fetch_uid(uid: Uid, set: &mut S) -> Result<Self, aopt::Error> {
+        fetch_vec_uid_impl::<Assign, S>(uid, set)
+            .map(|v| v.into_iter().map(Assign::into_pair).collect())
+    }
+}
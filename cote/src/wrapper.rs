@@ -0,0 +1,276 @@
+use std::fmt::Write;
+
+use aopt::opt::Opt;
+use aopt::opt::Style;
+use aopt::shell::Shell;
+
+use crate::prelude::Parser;
+
+/// A sub command argument need to be rewritten by the wrapper.
+#[derive(Debug, Clone)]
+struct Rewrite {
+    /// The sub command names before the argument, joined by space.
+    path: String,
+
+    /// The argument, name or alias of sub command.
+    arg: String,
+
+    /// The name of sub command.
+    name: String,
+}
+
+impl Rewrite {
+    fn next_path(&self) -> String {
+        if self.path.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} {}", self.path, self.name)
+        }
+    }
+}
+
+impl<Set, Ser> Parser<'_, Set, Ser>
+where
+    Set: aopt::set::Set,
+{
+    /// Generate a wrapper function of the application for `shell`.
+    ///
+    /// The wrapper maps the aliases of sub commands to their names, then call the application,
+    /// the arguments after `--` are not changed.
+    /// For [`Shell::PowerShell`], the boolean options of current parser are declared as switch
+    /// parameters in the `param` block, such as `-Verbose` for `--verbose`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cote::prelude::*;
+    /// # use cote::aopt::shell::Shell;
+    /// #
+    /// #[derive(Debug, Cote)]
+    /// #[cote(name = "app")]
+    /// pub struct Cli {
+    ///     verbose: bool,
+    ///
+    ///     #[sub(alias = "b")]
+    ///     build: Option<Build>,
+    /// }
+    ///
+    /// #[derive(Debug, Cote)]
+    /// pub struct Build {
+    ///     #[sub(alias = "r")]
+    ///     run: Option<Run>,
+    /// }
+    ///
+    /// #[derive(Debug, Cote)]
+    /// pub struct Run {
+    ///     jobs: Option<u64>,
+    /// }
+    ///
+    /// # fn main() -> cote::Result<()> {
+    /// let parser = Cli::into_parser()?;
+    /// let bash = parser.generate_wrapper(Shell::Bash);
+    ///
+    /// assert!(bash.starts_with("# wrapper of `app`"));
+    /// assert!(bash.contains(r#"":b") arg="build"; cmdpath="build" ;;"#));
+    /// assert!(bash.contains(r#""build:r") arg="run"; cmdpath="build run" ;;"#));
+    /// assert!(bash.contains(r#"command app "$@""#));
+    ///
+    /// let pwsh = parser.generate_wrapper(Shell::PowerShell);
+    ///
+    /// assert!(pwsh.contains("[switch] $Verbose"));
+    /// assert!(pwsh.contains("if ($Verbose) { $cmdargs += '--verbose' }"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate_wrapper(&self, shell: Shell) -> String {
+        let mut rewrites = vec![];
+        let mut wrapper = String::default();
+
+        self.collect_rewrites("", &mut rewrites);
+        match shell {
+            Shell::Bash | Shell::Zsh => self.write_sh_wrapper(&rewrites, &mut wrapper),
+            Shell::Fish => self.write_fish_wrapper(&rewrites, &mut wrapper),
+            Shell::PowerShell => self.write_pwsh_wrapper(&rewrites, &mut wrapper),
+        }
+        .expect("write to String never fails");
+        wrapper
+    }
+
+    fn collect_rewrites(&self, path: &str, rewrites: &mut Vec<Rewrite>) {
+        for opt in self.optset().iter().filter(|v| v.mat_style(Style::Cmd)) {
+            let name = opt.name().to_string();
+            let args = std::iter::once(&name).chain(opt.alias().into_iter().flatten());
+
+            for arg in args {
+                rewrites.push(Rewrite {
+                    path: path.to_string(),
+                    arg: arg.clone(),
+                    name: name.clone(),
+                });
+            }
+            if let Ok(parser) = self.find_parser(&name) {
+                let path = Rewrite {
+                    path: path.to_string(),
+                    arg: name.clone(),
+                    name: name.clone(),
+                }
+                .next_path();
+
+                parser.collect_rewrites(&path, rewrites);
+            }
+        }
+    }
+
+    fn write_sh_wrapper(&self, rewrites: &[Rewrite], out: &mut String) -> std::fmt::Result {
+        let name = self.name();
+
+        writeln!(
+            out,
+            "# wrapper of `{name}`, map the sub command aliases to name"
+        )?;
+        writeln!(out, "{name}() {{")?;
+        writeln!(out, "    local cmdpath=\"\" arg")?;
+        writeln!(out, "    local n=$#")?;
+        writeln!(out)?;
+        writeln!(out, "    while [ \"$n\" -gt 0 ]; do")?;
+        writeln!(out, "        arg=\"$1\"")?;
+        writeln!(out, "        shift")?;
+        writeln!(out, "        n=$((n - 1))")?;
+        writeln!(out, "        case \"$cmdpath:$arg\" in")?;
+        writeln!(out, "            \"--:\"*) ;;")?;
+        writeln!(out, "            *\":--\") cmdpath=\"--\" ;;")?;
+        for rewrite in rewrites {
+            let (path, arg) = (&rewrite.path, &rewrite.arg);
+            let next = rewrite.next_path();
+
+            if rewrite.arg == rewrite.name {
+                writeln!(out, "            \"{path}:{arg}\") cmdpath=\"{next}\" ;;")?;
+            } else {
+                writeln!(
+                    out,
+                    "            \"{path}:{arg}\") arg=\"{}\"; cmdpath=\"{next}\" ;;",
+                    rewrite.name
+                )?;
+            }
+        }
+        writeln!(out, "        esac")?;
+        writeln!(out, "        set -- \"$@\" \"$arg\"")?;
+        writeln!(out, "    done")?;
+        writeln!(out, "    command {name} \"$@\"")?;
+        writeln!(out, "}}")
+    }
+
+    fn write_fish_wrapper(&self, rewrites: &[Rewrite], out: &mut String) -> std::fmt::Result {
+        let name = self.name();
+
+        writeln!(
+            out,
+            "# wrapper of `{name}`, map the sub command aliases to name"
+        )?;
+        writeln!(out, "function {name} --wraps {name}")?;
+        writeln!(out, "    set -l cmdpath ''")?;
+        writeln!(out, "    set -l cmdargs")?;
+        writeln!(out, "    for arg in $argv")?;
+        writeln!(out, "        switch \"$cmdpath:$arg\"")?;
+        writeln!(out, "            case '--:*'")?;
+        writeln!(out, "            case '*:--'")?;
+        writeln!(out, "                set cmdpath '--'")?;
+        for rewrite in rewrites {
+            writeln!(out, "            case '{}:{}'", rewrite.path, rewrite.arg)?;
+            if rewrite.arg != rewrite.name {
+                writeln!(out, "                set arg '{}'", rewrite.name)?;
+            }
+            writeln!(out, "                set cmdpath '{}'", rewrite.next_path())?;
+        }
+        writeln!(out, "        end")?;
+        writeln!(out, "        set -a cmdargs $arg")?;
+        writeln!(out, "    end")?;
+        writeln!(out, "    command {name} $cmdargs")?;
+        writeln!(out, "end")
+    }
+
+    fn write_pwsh_wrapper(&self, rewrites: &[Rewrite], out: &mut String) -> std::fmt::Result {
+        let name = self.name();
+        let mut switches: Vec<(String, String)> = vec![];
+
+        for opt in self.optset().iter() {
+            if opt.mat_style(Style::Boolean) {
+                if let Some(param) = pwsh_param(opt.name()) {
+                    if !switches.iter().any(|(v, _)| v.eq_ignore_ascii_case(&param)) {
+                        switches.push((param, opt.name().to_string()));
+                    }
+                }
+            }
+        }
+
+        writeln!(
+            out,
+            "# wrapper of `{name}`, map the sub command aliases to name"
+        )?;
+        writeln!(out, "function {name} {{")?;
+        if switches.is_empty() {
+            writeln!(out, "    param()")?;
+        } else {
+            let params: Vec<_> = switches
+                .iter()
+                .map(|(param, _)| format!("        [switch] ${param}"))
+                .collect();
+
+            writeln!(out, "    param(")?;
+            writeln!(out, "{}", params.join(",\n"))?;
+            writeln!(out, "    )")?;
+        }
+        writeln!(out)?;
+        writeln!(out, "    $cmdargs = @()")?;
+        for (param, opt) in switches.iter() {
+            writeln!(out, "    if (${param}) {{ $cmdargs += '{opt}' }}")?;
+        }
+        writeln!(out, "    $cmdpath = ''")?;
+        writeln!(out, "    foreach ($arg in $args) {{")?;
+        writeln!(out, "        $key = \"${{cmdpath}}:$arg\"")?;
+        writeln!(out, "        if ($cmdpath -ceq '--') {{")?;
+        writeln!(out, "        }} elseif ($arg -ceq '--') {{")?;
+        writeln!(out, "            $cmdpath = '--'")?;
+        for rewrite in rewrites {
+            writeln!(
+                out,
+                "        }} elseif ($key -ceq '{}:{}') {{",
+                rewrite.path, rewrite.arg
+            )?;
+            if rewrite.arg != rewrite.name {
+                writeln!(out, "            $arg = '{}'", rewrite.name)?;
+            }
+            writeln!(out, "            $cmdpath = '{}'", rewrite.next_path())?;
+        }
+        writeln!(out, "        }}")?;
+        writeln!(out, "        $cmdargs += $arg")?;
+        writeln!(out, "    }}")?;
+        writeln!(
+            out,
+            "    & (Get-Command -Name '{name}' -CommandType Application | Select-Object -First 1) @cmdargs"
+        )?;
+        writeln!(out, "}}")
+    }
+}
+
+/// Convert the long option name such as `--dry-run` to parameter name `DryRun`.
+fn pwsh_param(name: &str) -> Option<String> {
+    let name = name.strip_prefix("--")?;
+    let mut param = String::default();
+
+    for part in name.split(['-', '_']).filter(|v| !v.is_empty()) {
+        if !part.chars().all(|v| v.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let mut chars = part.chars();
+
+        if let Some(first) = chars.next() {
+            param.push(first.to_ascii_uppercase());
+            param.extend(chars);
+        }
+    }
+    param
+        .starts_with(|v: char| v.is_ascii_alphabetic())
+        .then_some(param)
+}
@@ -0,0 +1,85 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, combine)]
+pub struct Cli {
+    #[arg(alias = "-v", global)]
+    verbose: bool,
+
+    #[arg(alias = "-q", global)]
+    quiet: bool,
+
+    debug: bool,
+
+    verbose_level: Option<i64>,
+
+    #[sub()]
+    query: Option<Query>,
+}
+
+#[derive(Debug, Cote, PartialEq, Eq)]
+pub struct Query {
+    debug: bool,
+
+    verbose_level: Option<i64>,
+
+    #[pos()]
+    format: String,
+}
+
+#[test]
+fn global() {
+    assert!(global_impl().is_ok());
+}
+
+fn global_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "query", "--verbose", "--debug", "json"]))?;
+
+    assert!(cli.verbose);
+    assert!(!cli.debug);
+    assert_eq!(
+        cli.query,
+        Some(Query {
+            debug: true,
+            verbose_level: None,
+            format: "json".to_owned()
+        })
+    );
+
+    let cli = Cli::parse(Args::from(["app", "--verbose", "--debug", "query", "json"]))?;
+
+    assert!(cli.verbose);
+    assert!(cli.debug);
+    assert_eq!(
+        cli.query,
+        Some(Query {
+            debug: false,
+            verbose_level: None,
+            format: "json".to_owned()
+        })
+    );
+
+    // only the exact name of global option matched after sub command
+    let cli = Cli::parse(Args::from([
+        "app",
+        "query",
+        "-vq",
+        "--verbose-level=2",
+        "json",
+    ]))?;
+
+    assert!(cli.verbose);
+    assert!(cli.quiet);
+    assert_eq!(cli.verbose_level, None);
+    assert_eq!(
+        cli.query,
+        Some(Query {
+            debug: false,
+            verbose_level: Some(2),
+            format: "json".to_owned()
+        })
+    );
+    Ok(())
+}
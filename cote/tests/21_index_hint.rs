@@ -0,0 +1,50 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, index_hint = IndexHint::Human)]
+pub struct Cli {
+    #[pos()]
+    name: String,
+
+    #[pos(index = 2..)]
+    files: Vec<String>,
+}
+
+fn template(index: &Index) -> String {
+    format!("at position {}", index.to_help())
+}
+
+#[test]
+fn index_hint() {
+    assert!(index_hint_impl().is_ok());
+}
+
+fn index_hint_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ctx = Cli::new_help_context();
+    let hint = ctx.index_hint();
+
+    assert_eq!(
+        hint.describe(&Index::forward(1)).as_deref(),
+        Some("first argument")
+    );
+    assert_eq!(
+        hint.describe(&Index::range(Some(2), None)).as_deref(),
+        Some("second and later arguments")
+    );
+
+    let ctx = ctx.with_index_hint(IndexHint::Custom(template));
+
+    assert_eq!(
+        ctx.index_hint().describe(&Index::forward(1)).as_deref(),
+        Some("at position 1")
+    );
+    assert_eq!(IndexHint::Raw.describe(&Index::forward(1)), None);
+
+    let cli = Cli::parse(Args::from(["app", "foo", "a.txt", "b.txt"]))?;
+
+    assert_eq!(cli.name, "foo");
+    assert_eq!(cli.files, vec!["a.txt".to_owned(), "b.txt".to_owned()]);
+    Ok(())
+}
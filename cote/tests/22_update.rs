@@ -0,0 +1,58 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote, PartialEq, Eq)]
+pub struct Cli {
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    #[arg(value = 4usize)]
+    jobs: usize,
+
+    output: Option<String>,
+
+    #[pos()]
+    target: Option<String>,
+}
+
+#[test]
+fn update() {
+    assert!(update_impl().is_ok());
+}
+
+fn update_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // the values loaded from config file
+    let mut cli = Cli {
+        verbose: true,
+        jobs: 8,
+        output: Some("build".to_owned()),
+        target: None,
+    };
+    let CoteRes { mut parser, .. } = Cli::parse_args(Args::from(["app", "--jobs=2", "debug"]))?;
+
+    parser.update_type(&mut cli)?;
+    assert_eq!(
+        cli,
+        Cli {
+            verbose: true,
+            jobs: 2,
+            output: Some("build".to_owned()),
+            target: Some("debug".to_owned()),
+        }
+    );
+
+    let CoteRes { mut parser, .. } = Cli::parse_args(Args::from(["app", "--output", "dist"]))?;
+
+    cli.update_from_set(parser.optset_mut())?;
+    assert_eq!(
+        cli,
+        Cli {
+            verbose: true,
+            jobs: 2,
+            output: Some("dist".to_owned()),
+            target: Some("debug".to_owned()),
+        }
+    );
+    Ok(())
+}
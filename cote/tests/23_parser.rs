@@ -0,0 +1,49 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    debug: bool,
+
+    #[pos()]
+    name: String,
+}
+
+#[test]
+fn parser() {
+    assert!(parser_impl().is_ok());
+}
+
+fn parser_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut parser = Cli::into_parser()?;
+
+    // add an option not in the struct
+    parser.add_opt("--level=i")?.set_value_t(1i64);
+
+    let mut policy = Cli::into_policy();
+    let CoteRes { parser, .. } =
+        Cli::parse_args_with_parser(parser, Args::from(["app", "--level=3", "foo"]), &mut policy)?;
+
+    assert_eq!(parser.find_val::<i64>("--level")?, &3);
+    assert_eq!(parser.find_val::<String>("name")?, "foo");
+
+    let mut parser = Parser::<ASet, ASer>::default();
+
+    // change the validator before adding options
+    parser.validator_mut().add_prefix("+");
+    Cli::apply_to(&mut parser)?;
+    parser.add_opt("+quiet=b")?;
+    assert_eq!(parser.name(), env!("CARGO_PKG_NAME"));
+
+    let mut policy = Cli::into_policy();
+    let CoteRes { mut parser, .. } =
+        Cli::parse_args_with_parser(parser, Args::from(["app", "+quiet", "bar"]), &mut policy)?;
+
+    assert_eq!(parser.find_val::<bool>("+quiet")?, &true);
+    let cli = parser.extract_type::<Cli>()?;
+
+    assert!(!cli.debug);
+    assert_eq!(cli.name, "bar");
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use cote::prelude::*;
+use std::ffi::OsString;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-u")]
+    user: String,
+
+    #[arg(alias = "-p", sensitive)]
+    password: String,
+}
+
+#[test]
+fn sensitive() {
+    assert!(sensitive_impl().is_ok());
+}
+
+fn sensitive_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut policy = Cli::into_policy();
+    let CoteRes {
+        ret, mut parser, ..
+    } = Cli::parse_args_with(
+        Args::from(["app", "-u", "alice", "-p", "hunter2"]),
+        &mut policy,
+    )?;
+
+    assert!(ret.status());
+    let cli = Cli::try_extract(parser.optset_mut())?;
+
+    assert_eq!(cli.password, "hunter2");
+
+    let dump = Redactor::default().redact(parser.optset());
+
+    assert_eq!(
+        dump,
+        vec![
+            ("--user".to_owned(), vec![OsString::from("alice")]),
+            ("--password".to_owned(), vec![OsString::from("******")]),
+        ]
+    );
+
+    let hash = Redactor::new(|name, val| OsString::from(format!("{}:{}", name, val.len())));
+
+    assert_eq!(
+        hash.redact(parser.optset())[1].1,
+        vec![OsString::from("--password:7")]
+    );
+    Ok(())
+}
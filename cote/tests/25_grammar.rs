@@ -0,0 +1,57 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    #[sub(alias = "q")]
+    query: Option<Query>,
+
+    #[sub()]
+    list: Option<List>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Query {
+    #[arg(alias = "-f")]
+    format: Option<String>,
+
+    #[pos()]
+    name: String,
+
+    #[pos(index = 2..)]
+    args: Vec<String>,
+}
+
+#[derive(Debug, Cote)]
+pub struct List {
+    all: bool,
+}
+
+#[test]
+fn grammar() {
+    assert!(grammar_impl().is_ok());
+}
+
+fn grammar_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(
+        parser.grammar(),
+        r#"cote = { cote_option }, cote_command ;
+cote_option = ( "--verbose" | "-v" ) ;
+cote_command = ( "query" | "q" ), query
+             | "list", list ;
+query = { query_option }, { query_arg } ;
+query_option = ( "--format" | "-f" ), ? value ? ;
+query_arg = ? name ? (* first argument, required *)
+          | ? args ? (* second and later arguments, required *) ;
+list = { list_option } ;
+list_option = "--all" ;
+"#
+    );
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-q", bind = Self::set_quiet)]
+    quiet: bool,
+
+    #[arg(value = 1usize, bind = Self::check_level)]
+    level: usize,
+
+    #[arg(bind = Self::normalize_format)]
+    format: Option<String>,
+}
+
+impl Cli {
+    // quiet will override the level
+    fn set_quiet(&mut self) -> cote::Result<()> {
+        self.level = 0;
+        Ok(())
+    }
+
+    fn check_level(&mut self) -> cote::Result<()> {
+        if self.level > 3 {
+            return Err(cote::aopt::raise_error!("level must not greater than 3"));
+        }
+        Ok(())
+    }
+
+    fn normalize_format(&mut self) -> cote::Result<()> {
+        if let Some(format) = self.format.as_mut() {
+            *format = format.to_lowercase();
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn bind() {
+    assert!(bind_impl().is_ok());
+}
+
+fn bind_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "--level=2", "-q"]))?;
+
+    assert!(cli.quiet);
+    assert_eq!(cli.level, 0);
+
+    let mut cli = Cli::parse(Args::from(["app", "--format", "JSON"]))?;
+
+    assert_eq!(cli.level, 1);
+    assert_eq!(cli.format.as_deref(), Some("json"));
+
+    assert!(Cli::parse(Args::from(["app", "--level=4"])).is_err());
+
+    let CoteRes { mut parser, .. } = Cli::parse_args(Args::from(["app", "--format=YAML"]))?;
+
+    parser.update_type(&mut cli)?;
+    assert_eq!(cli.level, 1);
+    assert_eq!(cli.format.as_deref(), Some("yaml"));
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use cote::prelude::*;
+use std::ffi::OsString;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    #[pos()]
+    program: Option<String>,
+
+    #[arg(passthrough)]
+    rest: Vec<OsString>,
+}
+
+#[test]
+fn passthrough() {
+    assert!(passthrough_impl().is_ok());
+}
+
+fn passthrough_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-v", "cargo", "--", "build", "-v"]))?;
+
+    assert!(cli.verbose);
+    assert_eq!(cli.program.as_deref(), Some("cargo"));
+    assert_eq!(
+        cli.rest,
+        vec![OsString::from("build"), OsString::from("-v")]
+    );
+
+    let cli = Cli::parse(Args::from(["app", "cargo"]))?;
+
+    assert!(!cli.verbose);
+    assert!(cli.rest.is_empty());
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(policy = pre)]
+pub struct Cli {
+    #[arg(alias = "-d")]
+    debug: bool,
+
+    #[sub()]
+    run: Option<Run>,
+
+    #[sub()]
+    build: Option<Build>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Run {
+    release: bool,
+
+    /// The `@1` of sub parser is the first argument after `run`
+    #[pos(on = orig_index)]
+    target: usize,
+}
+
+#[derive(Debug, Cote)]
+#[cote(policy = pre)]
+pub struct Build {
+    #[sub()]
+    test: Option<Test>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Test {
+    #[pos(on = orig_index)]
+    filter: usize,
+}
+
+fn orig_index<Set, Ser>(_: &mut Set, ser: &mut Ser, ctx: &Ctx) -> cote::Result<Option<usize>>
+where
+    Ser: ServicesValExt,
+{
+    // map to the index of top level parser
+    Ok(Some(ser.sve_val::<RunningCtx>()?.orig_index(ctx.idx()?)))
+}
+
+#[test]
+fn sub_offset() {
+    assert!(sub_offset_impl().is_ok());
+}
+
+fn sub_offset_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-d", "run", "--release", "x86"]))?;
+    let run = cli.run.unwrap();
+
+    assert!(cli.debug);
+    assert!(run.release);
+    assert_eq!(run.target, 2);
+
+    let cli = Cli::parse(Args::from(["app", "build", "test", "parse"]))?;
+    let build = cli.build.unwrap();
+
+    assert_eq!(build.test.unwrap().filter, 3);
+
+    // the index in failure of sub parser is the index of top level parser
+    let error = Cli::parse(Args::from(["app", "-d", "run", "--release"])).unwrap_err();
+    let error = error.find_kind(ErrorKind::PosRequired).unwrap();
+
+    assert_eq!(error.field(), Some("target@2"));
+    assert_eq!(
+        error.desp(),
+        Some("positional `target@2` is force required")
+    );
+
+    let error = Cli::parse(Args::from(["app", "build", "test"])).unwrap_err();
+
+    assert_eq!(
+        error.find_kind(ErrorKind::PosRequired).unwrap().field(),
+        Some("filter@3")
+    );
+
+    // same for the sub parser registered at runtime
+    let mut parser = Parser::<ASet, ASer>::default().with_name("app");
+    let mut plugin = Parser::<ASet, ASer>::default();
+
+    plugin
+        .add_opt("target=p@1")?
+        .set_value_type::<usize>()
+        .set_force(true);
+    parser.register_sub_parser("run", plugin, |_, _| Ok(Some(())))?;
+
+    let ret = parser.parse_policy(Args::from(["app", "run"]), &mut PrePolicy::default())?;
+    let rctx = parser.rctx()?;
+
+    assert!(!ret.status());
+    assert_eq!(
+        rctx.failed_info()[0]
+            .failure()
+            .and_then(|v| v.find_kind(ErrorKind::PosRequired))
+            .and_then(|v| v.field()),
+        Some("target@2")
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, long_help)]
+pub struct Cli {
+    /// Set the level of compression.
+    ///
+    /// The level should be in range of 0 to 9.
+    #[arg(value = 6usize)]
+    level: usize,
+
+    /// Print more message
+    verbose: bool,
+
+    /// Compress the given files.
+    ///
+    /// Read from standard input if no file given.
+    #[sub()]
+    zip: Option<Zip>,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Zip {
+    #[pos(index = 1..)]
+    files: Vec<String>,
+}
+
+#[test]
+fn long_help() {
+    assert!(long_help_impl().is_ok());
+}
+
+fn long_help_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let ctx = Cli::new_help_context();
+    let find = |name: &str| parser.optset().iter().find(|v| v.name() == name);
+    let level = find("--level").unwrap();
+    let verbose = find("--verbose").unwrap();
+    let zip = find("zip").unwrap();
+
+    assert_eq!(level.help(), "Set the level of compression. [6]");
+    assert_eq!(
+        ctx.long_help(level.uid()),
+        Some("Set the level of compression. The level should be in range of 0 to 9. [6]")
+    );
+    assert_eq!(verbose.help(), "Print more message");
+    assert_eq!(ctx.long_help(verbose.uid()), None);
+    assert_eq!(zip.help(), "Compress the given files.");
+    assert_eq!(
+        ctx.long_help(zip.uid()),
+        Some("Compress the given files. Read from standard input if no file given.")
+    );
+
+    // `--help` display the long help, `-h` display the short help
+    for (arg, long) in [("--help", true), ("-h", false)] {
+        let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", arg]))?;
+        let ctx = parser.rctx()?.help_context().unwrap();
+
+        assert_eq!(ctx.long(), long);
+    }
+
+    let cli = Cli::parse(Args::from(["app", "--level=9", "zip", "a.txt"]))?;
+
+    assert_eq!(cli.level, 9);
+    assert!(!cli.verbose);
+    assert_eq!(cli.zip.unwrap().files, vec!["a.txt".to_owned()]);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, sub_fallback = pos)]
+pub struct Cli {
+    #[pos()]
+    file: Option<String>,
+
+    #[sub()]
+    zip: Option<Zip>,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Zip {
+    #[pos(index = 1..)]
+    files: Vec<String>,
+}
+
+#[test]
+fn sub_fallback() {
+    assert!(sub_fallback_impl().is_ok());
+}
+
+fn sub_fallback_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // unknown sub command `somefile.txt` is treated as positional
+    let cli = Cli::parse(Args::from(["app", "somefile.txt"]))?;
+
+    assert_eq!(cli.file.as_deref(), Some("somefile.txt"));
+    assert!(cli.zip.is_none());
+
+    let cli = Cli::parse(Args::from(["app", "zip", "a.txt", "b.txt"]))?;
+    let zip = cli.zip.unwrap();
+
+    assert_eq!(zip.files, vec!["a.txt".to_owned(), "b.txt".to_owned()]);
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert!(cli.file.is_none());
+    assert!(cli.zip.is_none());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, default_sub = "status")]
+pub struct Cli {
+    #[sub()]
+    status: Option<Status>,
+
+    #[sub()]
+    commit: Option<Commit>,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Status {
+    /// Show short format
+    short: bool,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Commit {
+    #[arg(alias = "-m")]
+    message: String,
+}
+
+#[test]
+fn default_sub() {
+    assert!(default_sub_impl().is_ok());
+}
+
+fn default_sub_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // no sub command present, `status` is inserted as first argument
+    let cli = Cli::parse(Args::from(["git"]))?;
+
+    assert!(cli.status.is_some());
+    assert!(cli.commit.is_none());
+
+    let cli = Cli::parse(Args::from(["git", "--short"]))?;
+
+    assert!(cli.status.unwrap().short);
+
+    let cli = Cli::parse(Args::from(["git", "commit", "-m", "init"]))?;
+
+    assert!(cli.status.is_none());
+    assert_eq!(cli.commit.unwrap().message, "init");
+    Ok(())
+}
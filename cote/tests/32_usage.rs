@@ -0,0 +1,33 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, name = "cat", usage = "Usage: {name} {options} <FILE> -- [ARGS]")]
+pub struct Cli {
+    /// Number all output lines
+    #[arg(alias = "-n")]
+    number: bool,
+
+    /// The file need display
+    #[pos()]
+    file: String,
+}
+
+#[test]
+fn usage() {
+    assert!(usage_impl().is_ok());
+}
+
+fn usage_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let ctx = Cli::new_help_context();
+    let help = render_set_help_ctx(parser.optset(), &ctx)?;
+
+    assert_eq!(
+        ctx.usage(),
+        Some("Usage: {name} {options} <FILE> -- [ARGS]")
+    );
+    assert!(help.starts_with("Usage: cat [-n, --number] [-h, --help] <FILE> -- [ARGS]"));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    #[arg(alias = "-n")]
+    name: String,
+
+    #[sub()]
+    query: Option<Query>,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Query {
+    #[arg(alias = "-d")]
+    depth: usize,
+}
+
+#[test]
+fn error_kind() {
+    assert!(error_kind_impl().is_ok());
+}
+
+fn error_kind_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let error = Cli::parse(Args::from(["app"])).unwrap_err();
+
+    assert_eq!(error.kind(), &ErrorKind::Failure);
+    assert_eq!(
+        error
+            .find_kind(ErrorKind::OptRequired)
+            .and_then(|v| v.field()),
+        Some("-n, --name")
+    );
+
+    let error = Cli::parse(Args::from(["app", "-n", "foo", "query", "-d", "bar"])).unwrap_err();
+
+    assert_eq!(error.kind(), &ErrorKind::SubCommandFailed);
+    assert_eq!(error.field(), Some("query"));
+    assert!(error.find_kind(ErrorKind::RawValParse).is_some());
+    assert!(error.chain().all(|v| v.kind() != &ErrorKind::MissingValue));
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use cote::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CoteOpt, CoteVal)]
+#[coteval(fromstr)]
+pub struct Rgb(u8, u8, u8);
+
+impl FromStr for Rgb {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix('#')
+            .filter(|v| v.len() == 6)
+            .ok_or_else(|| format!("invalid color `{s}`"))?;
+        let channel = |idx: usize| u8::from_str_radix(&hex[idx..idx + 2], 16);
+
+        match (channel(0), channel(2), channel(4)) {
+            (Ok(r), Ok(g), Ok(b)) => Ok(Rgb(r, g, b)),
+            _ => Err(format!("invalid color `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the foreground color
+    #[arg(alias = "-f")]
+    fg: Rgb,
+
+    /// Set the background color
+    #[arg(alias = "-b")]
+    bg: Option<Rgb>,
+}
+
+#[test]
+fn fromstr() {
+    assert!(fromstr_impl().is_ok());
+}
+
+fn fromstr_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-f", "#ff8000"]))?;
+
+    assert_eq!(cli.fg, Rgb(255, 128, 0));
+    assert_eq!(cli.bg, None);
+
+    let cli = Cli::parse(Args::from(["app", "-f=#000000", "-b=#ffffff"]))?;
+
+    assert_eq!(cli.fg, Rgb(0, 0, 0));
+    assert_eq!(cli.bg, Some(Rgb(255, 255, 255)));
+    assert!(Cli::parse(Args::from(["app", "-f", "red"])).is_err());
+    Ok(())
+}
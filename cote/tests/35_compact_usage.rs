@@ -0,0 +1,36 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, name = "ls", compact_usage)]
+pub struct Cli {
+    /// Do not ignore entries starting with .
+    #[arg(alias = "-a", alias = "--everything")]
+    all: bool,
+
+    /// Use a long listing format
+    #[arg(name = "-l")]
+    long: bool,
+
+    /// Print the size of each file
+    #[arg(alias = "-s", hint = "-s, --size <SIZE>")]
+    size: bool,
+}
+
+#[test]
+fn compact_usage() {
+    assert!(compact_usage_impl().is_ok());
+}
+
+fn compact_usage_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let ctx = Cli::new_help_context();
+    let help = render_set_help_ctx(parser.optset(), &ctx)?;
+
+    // only the shortest and longest name displayed in usage
+    assert!(help.starts_with("Usage: ls [-a|--everything] [-l] [-s, --size <SIZE>] [-h|--help]"));
+    // all the alias are displayed in options table
+    assert!(help.contains("-a, --all, --everything"));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the configuration file
+    #[arg(alias = "-c", value_name = "FILE")]
+    config: String,
+
+    /// Set the output directory
+    #[arg(alias = "-o", value_name = "DIR")]
+    output: Option<String>,
+
+    /// Set the jobs count
+    #[arg(alias = "-j", value_name = "N", hint = "-j <JOBS>")]
+    jobs: Option<usize>,
+}
+
+#[test]
+fn value_name() {
+    assert!(value_name_impl().is_ok());
+}
+
+fn value_name_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let find = |name: &str| parser.optset().iter().find(|v| v.name() == name);
+
+    assert_eq!(find("--config").unwrap().hint(), "-c, --config <FILE>");
+    assert_eq!(find("--output").unwrap().hint(), "-o, --output [DIR]");
+    // the hint set by user is not changed
+    assert_eq!(find("--jobs").unwrap().hint(), "-j <JOBS>");
+
+    let cli = Cli::parse(Args::from(["app", "-c", "cote.toml", "-j=4"]))?;
+
+    assert_eq!(cli.config, "cote.toml");
+    assert_eq!(cli.output, None);
+    assert_eq!(cli.jobs, Some(4));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use cote::prelude::*;
+
+// Select the policy at runtime, `parse_with_policy` is available
+#[derive(Debug, Cote)]
+#[cote(policy = runtime)]
+pub struct Cli {
+    debug: bool,
+
+    #[sub(force = false)]
+    build: Option<Build>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Build {
+    release: bool,
+}
+
+#[test]
+fn runtime_policy() {
+    assert!(runtime_policy_impl().is_ok());
+}
+
+fn runtime_policy_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let select = |args: &[&str]| PolicyKind::select(parser.optset(), &Args::from(args));
+
+    assert_eq!(select(&["app", "--debug"]), PolicyKind::Fwd);
+    assert_eq!(select(&["app", "build", "--release"]), PolicyKind::Pre);
+
+    // `pre` is selected
+    let cli = Cli::parse(Args::from(["app", "--debug", "build", "--release"]))?;
+
+    assert!(cli.debug);
+    assert!(cli.build.unwrap().release);
+
+    // `fwd` is selected
+    let cli = Cli::parse(Args::from(["app", "--debug"]))?;
+
+    assert!(cli.debug);
+    assert!(cli.build.is_none());
+
+    let cli = Cli::parse_with_policy(Args::from(["app", "--debug"]), PolicyKind::Delay)?;
+
+    assert!(cli.debug);
+    assert!(cli.build.is_none());
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use cote::prelude::*;
+
+// Add profiles, the profile is activated by option `--profile`
+#[derive(Debug, Cote)]
+#[cote(
+    profile("dev", [("--opt", "0")]),
+    profile("release", [("--opt", "3"), ("--lto", "true"), ("--feature", "fast")])
+)]
+pub struct Cli {
+    #[arg(value = 1u64)]
+    opt: u64,
+
+    lto: bool,
+
+    feature: Option<Vec<String>>,
+
+    // apply the profile by `profile_handler`
+    #[arg(on = profile_handler)]
+    profile: Option<String>,
+}
+
+#[test]
+fn profile() {
+    assert!(profile_impl().is_ok());
+}
+
+fn profile_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.opt, 1);
+    assert!(cli.profile.is_none());
+
+    let cli = Cli::parse(Args::from(["app", "--profile", "release"]))?;
+
+    assert_eq!(cli.opt, 3);
+    assert!(cli.lto);
+    assert_eq!(cli.feature, Some(vec!["fast".to_owned()]));
+    assert_eq!(cli.profile.as_deref(), Some("release"));
+
+    // the value from command line is kept
+    let cli = Cli::parse(Args::from(["app", "--opt=2", "--profile", "release"]))?;
+
+    assert_eq!(cli.opt, 2);
+    assert!(cli.lto);
+
+    let cli = Cli::parse(Args::from(["app", "--profile", "dev"]))?;
+
+    assert_eq!(cli.opt, 0);
+    assert!(!cli.lto);
+    assert!(Cli::parse(Args::from(["app", "--profile", "debug"])).is_err());
+    Ok(())
+}
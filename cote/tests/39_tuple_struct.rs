@@ -0,0 +1,67 @@
+use cote::prelude::*;
+
+// The fields of tuple struct are positional in default,
+// they are named by position, such as `arg0`
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Copy(
+    String,
+    String,
+    // the option need a name
+    #[arg(name = "--force", alias = "-f")] bool,
+);
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    debug: bool,
+
+    #[sub(force = false)]
+    copy: Option<Copy>,
+
+    #[sub(force = false)]
+    fast: Option<FastCopy>,
+}
+
+// The newtype struct delegates to the inner type
+#[derive(Debug, Cote)]
+pub struct FastCopy(Copy);
+
+#[test]
+fn tuple_struct() {
+    assert!(tuple_struct_impl().is_ok());
+}
+
+fn tuple_struct_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let Copy(from, to, force) = Copy::parse(Args::from(["app", "a.txt", "-f", "b.txt"]))?;
+
+    assert_eq!(from, "a.txt");
+    assert_eq!(to, "b.txt");
+    assert!(force);
+
+    let parser = Copy::into_parser()?;
+
+    assert_eq!(parser.find_opt("arg0")?.index(), Some(&Index::forward(1)));
+    assert_eq!(parser.find_opt("arg1")?.index(), Some(&Index::forward(2)));
+
+    let FastCopy(copy) = FastCopy::parse(Args::from(["app", "a.txt", "b.txt"]))?;
+
+    assert_eq!(copy.0, "a.txt");
+    assert_eq!(copy.1, "b.txt");
+    assert!(!copy.2);
+
+    let cli = Cli::parse(Args::from([
+        "app", "--debug", "fast", "c.txt", "d.txt", "--force",
+    ]))?;
+
+    assert!(cli.debug);
+    assert!(cli.copy.is_none());
+
+    let FastCopy(copy) = cli.fast.unwrap();
+
+    assert_eq!(copy.0, "c.txt");
+    assert_eq!(copy.1, "d.txt");
+    assert!(copy.2);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    #[arg(force = true)]
+    name: String,
+
+    debug: bool,
+}
+
+#[test]
+fn ok_or_print_help() {
+    assert!(ok_or_print_help_impl().is_ok());
+}
+
+fn ok_or_print_help_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ctx = Cli::new_help_context();
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    // parsing successes, continue the program
+    let ret = parser.parse_policy(Args::from(["app", "--name=foo"]), &mut policy)?;
+
+    assert!(parser.ok_or_print_help(&ret, "--help", &ctx)?);
+    assert_eq!(parser.find_val::<String>("--name")?, "foo");
+
+    // help is requested, the help message is displayed
+    parser.reset()?;
+    let ret = parser.parse_policy(Args::from(["app", "-h"]), &mut policy)?;
+
+    assert!(!parser.ok_or_print_help(&ret, "--help", &ctx)?);
+
+    // parsing failed, the help message is displayed and return the failure
+    parser.reset()?;
+    let ret = parser.parse_policy(Args::from(["app", "--debug"]), &mut policy)?;
+    let err = parser.ok_or_print_help(&ret, "--help", &ctx).unwrap_err();
+
+    assert!(err.to_string().contains("app --debug"));
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, color, help_style = HelpStyle::default().with_header("1;33"))]
+pub struct Cli {
+    /// Set the count of threads
+    #[arg(alias = "-j", value = 4u64)]
+    jobs: u64,
+
+    /// Print debug message
+    debug: bool,
+}
+
+#[test]
+fn help_style() {
+    assert!(help_style_impl().is_ok());
+}
+
+fn help_style_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    // the color is disabled automatically when stdout is not a terminal,
+    // force enable it here
+    let ctx = Cli::new_help_context().with_color(true);
+    let help = render_set_help_ctx(parser.optset(), &ctx)?;
+
+    // section headers are colored
+    assert!(help.contains("\x1b[1;33mOptions:\x1b[0m"));
+    // option names are bold
+    assert!(help.contains("\x1b[1m--debug\x1b[0m"));
+    // default values are dimmed
+    assert!(help.contains("Set the count of threads \x1b[2m[4]\x1b[0m"));
+    // the usage line is not highlighted
+    assert!(help.contains("[--debug]"));
+
+    let ctx = ctx.with_color(false);
+    let help = render_set_help_ctx(parser.optset(), &ctx)?;
+
+    assert!(!help.contains('\x1b'));
+    Ok(())
+}
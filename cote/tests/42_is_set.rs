@@ -0,0 +1,45 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-j", value = 4u64)]
+    jobs: u64,
+
+    debug: bool,
+
+    #[pos()]
+    name: Option<String>,
+}
+
+#[test]
+fn is_set() {
+    assert!(is_set_impl().is_ok());
+}
+
+fn is_set_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    parser.parse_policy(Args::from(["app", "-j", "4"]), &mut policy)?;
+
+    let set = parser.optset();
+
+    // the value is same as default, but it is supplied by user
+    assert!(Cli::is_set_jobs(set)?);
+    assert!(!Cli::is_set_debug(set)?);
+    assert!(!Cli::is_set_name(set)?);
+    assert_eq!(set.value_source(set.find_uid("--jobs")?)?, ValueSource::Cli);
+
+    parser.reset()?;
+    parser.parse_policy(Args::from(["app", "--debug", "foo"]), &mut policy)?;
+
+    let set = parser.optset();
+
+    assert!(!Cli::is_set_jobs(set)?);
+    assert!(Cli::is_set_debug(set)?);
+    assert!(Cli::is_set_name(set)?);
+    assert_eq!(set.find_opt("--jobs")?.source(), ValueSource::Default);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    debug: bool,
+
+    /// Add a package, can be repeated
+    #[sub()]
+    add: Vec<Add>,
+
+    #[sub()]
+    list: Option<List>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Add {
+    force: bool,
+
+    #[pos()]
+    name: String,
+}
+
+#[derive(Debug, Cote)]
+pub struct List {
+    all: bool,
+}
+
+#[test]
+fn repeat_sub() {
+    assert!(repeat_sub_impl().is_ok());
+}
+
+fn repeat_sub_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from([
+        "app", "--debug", "add", "foo", "add", "--force", "bar", "add", "baz",
+    ]))?;
+
+    assert!(cli.debug);
+    assert!(cli.list.is_none());
+    assert_eq!(
+        cli.add.iter().map(|v| v.name.as_str()).collect::<Vec<_>>(),
+        ["foo", "bar", "baz"]
+    );
+    assert_eq!(
+        cli.add.iter().map(|v| v.force).collect::<Vec<_>>(),
+        [false, true, false]
+    );
+
+    let cli = Cli::parse(Args::from(["app", "list", "--all"]))?;
+
+    assert!(cli.add.is_empty());
+    assert!(cli.list.unwrap().all);
+
+    // the name of second `add` is missing
+    assert!(Cli::parse(Args::from(["app", "add", "foo", "add"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-o", force_if_cmd = "build")]
+    output: Option<String>,
+
+    #[sub()]
+    build: Option<Build>,
+
+    #[sub()]
+    clean: Option<Clean>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Build {
+    release: bool,
+}
+
+#[derive(Debug, Cote)]
+pub struct Clean {
+    all: bool,
+}
+
+#[test]
+fn force_if_cmd() {
+    assert!(force_if_cmd_impl().is_ok());
+}
+
+fn force_if_cmd_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-o", "target", "build", "--release"]))?;
+
+    assert_eq!(cli.output.as_deref(), Some("target"));
+    assert!(cli.build.is_some_and(|v| v.release));
+
+    // `--output` is not required by `clean`
+    let cli = Cli::parse(Args::from(["app", "clean", "--all"]))?;
+
+    assert_eq!(cli.output, None);
+    assert!(cli.clean.is_some_and(|v| v.all));
+
+    // `--output` is required if `build` matched
+    assert!(Cli::parse(Args::from(["app", "build", "--release"])).is_err());
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--output")?.force_if_cmd(), Some("build"));
+    Ok(())
+}
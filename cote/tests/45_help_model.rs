@@ -0,0 +1,82 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    /// Print more message
+    verbose: bool,
+
+    /// The input file
+    #[pos()]
+    input: Option<String>,
+
+    #[sub(alias = "c", help = "Clean the build directory")]
+    clean: Option<Clean>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Clean {
+    /// Remove all the files
+    #[arg(alias = "-a", value = false)]
+    all: bool,
+}
+
+#[test]
+fn help_model() {
+    assert!(help_model_impl().is_ok());
+}
+
+fn help_model_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let ctx = HelpContext::default()
+        .with_name("app")
+        .with_head("A simple app")
+        .with_index_hint(IndexHint::Human);
+    let model = parser.help_model(&ctx)?;
+
+    assert_eq!(model.name(), "app");
+    assert_eq!(model.head(), "A simple app");
+    assert!(model.usage().starts_with("Usage: app"));
+    assert_eq!(
+        model
+            .sections()
+            .iter()
+            .map(|v| v.title())
+            .collect::<Vec<_>>(),
+        ["Commands:", "Options:", "Args:"]
+    );
+
+    let options = model.section("option").unwrap();
+    let verbose = options
+        .rows()
+        .iter()
+        .find(|v| v.name() == "--verbose")
+        .unwrap();
+
+    assert_eq!(verbose.help(), "Print more message");
+    assert_eq!(verbose.default(), None);
+    assert_eq!(verbose.uid(), parser.find_uid("--verbose")?);
+
+    let input = &model.section("args").unwrap().rows()[0];
+
+    assert_eq!(input.hint(), "input");
+    assert_eq!(input.help(), "The input file (first argument)");
+    assert!(input.optional());
+
+    let clean = &model.section("command").unwrap().rows()[0];
+
+    assert_eq!(clean.name(), "clean");
+    assert_eq!(clean.alias(), ["c"]);
+
+    let clean = model.find_sub("clean").unwrap();
+    let all = &clean.section("option").unwrap().rows()[0];
+
+    assert_eq!(clean.name(), "app clean");
+    assert!(clean.usage().starts_with("Usage: app clean"));
+    assert_eq!(all.hint(), "-a, --all");
+    assert_eq!(all.default(), Some("false"));
+    assert!(clean.subs().is_empty());
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    /// Print more message
+    verbose: bool,
+
+    /// The server settings
+    #[arg(nested)]
+    server: Server,
+
+    #[arg(nested, name = "db")]
+    database: Database,
+}
+
+#[derive(Debug, Default, Cote)]
+pub struct Server {
+    /// The host of server
+    #[arg(value = "localhost")]
+    host: String,
+
+    /// The port of server
+    #[arg(alias = "-p", value = 80u16)]
+    port: u16,
+
+    #[arg(nested)]
+    tls: Tls,
+}
+
+#[derive(Debug, Default, Cote)]
+pub struct Tls {
+    /// Enable the tls
+    enable: bool,
+
+    /// The certificate file
+    cert: Option<String>,
+}
+
+#[derive(Debug, Default, Cote)]
+pub struct Database {
+    /// The url of database
+    url: Option<String>,
+}
+
+#[test]
+fn key_path() {
+    assert!(key_path_impl().is_ok());
+}
+
+fn key_path_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--server.port=8080",
+        "--server.tls.enable",
+        "--db.url",
+        "postgres://localhost",
+    ]))?;
+
+    assert!(!cli.verbose);
+    assert_eq!(cli.server.host, "localhost");
+    assert_eq!(cli.server.port, 8080);
+    assert!(cli.server.tls.enable);
+    assert_eq!(cli.server.tls.cert, None);
+    assert_eq!(cli.database.url.as_deref(), Some("postgres://localhost"));
+
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--server.p",
+        "443",
+        "--server.tls.cert=cert.pem",
+    ]))?;
+
+    assert_eq!(cli.server.port, 443);
+    assert_eq!(cli.server.tls.cert.as_deref(), Some("cert.pem"));
+    assert_eq!(cli.database.url, None);
+
+    // the options under key path are displayed in their own sections
+    let parser = Cli::into_parser()?;
+    let model = parser.help_model(&Cli::new_help_context())?;
+    let names = |section: &str| -> Vec<String> {
+        model
+            .section(section)
+            .map(|v| v.rows().iter().map(|v| v.name().to_string()).collect())
+            .unwrap_or_default()
+    };
+
+    assert_eq!(names("option"), ["--verbose", "--help"]);
+    assert_eq!(names("option.server"), ["--server.host", "--server.port"]);
+    assert_eq!(
+        names("option.server.tls"),
+        ["--server.tls.enable", "--server.tls.cert"]
+    );
+    assert_eq!(names("option.db"), ["--db.url"]);
+    assert_eq!(
+        model.section("option.server").map(|v| v.title()),
+        Some("Options of server:")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use cote::prelude::*;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the log level
+    #[arg(normalize = [trim, lowercase], valid = valid!(["debug", "info"]))]
+    level: String,
+
+    /// Set the region
+    #[arg(normalize = uppercase)]
+    region: Option<String>,
+
+    /// Set the name, the dashes are replaced with underscores
+    #[arg(normalize = [trim, dash2underscore])]
+    name: Option<String>,
+}
+
+fn dash2underscore(raw: &OsStr) -> cote::Result<OsString> {
+    Ok(raw.to_string_lossy().replace('-', "_").into())
+}
+
+#[test]
+fn normalize() {
+    assert!(normalize_impl().is_ok());
+}
+
+fn normalize_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--level",
+        " INFO ",
+        "--region=us-east",
+        "--name= my-app",
+    ]))?;
+
+    assert_eq!(cli.level, "info");
+    assert_eq!(cli.region.as_deref(), Some("US-EAST"));
+    assert_eq!(cli.name.as_deref(), Some("my_app"));
+    assert!(Cli::parse(Args::from(["app", "--level", "Trace"])).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    /// Print more message
+    verbose: bool,
+
+    #[sub(help = "Clean the build directory")]
+    clean: Option<Clean>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Clean {
+    all: bool,
+}
+
+// the plugin may be discovered from a directory
+fn plugin<'a>() -> cote::Result<Parser<'a, ASet, ASer>> {
+    let mut parser = Parser::<ASet, ASer>::default();
+
+    parser
+        .add_opt("--name=s!")?
+        .set_help("The name of greeting");
+    parser.add_opt("--help=b")?.add_alias("-h");
+    Ok(parser)
+}
+
+fn parser_with_plugin<'a>() -> cote::Result<Parser<'a, ASet, ASer>> {
+    let mut parser = Cli::into_parser()?;
+
+    parser.register_sub_parser("greet", plugin()?, |plugin, _| {
+        Ok(Some(format!(
+            "hello {}",
+            plugin.find_val::<String>("--name")?
+        )))
+    })?;
+    parser
+        .find_opt_mut("greet")?
+        .set_help("Greet someone from plugin");
+    Ok(parser)
+}
+
+#[test]
+fn sub_parser() {
+    assert!(sub_parser_impl().is_ok());
+}
+
+fn sub_parser_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::from(["app", "--verbose", "greet", "--name", "cote"]);
+    let CoteRes {
+        ret, mut parser, ..
+    } = Cli::parse_args_with_parser(parser_with_plugin()?, args, &mut Cli::into_policy())?;
+
+    assert!(ret.status());
+    assert_eq!(parser.find_val::<String>("greet")?, "hello cote");
+
+    let cli = parser.extract_type::<Cli>()?;
+
+    assert!(cli.verbose);
+    assert!(cli.clean.is_none());
+
+    // the sub commands of derive still work
+    let args = Args::from(["app", "clean", "--all"]);
+    let CoteRes {
+        ret, mut parser, ..
+    } = Cli::parse_args_with_parser(parser_with_plugin()?, args, &mut Cli::into_policy())?;
+
+    assert!(ret.status());
+    assert!(parser.extract_type::<Cli>()?.clean.unwrap().all);
+
+    // the `--name` of plugin is force required
+    let args = Args::from(["app", "greet"]);
+    let CoteRes { ret, parser, .. } =
+        Cli::parse_args_with_parser(parser_with_plugin()?, args, &mut Cli::into_policy())?;
+
+    assert!(!ret.status());
+    assert_eq!(
+        parser.rctx()?.failed_info().first().map(FailedInfo::name),
+        Some("greet")
+    );
+
+    // the plugin is listed in help, and its help can be rendered
+    let parser = parser_with_plugin()?;
+    let ctx = HelpContext::default().with_name("app");
+    let help = parser.render_help_ctx(&ctx)?;
+
+    assert!(help.contains("greet"));
+    assert!(help.contains("Greet someone from plugin"));
+
+    let help = parser.render_sub_help(vec!["app", "greet"], &ctx)?;
+
+    assert!(help.contains("--name"));
+    assert!(help.contains("The name of greeting"));
+
+    // the name of sub parser must be unique
+    let mut parser = parser_with_plugin()?;
+
+    assert!(parser
+        .register_sub_parser("greet", plugin()?, |_, _| Ok(Some(())))
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app")]
+pub struct Cli {
+    /// The number of jobs
+    #[arg(default_from_env = "COTE_DEFAULT_FROM_ENV_JOBS")]
+    jobs: Option<i64>,
+
+    /// The output directory
+    #[arg(default_from_env = "COTE_DEFAULT_FROM_ENV_OUT")]
+    out: Option<String>,
+}
+
+#[test]
+fn default_from_env() {
+    assert!(default_from_env_impl().is_ok());
+}
+
+fn default_from_env_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    std::env::set_var("COTE_DEFAULT_FROM_ENV_JOBS", "8");
+
+    // the value is captured when parsing
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.jobs, Some(8));
+    assert_eq!(cli.out, None);
+
+    let cli = Cli::parse(Args::from(["app", "--jobs", "2", "--out", "target"]))?;
+
+    assert_eq!(cli.jobs, Some(2));
+    assert_eq!(cli.out.as_deref(), Some("target"));
+
+    // the help displays the name of environment variable, not its value
+    let parser = Cli::into_parser()?;
+    let model = parser.help_model(&Cli::new_help_context())?;
+    let rows = model.section("option").unwrap().rows();
+
+    assert_eq!(rows[0].help(), "The number of jobs");
+    assert_eq!(rows[0].default(), Some("env: COTE_DEFAULT_FROM_ENV_JOBS"));
+    assert_eq!(rows[1].default(), Some("env: COTE_DEFAULT_FROM_ENV_OUT"));
+
+    // invalid value of environment variable
+    std::env::set_var("COTE_DEFAULT_FROM_ENV_JOBS", "eight");
+    assert!(Cli::parse(Args::from(["app"])).is_err());
+    Ok(())
+}
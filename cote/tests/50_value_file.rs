@@ -0,0 +1,63 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// The files to include, support `@list.txt`
+    #[arg(value_file)]
+    include: Vec<String>,
+
+    /// The number of jobs
+    #[arg(value_file)]
+    jobs: Vec<u64>,
+
+    /// The value file is not enabled
+    exclude: Vec<String>,
+}
+
+#[test]
+fn value_file() {
+    assert!(value_file_impl().is_ok());
+}
+
+fn value_file_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let dir = std::env::temp_dir();
+    let list = dir.join("cote_value_file_list.txt");
+    let jobs = dir.join("cote_value_file_jobs.txt");
+
+    std::fs::write(&list, "src/lib.rs\r\nsrc/main.rs\n\n@tests\n")?;
+    std::fs::write(&jobs, "1\n2\n")?;
+
+    let list_arg = format!("@{}", list.display());
+    let jobs_arg = format!("@{}", jobs.display());
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--include",
+        "build.rs",
+        "--include",
+        &list_arg,
+        "--include",
+        "@@home",
+        "--jobs",
+        &jobs_arg,
+        "--exclude",
+        &list_arg,
+    ]))?;
+
+    assert_eq!(
+        cli.include,
+        ["build.rs", "src/lib.rs", "src/main.rs", "@tests", "@home"]
+    );
+    assert_eq!(cli.jobs, [1, 2]);
+    assert_eq!(cli.exclude, [list_arg.as_str()]);
+
+    // the file can not be read
+    let missing = format!("@{}", dir.join("cote_value_file_missing.txt").display());
+
+    assert!(Cli::parse(Args::from(["app", "--include", &missing])).is_err());
+
+    std::fs::remove_file(&list)?;
+    std::fs::remove_file(&jobs)?;
+    Ok(())
+}
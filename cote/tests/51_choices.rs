@@ -0,0 +1,36 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// Set the log level
+    #[arg(value = "info", choices = ["debug", "info", "warn"])]
+    level: String,
+
+    /// The choices are only used in documentation, use `valid` check the value
+    #[arg(choices = vec!["fast", "slow"], valid = valid!(["fast", "slow"]))]
+    mode: Option<String>,
+}
+
+#[test]
+fn choices() {
+    assert!(choices_impl().is_ok());
+}
+
+fn choices_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let level = parser.find_opt("--level")?;
+
+    assert_eq!(
+        level.choices().map(|v| v.as_slice()),
+        Some(["debug", "info", "warn"].map(String::from).as_slice())
+    );
+
+    let cli = Cli::parse(Args::from(["app", "--level", "trace", "--mode", "fast"]))?;
+
+    assert_eq!(cli.level, "trace");
+    assert_eq!(cli.mode.as_deref(), Some("fast"));
+    assert!(Cli::parse(Args::from(["app", "--mode", "normal"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, version, about)]
+pub struct Cli;
+
+#[derive(Debug, Cote)]
+#[cote(
+    author = "araraloren",
+    version = "1.0.0",
+    about = "Quickly build your tools"
+)]
+pub struct Tool;
+
+#[test]
+fn app_info() {
+    assert!(app_info_impl().is_ok());
+}
+
+fn app_info_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let info = Cli::app_info();
+
+    assert_eq!(info.name(), env!("CARGO_PKG_NAME"));
+    assert_eq!(info.version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.about(), "Quickly build your command line utils");
+
+    // only the version is displayed in the foot
+    let help = Cli::new_help_context();
+
+    assert_eq!(help.head(), "Quickly build your command line utils");
+    assert_eq!(help.foot(), &format!("v{}", env!("CARGO_PKG_VERSION")));
+
+    let info = Tool::app_info();
+
+    assert_eq!(info.author(), "araraloren");
+    assert_eq!(info.version(), "1.0.0");
+    assert_eq!(info.about(), "Quickly build your tools");
+
+    let help = Tool::new_help_context();
+
+    assert_eq!(help.head(), "Quickly build your tools");
+    assert_eq!(help.foot(), "Create by araraloren v1.0.0");
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use cote::prelude::*;
+
+// the localized names of values, loaded at runtime
+static LOCALIZED: RwLock<Option<HashMap<(&str, String), String>>> = RwLock::new(None);
+
+fn localized(ty: &str, name: &str) -> Option<String> {
+    LOCALIZED
+        .read()
+        .ok()?
+        .as_ref()?
+        .get(&(ty, name.to_string()))
+        .cloned()
+}
+
+#[derive(Debug, CoteVal, CoteOpt, PartialEq, Eq)]
+#[coteval(igcase, lookup = localized)]
+pub enum Mode {
+    #[coteval(alias = "f")]
+    Fast,
+
+    Slow,
+}
+
+#[derive(Debug, Cote, PartialEq, Eq)]
+pub struct Cli {
+    #[arg(alias = "-m")]
+    mode: Mode,
+}
+
+#[test]
+fn value_lookup() {
+    assert!(value_lookup_impl().is_ok());
+}
+
+fn value_lookup_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // the static names always work
+    assert_eq!(Cli::parse(Args::from(["app", "-m", "f"]))?.mode, Mode::Fast);
+    assert!(Cli::parse(Args::from(["app", "-m", "schnell"])).is_err());
+
+    let table = [("schnell", "Fast"), ("langsam", "slow"), ("rapide", "f")];
+
+    *LOCALIZED.write().unwrap() = Some(HashMap::from_iter(
+        table.map(|(k, v)| (("Mode", k.to_string()), v.to_string())),
+    ));
+    assert_eq!(
+        Cli::parse(Args::from(["app", "--mode", "schnell"]))?.mode,
+        Mode::Fast
+    );
+    // the name is lowercase when `igcase` enabled
+    assert_eq!(
+        Cli::parse(Args::from(["app", "--mode", "Langsam"]))?.mode,
+        Mode::Slow
+    );
+    assert_eq!(
+        Cli::parse(Args::from(["app", "--mode", "rapide"]))?.mode,
+        Mode::Fast
+    );
+    assert!(Cli::parse(Args::from(["app", "--mode", "lent"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(
+    help,
+    width = 60,
+    topic("formats", "The supported formats of input\n\n  json, toml and yaml"),
+    topic("env", "The environment variables\n\n  APP_LOG  the log level")
+)]
+pub struct Cli {
+    /// Set the input file
+    #[pos()]
+    input: Option<String>,
+}
+
+#[test]
+fn help_topic() {
+    assert!(help_topic_impl().is_ok());
+}
+
+fn help_topic_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ctx = Cli::new_help_context();
+
+    assert_eq!(ctx.topics().len(), 2);
+    assert_eq!(
+        ctx.topic("env"),
+        Some("The environment variables\n\n  APP_LOG  the log level")
+    );
+
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    // the topics are listed at the bottom of main help
+    let help = parser.render_help_ctx(&ctx)?;
+
+    assert!(help.contains("Topics:"));
+    assert!(help.contains("The supported formats of input"));
+    assert!(!help.contains("APP_LOG"));
+
+    // the argument following `--help` is saved as topic
+    parser.parse_policy(Args::from(["app", "--help", "formats"]), &mut policy)?;
+
+    let topic = parser.service().sve_val::<HelpTopic>()?;
+    let help = parser.render_help_ctx(&ctx.with_show_topic(topic.0.clone()))?;
+
+    assert_eq!(
+        help,
+        "The supported formats of input\n\n  json, toml and yaml\n"
+    );
+    Ok(())
+}
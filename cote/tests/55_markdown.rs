@@ -0,0 +1,48 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, head = "A tool for building project")]
+pub struct Cli {
+    /// Print more messages
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    /// Set the name of project
+    name: String,
+
+    /// Build the project
+    #[sub(alias = "b")]
+    build: Option<Build>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Build {
+    /// The number of jobs
+    #[arg(value = 4u64)]
+    jobs: u64,
+}
+
+#[test]
+fn markdown() {
+    assert!(markdown_impl().is_ok());
+}
+
+fn markdown_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let ctx = Cli::new_help_context().with_name("app");
+    let sources = ValueSources::default().with_env("--jobs", "APP_JOBS");
+    let doc = parser.render_markdown(&ctx, &sources)?;
+
+    assert!(doc.starts_with(
+        "<a id=\"app\"></a>\n\n## app\n\nA tool for building project\n\n```text\nUsage: app"
+    ));
+    // the commands link to the pages of sub commands
+    assert!(doc.contains("| [`build`](#app-build) | `b` | Build the project |"));
+    assert!(doc.contains("| `-v, --verbose` | Print more messages |  |  |"));
+    assert!(doc.contains("| `--name` | Set the name of project **Required** |  |  |"));
+    assert!(doc.contains("<a id=\"app-build\"></a>\n\n### app build\n\n"));
+    assert!(doc.contains("| `--jobs` | The number of jobs | `4` | `APP_JOBS` |\n"));
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    // `once` is an alias of `action = cote::Action::SetOnce`
+    #[arg(alias = "-o", once)]
+    output: String,
+
+    #[arg(once)]
+    debug: bool,
+}
+
+#[test]
+fn once() {
+    assert!(once_impl().is_ok());
+}
+
+fn once_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-o", "a.txt", "--debug"]))?;
+
+    assert_eq!(cli.output, "a.txt");
+    assert!(cli.debug);
+
+    let err = Cli::parse(Args::from(["app", "-o", "a.txt", "--output=b.txt"])).unwrap_err();
+
+    assert!(err.chain().any(|e| e
+        .to_string()
+        .contains("option `--output` specified multiple times")));
+
+    let err = Cli::parse(Args::from(["app", "-o", "a.txt", "--debug", "--debug"])).unwrap_err();
+
+    assert!(err.chain().any(|e| e
+        .to_string()
+        .contains("option `--debug` specified multiple times")));
+    Ok(())
+}
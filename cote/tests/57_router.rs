@@ -0,0 +1,59 @@
+use cote::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    Ban { user: String, days: i64 },
+
+    Kick(String),
+}
+
+#[test]
+fn router() {
+    assert!(router_impl().is_ok());
+}
+
+fn router_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut router = Router::<ASet, ASer, Reply>::new("bot");
+    let mut ban = Parser::<ASet, ASer>::default();
+    let mut kick = Parser::<ASet, ASer>::default();
+
+    ban.add_opt("--days;-d=i")?.set_value_t(1i64);
+    ban.add_opt("user=p!@1")?.set_pos_type::<String>();
+    kick.add_opt("user=p!@1")?.set_pos_type::<String>();
+    router
+        .route("ban", ban, |parser, _| {
+            Ok(Reply::Ban {
+                user: parser.take_val("user")?,
+                days: *parser.find_val("--days")?,
+            })
+        })?
+        .route("kick", kick, |parser, _| {
+            Ok(Reply::Kick(parser.take_val("user")?))
+        })?;
+
+    assert_eq!(router.routes(), ["ban", "kick"]);
+    assert_eq!(
+        router.dispatch_line("ban -d 7 \"spam bot\"")?,
+        Reply::Ban {
+            user: "spam bot".to_owned(),
+            days: 7
+        }
+    );
+    assert_eq!(
+        router.dispatch_line("kick alice")?,
+        Reply::Kick("alice".to_owned())
+    );
+    assert_eq!(
+        router.dispatch_line("ban bob")?,
+        Reply::Ban {
+            user: "bob".to_owned(),
+            days: 1
+        }
+    );
+    // the user is required
+    assert!(router.dispatch_line("kick").is_err());
+    assert!(router.dispatch_line("mute alice").is_err());
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// The features enabled, the repeated features are collapsed
+    #[arg(alias = "-F", dedup)]
+    feature: Vec<String>,
+
+    /// The ports listened
+    #[arg(dedup)]
+    port: Option<Vec<u16>>,
+
+    /// The tags are kept as is
+    tag: Vec<String>,
+}
+
+#[test]
+fn dedup() {
+    assert!(dedup_impl().is_ok());
+}
+
+fn dedup_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--feature=x",
+        "-F",
+        "y",
+        "--feature",
+        "x",
+        "--port=80",
+        "--port=80",
+        "--port=443",
+        "--tag=a",
+        "--tag=a",
+    ]))?;
+
+    assert_eq!(cli.feature, ["x", "y"]);
+    assert_eq!(cli.port, Some(vec![80, 443]));
+    assert_eq!(cli.tag, ["a", "a"]);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(
+    name = "app",
+    help,
+    head = "Build the project",
+    foot = "Create by araraloren"
+)]
+pub struct Cli {
+    /// Set the number of jobs
+    jobs: Option<i64>,
+}
+
+#[test]
+fn help_context() {
+    assert!(help_context_impl().is_ok());
+}
+
+fn help_context_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let parser = Cli::into_parser()?;
+    let mut ctx = Cli::help_context();
+
+    assert_eq!(ctx.name(), "app");
+    assert_eq!(ctx.head(), "Build the project");
+    assert_eq!(ctx.foot(), "Create by araraloren");
+
+    // add the build information at runtime
+    ctx.set_foot(format!("{} (commit {})", ctx.foot(), "0a1b2c3"));
+
+    let help = parser.render_help_ctx(&ctx)?;
+
+    assert!(help.contains("--jobs"));
+    assert!(help.contains("Build the project"));
+    assert!(help.contains("Create by araraloren (commit 0a1b2c3)"));
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// The variables passed as `NAME=value`
+    vars: BTreeMap<String, String>,
+
+    /// The target will be built
+    #[pos()]
+    target: String,
+
+    /// The other files
+    #[pos(index = 2..)]
+    files: Option<Vec<String>>,
+}
+
+#[test]
+fn assign() {
+    assert!(assign_impl().is_ok());
+}
+
+fn assign_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from([
+        "app",
+        "CC=gcc",
+        "install",
+        "PREFIX=/usr",
+        "a.c",
+        "b.c",
+    ]))?;
+
+    assert_eq!(cli.target, "install");
+    assert_eq!(cli.files, Some(vec!["a.c".to_owned(), "b.c".to_owned()]));
+    assert_eq!(cli.vars.get("CC").map(String::as_str), Some("gcc"));
+    assert_eq!(cli.vars.get("PREFIX").map(String::as_str), Some("/usr"));
+
+    let cli = Cli::parse(Args::from(["app", "all"]))?;
+
+    assert_eq!(cli.target, "all");
+    assert_eq!(cli.files, None);
+    assert!(cli.vars.is_empty());
+    Ok(())
+}
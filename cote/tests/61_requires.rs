@@ -0,0 +1,46 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-u")]
+    user: Option<String>,
+
+    #[arg(alias = "-p", requires = "--user")]
+    password: Option<String>,
+
+    #[arg(conflicts_with = "--user", conflicts_with = "--password")]
+    anonymous: bool,
+}
+
+#[test]
+fn requires() {
+    assert!(requires_impl().is_ok());
+}
+
+fn requires_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse(Args::from(["app", "-u", "root", "-p", "123"]))?;
+
+    assert_eq!(cli.user.as_deref(), Some("root"));
+    assert_eq!(cli.password.as_deref(), Some("123"));
+
+    let cli = Cli::parse(Args::from(["app", "--anonymous"]))?;
+
+    assert!(cli.anonymous);
+
+    // `--password` requires `--user`
+    let error = Cli::parse(Args::from(["app", "-p", "123"])).unwrap_err();
+
+    assert!(error.find_kind(ErrorKind::OptRequired).is_some());
+
+    // `--anonymous` conflicts with `--user` and `--password`
+    let error = Cli::parse(Args::from(["app", "--anonymous", "-u", "root"])).unwrap_err();
+
+    assert!(error.find_kind(ErrorKind::OptConflict).is_some());
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--password")?.requires(), ["--user"]);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use cote::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CoteOpt, CoteVal)]
+#[infer(choices)]
+#[coteval(igcase)]
+pub enum Level {
+    Debug,
+
+    Info,
+
+    #[coteval(alias = "warning")]
+    Warn,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the log level
+    #[arg(alias = "-l")]
+    level: Option<Level>,
+
+    /// Set the level of file logger
+    #[arg(valid = cote::valid::Variants::<Level>::new())]
+    file_level: Option<String>,
+}
+
+#[test]
+fn value_enum() {
+    assert!(value_enum_impl().is_ok());
+}
+
+fn value_enum_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    assert_eq!(Level::variants(), ["debug", "info", "warn"]);
+
+    let cli = Cli::parse(Args::from(["app", "-l", "warning", "--file-level=info"]))?;
+
+    assert_eq!(cli.level, Some(Level::Warn));
+    assert_eq!(cli.file_level.as_deref(), Some("info"));
+    assert!(Cli::parse(Args::from(["app", "--file-level=trace"])).is_err());
+
+    // the variants are matched case insensitively
+    let cli = Cli::parse(Args::from(["app", "--file-level=INFO"]))?;
+
+    assert_eq!(cli.file_level.as_deref(), Some("INFO"));
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(
+        parser.find_opt("--level")?.choices().map(|v| v.as_slice()),
+        Some(["debug", "info", "warn"].map(String::from).as_slice())
+    );
+    // the choices of `Variants` are set too
+    assert_eq!(
+        parser
+            .find_opt("--file-level")?
+            .choices()
+            .map(|v| v.as_slice()),
+        Some(["debug", "info", "warn"].map(String::from).as_slice())
+    );
+
+    // the help message may be wrapped
+    let help = parser.render_help_ctx(&Cli::help_context())?;
+    let help = help.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    assert_eq!(
+        help.matches("[possible values: debug, info, warn]").count(),
+        2
+    );
+    Ok(())
+}
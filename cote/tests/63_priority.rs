@@ -0,0 +1,43 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    #[arg(alias = "-v", priority = 1)]
+    version: bool,
+
+    #[arg(alias = "-d")]
+    debug: bool,
+
+    #[arg(alias = "-d")]
+    dry_run: bool,
+}
+
+#[test]
+fn priority() {
+    assert!(priority_impl().is_ok());
+}
+
+fn priority_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // `--version` has higher priority than `--verbose`
+    let cli = Cli::parse(Args::from(["app", "-v"]))?;
+
+    assert!(cli.version);
+    assert!(!cli.verbose);
+
+    // same priority, the option inserted first is matched
+    let cli = Cli::parse(Args::from(["app", "-d"]))?;
+
+    assert!(cli.debug);
+    assert!(!cli.dry_run);
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--version")?.priority(), 1);
+    assert_eq!(parser.find_opt("--verbose")?.priority(), 0);
+    Ok(())
+}
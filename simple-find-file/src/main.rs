@@ -131,8 +131,9 @@ fn find_file_in_directory(dir: &str) -> color_eyre::Result<Vec<String>> {
     Ok(ret)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 enum FilterType {
+    #[default]
     All,
     Dir,
     Link,
@@ -141,12 +142,6 @@ enum FilterType {
     Regex(String),
 }
 
-impl Default for FilterType {
-    fn default() -> Self {
-        Self::All
-    }
-}
-
 impl FilterType {
     pub fn copy_value_from(&mut self, value: String) -> &mut Self {
         match self {